@@ -1,10 +1,14 @@
 //! Main [`Asset`] type
 
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use std::mem::size_of;
 
 use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
+#[cfg(feature = "fingerprint")]
+use sha2::{Digest, Sha256};
+use thiserror::Error;
 
 use unreal_asset_base::flags::EObjectFlags;
 use unreal_asset_base::passthrough_archive_reader;
@@ -14,24 +18,37 @@ use unreal_asset_base::{
     containers::{Chain, IndexedMap, NameMap, SharedResource},
     crc,
     custom_version::{CustomVersion, CustomVersionTrait},
-    engine_version::EngineVersion,
+    engine_version::{get_object_versions, guess_engine_version, EngineVersion},
     enums::ECustomVersionSerializationFormat,
-    error::Error,
+    error::{Error, PropertyError},
     flags::EPackageFlags,
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::{ParseAnomaly, ParseOptions},
     reader::{ArchiveReader, ArchiveTrait, ArchiveType, ArchiveWriter, RawReader, RawWriter},
-    types::{fname::FNameContainer, FName, GenerationInfo, PackageIndex},
-    unversioned::Usmap,
+    types::{
+        fname::{FNameContainer, ToSerializedName},
+        property_type_name::PropertyTagType,
+        FName, GenerationInfo, PackageIndex,
+    },
+    unversioned::{Ancestry, Usmap},
     FNameContainer, Guid, Import,
 };
 use unreal_asset_exports::{BaseExport, Export, ExportBaseTrait, ExportNormalTrait, ExportTrait};
 use unreal_asset_properties::world_tile_property::FWorldTileInfo;
+use unreal_asset_properties::Property;
 
 use crate::asset_archive_writer::AssetArchiveWriter;
 use crate::asset_data::{AssetData, AssetTrait, ExportReaderTrait};
 use crate::fengineversion::FEngineVersion;
+#[cfg(feature = "fingerprint")]
+use crate::fingerprint_writer::FingerprintWriter;
+use crate::package_file_summary::PackageFileSummary;
+use crate::thumbnail::AssetThumbnail;
 use crate::UE4_ASSET_MAGIC;
 
+#[cfg(feature = "json")]
+mod json;
+
 /// Parent Class Info
 #[derive(FNameContainer, Debug, Clone, Eq, PartialEq)]
 pub struct ParentClassInfo {
@@ -82,6 +99,10 @@ pub struct UAssetExportMapEntry {
     pub public_export_hash: u64,
     /// First dependency serialized offset
     pub first_export_dependency_offset: i32,
+    /// Offset relative to the serial offset where script serialization of this export starts
+    pub script_serialization_start_offset: i64,
+    /// Offset relative to the serial offset where script serialization of this export ends
+    pub script_serialization_end_offset: i64,
 
     /// Dependencies that should be serialized before this export is serialized
     pub serialization_before_serialization_dependencies: Vec<PackageIndex>,
@@ -148,15 +169,28 @@ impl UAssetExportMapEntry {
         if archive.get_object_version()
             >= ObjectVersion::VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS
         {
+            // the dependencies are read after the export map, only their counts are stored here
+            let dependencies = |archive: &mut R| -> Result<Vec<PackageIndex>, Error> {
+                let count = archive.read_i32::<LE>()?;
+                let remaining = archive.data_length()?.saturating_sub(archive.position());
+                if count < 0 || count as u64 > remaining / size_of::<i32>() as u64 {
+                    return Err(Error::invalid_file(format!(
+                        "Invalid export dependency count {count}, only {remaining} bytes remain"
+                    )));
+                }
+                Ok(Vec::with_capacity(count as usize))
+            };
+
             entry.first_export_dependency_offset = archive.read_i32::<LE>()?;
-            entry.serialization_before_serialization_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
-            entry.create_before_serialization_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
-            entry.serialization_before_create_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
-            entry.create_before_create_dependencies =
-                Vec::with_capacity(archive.read_i32::<LE>()? as usize);
+            entry.serialization_before_serialization_dependencies = dependencies(archive)?;
+            entry.create_before_serialization_dependencies = dependencies(archive)?;
+            entry.serialization_before_create_dependencies = dependencies(archive)?;
+            entry.create_before_create_dependencies = dependencies(archive)?;
+        }
+
+        if archive.get_object_version_ue5() >= ObjectVersionUE5::SCRIPT_SERIALIZATION_OFFSET {
+            entry.script_serialization_start_offset = archive.read_i64::<LE>()?;
+            entry.script_serialization_end_offset = archive.read_i64::<LE>()?;
         }
 
         Ok(entry)
@@ -247,6 +281,11 @@ impl UAssetExportMapEntry {
             archive.write_i32::<LE>(self.serialization_before_create_dependencies.len() as i32)?;
             archive.write_i32::<LE>(self.create_before_create_dependencies.len() as i32)?;
         }
+
+        if archive.get_object_version_ue5() >= ObjectVersionUE5::SCRIPT_SERIALIZATION_OFFSET {
+            archive.write_i64::<LE>(self.script_serialization_start_offset)?;
+            archive.write_i64::<LE>(self.script_serialization_end_offset)?;
+        }
         Ok(())
     }
 
@@ -272,6 +311,8 @@ impl UAssetExportMapEntry {
             generate_public_hash: self.generate_public_hash,
             public_export_hash: self.public_export_hash,
             first_export_dependency_offset: self.first_export_dependency_offset,
+            script_serialization_start_offset: self.script_serialization_start_offset,
+            script_serialization_end_offset: self.script_serialization_end_offset,
             serialization_before_serialization_dependencies: self
                 .serialization_before_serialization_dependencies,
             create_before_serialization_dependencies: self.create_before_serialization_dependencies,
@@ -302,6 +343,8 @@ impl UAssetExportMapEntry {
             generate_public_hash: b.generate_public_hash,
             public_export_hash: b.public_export_hash,
             first_export_dependency_offset: b.first_export_dependency_offset,
+            script_serialization_start_offset: b.script_serialization_start_offset,
+            script_serialization_end_offset: b.script_serialization_end_offset,
             serialization_before_serialization_dependencies: b
                 .serialization_before_serialization_dependencies
                 .clone(),
@@ -328,6 +371,8 @@ struct AssetHeader {
     depends_offset: i32,
     /// Soft package references offset
     soft_package_reference_offset: i32,
+    /// Thumbnail table offset
+    thumbnail_table_offset: i32,
     /// Asset registry data offset
     asset_registry_data_offset: i32,
     /// World tile info offset
@@ -446,9 +491,16 @@ pub struct Asset<C: Read + Seek> {
     /// Soft package reference list
     #[container_ignore]
     soft_package_reference_list: Option<Vec<String>>,
+    /// Thumbnails
+    #[container_ignore]
+    thumbnails: Vec<AssetThumbnail>,
 
     /// Parent class
     parent_class: Option<ParentClassInfo>,
+
+    /// Inconsistencies recovered from while parsing
+    #[container_ignore]
+    anomalies: Vec<ParseAnomaly>,
 }
 
 impl<'a, C: Read + Seek> Asset<C> {
@@ -459,9 +511,119 @@ impl<'a, C: Read + Seek> Asset<C> {
         engine_version: EngineVersion,
         mappings: Option<Usmap>,
     ) -> Result<Self, Error> {
-        let use_event_driven_loader = bulk_data.is_some();
+        Self::new_with_options(
+            asset_data,
+            bulk_data,
+            engine_version,
+            mappings,
+            ParseOptions::default(),
+        )
+    }
 
+    /// Create an asset from a binary file using usmap mappings
+    ///
+    /// Mappings are required to parse and write assets with unversioned properties
+    pub fn new_with_mappings(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Usmap,
+    ) -> Result<Self, Error> {
+        Self::new(asset_data, bulk_data, engine_version, Some(mappings))
+    }
+
+    /// Create an asset from a binary file with custom parse options
+    pub fn new_with_options(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        parse_options: ParseOptions,
+    ) -> Result<Self, Error> {
+        Self::new_with_map_struct_type_overrides(
+            asset_data,
+            bulk_data,
+            engine_version,
+            mappings,
+            parse_options,
+            HashMap::new(),
+        )
+    }
+
+    /// Create an asset from a binary file with custom parse options
+    /// and user supplied key and value struct types for map properties
+    ///
+    /// `map_struct_type_overrides` maps a map property name to its key and value struct types,
+    /// these are used for maps containing structs that can't be resolved otherwise
+    pub fn new_with_map_struct_type_overrides(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        parse_options: ParseOptions,
+        map_struct_type_overrides: HashMap<String, (FName, FName)>,
+    ) -> Result<Self, Error> {
         let chain = Chain::new(asset_data, bulk_data);
+        let mut asset = Self::from_chain(
+            chain,
+            engine_version,
+            mappings,
+            parse_options,
+            map_struct_type_overrides,
+        );
+
+        match asset.parse_data() {
+            Ok(_) => Ok(asset),
+            Err(e) if asset.asset_data.summary.unversioned && is_version_mismatch(&e) => {
+                let offset = asset.raw_reader.position();
+                let suggestions = asset.suggest_engine_versions(engine_version);
+                match suggestions.is_empty() {
+                    true => Err(with_io_context(e, offset)),
+                    false => Err(Error::wrong_engine_version(engine_version, suggestions, e)),
+                }
+            }
+            Err(e) => {
+                let offset = asset.raw_reader.position();
+                Err(with_io_context(e, offset))
+            }
+        }
+    }
+
+    /// Read only the package file summary of a versioned asset
+    ///
+    /// This is a lot cheaper than parsing the whole asset, names, imports and exports are not read.
+    /// Unversioned assets need an engine version, see [`Asset::read_summary_with_version`].
+    pub fn read_summary(asset_data: &mut C) -> Result<PackageFileSummary, Error> {
+        Self::read_summary_with_version(asset_data, EngineVersion::UNKNOWN)
+    }
+
+    /// Read only the package file summary of an asset
+    ///
+    /// `engine_version` is only used for unversioned assets.
+    pub fn read_summary_with_version(
+        asset_data: &mut C,
+        engine_version: EngineVersion,
+    ) -> Result<PackageFileSummary, Error> {
+        let mut asset = Asset::<&mut C>::from_chain(
+            Chain::new(asset_data, None),
+            engine_version,
+            None,
+            ParseOptions::default(),
+            HashMap::new(),
+        );
+        asset.parse_header()?;
+        Ok(asset.asset_data.summary)
+    }
+
+    /// Create an unparsed asset reading from a chain
+    fn from_chain(
+        chain: Chain<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        parse_options: ParseOptions,
+        map_struct_type_overrides: HashMap<String, (FName, FName)>,
+    ) -> Self {
+        let use_event_driven_loader = chain.has_second();
         let name_map = NameMap::new();
         let raw_reader = RawReader::new(
             chain,
@@ -476,6 +638,7 @@ impl<'a, C: Read + Seek> Asset<C> {
             info: String::from("Serialized with unrealmodding/uasset"),
             asset_data: AssetData {
                 use_event_driven_loader,
+                parse_options,
                 ..Default::default()
             },
             legacy_file_version: 0,
@@ -515,12 +678,63 @@ impl<'a, C: Read + Seek> Asset<C> {
             imports: Vec::new(),
             depends_map: None,
             soft_package_reference_list: None,
+            thumbnails: Vec::new(),
             parent_class: None,
+            anomalies: Vec::new(),
         };
         asset.set_engine_version(engine_version);
         asset.asset_data.mappings = mappings;
-        asset.parse_data()?;
-        Ok(asset)
+        asset
+            .asset_data
+            .set_map_struct_type_overrides(map_struct_type_overrides);
+        asset
+    }
+
+    /// Find the engine versions an unversioned asset that failed to parse can be parsed with
+    ///
+    /// The engine version is guessed from the versions the mappings were generated for.
+    /// Without mappings the candidates are all engine versions matching the header's legacy file version,
+    /// newest first. A candidate is only suggested if the asset parses with it.
+    fn suggest_engine_versions(self, tried: EngineVersion) -> Vec<EngineVersion> {
+        let candidates = match self.asset_data.mappings.as_ref() {
+            Some(mappings) => vec![guess_engine_version(
+                mappings.object_version,
+                mappings.object_version_ue5,
+                &mappings.custom_versions,
+            )],
+            None => header_engine_versions(self.legacy_file_version),
+        };
+
+        let tried_object_versions = get_object_versions(tried);
+        let parse_options = self.asset_data.parse_options;
+        let mut mappings = self.asset_data.mappings;
+        let mut map_struct_type_overrides = self.asset_data.map_struct_type_overrides;
+        let mut chain = self.raw_reader.into_inner();
+
+        let mut suggestions = Vec::new();
+        for candidate in candidates {
+            if candidate == EngineVersion::UNKNOWN
+                || get_object_versions(candidate) == tried_object_versions
+            {
+                continue;
+            }
+
+            let mut asset = Self::from_chain(
+                chain,
+                candidate,
+                mappings,
+                parse_options,
+                map_struct_type_overrides,
+            );
+            if asset.parse_data().is_ok() {
+                suggestions.push(candidate);
+            }
+
+            mappings = asset.asset_data.mappings;
+            map_struct_type_overrides = asset.asset_data.map_struct_type_overrides;
+            chain = asset.raw_reader.into_inner();
+        }
+        suggestions
     }
 
     /// Set asset engine version
@@ -539,9 +753,7 @@ impl<'a, C: Read + Seek> Asset<C> {
 
         // read and check magic
         if self.read_u32::<BE>()? != UE4_ASSET_MAGIC {
-            return Err(Error::invalid_file(
-                "File is not a valid uasset file".to_string(),
-            ));
+            return Err(Error::NotAnAsset);
         }
 
         // read legacy version
@@ -594,6 +806,15 @@ impl<'a, C: Read + Seek> Asset<C> {
             )?;
         }
 
+        self.asset_data.summary.engine_version = match self.asset_data.summary.unversioned {
+            true => self.asset_data.get_engine_version(),
+            false => guess_engine_version(
+                self.asset_data.object_version,
+                self.asset_data.object_version_ue5,
+                &self.asset_data.summary.custom_versions,
+            ),
+        };
+
         // read header offset
         self.header_offset = self.read_i32::<LE>()?;
 
@@ -609,6 +830,7 @@ impl<'a, C: Read + Seek> Asset<C> {
         // read name count and offset
         self.name_count = self.read_i32::<LE>()?;
         self.name_offset = self.read_i32::<LE>()?;
+        self.asset_data.summary.name_count = self.name_count;
 
         if self.get_object_version_ue5() >= ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST {
             self.soft_object_paths_count = self.read_i32::<LE>()?;
@@ -827,13 +1049,241 @@ impl<'a, C: Read + Seek> Asset<C> {
         }
 
         let index = -index.index - 1;
-        if index < 0 || index > self.imports.len() as i32 {
+        if index < 0 || index >= self.imports.len() as i32 {
             return None;
         }
 
         Some(self.imports[index as usize].clone())
     }
 
+    /// Get imports grouped by the package of their class
+    ///
+    /// Keys are the `class_package` of the imports, like `/Script/CoreUObject` or `/Script/Engine`,
+    /// imports are in the order of the import map within each group.
+    pub fn imports_by_package(&self) -> HashMap<String, Vec<&Import>> {
+        let mut packages: HashMap<String, Vec<&Import>> = HashMap::new();
+        for import in &self.imports {
+            packages
+                .entry(import.class_package.get_owned_content())
+                .or_default()
+                .push(import);
+        }
+        packages
+    }
+
+    /// Get the class name of an export by the export's [`PackageIndex`]
+    ///
+    /// The class can be an import, another export of this asset like a blueprint generated class,
+    /// or a null index for exports that are classes themselves, which resolves to `Class`.
+    pub fn export_class_name(&self, index: PackageIndex) -> Option<String> {
+        let class_index = self.get_export(index)?.get_base_export().class_index;
+
+        match class_index.index {
+            0 => Some(String::from("Class")),
+            _ if class_index.is_import() => self
+                .get_import(class_index)
+                .map(|e| e.object_name.get_owned_content()),
+            _ => self
+                .get_export(class_index)
+                .map(|e| e.get_base_export().object_name.get_owned_content()),
+        }
+    }
+
+    /// Get the main export of this asset
+    ///
+    /// This is the export flagged with `is_asset`, or the first top level export
+    /// for assets where no export has that flag set.
+    pub fn main_export(&self) -> Option<PackageIndex> {
+        let exports = &self.asset_data.exports;
+        exports
+            .iter()
+            .position(|e| e.get_base_export().is_asset())
+            .or_else(|| {
+                exports
+                    .iter()
+                    .position(|e| e.get_base_export().outer_index.index == 0)
+            })
+            .map(|i| PackageIndex::new(i as i32 + 1))
+    }
+
+    /// Get the package source of this asset, usually a hash of the package name for cooked assets
+    pub fn package_source(&self) -> u32 {
+        self.package_source
+    }
+
+    /// Get the licensee version this asset was serialized with
+    pub fn file_licensee_version(&self) -> i32 {
+        self.asset_data.summary.file_licensee_version
+    }
+
+    /// Get the size of the package header, which is the offset export data starts at
+    ///
+    /// This is the value read from the file, [`Asset::write_data`] recomputes it
+    /// from the size of the written name, import and export maps.
+    pub fn header_size(&self) -> i32 {
+        self.header_offset
+    }
+
+    /// Get the package-level soft references of this asset
+    pub fn soft_package_references(&self) -> &[String] {
+        self.soft_package_reference_list
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    /// Get a mutable reference to the package-level soft references of this asset
+    pub fn soft_package_references_mut(&mut self) -> &mut Vec<String> {
+        self.soft_package_reference_list
+            .get_or_insert_with(Vec::new)
+    }
+
+    /// Add a package-level soft reference, e.g. `/Game/Maps/MyMap`
+    pub fn add_soft_package_reference(&mut self, reference: String) {
+        self.soft_package_references_mut().push(reference);
+    }
+
+    /// Get the editor thumbnails stored in this asset
+    pub fn thumbnails(&self) -> &[AssetThumbnail] {
+        &self.thumbnails
+    }
+
+    /// Get a mutable reference to the editor thumbnails stored in this asset
+    pub fn thumbnails_mut(&mut self) -> &mut Vec<AssetThumbnail> {
+        &mut self.thumbnails
+    }
+
+    /// Get the thumbnail of an export
+    ///
+    /// Thumbnails are matched by object path, thumbnails of objects that
+    /// aren't exports of this asset are never returned.
+    pub fn thumbnail_for_export(&self, index: PackageIndex) -> Option<&AssetThumbnail> {
+        let object_path = self.export_object_path(index)?;
+        self.thumbnails
+            .iter()
+            .find(|e| e.object_path == object_path)
+    }
+
+    /// Get the path of an export without the package name, e.g. `MyAsset:SubObject`
+    fn export_object_path(&self, index: PackageIndex) -> Option<String> {
+        let mut names = Vec::new();
+        let mut current = index;
+        while current.is_export() {
+            // guard against outer cycles
+            if names.len() > self.asset_data.exports.len() {
+                return None;
+            }
+
+            let export = self.get_export(current)?.get_base_export();
+            names.push(match export.object_name.get_number() {
+                0 => export.object_name.get_owned_content(),
+                number => format!("{}_{}", export.object_name.get_owned_content(), number - 1),
+            });
+            current = export.outer_index;
+        }
+
+        if current.index != 0 {
+            return None;
+        }
+
+        let mut names = names.into_iter().rev();
+        let mut object_path = names.next()?;
+        for (i, name) in names.enumerate() {
+            // objects directly inside a top level object use the subobject delimiter
+            object_path.push(match i {
+                0 => ':',
+                _ => '.',
+            });
+            object_path.push_str(&name);
+        }
+
+        Some(object_path)
+    }
+
+    /// Set the usmap mappings used for unversioned properties
+    ///
+    /// Exports that were already parsed are not reparsed, new mappings only
+    /// apply to properties read or written afterwards.
+    pub fn set_mappings(&mut self, mappings: Option<Usmap>) {
+        self.asset_data.mappings = mappings;
+    }
+
+    /// Switch between tagged and unversioned property serialization
+    ///
+    /// Parsed properties are kept as they are, only the format they are written in changes.
+    /// Unversioned properties are written in the order given by the mappings, so mappings are required
+    /// for them, `mappings` replaces the current mappings if given.
+    ///
+    /// When switching to tagged properties, zero values of unversioned properties are replaced with
+    /// their defaults, see [`to_default_property`]. If one of them has no default the
+    /// asset is left unchanged and an error is returned.
+    ///
+    /// [`to_default_property`]: unreal_asset_properties::empty_property::EmptyProperty::to_default_property
+    pub fn set_unversioned(
+        &mut self,
+        unversioned: bool,
+        mappings: Option<&Usmap>,
+    ) -> Result<(), Error> {
+        if let Some(mappings) = mappings {
+            self.asset_data.mappings = Some(mappings.clone());
+        }
+        if unversioned && self.asset_data.mappings.is_none() {
+            return Err(PropertyError::no_mappings().into());
+        }
+        if !unversioned && self.has_unversioned_properties() {
+            self.prepare_tagged_properties()?;
+        }
+
+        self.asset_data
+            .summary
+            .package_flags
+            .set(EPackageFlags::PKG_UNVERSIONED_PROPERTIES, unversioned);
+        Ok(())
+    }
+
+    /// Prepare properties read as unversioned to be written as tagged properties
+    fn prepare_tagged_properties(&mut self) -> Result<(), Error> {
+        let mappings = self.asset_data.mappings.as_ref();
+        let mut exports = self.asset_data.exports.clone();
+
+        let mut error = None;
+        visit_properties_mut(&mut exports, &mut |property| {
+            let Property::EmptyProperty(empty) = property else {
+                return;
+            };
+            if error.is_some() {
+                return;
+            }
+
+            match mappings
+                .ok_or_else(|| PropertyError::no_mappings().into())
+                .and_then(|mappings| empty.to_default_property(mappings))
+            {
+                Ok(default) => *property = default,
+                Err(e) => error = Some(e),
+            }
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        // tagged properties reference their type names, these have to be in the name map before
+        // it is written
+        let mut name_map = self.name_map.clone();
+        visit_properties_mut(&mut exports, &mut |property| {
+            name_map.get_mut().add_fname(&property.to_serialized_name());
+        });
+        name_map.get_mut().add_fname("None");
+        self.asset_data.exports = exports;
+
+        // names of unversioned properties come from the mappings and aren't in the name map yet
+        self.traverse_fnames(&mut |name| {
+            if let FName::Dummy { value, number } = name {
+                *name = name_map.get_mut().add_fname_with_number(value, *number);
+            }
+        });
+        Ok(())
+    }
+
     /// Get an export
     pub fn get_export(&'a self, index: PackageIndex) -> Option<&'a Export<PackageIndex>> {
         self.asset_data.get_export(index)
@@ -847,6 +1297,124 @@ impl<'a, C: Read + Seek> Asset<C> {
         self.asset_data.get_export_mut(index)
     }
 
+    /// Set the class of an export
+    ///
+    /// `class` must be null, an import or an export of this asset.
+    pub fn set_export_class(
+        &mut self,
+        export: PackageIndex,
+        class: PackageIndex,
+    ) -> Result<(), Error> {
+        self.check_package_index(class)?;
+        self.export_base_mut(export)?.class_index = class;
+        Ok(())
+    }
+
+    /// Set the struct an export inherits from
+    ///
+    /// `super_struct` must be null, an import or an export of this asset.
+    pub fn set_export_super(
+        &mut self,
+        export: PackageIndex,
+        super_struct: PackageIndex,
+    ) -> Result<(), Error> {
+        self.check_package_index(super_struct)?;
+        self.export_base_mut(export)?.super_index = super_struct;
+        Ok(())
+    }
+
+    /// Set the archetype an export was created from
+    ///
+    /// `template` must be null, an import or an export of this asset.
+    /// Fails for assets older than `VER_UE4_TemplateIndex_IN_COOKED_EXPORTS`,
+    /// as the template index would not be written.
+    pub fn set_export_template(
+        &mut self,
+        export: PackageIndex,
+        template: PackageIndex,
+    ) -> Result<(), Error> {
+        if self.get_object_version() < ObjectVersion::VER_UE4_TemplateIndex_IN_COOKED_EXPORTS {
+            return Err(Error::invalid_package_index(format!(
+                "Template indices are not serialized for object version {:?}",
+                self.get_object_version()
+            )));
+        }
+        self.check_package_index(template)?;
+        self.export_base_mut(export)?.template_index = template;
+        Ok(())
+    }
+
+    /// Set the object an export is nested in
+    ///
+    /// `outer` must be null, an import or an export of this asset
+    /// that is not the export itself or nested inside of it, and whose outers don't form a cycle.
+    pub fn set_export_outer(
+        &mut self,
+        export: PackageIndex,
+        outer: PackageIndex,
+    ) -> Result<(), Error> {
+        self.check_package_index(outer)?;
+        self.export_base_mut(export)?;
+
+        // walk up the new outer chain to make sure no cycle is created,
+        // a chain longer than the number of exports already contains one
+        let mut current = outer;
+        let mut steps = 0;
+        while current.is_export() {
+            if current == export {
+                return Err(Error::invalid_package_index(format!(
+                    "Setting outer of export {} to {} would create a cycle",
+                    export.index, outer.index
+                )));
+            }
+            if steps == self.asset_data.exports.len() {
+                return Err(Error::invalid_package_index(format!(
+                    "The outer chain of export {} contains a cycle",
+                    outer.index
+                )));
+            }
+            steps += 1;
+
+            current = match self.asset_data.get_export(current) {
+                Some(e) => e.get_base_export().outer_index,
+                None => break,
+            };
+        }
+
+        self.export_base_mut(export)?.outer_index = outer;
+        Ok(())
+    }
+
+    /// Check that a [`PackageIndex`] is null or points to an existing import or export
+    fn check_package_index(&self, index: PackageIndex) -> Result<(), Error> {
+        let exists = match index.index {
+            0 => true,
+            _ if index.is_import() => self.get_import(index).is_some(),
+            _ => self.asset_data.get_export(index).is_some(),
+        };
+
+        match exists {
+            true => Ok(()),
+            false => Err(Error::invalid_package_index(format!(
+                "{} does not point to an import or export",
+                index.index
+            ))),
+        }
+    }
+
+    /// Get the base export of an export, failing if the index is not an export of this asset
+    fn export_base_mut(
+        &mut self,
+        index: PackageIndex,
+    ) -> Result<&mut BaseExport<PackageIndex>, Error> {
+        self.asset_data
+            .get_export_mut(index)
+            .map(|e| e.get_base_export_mut())
+            .ok_or_else(|| {
+                Error::invalid_package_index(format!("{} is not a valid export index", index.index))
+            })
+    }
+
     /// Get custom version serialization format
     pub fn get_custom_version_serialization_format(&self) -> ECustomVersionSerializationFormat {
         if self.legacy_file_version > 3 {
@@ -858,14 +1426,134 @@ impl<'a, C: Read + Seek> Asset<C> {
         ECustomVersionSerializationFormat::Optimized
     }
 
+    /// Get the custom versions declared by this asset as friendly name and version pairs
+    ///
+    /// Custom versions that are not well-known are named by their guid.
+    pub fn custom_versions_named(&self) -> Vec<(String, i32)> {
+        self.asset_data
+            .summary
+            .custom_versions
+            .iter()
+            .map(|custom_version| {
+                let name = custom_version
+                    .friendly_name
+                    .clone()
+                    .unwrap_or_else(|| custom_version.guid.to_string());
+                (name, custom_version.version)
+            })
+            .collect()
+    }
+
+    /// Offset the name map ends at, which is the start of the section following it
+    fn name_map_end(&self) -> Option<u64> {
+        // soft object paths and gatherable text data are written back with their original offsets,
+        // so they are only reliable if they hold any data
+        let untracked = [
+            (self.soft_object_paths_count, self.soft_object_paths_offset),
+            (
+                self.gatherable_text_data_count,
+                self.gatherable_text_data_offset,
+            ),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(_, offset)| offset);
+
+        [
+            self.header_offset,
+            self.import_offset,
+            self.export_offset,
+            self.depends_offset,
+            self.soft_package_reference_offset,
+            self.thumbnail_table_offset,
+            self.asset_registry_data_offset,
+            self.world_tile_info_offset,
+            self.preload_dependency_offset,
+        ]
+        .into_iter()
+        .chain(untracked)
+        .filter(|offset| *offset > self.name_offset)
+        .min()
+        .map(|offset| offset as u64)
+    }
+
+    /// Read the name map, checking the summary name count against the names before the next section
+    fn read_name_map(&mut self) -> Result<Vec<(String, u32)>, Error> {
+        let end = self.name_map_end();
+
+        let mut names = Vec::with_capacity(self.name_count.max(0) as usize);
+        for _ in 0..self.name_count {
+            if end.is_some_and(|end| self.position() >= end) {
+                break;
+            }
+            names.push(self.read_name_map_string(None)?);
+        }
+
+        if let Some(end) = end {
+            let start = self.position();
+            match self.read_names_until(end) {
+                Ok(trailing) if self.position() == end => names.extend(trailing),
+                // whatever is in between isn't a name, only trust the summary
+                _ => {
+                    self.seek(SeekFrom::Start(start))?;
+                }
+            }
+        }
+
+        let actual = names.len() as i32;
+        if actual != self.name_count {
+            if !self.asset_data.parse_options.lenient {
+                return Err(Error::name_count_mismatch(self.name_count, actual));
+            }
+
+            self.anomalies.push(ParseAnomaly::NameCountMismatch {
+                expected: self.name_count,
+                actual,
+            });
+            if self.get_object_version_ue5() < ObjectVersionUE5::NAMES_REFERENCED_FROM_EXPORT_DATA {
+                self.names_referenced_from_export_data_count = actual;
+            }
+            self.name_count = actual;
+            self.asset_data.summary.name_count = actual;
+        }
+
+        Ok(names)
+    }
+
+    /// Read name map strings up to `end`, failing on a string that would reach past it
+    fn read_names_until(&mut self, end: u64) -> Result<Vec<(String, u32)>, Error> {
+        let mut names = Vec::new();
+        while self.position() < end {
+            // check the length before reading, garbage lengths could be huge
+            let len = self.read_i32::<LE>()? as i64;
+            let size = match len < 0 {
+                true => -len * 2,
+                false => len,
+            };
+            if self.position() as i64 + size > end as i64 {
+                return Err(Error::invalid_file(
+                    "Name map string reaches past the name map".to_string(),
+                ));
+            }
+
+            self.seek(SeekFrom::Current(-(size_of::<i32>() as i64)))?;
+            names.push(self.read_name_map_string(None)?);
+        }
+        Ok(names)
+    }
+
+    /// Get the inconsistencies recovered from while parsing with [`ParseOptions::lenient`]
+    pub fn anomalies(&self) -> &[ParseAnomaly] {
+        &self.anomalies
+    }
+
     /// Parse asset data
     fn parse_data(&mut self) -> Result<(), Error> {
         self.parse_header()?;
 
         self.seek(SeekFrom::Start(self.name_offset as u64))?;
 
-        for _ in 0..self.name_count {
-            let (name, hash) = self.read_name_map_string(None)?;
+        for (name, hash) in self.read_name_map()? {
             if hash == 0 {
                 // todo: good FString type
                 self.override_name_map_hashes.insert(name.clone(), 0);
@@ -943,6 +1631,11 @@ impl<'a, C: Read + Seek> Asset<C> {
             self.soft_package_reference_list = Some(soft_package_reference_list);
         }
 
+        if self.thumbnail_table_offset > 0 {
+            self.seek(SeekFrom::Start(self.thumbnail_table_offset as u64))?;
+            self.thumbnails = AssetThumbnail::read_table(self)?;
+        }
+
         // TODO: Asset registry data parsing should be here
 
         if self.world_tile_info_offset > 0 {
@@ -973,27 +1666,76 @@ impl<'a, C: Read + Seek> Asset<C> {
         }
 
         if self.header_offset > 0 && !export_map.is_empty() {
-            let map_len = export_map.len();
-            self.asset_data.exports.reserve(map_len);
+            self.asset_data.exports = export_map
+                .into_iter()
+                .map(|e| Export::BaseExport(e.to_base_export()))
+                .collect();
 
-            let serial_offsets = export_map
-                .iter()
-                .map(|e| e.serial_offset as u64)
-                .collect::<Vec<_>>();
+            if !self.asset_data.parse_options.skip_export_bodies {
+                self.read_exports()?;
+            }
+        }
 
-            for (i, entry) in export_map.into_iter().enumerate() {
-                let base_export = entry.to_base_export();
+        Ok(())
+    }
+
+    /// Read the bodies of every export in the export map
+    fn read_exports(&mut self) -> Result<(), Error> {
+        let exports = self.export_iter().collect::<Result<Vec<_>, _>>()?;
+        self.asset_data.exports = exports;
+        Ok(())
+    }
 
-                let next_starting = match i < (map_len - 1) {
-                    true => serial_offsets[i + 1],
-                    false => self.data_length()? - 4,
+    /// Parse exports one at a time
+    ///
+    /// Each export is read from its serial offset when the iterator is advanced
+    /// and is not kept by the asset, so scanning an asset parsed with
+    /// [`ParseOptions::skip_export_bodies`] only holds one export body in memory at a time.
+    pub fn export_iter(
+        &mut self,
+    ) -> impl Iterator<Item = Result<Export<PackageIndex>, Error>> + '_ {
+        let base_exports = self
+            .asset_data
+            .exports
+            .iter()
+            .map(|e| e.get_base_export().clone())
+            .collect::<Vec<_>>();
+        let serial_offsets = base_exports
+            .iter()
+            .map(|e| e.serial_offset as u64)
+            .collect::<Vec<_>>();
+
+        base_exports
+            .into_iter()
+            .enumerate()
+            .map(move |(i, base_export)| {
+                let next_starting = match serial_offsets.get(i + 1) {
+                    Some(next_offset) => *next_offset,
+                    None => self.data_length()? - 4,
                 };
 
-                let export = self.read_export(base_export, next_starting)?;
-                self.asset_data.exports.push(export);
-            }
+                self.read_export(base_export, next_starting)
+            })
+    }
+
+    /// Reload export bodies from a new .uexp file
+    ///
+    /// Keeps the already parsed summary, name map, imports and export map,
+    /// only export bodies are re-parsed from `bulk_data`.
+    ///
+    /// This requires the asset to use separate bulk data files.
+    pub fn reload_bulk_data(&mut self, bulk_data: C) -> Result<(), Error> {
+        if !self.asset_data.use_event_driven_loader {
+            return Err(Error::no_data(
+                "Cannot reload bulk data of an asset without separate bulk data files".to_string(),
+            ));
         }
 
+        self.raw_reader.set_bulk_data(bulk_data)?;
+        self.read_exports()?;
+
+        // every export body is loaded now, so the asset can be written again
+        self.asset_data.parse_options.skip_export_bodies = false;
         Ok(())
     }
 
@@ -1063,7 +1805,10 @@ impl<'a, C: Read + Seek> Asset<C> {
 
         if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP
         {
-            cursor.write_i32::<LE>(self.soft_package_reference_count)?;
+            cursor.write_i32::<LE>(match self.soft_package_reference_list {
+                Some(ref package_references) => package_references.len() as i32,
+                None => self.soft_package_reference_count,
+            })?;
             cursor.write_i32::<LE>(asset_header.soft_package_reference_offset)?;
         }
 
@@ -1071,7 +1816,7 @@ impl<'a, C: Read + Seek> Asset<C> {
             cursor.write_i32::<LE>(self.searchable_names_offset)?;
         }
 
-        cursor.write_i32::<LE>(self.thumbnail_table_offset)?;
+        cursor.write_i32::<LE>(asset_header.thumbnail_table_offset)?;
         cursor.write_guid(&self.package_guid)?;
         cursor.write_i32::<LE>(self.generations.len() as i32)?;
 
@@ -1171,11 +1916,29 @@ impl<'a, C: Read + Seek> Asset<C> {
     }
 
     /// Write asset data
+    ///
+    /// Fails if the asset was parsed with [`ParseOptions::skip_export_bodies`], as the export data is missing.
     pub fn write_data<W: Read + Seek + Write>(
         &self,
         cursor: &mut W,
         uexp_cursor: Option<&mut W>,
     ) -> Result<(), Error> {
+        self.write_data_with_header(cursor, uexp_cursor).map(|_| ())
+    }
+
+    /// Write asset data, returning the header that was written
+    fn write_data_with_header<W: Read + Seek + Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+    ) -> Result<AssetHeader, Error> {
+        if self.asset_data.parse_options.skip_export_bodies {
+            return Err(Error::no_data(
+                "Cannot write an asset parsed with skip_export_bodies, export bodies were not read"
+                    .to_string(),
+            ));
+        }
+
         if self.asset_data.use_event_driven_loader != uexp_cursor.is_some() {
             return Err(Error::no_data(format!(
                 "use_separate_bulk_data_files is {} but uexp_cursor is {}",
@@ -1193,6 +1956,7 @@ impl<'a, C: Read + Seek> Asset<C> {
             export_offset: self.export_offset,
             depends_offset: self.depends_offset,
             soft_package_reference_offset: self.soft_package_reference_offset,
+            thumbnail_table_offset: self.thumbnail_table_offset,
             asset_registry_data_offset: self.asset_registry_data_offset,
             world_tile_info_offset: self.world_tile_info_offset,
             preload_dependency_count: 0,
@@ -1297,6 +2061,11 @@ impl<'a, C: Read + Seek> Asset<C> {
             }
         }
 
+        let thumbnail_table_offset = match self.thumbnails.is_empty() {
+            true => 0,
+            false => AssetThumbnail::write_table(&mut serializer, &self.thumbnails)?,
+        };
+
         // todo: asset registry data support
         // we can support it now I think?
         let asset_registry_data_offset = match self.asset_registry_data_offset != 0 {
@@ -1442,6 +2211,7 @@ impl<'a, C: Read + Seek> Asset<C> {
             export_offset,
             depends_offset,
             soft_package_reference_offset,
+            thumbnail_table_offset,
             asset_registry_data_offset,
             world_tile_info_offset,
             preload_dependency_count,
@@ -1453,8 +2223,330 @@ impl<'a, C: Read + Seek> Asset<C> {
 
         serializer.rewind()?;
 
+        Ok(header)
+    }
+
+    /// Serialize a single export
+    ///
+    /// Returns the bytes that a full [`Asset::write_data`] would place at the export's serial offset
+    pub fn serialize_export(&self, index: PackageIndex) -> Result<Vec<u8>, Error> {
+        let export = self.asset_data.get_export(index).ok_or_else(|| {
+            Error::invalid_package_index(format!("{} is not a valid export index", index.index))
+        })?;
+
+        self.write_export(export)
+    }
+
+    /// Serialize an export in the context of this asset, the export doesn't have to be part of it
+    fn write_export(&self, export: &Export<PackageIndex>) -> Result<Vec<u8>, Error> {
+        self.write_to_vec(|serializer| {
+            export.write(serializer)?;
+
+            if let Some(normal_export) = export.get_normal_export() {
+                serializer.write_all(&normal_export.extras)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Write into a new buffer with a serializer for this asset
+    fn write_to_vec(
+        &self,
+        write: impl FnOnce(
+            &mut AssetArchiveWriter<RawWriter<PackageIndex, Cursor<Vec<u8>>>>,
+        ) -> Result<(), Error>,
+    ) -> Result<Vec<u8>, Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut raw_serializer = RawWriter::new(
+            &mut cursor,
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        let mut serializer = AssetArchiveWriter::new(
+            &mut raw_serializer,
+            &self.asset_data,
+            &self.imports,
+            self.name_map.clone(),
+        );
+
+        write(&mut serializer)?;
+
+        Ok(cursor.into_inner())
+    }
+
+    /// Compute a hash of the asset's logical content
+    ///
+    /// Imports, exports and their data are hashed with every `FName` resolved to its content,
+    /// so assets that only differ in the order of their name map, or in offsets and sizes, get the same fingerprint.
+    #[cfg(feature = "fingerprint")]
+    pub fn content_fingerprint(&self) -> Result<[u8; 32], Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut raw_serializer = RawWriter::new(
+            &mut cursor,
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        let mut asset_serializer = AssetArchiveWriter::new(
+            &mut raw_serializer,
+            &self.asset_data,
+            &self.imports,
+            self.name_map.clone(),
+        );
+        let mut serializer = FingerprintWriter::new(&mut asset_serializer);
+
+        serializer.write_i32::<LE>(self.imports.len() as i32)?;
+        for import in &self.imports {
+            serializer.write_fname(&import.class_package)?;
+            serializer.write_fname(&import.class_name)?;
+            serializer.write_i32::<LE>(import.outer_index.index)?;
+            serializer.write_fname(&import.object_name)?;
+            serializer.write_bool(import.optional)?;
+        }
+
+        serializer.write_i32::<LE>(self.asset_data.exports.len() as i32)?;
+        for export in &self.asset_data.exports {
+            let base_export = export.get_base_export();
+            serializer.write_i32::<LE>(base_export.class_index.index)?;
+            serializer.write_i32::<LE>(base_export.super_index.index)?;
+            serializer.write_i32::<LE>(base_export.template_index.index)?;
+            serializer.write_i32::<LE>(base_export.outer_index.index)?;
+            serializer.write_fname(&base_export.object_name)?;
+            serializer.write_u32::<LE>(base_export.object_flags.bits())?;
+            serializer.write_bool(base_export.not_for_client)?;
+            serializer.write_bool(base_export.not_for_server)?;
+            serializer.write_bool(base_export.is_asset)?;
+
+            export.write(&mut serializer)?;
+            if let Some(normal_export) = export.get_normal_export() {
+                serializer.write_all(&normal_export.extras)?;
+            }
+        }
+
+        Ok(Sha256::digest(cursor.into_inner()).into())
+    }
+
+    /// Write asset data and check that the written length fields match the serialized sizes
+    ///
+    /// The written data is read back, then the serial size of every export and the length of every tagged property
+    /// is compared with the bytes they serialize to, returning [`Error::SerializationLengthMismatch`] on divergence.
+    /// The header read back has to match the header that was written,
+    /// and the header offset has to match the serial offset of the first export.
+    pub fn write_verified<W: Read + Seek + Write>(
+        &self,
+        cursor: &mut W,
+        mut uexp_cursor: Option<&mut W>,
+    ) -> Result<(), Error> {
+        let header = self.write_data_with_header(cursor, uexp_cursor.as_deref_mut())?;
+
+        let read_back = |cursor: &mut W| -> Result<Vec<u8>, Error> {
+            let mut data = Vec::new();
+            cursor.seek(SeekFrom::Start(0))?;
+            cursor.read_to_end(&mut data)?;
+            Ok(data)
+        };
+
+        let asset_data = read_back(cursor)?;
+        let bulk_data = match uexp_cursor {
+            Some(uexp_cursor) => Some(read_back(uexp_cursor)?),
+            None => None,
+        };
+
+        let written = Asset::new_with_map_struct_type_overrides(
+            Cursor::new(asset_data),
+            bulk_data.map(Cursor::new),
+            self.get_engine_version(),
+            self.asset_data.mappings.clone(),
+            self.asset_data.parse_options,
+            self.asset_data.map_struct_type_overrides.clone(),
+        )?;
+        written.verify_header(
+            &header,
+            self.name_map.get_ref().get_name_map_index_list().len(),
+            self.imports.len(),
+            self.asset_data.exports.len(),
+        )?;
+        written.verify_lengths()
+    }
+
+    /// Check that the header read from the file matches the header and map sizes that were written
+    fn verify_header(
+        &self,
+        header: &AssetHeader,
+        name_count: usize,
+        import_count: usize,
+        export_count: usize,
+    ) -> Result<(), Error> {
+        let fields = [
+            (
+                "header offset",
+                self.header_offset as i64,
+                header.header_offset as i64,
+            ),
+            (
+                "name offset",
+                self.name_offset as i64,
+                header.name_offset as i64,
+            ),
+            (
+                "import offset",
+                self.import_offset as i64,
+                header.import_offset as i64,
+            ),
+            (
+                "export offset",
+                self.export_offset as i64,
+                header.export_offset as i64,
+            ),
+            ("name count", self.name_count as i64, name_count as i64),
+            (
+                "import count",
+                self.asset_data.summary.import_count as i64,
+                import_count as i64,
+            ),
+            (
+                "export count",
+                self.asset_data.summary.export_count as i64,
+                export_count as i64,
+            ),
+        ];
+
+        for (field, read, written) in fields {
+            if read != written {
+                return Err(Error::invalid_file(format!(
+                    "Read back {field} {read} does not match the written {field} {written}"
+                )));
+            }
+        }
+
         Ok(())
     }
+
+    /// Check that every export's serial size and every tagged property's length match their serialized sizes
+    fn verify_lengths(&self) -> Result<(), Error> {
+        let mut scratch = Cursor::new(Vec::new());
+        let mut raw_serializer = RawWriter::new(
+            &mut scratch,
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        let mut serializer = AssetArchiveWriter::new(
+            &mut raw_serializer,
+            &self.asset_data,
+            &self.imports,
+            self.name_map.clone(),
+        );
+
+        if let Some(first_export) = self.asset_data.exports.first() {
+            let serial_offset = first_export.get_base_export().serial_offset;
+            if serial_offset != self.header_offset as i64 {
+                return Err(Error::invalid_file(format!(
+                    "Header offset {} does not match the first export's serial offset {}",
+                    self.header_offset, serial_offset
+                )));
+            }
+        }
+
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let base_export = export.get_base_export();
+
+            let actual = self
+                .serialize_export(PackageIndex::from_export(i as i32)?)?
+                .len() as u64;
+            if base_export.serial_size as u64 != actual {
+                return Err(Error::serialization_length_mismatch(
+                    base_export.object_name.get_owned_content(),
+                    base_export.serial_size as u64,
+                    actual,
+                ));
+            }
+
+            // unversioned properties have no length fields
+            if self.asset_data.has_unversioned_properties() {
+                continue;
+            }
+
+            if let Some(normal_export) = export.get_normal_export() {
+                for property in &normal_export.properties {
+                    Property::verify_length(property, &mut serializer)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Attach the offset parsing stopped at to an I/O error for diagnostics, other errors already say what went wrong
+#[cfg(feature = "diagnostic")]
+fn with_io_context(error: Error, offset: u64) -> Error {
+    match error {
+        Error::Io(_) => error.with_context(offset, String::from("Failed to parse asset")),
+        _ => error,
+    }
+}
+
+/// Without diagnostics I/O errors are returned as they are
+#[cfg(not(feature = "diagnostic"))]
+fn with_io_context(error: Error, _offset: u64) -> Error {
+    error
+}
+
+/// Get the engine versions an unversioned asset with `legacy_file_version` can have been saved with, newest first
+///
+/// Unversioned assets don't store their object versions, only the legacy file version tells UE4 and UE5 assets apart.
+/// Engine versions with the same object versions as a newer engine version are left out.
+fn header_engine_versions(legacy_file_version: i32) -> Vec<EngineVersion> {
+    let (oldest, newest) = match legacy_file_version <= -8 {
+        true => (EngineVersion::VER_UE5_0, EngineVersion::VER_UE5_4),
+        false => (EngineVersion::VER_UE4_0, EngineVersion::VER_UE4_27),
+    };
+
+    let mut versions: Vec<EngineVersion> = Vec::new();
+    for version in (oldest as i32..=newest as i32).rev() {
+        let Ok(version) = EngineVersion::try_from(version) else {
+            continue;
+        };
+        let object_versions = get_object_versions(version);
+        if versions.last().map(|e| get_object_versions(*e)) != Some(object_versions) {
+            versions.push(version);
+        }
+    }
+    versions
+}
+
+/// Check if a parse error is likely caused by an unversioned asset being read with the wrong engine version
+fn is_version_mismatch(error: &Error) -> bool {
+    match error {
+        Error::Io(e) => e.kind() == std::io::ErrorKind::UnexpectedEof,
+        Error::FString(_) | Error::FName(_) | Error::InvalidEnumValue(_) => true,
+        // raw data longer than the rest of the archive, see `ArchiveReader::read_raw_data`
+        Error::Context { source, .. } => matches!(**source, Error::InvalidFile(_)),
+        _ => false,
+    }
+}
+
+/// Visit all properties of `exports`, including data table rows and nested properties
+fn visit_properties_mut<F: FnMut(&mut Property)>(exports: &mut [Export<PackageIndex>], f: &mut F) {
+    for export in exports {
+        if let Export::DataTableExport(data_table) = export {
+            for row in &mut data_table.table.data {
+                for property in &mut row.value {
+                    property.visit_mut(f);
+                }
+            }
+        }
+        if let Some(normal_export) = export.get_normal_export_mut() {
+            for property in &mut normal_export.properties {
+                property.visit_mut(f);
+            }
+        }
+    }
 }
 
 impl<C: Read + Seek> AssetTrait<PackageIndex> for Asset<C> {
@@ -1529,6 +2621,12 @@ impl<C: Read + Seek> ArchiveTrait<PackageIndex> for Asset<C> {
         &self.asset_data.map_value_override
     }
 
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.asset_data
+            .property_tag_types
+            .get_by_key(&(ancestry.clone(), name.clone()))
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.asset_data.get_engine_version()
     }
@@ -1545,6 +2643,10 @@ impl<C: Read + Seek> ArchiveTrait<PackageIndex> for Asset<C> {
         self.asset_data.mappings.as_ref()
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.asset_data.parse_options
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.asset_data
             .exports
@@ -1566,6 +2668,17 @@ impl<C: Read + Seek> ArchiveTrait<PackageIndex> for Asset<C> {
 
 impl<C: Read + Seek> ArchiveReader<PackageIndex> for Asset<C> {
     passthrough_archive_reader!(raw_reader);
+
+    fn add_property_tag_type(
+        &mut self,
+        name: FName,
+        ancestry: Ancestry,
+        tag_type: PropertyTagType,
+    ) {
+        self.asset_data
+            .property_tag_types
+            .insert((ancestry, name), tag_type);
+    }
 }
 
 impl<C: Read + Seek> Read for Asset<C> {
@@ -1638,6 +2751,134 @@ impl<C: Read + Seek> Debug for Asset<C> {
             .field("world_tile_info_data_offset", &self.world_tile_info_offset)
             .field("preload_dependency_count", &self.preload_dependency_count)
             .field("preload_dependency_offset", &self.preload_dependency_offset)
+            .field("anomalies", &self.anomalies)
             .finish()
     }
 }
+
+/// Part of an asset a roundtrip divergence was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundtripRegion {
+    /// Package file summary and any tables not listed separately
+    Header,
+    /// Name map
+    NameMap,
+    /// Import map
+    Imports,
+    /// Export map
+    ExportMap,
+    /// Body of the export at this index in [`AssetData::exports`]
+    Export(usize),
+    /// Data after the last export, e.g. the package file tag
+    Trailer,
+}
+
+impl std::fmt::Display for RoundtripRegion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundtripRegion::Header => write!(f, "header"),
+            RoundtripRegion::NameMap => write!(f, "name map"),
+            RoundtripRegion::Imports => write!(f, "imports"),
+            RoundtripRegion::ExportMap => write!(f, "export map"),
+            RoundtripRegion::Export(index) => write!(f, "export {index}"),
+            RoundtripRegion::Trailer => write!(f, "trailer"),
+        }
+    }
+}
+
+/// Thrown when an asset failed to roundtrip
+#[derive(Error, Debug)]
+pub enum RoundtripError {
+    /// Asset failed to parse or write
+    #[error("{0}")]
+    Asset(#[from] Error),
+    /// Written asset differs from the original
+    ///
+    /// Offsets past the end of the .uasset point into the .uexp, the same way export serial offsets do.
+    #[error("Roundtrip diverged at offset {offset:#x} in {region}, expected {expected:?}, got {actual:?}")]
+    Mismatch {
+        /// First differing offset
+        offset: u64,
+        /// Region the offset belongs to in the original asset
+        region: RoundtripRegion,
+        /// Original byte, `None` if the original ends here
+        expected: Option<u8>,
+        /// Written byte, `None` if the written asset ends here
+        actual: Option<u8>,
+    },
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Find the region of the asset an offset belongs to
+    fn get_roundtrip_region(&self, offset: u64) -> RoundtripRegion {
+        for (index, export) in self.asset_data.exports.iter().enumerate() {
+            let export = export.get_base_export();
+            let start = export.serial_offset as u64;
+            if offset >= start && offset < start + export.serial_size as u64 {
+                return RoundtripRegion::Export(index);
+            }
+        }
+
+        if self.bulk_data_start_offset > 0 && offset >= self.bulk_data_start_offset as u64 {
+            return RoundtripRegion::Trailer;
+        }
+
+        [
+            (self.name_offset, RoundtripRegion::NameMap),
+            (self.import_offset, RoundtripRegion::Imports),
+            (self.export_offset, RoundtripRegion::ExportMap),
+            (self.depends_offset, RoundtripRegion::Header),
+            (self.soft_package_reference_offset, RoundtripRegion::Header),
+            (self.asset_registry_data_offset, RoundtripRegion::Header),
+            (self.world_tile_info_offset, RoundtripRegion::Header),
+            (self.preload_dependency_offset, RoundtripRegion::Header),
+        ]
+        .into_iter()
+        .filter(|(start, _)| *start > 0 && *start as u64 <= offset)
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, region)| region)
+        .unwrap_or(RoundtripRegion::Header)
+    }
+}
+
+/// Parse an asset, write it back and check that the result is byte for byte identical
+///
+/// Assets written by this crate are deterministic, so no bytes are excluded from the comparison.
+/// On divergence the first differing offset is reported along with the part of the original asset it is in.
+pub fn assert_roundtrip(
+    asset_data: &[u8],
+    bulk_data: Option<&[u8]>,
+    engine_version: EngineVersion,
+) -> Result<(), RoundtripError> {
+    let asset = Asset::new(
+        Cursor::new(asset_data),
+        bulk_data.map(Cursor::new),
+        engine_version,
+        None,
+    )?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, bulk_data.is_some().then_some(&mut bulk_cursor))?;
+
+    let expected = asset_data
+        .iter()
+        .chain(bulk_data.unwrap_or_default())
+        .copied()
+        .collect::<Vec<_>>();
+    let mut actual = cursor.into_inner();
+    actual.extend(bulk_cursor.into_inner());
+
+    let offset = match expected.iter().zip(&actual).position(|(a, b)| a != b) {
+        Some(offset) => offset,
+        None if expected.len() == actual.len() => return Ok(()),
+        None => expected.len().min(actual.len()),
+    };
+
+    Err(RoundtripError::Mismatch {
+        offset: offset as u64,
+        region: asset.get_roundtrip_region(offset as u64),
+        expected: expected.get(offset).copied(),
+        actual: actual.get(offset).copied(),
+    })
+}
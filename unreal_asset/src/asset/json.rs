@@ -0,0 +1,1354 @@
+//! JSON conversion of assets
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek};
+
+use serde_json::{json, Map, Value};
+
+use unreal_asset_base::{
+    containers::{Chain, IndexedMap, NameMap, SharedResource},
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    error::Error,
+    flags::{EObjectFlags, EPackageFlags},
+    parse_options::ParseOptions,
+    reader::BufferReader,
+    types::{
+        fname::ToSerializedName,
+        property_type_name::{PropertyTagType, PropertyTypeName},
+        vector::{Color, Vector, Vector2, Vector4},
+        FName, GenerationInfo, PackageIndex,
+    },
+    unversioned::{Ancestry, Usmap},
+    Guid, Import,
+};
+use unreal_asset_exports::{
+    data_table_export::{DataTable, DataTableExport},
+    normal_export::NormalExport,
+    raw_export::RawExport,
+    BaseExport, Export, ExportBaseTrait, ExportNormalTrait,
+};
+use unreal_asset_properties::{
+    array_property::ArrayProperty,
+    color_property::{ColorProperty, LinearColorProperty},
+    enum_property::EnumProperty,
+    guid_property::GuidProperty,
+    int_property::{
+        BoolProperty, ByteProperty, BytePropertyValue, DoubleProperty, FloatProperty,
+        Int16Property, Int64Property, Int8Property, IntProperty, UInt16Property, UInt32Property,
+        UInt64Property,
+    },
+    map_property::MapProperty,
+    object_property::{ObjectProperty, SoftObjectPath, SoftObjectProperty, TopLevelAssetPath},
+    set_property::SetProperty,
+    str_property::{NameProperty, StrProperty, TextHistoryType, TextProperty},
+    struct_property::StructProperty,
+    unknown_property::UnknownProperty,
+    vector_property::{
+        QuatProperty, RotatorProperty, Vector2DProperty, Vector4Property, VectorProperty,
+    },
+    world_tile_property::FWorldTileInfo,
+    Property, PropertyDataTrait, PropertyTrait,
+};
+
+use super::Asset;
+use crate::fengineversion::FEngineVersion;
+use crate::thumbnail::AssetThumbnail;
+
+impl<C: Read + Seek> Asset<C> {
+    /// Convert the asset to JSON
+    ///
+    /// The package summary, name map, imports and exports are stored as JSON values.
+    /// Properties of every export are stored one by one, data table rows are stored like struct properties.
+    /// Properties that have no JSON representation, e.g. unsupported property types or floats that aren't finite,
+    /// are stored as hex encoded serialized values, so is the data that follows the properties of other export types.
+    ///
+    /// `FName`s are stored as strings, or as `{ "name", "number" }` objects when they have an instance number,
+    /// an `index` is added for names that can't be found by their content, e.g. with duplicate name map entries.
+    ///
+    /// [`Asset::from_json`] turns the value back into an asset that writes the same bytes as this one.
+    pub fn to_json(&self) -> Result<Value, Error> {
+        let name_map_hashes = self
+            .override_name_map_hashes
+            .iter()
+            .map(|(_, name, hash)| (name.clone(), Value::from(*hash)))
+            .collect::<Map<_, _>>();
+
+        let imports = self
+            .imports
+            .iter()
+            .map(|import| {
+                json!({
+                    "class_package": fname_to_json(&import.class_package),
+                    "class_name": fname_to_json(&import.class_name),
+                    "outer_index": import.outer_index.index,
+                    "object_name": fname_to_json(&import.object_name),
+                    "optional": import.optional,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let exports = self
+            .asset_data
+            .exports
+            .iter()
+            .map(|export| self.export_to_json(export))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // UE5.4+ property tags store complete type names, which aren't all part of the properties
+        let property_tag_types = self
+            .asset_data
+            .property_tag_types
+            .iter()
+            .map(|(_, (ancestry, name), tag_type)| {
+                json!({
+                    "name": fname_to_json(name),
+                    "ancestry": ancestry.ancestry.iter().map(fname_to_json).collect::<Vec<_>>(),
+                    "type_name": type_name_to_json(&tag_type.type_name),
+                    "binary_or_native": tag_type.binary_or_native,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(json!({
+            "summary": self.summary_to_json()?,
+            "names": self.name_map.get_ref().get_name_map_index_list(),
+            "name_map_hashes": name_map_hashes,
+            "imports": imports,
+            "exports": exports,
+            "property_tag_types": property_tag_types,
+        }))
+    }
+
+    /// Convert package summary and the header sections that aren't names, imports or exports to JSON
+    fn summary_to_json(&self) -> Result<Value, Error> {
+        let summary = &self.asset_data.summary;
+
+        let world_tile_info = match self.asset_data.world_tile_info {
+            Some(ref world_tile_info) => Some(hex(
+                &self.write_to_vec(|writer| world_tile_info.write(writer))?
+            )),
+            None => None,
+        };
+
+        Ok(json!({
+            "legacy_file_version": self.legacy_file_version,
+            "unversioned": summary.unversioned,
+            "object_version": self.asset_data.object_version as i32,
+            "object_version_ue5": self.asset_data.object_version_ue5 as i32,
+            "file_licensee_version": summary.file_licensee_version,
+            "custom_versions": summary
+                .custom_versions
+                .iter()
+                .map(|e| json!({ "guid": e.guid.to_string(), "version": e.version }))
+                .collect::<Vec<_>>(),
+            "package_flags": summary.package_flags.bits(),
+            "folder_name": self.folder_name,
+            "package_guid": self.package_guid.to_string(),
+            "generations": self
+                .generations
+                .iter()
+                .map(|e| json!({ "export_count": e.export_count, "name_count": e.name_count }))
+                .collect::<Vec<_>>(),
+            "engine_version_recorded": engine_version_to_json(&self.engine_version_recorded),
+            "engine_version_compatible": engine_version_to_json(&self.engine_version_compatible),
+            "compression_flags": self.compression_flags,
+            "package_source": self.package_source,
+            "chunk_ids": self.chunk_ids,
+            "soft_object_paths_count": self.soft_object_paths_count,
+            "soft_object_paths_offset": self.soft_object_paths_offset,
+            "gatherable_text_data_count": self.gatherable_text_data_count,
+            "gatherable_text_data_offset": self.gatherable_text_data_offset,
+            "soft_package_reference_count": self.soft_package_reference_count,
+            "searchable_names_offset": self.searchable_names_offset,
+            "names_referenced_from_export_data_count": self.names_referenced_from_export_data_count,
+            "payload_toc_offset": self.payload_toc_offset,
+            "data_resource_offset": self.data_resource_offset,
+            "has_asset_registry_data": self.asset_registry_data_offset != 0,
+            "use_event_driven_loader": self.asset_data.use_event_driven_loader,
+            "depends_map": self.depends_map,
+            "soft_package_references": self.soft_package_reference_list,
+            "thumbnails": self
+                .thumbnails
+                .iter()
+                .map(|e| {
+                    json!({
+                        "object_class_name": e.object_class_name,
+                        "object_path": e.object_path,
+                        "image_width": e.image_width,
+                        "image_height": e.image_height,
+                        "is_jpeg": e.is_jpeg,
+                        "compressed_image_data": hex(&e.compressed_image_data),
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "world_tile_info": world_tile_info,
+        }))
+    }
+
+    /// Convert an export to JSON
+    ///
+    /// Serial sizes, offsets and dependency offsets aren't stored, they are recomputed when writing.
+    fn export_to_json(&self, export: &Export<PackageIndex>) -> Result<Value, Error> {
+        let base = export.get_base_export();
+        let dependencies = |list: &[PackageIndex]| list.iter().map(|e| e.index).collect::<Vec<_>>();
+
+        let mut value = json!({
+            "type": export_type(export),
+            "class_index": base.class_index.index,
+            "super_index": base.super_index.index,
+            "template_index": base.template_index.index,
+            "outer_index": base.outer_index.index,
+            "object_name": fname_to_json(&base.object_name),
+            "object_flags": base.object_flags.bits(),
+            "forced_export": base.forced_export,
+            "not_for_client": base.not_for_client,
+            "not_for_server": base.not_for_server,
+            "package_guid": base.package_guid.to_string(),
+            "is_inherited_instance": base.is_inherited_instance,
+            "package_flags": base.package_flags,
+            "not_always_loaded_for_editor_game": base.not_always_loaded_for_editor_game,
+            "is_asset": base.is_asset,
+            "generate_public_hash": base.generate_public_hash,
+            "public_export_hash": base.public_export_hash,
+            "script_serialization_start_offset": base.script_serialization_start_offset,
+            "script_serialization_end_offset": base.script_serialization_end_offset,
+            "serialization_before_serialization_dependencies":
+                dependencies(&base.serialization_before_serialization_dependencies),
+            "create_before_serialization_dependencies":
+                dependencies(&base.create_before_serialization_dependencies),
+            "serialization_before_create_dependencies":
+                dependencies(&base.serialization_before_create_dependencies),
+            "create_before_create_dependencies":
+                dependencies(&base.create_before_create_dependencies),
+        });
+
+        let Some(normal_export) = export.get_normal_export() else {
+            value["data"] = hex(&self.write_export(export)?);
+            return Ok(value);
+        };
+
+        value["properties"] = self.properties_to_json(&normal_export.properties)?;
+        match export {
+            Export::NormalExport(_) => value["extras"] = hex(&normal_export.extras),
+            Export::DataTableExport(data_table) => {
+                value["rows"] = data_table
+                    .table
+                    .data
+                    .iter()
+                    .map(|e| self.struct_to_json(e))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into();
+                value["extras"] = hex(&normal_export.extras);
+            }
+            _ => value["data"] = hex(&self.export_data(export)?),
+        }
+
+        Ok(value)
+    }
+
+    /// Write the data that follows the properties of an export, including its extras
+    ///
+    /// The properties are left out of the export before it's written, so they aren't serialized.
+    fn export_data(&self, export: &Export<PackageIndex>) -> Result<Vec<u8>, Error> {
+        let mut export = export.clone();
+        let Some(normal_export) = export.get_normal_export_mut() else {
+            return self.write_export(&export);
+        };
+        normal_export.properties.clear();
+
+        // what an export without properties writes before its own data
+        let properties_end = self
+            .write_export(&Export::NormalExport(NormalExport {
+                base_export: normal_export.base_export.clone(),
+                extras: Vec::new(),
+                properties: Vec::new(),
+            }))?
+            .len();
+
+        let mut data = self.write_export(&export)?;
+        data.drain(..properties_end);
+        Ok(data)
+    }
+
+    /// Convert a property to JSON
+    fn property_to_json(&self, property: &Property) -> Result<Value, Error> {
+        if !has_finite_floats(property) {
+            return self.unknown_property_to_json(property);
+        }
+
+        let (mut object, value) = match property {
+            Property::BoolProperty(e) => (tag_to_json("BoolProperty", e), e.value.into()),
+            Property::Int8Property(e) => (tag_to_json("Int8Property", e), e.value.into()),
+            Property::Int16Property(e) => (tag_to_json("Int16Property", e), e.value.into()),
+            Property::IntProperty(e) => (tag_to_json("IntProperty", e), e.value.into()),
+            Property::Int64Property(e) => (tag_to_json("Int64Property", e), e.value.into()),
+            Property::UInt16Property(e) => (tag_to_json("UInt16Property", e), e.value.into()),
+            Property::UInt32Property(e) => (tag_to_json("UInt32Property", e), e.value.into()),
+            Property::UInt64Property(e) => (tag_to_json("UInt64Property", e), e.value.into()),
+            Property::FloatProperty(e) => (tag_to_json("FloatProperty", e), e.value.0.into()),
+            Property::DoubleProperty(e) => (tag_to_json("DoubleProperty", e), e.value.0.into()),
+            Property::StrProperty(e) => (tag_to_json("StrProperty", e), e.value.clone().into()),
+            Property::NameProperty(e) => (tag_to_json("NameProperty", e), fname_to_json(&e.value)),
+            Property::ObjectProperty(e) => (tag_to_json("ObjectProperty", e), e.value.index.into()),
+            Property::ByteProperty(e) => {
+                let mut object = tag_to_json("ByteProperty", e);
+                object.insert("enum_type".into(), fname_opt_to_json(&e.enum_type));
+                let value = match e.value {
+                    BytePropertyValue::Byte(value) => value.into(),
+                    BytePropertyValue::FName(ref value) => fname_to_json(value),
+                };
+                (object, value)
+            }
+            Property::EnumProperty(e) => {
+                let mut object = tag_to_json("EnumProperty", e);
+                object.insert("enum_type".into(), fname_opt_to_json(&e.enum_type));
+                object.insert("inner_type".into(), fname_opt_to_json(&e.inner_type));
+                (object, fname_opt_to_json(&e.value))
+            }
+            Property::StructProperty(e) => return self.struct_to_json(e),
+            Property::ArrayProperty(e) => return self.array_to_json(e),
+            Property::SetProperty(e) => {
+                let mut object = tag_to_json("SetProperty", e);
+                object.insert("array_type".into(), fname_opt_to_json(&e.array_type));
+                object.insert(
+                    "removed_items".into(),
+                    self.array_to_json(&e.removed_items)?,
+                );
+                (object, self.array_to_json(&e.value)?)
+            }
+            Property::MapProperty(e) => {
+                let mut object = tag_to_json("MapProperty", e);
+                object.insert("key_type".into(), fname_to_json(&e.key_type));
+                object.insert("value_type".into(), fname_to_json(&e.value_type));
+                object.insert(
+                    "keys_to_remove".into(),
+                    match e.keys_to_remove {
+                        Some(ref keys) => self.properties_to_json(keys)?,
+                        None => Value::Null,
+                    },
+                );
+                let value = e
+                    .value
+                    .iter()
+                    .map(|(_, key, value)| {
+                        Ok(json!([
+                            self.property_to_json(key)?,
+                            self.property_to_json(value)?
+                        ]))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                (object, value.into())
+            }
+            Property::TextProperty(e) => {
+                let mut object = tag_to_json("TextProperty", e);
+                object.insert(
+                    "culture_invariant_string".into(),
+                    e.culture_invariant_string.clone().into(),
+                );
+                object.insert("namespace".into(), e.namespace.clone().into());
+                object.insert("table_id".into(), fname_opt_to_json(&e.table_id));
+                object.insert("flags".into(), e.flags.into());
+                object.insert("history_type".into(), (e.history_type as i8).into());
+                (object, e.value.clone().into())
+            }
+            Property::SoftObjectProperty(e) => {
+                let value = json!({
+                    "package_name": fname_opt_to_json(&e.value.asset_path.package_name),
+                    "asset_name": fname_to_json(&e.value.asset_path.asset_name),
+                    "sub_path_string": e.value.sub_path_string,
+                });
+                (tag_to_json("SoftObjectProperty", e), value)
+            }
+            Property::GuidProperty(e) => {
+                (tag_to_json("GuidProperty", e), e.value.to_string().into())
+            }
+            Property::VectorProperty(e) => {
+                let Vector { x, y, z } = e.value;
+                (tag_to_json("VectorProperty", e), json!([x.0, y.0, z.0]))
+            }
+            Property::RotatorProperty(e) => {
+                let Vector { x, y, z } = e.value;
+                (tag_to_json("RotatorProperty", e), json!([x.0, y.0, z.0]))
+            }
+            Property::Vector2DProperty(e) => {
+                let Vector2 { x, y } = e.value;
+                (tag_to_json("Vector2DProperty", e), json!([x.0, y.0]))
+            }
+            Property::Vector4Property(e) => {
+                let Vector4 { x, y, z, w } = e.value;
+                (
+                    tag_to_json("Vector4Property", e),
+                    json!([x.0, y.0, z.0, w.0]),
+                )
+            }
+            Property::QuatProperty(e) => {
+                let Vector4 { x, y, z, w } = e.value;
+                (tag_to_json("QuatProperty", e), json!([x.0, y.0, z.0, w.0]))
+            }
+            Property::LinearColorProperty(e) => {
+                let Color { r, g, b, a } = e.color;
+                (
+                    tag_to_json("LinearColorProperty", e),
+                    json!([r.0, g.0, b.0, a.0]),
+                )
+            }
+            Property::ColorProperty(e) => {
+                let Color { r, g, b, a } = e.color;
+                (tag_to_json("ColorProperty", e), json!([r, g, b, a]))
+            }
+            _ => return self.unknown_property_to_json(property),
+        };
+
+        object.insert("value".into(), value);
+        Ok(object.into())
+    }
+
+    /// Convert a property that has no JSON representation to JSON
+    ///
+    /// The property is stored with its serialized type and its value serialized without a header as hex.
+    fn unknown_property_to_json(&self, property: &Property) -> Result<Value, Error> {
+        let mut object = tag_to_json(&property.to_serialized_name(), property);
+        let data = self.write_to_vec(|writer| {
+            PropertyTrait::write(property, writer, false)?;
+            Ok(())
+        })?;
+        object.insert("data".into(), hex(&data));
+        Ok(object.into())
+    }
+
+    /// Convert a struct property to JSON
+    fn struct_to_json(&self, property: &StructProperty) -> Result<Value, Error> {
+        let mut object = tag_to_json("StructProperty", property);
+        object.insert(
+            "struct_type".into(),
+            fname_opt_to_json(&property.struct_type),
+        );
+        object.insert(
+            "struct_guid".into(),
+            guid_opt_to_json(&property.struct_guid),
+        );
+        object.insert("serialize_none".into(), property.serialize_none.into());
+        object.insert("value".into(), self.properties_to_json(&property.value)?);
+        Ok(object.into())
+    }
+
+    /// Convert an array property to JSON
+    fn array_to_json(&self, property: &ArrayProperty) -> Result<Value, Error> {
+        let mut object = tag_to_json("ArrayProperty", property);
+        object.insert("array_type".into(), fname_opt_to_json(&property.array_type));
+        object.insert(
+            "dummy_property".into(),
+            match property.dummy_property {
+                Some(ref dummy_property) => self.struct_to_json(dummy_property)?,
+                None => Value::Null,
+            },
+        );
+        object.insert("value".into(), self.properties_to_json(&property.value)?);
+        Ok(object.into())
+    }
+
+    /// Convert a list of properties to a JSON array
+    fn properties_to_json(&self, properties: &[Property]) -> Result<Value, Error> {
+        properties
+            .iter()
+            .map(|e| self.property_to_json(e))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    }
+}
+
+impl Asset<Cursor<Vec<u8>>> {
+    /// Reconstruct an asset from JSON produced by [`Asset::to_json`]
+    ///
+    /// The asset is serialized from the JSON values and parsed again with `engine_version` and `mappings`,
+    /// so exports are parsed just like they would be when reading the asset from a file.
+    /// Assets with unversioned properties need the mappings they were read with.
+    pub fn from_json(
+        value: &Value,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Result<Self, Error> {
+        let mut asset = Self::from_chain(
+            Chain::new(Cursor::new(Vec::new()), None),
+            engine_version,
+            mappings.clone(),
+            ParseOptions::default(),
+            HashMap::new(),
+        );
+
+        for name in as_array(field(value, "names")?)? {
+            asset.add_name_reference(as_str(name)?.to_string(), true);
+        }
+
+        for (name, hash) in as_object(field(value, "name_map_hashes")?)? {
+            asset
+                .override_name_map_hashes
+                .insert(name.clone(), as_uint(hash)?);
+        }
+
+        asset.summary_from_json(field(value, "summary")?)?;
+
+        asset.imports = as_array(field(value, "imports")?)?
+            .iter()
+            .map(|import| {
+                Ok(Import::new(
+                    fname_from_json(field(import, "class_package")?, &asset.name_map)?,
+                    fname_from_json(field(import, "class_name")?, &asset.name_map)?,
+                    PackageIndex::new(as_int(field(import, "outer_index")?)?),
+                    fname_from_json(field(import, "object_name")?, &asset.name_map)?,
+                    as_bool(field(import, "optional")?)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        asset.asset_data.exports = as_array(field(value, "exports")?)?
+            .iter()
+            .map(|export| export_from_json(export, &asset.name_map))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for tag_type in as_array(field(value, "property_tag_types")?)? {
+            let ancestry = Ancestry {
+                ancestry: as_array(field(tag_type, "ancestry")?)?
+                    .iter()
+                    .map(|e| fname_from_json(e, &asset.name_map))
+                    .collect::<Result<Vec<_>, _>>()?,
+            };
+            asset.asset_data.property_tag_types.insert(
+                (
+                    ancestry,
+                    fname_from_json(field(tag_type, "name")?, &asset.name_map)?,
+                ),
+                PropertyTagType {
+                    type_name: type_name_from_json(field(tag_type, "type_name")?, &asset.name_map)?,
+                    binary_or_native: as_bool(field(tag_type, "binary_or_native")?)?,
+                },
+            );
+        }
+
+        let use_event_driven_loader = asset.asset_data.use_event_driven_loader;
+        let mut cursor = Cursor::new(Vec::new());
+        let mut bulk_cursor = Cursor::new(Vec::new());
+        asset.write_data(
+            &mut cursor,
+            use_event_driven_loader.then_some(&mut bulk_cursor),
+        )?;
+
+        Asset::new(
+            Cursor::new(cursor.into_inner()),
+            use_event_driven_loader.then(|| Cursor::new(bulk_cursor.into_inner())),
+            engine_version,
+            mappings,
+        )
+    }
+
+    /// Read package summary and the header sections that aren't names, imports or exports from JSON
+    fn summary_from_json(&mut self, value: &Value) -> Result<(), Error> {
+        self.legacy_file_version = as_int(field(value, "legacy_file_version")?)?;
+        self.asset_data.summary.unversioned = as_bool(field(value, "unversioned")?)?;
+        if !self.asset_data.summary.unversioned {
+            self.asset_data.object_version =
+                as_int::<i32>(field(value, "object_version")?)?.try_into()?;
+            self.asset_data.object_version_ue5 =
+                as_int::<i32>(field(value, "object_version_ue5")?)?.try_into()?;
+        }
+        self.asset_data.summary.file_licensee_version =
+            as_int(field(value, "file_licensee_version")?)?;
+        self.asset_data.summary.custom_versions = as_array(field(value, "custom_versions")?)?
+            .iter()
+            .map(|e| {
+                Ok(CustomVersion::new(
+                    as_guid(field(e, "guid")?)?,
+                    as_int(field(e, "version")?)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.asset_data.summary.package_flags =
+            EPackageFlags::from_bits(as_uint(field(value, "package_flags")?)?)
+                .ok_or_else(|| Error::invalid_file("Invalid package flags".to_string()))?;
+
+        self.folder_name = as_str(field(value, "folder_name")?)?.to_string();
+        self.package_guid = as_guid(field(value, "package_guid")?)?;
+        self.generations = as_array(field(value, "generations")?)?
+            .iter()
+            .map(|e| {
+                Ok(GenerationInfo {
+                    export_count: as_int(field(e, "export_count")?)?,
+                    name_count: as_int(field(e, "name_count")?)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.engine_version_recorded =
+            engine_version_from_json(field(value, "engine_version_recorded")?)?;
+        self.engine_version_compatible =
+            engine_version_from_json(field(value, "engine_version_compatible")?)?;
+        self.compression_flags = as_uint(field(value, "compression_flags")?)?;
+        self.package_source = as_uint(field(value, "package_source")?)?;
+        self.chunk_ids = as_array(field(value, "chunk_ids")?)?
+            .iter()
+            .map(as_int)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.soft_object_paths_count = as_int(field(value, "soft_object_paths_count")?)?;
+        self.soft_object_paths_offset = as_int(field(value, "soft_object_paths_offset")?)?;
+        self.gatherable_text_data_count = as_int(field(value, "gatherable_text_data_count")?)?;
+        self.gatherable_text_data_offset = as_int(field(value, "gatherable_text_data_offset")?)?;
+        self.soft_package_reference_count = as_int(field(value, "soft_package_reference_count")?)?;
+        self.searchable_names_offset = as_int(field(value, "searchable_names_offset")?)?;
+        self.names_referenced_from_export_data_count =
+            as_int(field(value, "names_referenced_from_export_data_count")?)?;
+        self.payload_toc_offset = as_int(field(value, "payload_toc_offset")?)?;
+        self.data_resource_offset = as_int(field(value, "data_resource_offset")?)?;
+        // the asset registry data itself isn't kept, it is always written empty
+        self.asset_registry_data_offset = as_bool(field(value, "has_asset_registry_data")?)? as i32;
+        self.asset_data.use_event_driven_loader =
+            as_bool(field(value, "use_event_driven_loader")?)?;
+
+        self.depends_map = match field(value, "depends_map")? {
+            Value::Null => None,
+            depends_map => Some(
+                as_array(depends_map)?
+                    .iter()
+                    .map(|e| as_array(e)?.iter().map(as_int).collect())
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+        self.soft_package_reference_list = match field(value, "soft_package_references")? {
+            Value::Null => None,
+            references => Some(
+                as_array(references)?
+                    .iter()
+                    .map(|e| as_str(e).map(str::to_string))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+        self.thumbnails = as_array(field(value, "thumbnails")?)?
+            .iter()
+            .map(|e| {
+                Ok(AssetThumbnail {
+                    object_class_name: as_str(field(e, "object_class_name")?)?.to_string(),
+                    object_path: as_str(field(e, "object_path")?)?.to_string(),
+                    image_width: as_int(field(e, "image_width")?)?,
+                    image_height: as_int(field(e, "image_height")?)?,
+                    is_jpeg: as_bool(field(e, "is_jpeg")?)?,
+                    compressed_image_data: from_hex(field(e, "compressed_image_data")?)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.asset_data.world_tile_info = match field(value, "world_tile_info")? {
+            Value::Null => None,
+            world_tile_info => {
+                let data = from_hex(world_tile_info)?;
+                Some(FWorldTileInfo::new(&mut BufferReader::new(self, &data))?)
+            }
+        };
+
+        Ok(())
+    }
+}
+
+/// Read an export from JSON
+///
+/// Exports of types that have no JSON representation are read as [`NormalExport`]s
+/// with the data that follows their properties as extras.
+fn export_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<Export<PackageIndex>, Error> {
+    let dependencies = |key: &str| -> Result<Vec<PackageIndex>, Error> {
+        as_array(field(value, key)?)?
+            .iter()
+            .map(|e| as_int(e).map(PackageIndex::new))
+            .collect()
+    };
+
+    let base_export = BaseExport {
+        class_index: PackageIndex::new(as_int(field(value, "class_index")?)?),
+        super_index: PackageIndex::new(as_int(field(value, "super_index")?)?),
+        template_index: PackageIndex::new(as_int(field(value, "template_index")?)?),
+        outer_index: PackageIndex::new(as_int(field(value, "outer_index")?)?),
+        object_name: fname_from_json(field(value, "object_name")?, name_map)?,
+        object_flags: EObjectFlags::from_bits(as_uint(field(value, "object_flags")?)?)
+            .ok_or_else(|| Error::invalid_file("Invalid object flags".to_string()))?,
+        forced_export: as_bool(field(value, "forced_export")?)?,
+        not_for_client: as_bool(field(value, "not_for_client")?)?,
+        not_for_server: as_bool(field(value, "not_for_server")?)?,
+        package_guid: as_guid(field(value, "package_guid")?)?,
+        is_inherited_instance: as_bool(field(value, "is_inherited_instance")?)?,
+        package_flags: as_uint(field(value, "package_flags")?)?,
+        not_always_loaded_for_editor_game: as_bool(field(
+            value,
+            "not_always_loaded_for_editor_game",
+        )?)?,
+        is_asset: as_bool(field(value, "is_asset")?)?,
+        generate_public_hash: as_bool(field(value, "generate_public_hash")?)?,
+        public_export_hash: as_uint(field(value, "public_export_hash")?)?,
+        script_serialization_start_offset: as_int(field(
+            value,
+            "script_serialization_start_offset",
+        )?)?,
+        script_serialization_end_offset: as_int(field(value, "script_serialization_end_offset")?)?,
+        serialization_before_serialization_dependencies: dependencies(
+            "serialization_before_serialization_dependencies",
+        )?,
+        create_before_serialization_dependencies: dependencies(
+            "create_before_serialization_dependencies",
+        )?,
+        serialization_before_create_dependencies: dependencies(
+            "serialization_before_create_dependencies",
+        )?,
+        create_before_create_dependencies: dependencies("create_before_create_dependencies")?,
+        ..Default::default()
+    };
+
+    // exports without properties, e.g. exports that failed to parse, are stored as their serialized data
+    let Some(properties) = value.get("properties") else {
+        return Ok(Export::RawExport(RawExport {
+            base_export,
+            data: from_hex(field(value, "data")?)?,
+        }));
+    };
+    let properties = properties_from_json(properties, name_map)?;
+
+    Ok(match as_str(field(value, "type")?)? {
+        "NormalExport" => Export::NormalExport(NormalExport {
+            base_export,
+            extras: from_hex(field(value, "extras")?)?,
+            properties,
+        }),
+        "DataTableExport" => Export::DataTableExport(DataTableExport {
+            normal_export: NormalExport {
+                base_export,
+                extras: from_hex(field(value, "extras")?)?,
+                properties,
+            },
+            table: DataTable::new(
+                as_array(field(value, "rows")?)?
+                    .iter()
+                    .map(|e| struct_from_json(e, name_map))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        }),
+        // the export's own data is written after its properties like extras,
+        // the export is read as its actual type when the asset is parsed again
+        _ => Export::NormalExport(NormalExport {
+            base_export,
+            extras: from_hex(field(value, "data")?)?,
+            properties,
+        }),
+    })
+}
+
+/// Name of the export type
+fn export_type(export: &Export<PackageIndex>) -> &'static str {
+    match export {
+        Export::BaseExport(_) => "BaseExport",
+        Export::ClassExport(_) => "ClassExport",
+        Export::EnumExport(_) => "EnumExport",
+        Export::LevelExport(_) => "LevelExport",
+        Export::NormalExport(_) => "NormalExport",
+        Export::PropertyExport(_) => "PropertyExport",
+        Export::RawExport(_) => "RawExport",
+        Export::StringTableExport(_) => "StringTableExport",
+        Export::StructExport(_) => "StructExport",
+        Export::UserDefinedStructExport(_) => "UserDefinedStructExport",
+        Export::FunctionExport(_) => "FunctionExport",
+        Export::DataTableExport(_) => "DataTableExport",
+        Export::WorldExport(_) => "WorldExport",
+    }
+}
+
+/// Check that all floats of a property are finite, other floats have no JSON representation
+fn has_finite_floats(property: &Property) -> bool {
+    match property {
+        Property::FloatProperty(e) => e.value.is_finite(),
+        Property::DoubleProperty(e) => e.value.is_finite(),
+        Property::VectorProperty(e) => [e.value.x, e.value.y, e.value.z]
+            .iter()
+            .all(|e| e.is_finite()),
+        Property::RotatorProperty(e) => [e.value.x, e.value.y, e.value.z]
+            .iter()
+            .all(|e| e.is_finite()),
+        Property::Vector2DProperty(e) => [e.value.x, e.value.y].iter().all(|e| e.is_finite()),
+        Property::Vector4Property(e) => [e.value.x, e.value.y, e.value.z, e.value.w]
+            .iter()
+            .all(|e| e.is_finite()),
+        Property::QuatProperty(e) => [e.value.x, e.value.y, e.value.z, e.value.w]
+            .iter()
+            .all(|e| e.is_finite()),
+        Property::LinearColorProperty(e) => [e.color.r, e.color.g, e.color.b, e.color.a]
+            .iter()
+            .all(|e| e.is_finite()),
+        _ => true,
+    }
+}
+
+/// Start a JSON object with the fields every property has
+fn tag_to_json(property_type: &str, property: &impl PropertyDataTrait) -> Map<String, Value> {
+    let mut object = Map::new();
+    object.insert("type".into(), property_type.into());
+    object.insert("name".into(), fname_to_json(&property.get_name_ref()));
+    // unversioned properties are looked up in the mappings by their ancestry
+    object.insert(
+        "ancestry".into(),
+        property
+            .get_ancestry()
+            .ancestry
+            .iter()
+            .map(fname_to_json)
+            .collect(),
+    );
+    object.insert(
+        "duplication_index".into(),
+        property.get_duplication_index().into(),
+    );
+    object.insert(
+        "property_guid".into(),
+        guid_opt_to_json(&property.get_property_guid()),
+    );
+    object
+}
+
+/// Fields every property has
+struct PropertyTag {
+    /// Name
+    name: FName,
+    /// Ancestry
+    ancestry: Ancestry,
+    /// Property guid
+    property_guid: Option<Guid>,
+    /// Duplication index
+    duplication_index: i32,
+}
+
+impl PropertyTag {
+    /// Read property tag fields from JSON
+    fn from_json(value: &Value, name_map: &SharedResource<NameMap>) -> Result<Self, Error> {
+        Ok(PropertyTag {
+            name: fname_from_json(field(value, "name")?, name_map)?,
+            ancestry: Ancestry {
+                ancestry: as_array(field(value, "ancestry")?)?
+                    .iter()
+                    .map(|e| fname_from_json(e, name_map))
+                    .collect::<Result<Vec<_>, _>>()?,
+            },
+            property_guid: as_guid_opt(field(value, "property_guid")?)?,
+            duplication_index: as_int(field(value, "duplication_index")?)?,
+        })
+    }
+}
+
+/// Read a property from JSON
+fn property_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<Property, Error> {
+    let property_type = as_str(field(value, "type")?)?;
+    if let Some(data) = value.get("data") {
+        let tag = PropertyTag::from_json(value, name_map)?;
+        return Ok(Property::UnknownProperty(UnknownProperty {
+            name: tag.name,
+            ancestry: tag.ancestry,
+            property_guid: tag.property_guid,
+            duplication_index: tag.duplication_index,
+            value: from_hex(data)?,
+            // never written as a name, properties write their serialized type as a new name
+            serialized_type: FName::new_dummy(property_type.to_string(), 0),
+        }));
+    }
+
+    match property_type {
+        "StructProperty" => return Ok(struct_from_json(value, name_map)?.into()),
+        "ArrayProperty" => return Ok(array_from_json(value, name_map)?.into()),
+        _ => {}
+    }
+
+    let tag = PropertyTag::from_json(value, name_map)?;
+    let data = field(value, "value")?;
+    let fname_opt = |key: &str| fname_opt_from_json(field(value, key)?, name_map);
+
+    macro_rules! property {
+        ($property:ident { $($field:ident: $value:expr),* $(,)? }) => {
+            Property::$property($property {
+                name: tag.name,
+                ancestry: tag.ancestry,
+                property_guid: tag.property_guid,
+                duplication_index: tag.duplication_index,
+                $($field: $value),*
+            })
+        };
+    }
+
+    Ok(match property_type {
+        "BoolProperty" => property!(BoolProperty {
+            value: as_bool(data)?
+        }),
+        "Int8Property" => property!(Int8Property {
+            value: as_int(data)?
+        }),
+        "Int16Property" => property!(Int16Property {
+            value: as_int(data)?
+        }),
+        "IntProperty" => property!(IntProperty {
+            value: as_int(data)?
+        }),
+        "Int64Property" => property!(Int64Property {
+            value: as_int(data)?
+        }),
+        "UInt16Property" => property!(UInt16Property {
+            value: as_uint(data)?
+        }),
+        "UInt32Property" => property!(UInt32Property {
+            value: as_uint(data)?
+        }),
+        "UInt64Property" => property!(UInt64Property {
+            value: as_uint(data)?
+        }),
+        "FloatProperty" => property!(FloatProperty {
+            value: (as_f64(data)? as f32).into()
+        }),
+        "DoubleProperty" => property!(DoubleProperty {
+            value: as_f64(data)?.into()
+        }),
+        "StrProperty" => property!(StrProperty {
+            value: as_string_opt(data)?
+        }),
+        "NameProperty" => property!(NameProperty {
+            value: fname_from_json(data, name_map)?
+        }),
+        "ObjectProperty" => property!(ObjectProperty {
+            value: PackageIndex::new(as_int(data)?)
+        }),
+        "ByteProperty" => property!(ByteProperty {
+            enum_type: fname_opt("enum_type")?,
+            value: match data.is_number() {
+                true => BytePropertyValue::Byte(as_uint(data)?),
+                false => BytePropertyValue::FName(fname_from_json(data, name_map)?),
+            },
+        }),
+        "EnumProperty" => property!(EnumProperty {
+            enum_type: fname_opt("enum_type")?,
+            inner_type: fname_opt("inner_type")?,
+            value: fname_opt_from_json(data, name_map)?,
+        }),
+        "SetProperty" => property!(SetProperty {
+            array_type: fname_opt("array_type")?,
+            value: array_from_json(data, name_map)?,
+            removed_items: array_from_json(field(value, "removed_items")?, name_map)?,
+        }),
+        "MapProperty" => property!(MapProperty {
+            key_type: fname_from_json(field(value, "key_type")?, name_map)?,
+            value_type: fname_from_json(field(value, "value_type")?, name_map)?,
+            value: as_array(data)?
+                .iter()
+                .map(|e| match as_array(e)?.as_slice() {
+                    [key, value] => Ok((
+                        property_from_json(key, name_map)?,
+                        property_from_json(value, name_map)?
+                    )),
+                    _ => Err(Error::invalid_file(
+                        "Map entries in JSON must be [key, value] arrays".to_string()
+                    )),
+                })
+                .collect::<Result<IndexedMap<_, _>, Error>>()?,
+            keys_to_remove: match field(value, "keys_to_remove")? {
+                Value::Null => None,
+                keys => Some(properties_from_json(keys, name_map)?),
+            },
+        }),
+        "TextProperty" => {
+            let history_type = field(value, "history_type")?;
+            property!(TextProperty {
+                culture_invariant_string: as_string_opt(field(value, "culture_invariant_string")?)?,
+                namespace: as_string_opt(field(value, "namespace")?)?,
+                table_id: fname_opt("table_id")?,
+                flags: as_uint(field(value, "flags")?)?,
+                history_type: TextHistoryType::try_from(as_int::<i8>(history_type)?)
+                    .map_err(|_| invalid_json("a text history type", history_type))?,
+                value: as_string_opt(data)?,
+            })
+        }
+        "SoftObjectProperty" => property!(SoftObjectProperty {
+            value: SoftObjectPath {
+                asset_path: TopLevelAssetPath {
+                    package_name: fname_opt_from_json(field(data, "package_name")?, name_map)?,
+                    asset_name: fname_from_json(field(data, "asset_name")?, name_map)?,
+                },
+                sub_path_string: as_string_opt(field(data, "sub_path_string")?)?,
+            },
+        }),
+        "GuidProperty" => property!(GuidProperty {
+            value: as_guid(data)?
+        }),
+        "VectorProperty" => {
+            let [x, y, z] = as_floats(data)?;
+            property!(VectorProperty {
+                value: Vector::new(x.into(), y.into(), z.into())
+            })
+        }
+        "RotatorProperty" => {
+            let [x, y, z] = as_floats(data)?;
+            property!(RotatorProperty {
+                value: Vector::new(x.into(), y.into(), z.into())
+            })
+        }
+        "Vector2DProperty" => {
+            let [x, y] = as_floats(data)?;
+            property!(Vector2DProperty {
+                value: Vector2::new(x.into(), y.into())
+            })
+        }
+        "Vector4Property" => {
+            let [x, y, z, w] = as_floats(data)?;
+            property!(Vector4Property {
+                value: Vector4::new(x.into(), y.into(), z.into(), w.into())
+            })
+        }
+        "QuatProperty" => {
+            let [x, y, z, w] = as_floats(data)?;
+            property!(QuatProperty {
+                value: Vector4::new(x.into(), y.into(), z.into(), w.into())
+            })
+        }
+        "LinearColorProperty" => {
+            let [r, g, b, a] = as_floats(data)?.map(|e| (e as f32).into());
+            property!(LinearColorProperty {
+                color: Color::new(r, g, b, a)
+            })
+        }
+        "ColorProperty" => {
+            let [r, g, b, a] = as_fixed_array(data, as_uint)?;
+            property!(ColorProperty {
+                color: Color::new(r, g, b, a)
+            })
+        }
+        _ => {
+            return Err(Error::invalid_file(format!(
+                "Property type {property_type} can't be read from JSON"
+            )))
+        }
+    })
+}
+
+/// Read a struct property from JSON
+fn struct_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<StructProperty, Error> {
+    let tag = PropertyTag::from_json(value, name_map)?;
+    Ok(StructProperty {
+        name: tag.name,
+        ancestry: tag.ancestry,
+        struct_type: fname_opt_from_json(field(value, "struct_type")?, name_map)?,
+        struct_guid: as_guid_opt(field(value, "struct_guid")?)?,
+        property_guid: tag.property_guid,
+        duplication_index: tag.duplication_index,
+        serialize_none: as_bool(field(value, "serialize_none")?)?,
+        value: properties_from_json(field(value, "value")?, name_map)?,
+    })
+}
+
+/// Read an array property from JSON
+fn array_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<ArrayProperty, Error> {
+    let tag = PropertyTag::from_json(value, name_map)?;
+    Ok(ArrayProperty {
+        name: tag.name,
+        ancestry: tag.ancestry,
+        property_guid: tag.property_guid,
+        duplication_index: tag.duplication_index,
+        array_type: fname_opt_from_json(field(value, "array_type")?, name_map)?,
+        value: properties_from_json(field(value, "value")?, name_map)?,
+        dummy_property: match field(value, "dummy_property")? {
+            Value::Null => None,
+            dummy_property => Some(struct_from_json(dummy_property, name_map)?),
+        },
+    })
+}
+
+/// Read a JSON array of properties
+fn properties_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<Vec<Property>, Error> {
+    as_array(value)?
+        .iter()
+        .map(|e| property_from_json(e, name_map))
+        .collect()
+}
+
+/// Convert an `FName` to JSON
+fn fname_to_json(name: &FName) -> Value {
+    let (content, number) = (name.get_owned_content(), name.get_number());
+    let index = match name {
+        FName::Backed {
+            index, name_map, ..
+        } => match name_map.get_ref().search_name_reference(&content) == Some(*index) {
+            true => None,
+            false => Some(*index),
+        },
+        FName::Dummy { .. } => None,
+    };
+    let dummy = matches!(name, FName::Dummy { .. });
+
+    if number == 0 && index.is_none() && !dummy {
+        return Value::String(content);
+    }
+
+    let mut object = Map::new();
+    object.insert("name".into(), content.into());
+    object.insert("number".into(), number.into());
+    if let Some(index) = index {
+        object.insert("index".into(), index.into());
+    }
+    if dummy {
+        object.insert("dummy".into(), true.into());
+    }
+    object.into()
+}
+
+/// Convert an optional `FName` to JSON
+fn fname_opt_to_json(name: &Option<FName>) -> Value {
+    name.as_ref().map(fname_to_json).unwrap_or(Value::Null)
+}
+
+/// Read an `FName` from JSON, names that aren't in the name map yet are added to it
+fn fname_from_json(value: &Value, name_map: &SharedResource<NameMap>) -> Result<FName, Error> {
+    let (content, number, index) = match value {
+        Value::String(content) => (content.as_str(), 0, None),
+        object => {
+            let content = as_str(field(object, "name")?)?;
+            let number = as_int(field(object, "number")?)?;
+            if let Some(true) = object.get("dummy").and_then(Value::as_bool) {
+                return Ok(FName::new_dummy(content.to_string(), number));
+            }
+            let index = match object.get("index") {
+                Some(index) => Some(as_int(index)?),
+                None => None,
+            };
+            (content, number, index)
+        }
+    };
+
+    let Some(index) = index else {
+        let found = name_map.get_ref().search_name_reference(content);
+        return Ok(match found {
+            Some(index) => name_map.get_ref().create_fname(index, number),
+            None => name_map
+                .clone()
+                .get_mut()
+                .add_fname_with_number(content, number),
+        });
+    };
+
+    let name_map = name_map.get_ref();
+    let names = name_map.get_name_map_index_list();
+    match usize::try_from(index).ok().and_then(|e| names.get(e)) {
+        Some(name) if name == content => Ok(name_map.create_fname(index, number)),
+        _ => Err(Error::invalid_file(format!(
+            "Name map index {index} of name {content} in JSON doesn't point to that name"
+        ))),
+    }
+}
+
+/// Read an optional `FName` from JSON
+fn fname_opt_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<Option<FName>, Error> {
+    match value {
+        Value::Null => Ok(None),
+        value => fname_from_json(value, name_map).map(Some),
+    }
+}
+
+/// Convert an engine version to JSON
+/// Convert a complete property type name to JSON
+fn type_name_to_json(type_name: &PropertyTypeName) -> Value {
+    json!({
+        "name": fname_to_json(&type_name.name),
+        "parameters": type_name.parameters.iter().map(type_name_to_json).collect::<Vec<_>>(),
+    })
+}
+
+/// Read a complete property type name from JSON
+fn type_name_from_json(
+    value: &Value,
+    name_map: &SharedResource<NameMap>,
+) -> Result<PropertyTypeName, Error> {
+    Ok(PropertyTypeName::new(
+        fname_from_json(field(value, "name")?, name_map)?,
+        as_array(field(value, "parameters")?)?
+            .iter()
+            .map(|e| type_name_from_json(e, name_map))
+            .collect::<Result<Vec<_>, _>>()?,
+    ))
+}
+
+fn engine_version_to_json(version: &FEngineVersion) -> Value {
+    json!({
+        "major": version.major,
+        "minor": version.minor,
+        "patch": version.patch,
+        "build": version.build,
+        "branch": version.branch,
+    })
+}
+
+/// Read an engine version from JSON
+fn engine_version_from_json(value: &Value) -> Result<FEngineVersion, Error> {
+    Ok(FEngineVersion::new(
+        as_uint(field(value, "major")?)?,
+        as_uint(field(value, "minor")?)?,
+        as_uint(field(value, "patch")?)?,
+        as_uint(field(value, "build")?)?,
+        as_string_opt(field(value, "branch")?)?,
+    ))
+}
+
+/// Convert an optional guid to JSON
+fn guid_opt_to_json(guid: &Option<Guid>) -> Value {
+    match guid {
+        Some(guid) => guid.to_string().into(),
+        None => Value::Null,
+    }
+}
+
+/// Encode bytes as a hex string
+fn hex(data: &[u8]) -> Value {
+    data.iter()
+        .map(|e| format!("{e:02x}"))
+        .collect::<String>()
+        .into()
+}
+
+/// Decode a hex string
+fn from_hex(value: &Value) -> Result<Vec<u8>, Error> {
+    let hex = as_str(value)?;
+    if hex.len() % 2 != 0 {
+        return Err(invalid_json("a hex string", value));
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|e| u8::from_str_radix(e, 16).ok())
+                .ok_or_else(|| invalid_json("a hex string", value))
+        })
+        .collect()
+}
+
+/// Get a field of a JSON object
+fn field<'a>(value: &'a Value, key: &str) -> Result<&'a Value, Error> {
+    value
+        .get(key)
+        .ok_or_else(|| Error::invalid_file(format!("JSON value has no {key} field")))
+}
+
+/// Create an error for a JSON value that doesn't have the expected type
+fn invalid_json(expected: &str, value: &Value) -> Error {
+    let got = match value {
+        Value::Null => "null".to_string(),
+        Value::Array(_) => "an array".to_string(),
+        Value::Object(_) => "an object".to_string(),
+        // scalars are short enough to be shown
+        value => value.to_string(),
+    };
+    Error::invalid_file(format!("Expected {expected} in JSON, got {got}"))
+}
+
+/// Read a JSON boolean
+fn as_bool(value: &Value) -> Result<bool, Error> {
+    value
+        .as_bool()
+        .ok_or_else(|| invalid_json("a boolean", value))
+}
+
+/// Read a signed JSON integer
+fn as_int<T: TryFrom<i64>>(value: &Value) -> Result<T, Error> {
+    value
+        .as_i64()
+        .and_then(|e| T::try_from(e).ok())
+        .ok_or_else(|| invalid_json("an integer in range", value))
+}
+
+/// Read an unsigned JSON integer
+fn as_uint<T: TryFrom<u64>>(value: &Value) -> Result<T, Error> {
+    value
+        .as_u64()
+        .and_then(|e| T::try_from(e).ok())
+        .ok_or_else(|| invalid_json("an unsigned integer in range", value))
+}
+
+/// Read a JSON number
+fn as_f64(value: &Value) -> Result<f64, Error> {
+    value
+        .as_f64()
+        .ok_or_else(|| invalid_json("a number", value))
+}
+
+/// Read a JSON string
+fn as_str(value: &Value) -> Result<&str, Error> {
+    value
+        .as_str()
+        .ok_or_else(|| invalid_json("a string", value))
+}
+
+/// Read a JSON string that can be null
+fn as_string_opt(value: &Value) -> Result<Option<String>, Error> {
+    match value {
+        Value::Null => Ok(None),
+        value => as_str(value).map(|e| Some(e.to_string())),
+    }
+}
+
+/// Read a guid from a JSON string
+fn as_guid(value: &Value) -> Result<Guid, Error> {
+    as_str(value)?
+        .parse()
+        .map_err(|_| invalid_json("a guid", value))
+}
+
+/// Read a guid from a JSON string that can be null
+fn as_guid_opt(value: &Value) -> Result<Option<Guid>, Error> {
+    match value {
+        Value::Null => Ok(None),
+        value => as_guid(value).map(Some),
+    }
+}
+
+/// Read a JSON array
+fn as_array(value: &Value) -> Result<&Vec<Value>, Error> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid_json("an array", value))
+}
+
+/// Read a JSON array with exactly `N` elements
+fn as_fixed_array<T, const N: usize>(
+    value: &Value,
+    element: impl Fn(&Value) -> Result<T, Error>,
+) -> Result<[T; N], Error> {
+    as_array(value)?
+        .iter()
+        .map(element)
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| invalid_json(&format!("an array of {N} elements"), value))
+}
+
+/// Read a JSON array of `N` numbers
+fn as_floats<const N: usize>(value: &Value) -> Result<[f64; N], Error> {
+    as_fixed_array(value, as_f64)
+}
+
+/// Read a JSON object
+fn as_object(value: &Value) -> Result<&Map<String, Value>, Error> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid_json("an object", value))
+}
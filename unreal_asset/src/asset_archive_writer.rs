@@ -9,10 +9,11 @@ use unreal_asset_base::{
     engine_version::EngineVersion,
     flags::EPackageFlags,
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
     passthrough_archive_writer,
     reader::{ArchiveTrait, ArchiveType, ArchiveWriter},
-    types::{FName, PackageIndex, PackageIndexTrait},
-    unversioned::Usmap,
+    types::{property_type_name::PropertyTagType, FName, PackageIndex, PackageIndexTrait},
+    unversioned::{Ancestry, Usmap},
     Error, Import,
 };
 use unreal_asset_exports::Export;
@@ -114,6 +115,12 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter<PackageIndex>> ArchiveT
         &self.asset_data.map_value_override
     }
 
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.asset_data
+            .property_tag_types
+            .get_by_key(&(ancestry.clone(), name.clone()))
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.asset_data.get_engine_version()
     }
@@ -130,6 +137,10 @@ impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter<PackageIndex>> ArchiveT
         self.asset_data.mappings.as_ref()
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.asset_data.parse_options
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.asset_data
             .exports
@@ -1,6 +1,7 @@
 //! Generic unreal asset traits
 //! Must be implemented for all unreal assets
 
+use std::collections::HashMap;
 use std::io::SeekFrom;
 
 use unreal_asset_base::{
@@ -8,11 +9,12 @@ use unreal_asset_base::{
     containers::{indexed_map::IndexedMap, name_map::NameMap, shared_resource::SharedResource},
     custom_version::{CustomVersion, CustomVersionTrait},
     engine_version::{get_object_versions, EngineVersion},
-    error::Error,
+    error::{Error, PropertyError},
     flags::EPackageFlags,
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
     reader::ArchiveReader,
-    types::{FName, PackageIndex, PackageIndexTrait},
+    types::{property_type_name::PropertyTagTypes, FName, PackageIndex, PackageIndexTrait},
     unversioned::Usmap,
     FNameContainer,
 };
@@ -50,6 +52,9 @@ pub struct AssetData<Index: PackageIndexTrait> {
     /// .usmap mappings
     #[container_ignore]
     pub mappings: Option<Usmap>,
+    /// Parse options
+    #[container_ignore]
+    pub parse_options: ParseOptions,
 
     /// Object exports
     pub exports: Vec<Export<Index>>,
@@ -71,6 +76,17 @@ pub struct AssetData<Index: PackageIndexTrait> {
     /// This is used for specifying those types
     #[container_ignore]
     pub array_struct_type_override: IndexedMap<String, String>,
+
+    /// User supplied key and value struct types for map properties, by property name
+    ///
+    /// These are merged into the map key and value overrides and are never replaced by overrides found while parsing
+    #[container_ignore]
+    pub map_struct_type_overrides: HashMap<String, (FName, FName)>,
+
+    /// Complete property type names read from UE5.4+ property tags
+    ///
+    /// Used to write the same type names back, property tags of properties not in here are derived from the properties
+    pub property_tag_types: PropertyTagTypes,
 }
 
 /// Export read from [`AssetData`]
@@ -104,14 +120,17 @@ impl<Index: PackageIndexTrait> ReadExport<Index> {
 
     /// Reduce `ReadExport` to an [`Export`]
     pub fn reduce(self, asset_data: &mut AssetData<Index>) -> Export<Index> {
+        let user_overrides = &asset_data.map_struct_type_overrides;
         asset_data.map_key_override.extend(
             self.new_map_key_overrides
                 .into_iter()
+                .filter(|(_, k, _)| !user_overrides.contains_key(k))
                 .map(|(_, k, v)| (k, v)),
         );
         asset_data.map_value_override.extend(
             self.new_map_value_overrides
                 .into_iter()
+                .filter(|(_, k, _)| !user_overrides.contains_key(k))
                 .map(|(_, k, v)| (k, v)),
         );
         asset_data
@@ -219,6 +238,20 @@ impl<Index: PackageIndexTrait> AssetData<Index> {
             .package_flags
             .contains(EPackageFlags::PKG_UNVERSIONED_PROPERTIES)
     }
+
+    /// Set user supplied key and value struct types for map properties, by property name
+    pub fn set_map_struct_type_overrides(
+        &mut self,
+        map_struct_type_overrides: HashMap<String, (FName, FName)>,
+    ) {
+        for (name, (key_type, value_type)) in &map_struct_type_overrides {
+            self.map_key_override
+                .insert(name.clone(), key_type.get_owned_content());
+            self.map_value_override
+                .insert(name.clone(), value_type.get_owned_content());
+        }
+        self.map_struct_type_overrides = map_struct_type_overrides;
+    }
 }
 
 impl<Index: PackageIndexTrait> Default for AssetData<Index> {
@@ -233,6 +266,7 @@ impl<Index: PackageIndexTrait> Default for AssetData<Index> {
             object_version: ObjectVersion::UNKNOWN,
             object_version_ue5: ObjectVersionUE5::UNKNOWN,
             mappings: None,
+            parse_options: ParseOptions::default(),
             exports: Vec::new(),
             world_tile_info: None,
             map_key_override: IndexedMap::from([
@@ -281,6 +315,8 @@ impl<Index: PackageIndexTrait> Default for AssetData<Index> {
                 "Keys".to_string(),
                 "RichCurveKey".to_string(),
             )]),
+            map_struct_type_overrides: HashMap::new(),
+            property_tag_types: PropertyTagTypes::new(),
         }
     }
 }
@@ -331,83 +367,79 @@ pub trait ExportReaderTrait<Index: PackageIndexTrait>:
         self.seek(SeekFrom::Start(base_export.serial_offset as u64))?;
 
         //todo: manual skips
+        // owned, reading inline names adds them to the name map
         let export_class_type = self
             .get_export_class_type(base_export.class_index)
-            .ok_or_else(|| Error::invalid_package_index("Unknown class type".to_string()))?;
+            .ok_or_else(|| Error::invalid_package_index("Unknown class type".to_string()))?
+            .get_owned_content();
 
         let mut new_map_key_overrides = IndexedMap::new();
         let mut new_map_value_overrides = IndexedMap::new();
         let new_array_overrides = IndexedMap::new();
 
-        let mut export: Export<Index> = export_class_type.get_content(|class| {
-            Ok::<Export<Index>, Error>(match class {
-                "Level" => LevelExport::from_base(&base_export, self)?.into(),
-                "World" => WorldExport::from_base(&base_export, self)?.into(),
-                "UserDefinedStruct" => {
-                    UserDefinedStructExport::from_base(&base_export, self)?.into()
-                }
-                "StringTable" => StringTableExport::from_base(&base_export, self)?.into(),
-                "Enum" | "UserDefinedEnum" => EnumExport::from_base(&base_export, self)?.into(),
-                "Function" => FunctionExport::from_base(&base_export, self)?.into(),
-                _ => {
-                    if export_class_type.ends_with("DataTable") {
-                        DataTableExport::from_base(&base_export, self)?.into()
-                    } else if export_class_type.ends_with("StringTable") {
-                        StringTableExport::from_base(&base_export, self)?.into()
-                    } else if export_class_type.ends_with("BlueprintGeneratedClass") {
-                        let class_export = ClassExport::from_base(&base_export, self)?;
-
-                        for entry in &class_export.struct_export.loaded_properties {
-                            if let FProperty::FMapProperty(map) = entry {
-                                let key_override = match &*map.key_prop {
-                                    FProperty::FStructProperty(struct_property) => {
-                                        match struct_property.struct_value.is_import() {
-                                            true => self
-                                                .get_object_name_packageindex(
-                                                    struct_property.struct_value,
-                                                )
-                                                .map(|e| e.get_owned_content()),
-                                            false => None,
-                                        }
+        let mut export: Export<Index> = match export_class_type.as_str() {
+            "Level" => LevelExport::from_base(&base_export, self)?.into(),
+            "World" => WorldExport::from_base(&base_export, self)?.into(),
+            "UserDefinedStruct" => UserDefinedStructExport::from_base(&base_export, self)?.into(),
+            "StringTable" => StringTableExport::from_base(&base_export, self)?.into(),
+            "Enum" | "UserDefinedEnum" => EnumExport::from_base(&base_export, self)?.into(),
+            "Function" => FunctionExport::from_base(&base_export, self)?.into(),
+            _ => {
+                if export_class_type.ends_with("DataTable") {
+                    DataTableExport::from_base(&base_export, self)?.into()
+                } else if export_class_type.ends_with("StringTable") {
+                    StringTableExport::from_base(&base_export, self)?.into()
+                } else if export_class_type.ends_with("BlueprintGeneratedClass") {
+                    let class_export = ClassExport::from_base(&base_export, self)?;
+
+                    for entry in &class_export.struct_export.loaded_properties {
+                        if let FProperty::FMapProperty(map) = entry {
+                            let key_override = match &*map.key_prop {
+                                FProperty::FStructProperty(struct_property) => {
+                                    match struct_property.struct_value.is_import() {
+                                        true => self
+                                            .get_object_name_packageindex(
+                                                struct_property.struct_value,
+                                            )
+                                            .map(|e| e.get_owned_content()),
+                                        false => None,
                                     }
-                                    _ => None,
-                                };
-                                if let Some(key) = key_override {
-                                    new_map_key_overrides
-                                        .insert(map.generic_property.name.get_owned_content(), key);
                                 }
+                                _ => None,
+                            };
+                            if let Some(key) = key_override {
+                                new_map_key_overrides
+                                    .insert(map.generic_property.name.get_owned_content(), key);
+                            }
 
-                                let value_override = match &*map.value_prop {
-                                    FProperty::FStructProperty(struct_property) => {
-                                        match struct_property.struct_value.is_import() {
-                                            true => self
-                                                .get_object_name_packageindex(
-                                                    struct_property.struct_value,
-                                                )
-                                                .map(|e| e.get_owned_content()),
-                                            false => None,
-                                        }
+                            let value_override = match &*map.value_prop {
+                                FProperty::FStructProperty(struct_property) => {
+                                    match struct_property.struct_value.is_import() {
+                                        true => self
+                                            .get_object_name_packageindex(
+                                                struct_property.struct_value,
+                                            )
+                                            .map(|e| e.get_owned_content()),
+                                        false => None,
                                     }
-                                    _ => None,
-                                };
-
-                                if let Some(value) = value_override {
-                                    new_map_value_overrides.insert(
-                                        map.generic_property.name.get_owned_content(),
-                                        value,
-                                    );
                                 }
+                                _ => None,
+                            };
+
+                            if let Some(value) = value_override {
+                                new_map_value_overrides
+                                    .insert(map.generic_property.name.get_owned_content(), value);
                             }
                         }
-                        class_export.into()
-                    } else if export_class_type.ends_with("Property") {
-                        PropertyExport::from_base(&base_export, self)?.into()
-                    } else {
-                        NormalExport::from_base(&base_export, self)?.into()
                     }
+                    class_export.into()
+                } else if export_class_type.ends_with("Property") {
+                    PropertyExport::from_base(&base_export, self)?.into()
+                } else {
+                    NormalExport::from_base(&base_export, self)?.into()
                 }
-            })
-        })?;
+            }
+        };
 
         let extras_len = next_starting as i64 - self.position() as i64;
         if extras_len < 0 {
@@ -459,6 +491,8 @@ pub trait ExportReaderTrait<Index: PackageIndexTrait>:
 
                 Ok(reduced)
             }
+            // unknown property types only error out when requested by the parse options
+            Err(e @ Error::Property(PropertyError::UnknownType(..))) => Err(e),
             Err(_e) => {
                 // todo: warning?
                 self.seek(SeekFrom::Start(serial_offset))?;
@@ -0,0 +1,171 @@
+//! Archive writer used to compute asset fingerprints
+
+use std::io::{Seek, Write};
+
+use unreal_asset_base::{
+    containers::{IndexedMap, NameMap, SharedResource},
+    custom_version::{CustomVersion, CustomVersionTrait},
+    engine_version::EngineVersion,
+    error::FNameError,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
+    passthrough_archive_writer,
+    reader::{ArchiveTrait, ArchiveType, ArchiveWriter},
+    types::{property_type_name::PropertyTagType, FName, PackageIndex},
+    unversioned::{Ancestry, Usmap},
+    Error,
+};
+
+/// Archive writer that writes `FName`s by their content instead of their name map index
+///
+/// Data written through this writer doesn't depend on the order of the name map,
+/// so it can be hashed to compare assets by content. The output can't be read back as an asset.
+pub(crate) struct FingerprintWriter<'parent_writer, ParentWriter: ArchiveWriter<PackageIndex>> {
+    /// Parent writer for this writer
+    writer: &'parent_writer mut ParentWriter,
+    /// Copy of the name map contents
+    ///
+    /// Exports may hold a mutable borrow of the name map while writing an `FName`,
+    /// so names are looked up here instead of through the name map.
+    names: Vec<String>,
+}
+
+impl<'parent_writer, ParentWriter: ArchiveWriter<PackageIndex>>
+    FingerprintWriter<'parent_writer, ParentWriter>
+{
+    /// Create a new `FingerprintWriter` instance
+    pub fn new(parent_writer: &'parent_writer mut ParentWriter) -> Self {
+        let names = parent_writer
+            .get_name_map()
+            .get_ref()
+            .get_name_map_index_list()
+            .to_vec();
+        FingerprintWriter {
+            writer: parent_writer,
+            names,
+        }
+    }
+}
+
+impl<'parent_writer, ParentWriter: ArchiveWriter<PackageIndex>> ArchiveTrait<PackageIndex>
+    for FingerprintWriter<'parent_writer, ParentWriter>
+{
+    #[inline(always)]
+    fn get_archive_type(&self) -> ArchiveType {
+        self.writer.get_archive_type()
+    }
+
+    fn get_custom_version<T>(&self) -> CustomVersion
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        self.writer.get_custom_version::<T>()
+    }
+
+    fn has_unversioned_properties(&self) -> bool {
+        self.writer.has_unversioned_properties()
+    }
+
+    fn use_event_driven_loader(&self) -> bool {
+        self.writer.use_event_driven_loader()
+    }
+
+    fn position(&mut self) -> u64 {
+        self.writer.position()
+    }
+
+    fn set_position(&mut self, pos: u64) -> std::io::Result<()> {
+        self.writer.set_position(pos)
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.writer.get_name_map()
+    }
+
+    fn get_array_struct_type_override(&self) -> &IndexedMap<String, String> {
+        self.writer.get_array_struct_type_override()
+    }
+
+    fn get_map_key_override(&self) -> &IndexedMap<String, String> {
+        self.writer.get_map_key_override()
+    }
+
+    fn get_map_value_override(&self) -> &IndexedMap<String, String> {
+        self.writer.get_map_value_override()
+    }
+
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.writer.get_property_tag_type(name, ancestry)
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.writer.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.writer.get_object_version()
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.writer.get_object_version_ue5()
+    }
+
+    fn get_mappings(&self) -> Option<&Usmap> {
+        self.writer.get_mappings()
+    }
+
+    fn get_parse_options(&self) -> ParseOptions {
+        self.writer.get_parse_options()
+    }
+
+    fn get_parent_class_export_name(&self) -> Option<FName> {
+        self.writer.get_parent_class_export_name()
+    }
+
+    fn get_object_name(&self, index: PackageIndex) -> Option<FName> {
+        self.writer.get_object_name(index)
+    }
+
+    fn get_object_name_packageindex(&self, index: PackageIndex) -> Option<FName> {
+        self.writer.get_object_name_packageindex(index)
+    }
+}
+
+impl<'parent_writer, ParentWriter: ArchiveWriter<PackageIndex>> ArchiveWriter<PackageIndex>
+    for FingerprintWriter<'parent_writer, ParentWriter>
+{
+    fn write_fname(&mut self, fname: &FName) -> Result<(), Error> {
+        let content = match fname {
+            FName::Backed { index, .. } => self
+                .names
+                .get(*index as usize)
+                .ok_or_else(|| FNameError::out_of_range(*index, self.names.len()))?,
+            FName::Dummy { value, .. } => value,
+        };
+        self.writer.write_fstring(Some(content))?;
+        self.writer.write_all(&fname.get_number().to_le_bytes())?;
+        Ok(())
+    }
+
+    passthrough_archive_writer!(writer);
+}
+
+impl<'parent_writer, ParentWriter: ArchiveWriter<PackageIndex>> Write
+    for FingerprintWriter<'parent_writer, ParentWriter>
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<'parent_writer, ParentWriter: ArchiveWriter<PackageIndex>> Seek
+    for FingerprintWriter<'parent_writer, ParentWriter>
+{
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.writer.seek(pos)
+    }
+}
@@ -54,6 +54,7 @@ pub use base::error;
 pub use base::flags;
 pub use base::import;
 pub use base::object_version;
+pub use base::parse_options;
 pub use base::reader;
 pub use base::types;
 pub use base::unversioned;
@@ -88,7 +89,10 @@ pub mod asset;
 pub mod asset_archive_writer;
 pub mod asset_data;
 pub mod fengineversion;
+#[cfg(feature = "fingerprint")]
+mod fingerprint_writer;
 pub mod package_file_summary;
+pub mod thumbnail;
 
 pub use asset::Asset;
 
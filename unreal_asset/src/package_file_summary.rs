@@ -2,6 +2,7 @@
 
 use unreal_asset_base::{
     custom_version::{CustomVersion, CustomVersionTrait},
+    engine_version::EngineVersion,
     flags::EPackageFlags,
 };
 
@@ -10,6 +11,8 @@ use unreal_asset_base::{
 pub struct PackageFileSummary {
     /// Package flags
     pub package_flags: EPackageFlags,
+    /// Name count
+    pub name_count: i32,
     /// Export count
     pub export_count: i32,
     /// Import count
@@ -20,6 +23,8 @@ pub struct PackageFileSummary {
     pub custom_versions: Vec<CustomVersion>,
     /// Is unversioned
     pub unversioned: bool,
+    /// Engine version, guessed from the object and custom versions of versioned assets
+    pub engine_version: EngineVersion,
 }
 
 impl PackageFileSummary {
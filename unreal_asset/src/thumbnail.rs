@@ -0,0 +1,109 @@
+//! Editor package thumbnails
+
+use std::io::SeekFrom;
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    reader::{ArchiveReader, ArchiveWriter},
+    types::PackageIndexTrait,
+    Error,
+};
+
+/// Thumbnail of an object stored in an editor package
+///
+/// Cooked packages don't contain thumbnails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetThumbnail {
+    /// Class name of the object, e.g. `Blueprint`
+    pub object_class_name: String,
+    /// Path of the object without the package name, e.g. `MyAsset` or `MyAsset:SubObject`
+    pub object_path: String,
+    /// Image width
+    pub image_width: i32,
+    /// Image height
+    pub image_height: i32,
+    /// Is the image data JPEG compressed, PNG otherwise
+    pub is_jpeg: bool,
+    /// Compressed image data
+    pub compressed_image_data: Vec<u8>,
+}
+
+impl AssetThumbnail {
+    /// Read the thumbnail table of a package, the reader must be at the table offset
+    pub(crate) fn read_table<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        reader: &mut Reader,
+    ) -> Result<Vec<Self>, Error> {
+        let count = reader.read_i32::<LE>()?;
+
+        // every entry is at least two empty strings and an offset
+        let remaining = reader.data_length()?.saturating_sub(reader.position());
+        if count as i64 > (remaining / 12) as i64 {
+            return Err(Error::invalid_file(format!(
+                "Invalid thumbnail count {count}, only {remaining} bytes remain"
+            )));
+        }
+
+        let mut entries = Vec::with_capacity(count.max(0) as usize);
+        for _ in 0..count {
+            let object_class_name = reader.read_fstring()?.unwrap_or_default();
+            let object_path = reader.read_fstring()?.unwrap_or_default();
+            let file_offset = reader.read_i32::<LE>()?;
+            entries.push((object_class_name, object_path, file_offset));
+        }
+
+        let mut thumbnails = Vec::with_capacity(entries.len());
+        for (object_class_name, object_path, file_offset) in entries {
+            reader.seek(SeekFrom::Start(file_offset as u64))?;
+
+            let image_width = reader.read_i32::<LE>()?;
+            let image_height = reader.read_i32::<LE>()?;
+            // negative height marks a JPEG compressed image
+            let is_jpeg = image_height < 0;
+
+            let data_length = reader.read_i32::<LE>()?;
+            let compressed_image_data =
+                reader.read_raw_data(data_length.max(0) as i64, "thumbnail image data")?;
+
+            thumbnails.push(AssetThumbnail {
+                object_class_name,
+                object_path,
+                image_width,
+                image_height: image_height.abs(),
+                is_jpeg,
+                compressed_image_data,
+            });
+        }
+
+        Ok(thumbnails)
+    }
+
+    /// Write thumbnail data followed by the thumbnail table, returns the table offset
+    pub(crate) fn write_table<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        writer: &mut Writer,
+        thumbnails: &[Self],
+    ) -> Result<i32, Error> {
+        let mut file_offsets = Vec::with_capacity(thumbnails.len());
+        for thumbnail in thumbnails {
+            file_offsets.push(writer.position() as i32);
+
+            writer.write_i32::<LE>(thumbnail.image_width)?;
+            writer.write_i32::<LE>(match thumbnail.is_jpeg {
+                true => -thumbnail.image_height,
+                false => thumbnail.image_height,
+            })?;
+            writer.write_i32::<LE>(thumbnail.compressed_image_data.len() as i32)?;
+            writer.write_all(&thumbnail.compressed_image_data)?;
+        }
+
+        let table_offset = writer.position() as i32;
+        writer.write_i32::<LE>(thumbnails.len() as i32)?;
+        for (thumbnail, file_offset) in thumbnails.iter().zip(file_offsets) {
+            writer.write_fstring(Some(&thumbnail.object_class_name))?;
+            writer.write_fstring(Some(&thumbnail.object_path))?;
+            writer.write_i32::<LE>(file_offset)?;
+        }
+
+        Ok(table_offset)
+    }
+}
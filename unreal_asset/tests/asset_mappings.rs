@@ -0,0 +1,152 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::ArchiveTrait,
+    unversioned::{EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_looseWeapon.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_looseWeapon.uexp"));
+
+const TAGGED_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/StarlitSeason/CharacterCostume_chr0001_DataTable.uasset"
+));
+const TAGGED_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/StarlitSeason/CharacterCostume_chr0001_DataTable.uexp"
+));
+
+/// Unversioned asset together with the mappings it was written with
+///
+/// There is no unversioned fixture with matching mappings, so one is generated from a tagged asset.
+fn unversioned_fixture() -> Result<(Vec<u8>, Vec<u8>, Usmap), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TAGGED_ASSET),
+        Some(Cursor::new(TAGGED_BULK)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+    let mappings = shared::mappings(&asset);
+    asset.set_unversioned(true, Some(&mappings))?;
+
+    let mut data = Cursor::new(Vec::new());
+    let mut bulk = Cursor::new(Vec::new());
+    asset.write_data(&mut data, Some(&mut bulk))?;
+    Ok((data.into_inner(), bulk.into_inner(), mappings))
+}
+
+fn empty_mappings() -> Usmap {
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: vec![String::from("BP_looseWeapon_C")],
+        enum_map: IndexedMap::new(),
+        schemas: IndexedMap::new(),
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+#[test]
+fn new_with_mappings() -> Result<(), Error> {
+    let mappings = empty_mappings();
+    let mut asset = Asset::new_with_mappings(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        mappings.clone(),
+    )?;
+    assert_eq!(asset.get_mappings(), Some(&mappings));
+
+    let without_mappings = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert_eq!(
+        asset.asset_data.exports.len(),
+        without_mappings.asset_data.exports.len()
+    );
+
+    shared::verify_binary_equality(TEST_ASSET, Some(TEST_BULK), &mut asset)?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    Ok(())
+}
+
+#[test]
+fn set_mappings() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert!(asset.get_mappings().is_none());
+
+    asset.set_mappings(Some(empty_mappings()));
+    assert_eq!(
+        asset.get_mappings().map(|e| &e.name_map),
+        Some(&vec![String::from("BP_looseWeapon_C")])
+    );
+
+    asset.set_mappings(None);
+    assert!(asset.get_mappings().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn unversioned_with_mappings() -> Result<(), Error> {
+    let (data, bulk, mappings) = unversioned_fixture()?;
+
+    // unversioned properties can't be read without mappings
+    assert!(Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE4_24,
+        None,
+    )
+    .map_or(true, |asset| !shared::verify_all_exports_parsed(&asset)));
+
+    let mut asset = Asset::new_with_mappings(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE4_24,
+        mappings.clone(),
+    )?;
+    assert!(asset.has_unversioned_properties());
+    assert_eq!(asset.get_mappings(), Some(&mappings));
+    assert!(shared::verify_all_exports_parsed(&asset));
+    shared::verify_binary_equality(&data, Some(&bulk), &mut asset)?;
+
+    // mappings can be swapped on a parsed unversioned asset
+    asset.set_mappings(None);
+    assert!(asset.get_mappings().is_none());
+
+    asset.set_mappings(Some(mappings));
+    shared::verify_binary_equality(&data, Some(&bulk), &mut asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{object_property::AssetObjectProperty, PropertyTrait},
+    reader::{RawReader, RawWriter},
+    types::{FName, PackageIndex},
+    Error,
+};
+
+/// Write `property` and read it back with the given object versions
+fn reparse(
+    property: &AssetObjectProperty,
+    object_version: ObjectVersion,
+    object_version_ue5: ObjectVersionUE5,
+) -> Result<AssetObjectProperty, Error> {
+    let name_map = NameMap::new();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        object_version,
+        object_version_ue5,
+        false,
+        name_map.clone(),
+    );
+    property.write(&mut writer, false)?;
+
+    cursor.set_position(0);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(cursor, None),
+        object_version,
+        object_version_ue5,
+        false,
+        name_map,
+    );
+    AssetObjectProperty::new(&mut reader, FName::default(), Default::default(), false, 0)
+}
+
+#[test]
+fn asset_object_paths() {
+    let mut property = AssetObjectProperty {
+        value: Some("/Game/Items/Sword.Sword:Blade".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(property.asset_path(), "/Game/Items/Sword.Sword");
+    assert_eq!(property.sub_path(), Some("Blade"));
+
+    property.set_path("/Game/Items/Axe.Axe", None);
+    assert_eq!(property.value.as_deref(), Some("/Game/Items/Axe.Axe"));
+    assert_eq!(property.asset_path(), "/Game/Items/Axe.Axe");
+    assert_eq!(property.sub_path(), None);
+
+    property.set_path("", None);
+    assert_eq!(property.value, None);
+    assert_eq!(property.asset_path(), "");
+}
+
+#[test]
+fn retarget_asset_object_reference() -> Result<(), Error> {
+    for (object_version, object_version_ue5) in [
+        (
+            ObjectVersion::VER_UE4_ADDED_SOFT_OBJECT_PATH,
+            ObjectVersionUE5::UNKNOWN,
+        ),
+        (
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES,
+        ),
+    ] {
+        let property = AssetObjectProperty {
+            value: Some("/Game/Items/Sword.Sword".to_string()),
+            ..Default::default()
+        };
+
+        let mut read = reparse(&property, object_version, object_version_ue5)?;
+        assert_eq!(read.asset_path(), "/Game/Items/Sword.Sword");
+        assert_eq!(read.sub_path(), None);
+
+        read.set_path("/Game/Items/Axe.Axe", Some("Head"));
+        let read = reparse(&read, object_version, object_version_ue5)?;
+        assert_eq!(read.asset_path(), "/Game/Items/Axe.Axe");
+        assert_eq!(read.sub_path(), Some("Head"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{NameMap, SharedResource},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        int_property::{ByteProperty, BytePropertyValue},
+        PropertyTrait,
+    },
+    reader::RawWriter,
+    types::PackageIndex,
+    Error,
+};
+
+fn write(
+    property: &impl PropertyTrait,
+    name_map: SharedResource<NameMap>,
+) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::VER_UE4_AUTOMATIC_VERSION,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    property.write(&mut writer, true)?;
+    Ok(cursor.into_inner())
+}
+
+#[test]
+fn byte_enum_property_conversion() -> Result<(), Error> {
+    let mut name_map = NameMap::new();
+    let name = name_map.get_mut().add_fname("SyncMode");
+    let value = name_map.get_mut().add_fname("SyncMode::NewEnumerator1");
+    let byte_property = ByteProperty {
+        name: name.clone(),
+        enum_type: Some(name),
+        value: BytePropertyValue::FName(value),
+        ..Default::default()
+    };
+    assert!(byte_property.is_enum());
+
+    let enum_property = byte_property
+        .to_enum_property()
+        .expect("Byte enum was not converted");
+    assert_eq!(enum_property.enum_type, byte_property.enum_type);
+    assert_eq!(
+        enum_property.value.as_ref().map(|e| e.get_owned_content()),
+        Some(String::from("SyncMode::NewEnumerator1"))
+    );
+
+    // both representations serialize the same tagged value
+    assert_eq!(
+        write(&byte_property, name_map.clone())?,
+        write(&enum_property, name_map.clone())?
+    );
+
+    assert_eq!(enum_property.to_byte_property(), Some(byte_property));
+
+    Ok(())
+}
+
+#[test]
+fn byte_enum_property_raw_byte() {
+    let mut name_map = NameMap::new();
+    let name = name_map.get_mut().add_fname("Value");
+    let enum_type = name_map.get_mut().add_fname("None");
+    let mut byte_property = ByteProperty {
+        name,
+        enum_type: Some(enum_type),
+        value: BytePropertyValue::Byte(3),
+        ..Default::default()
+    };
+    assert!(!byte_property.is_enum());
+    assert!(byte_property.to_enum_property().is_none());
+
+    // an enum stored as a raw byte has no value name to convert
+    byte_property.enum_type = Some(name_map.get_mut().add_fname("SyncMode"));
+    assert!(byte_property.is_enum());
+    assert!(byte_property.to_enum_property().is_none());
+}
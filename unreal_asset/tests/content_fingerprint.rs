@@ -0,0 +1,91 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    containers::NameMap,
+    engine_version::EngineVersion,
+    exports::Export,
+    properties::{Property, PropertyDataTrait},
+    types::{fname::FNameContainer, FName},
+    Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+fn read_asset(data: Vec<u8>) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_23, None)
+}
+
+fn write_asset(asset: &Asset<Cursor<Vec<u8>>>) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    Ok(cursor.into_inner())
+}
+
+#[test]
+fn name_map_order_independent() -> Result<(), Error> {
+    let original = read_asset(TEST_ASSET.to_vec())?;
+    let fingerprint = original.content_fingerprint()?;
+
+    // reverse the name map and point every name at its new index
+    let mut reordered = read_asset(TEST_ASSET.to_vec())?;
+    let mut name_map = reordered.get_name_map();
+    let mut names = name_map.get_ref().get_name_map_index_list().to_vec();
+    names.reverse();
+    let reversed = NameMap::from_name_batch(&names);
+    *name_map.get_mut() = reversed.get_ref().clone();
+
+    let name_count = names.len() as i32;
+    reordered.traverse_fnames(&mut |name| {
+        if let FName::Backed { index, .. } = name {
+            *index = name_count - 1 - *index;
+        }
+    });
+
+    let reordered_data = write_asset(&reordered)?;
+    assert_ne!(reordered_data, TEST_ASSET);
+
+    let reordered = read_asset(reordered_data)?;
+    assert_eq!(
+        reordered.get_name_map().get_ref().get_owned_name(0),
+        original
+            .get_name_map()
+            .get_ref()
+            .get_owned_name(name_count - 1)
+    );
+    assert_eq!(reordered.content_fingerprint()?, fingerprint);
+
+    Ok(())
+}
+
+#[test]
+fn value_change_changes_fingerprint() -> Result<(), Error> {
+    let mut asset = read_asset(TEST_ASSET.to_vec())?;
+    let fingerprint = asset.content_fingerprint()?;
+
+    let sequence_number = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| cast!(Export, NormalExport, e))
+        .flat_map(|e| e.properties.iter_mut())
+        .find(|e| e.get_name() == "CategorySequenceNumber")
+        .expect("Failed to find CategorySequenceNumber");
+    let Property::IntProperty(sequence_number) = sequence_number else {
+        panic!("CategorySequenceNumber is not an IntProperty");
+    };
+    sequence_number.value += 1;
+
+    assert_ne!(asset.content_fingerprint()?, fingerprint);
+
+    Ok(())
+}
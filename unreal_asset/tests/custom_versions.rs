@@ -0,0 +1,58 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    custom_version::{self, CustomVersionTrait, FEditorObjectVersion},
+    engine_version::EngineVersion,
+    Asset, Error,
+};
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+#[test]
+fn custom_versions_named() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let mut custom_versions = asset.custom_versions_named();
+    custom_versions.sort();
+    assert_eq!(
+        custom_versions,
+        [
+            ("FCoreObjectVersion".to_string(), 4),
+            ("FEditorObjectVersion".to_string(), 38),
+            ("FFortniteMainBranchObjectVersion".to_string(), 31),
+            ("FFrameworkObjectVersion".to_string(), 37),
+            ("FReleaseObjectVersion".to_string(), 30),
+            ("FSequencerObjectVersion".to_string(), 12),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn well_known_custom_versions() {
+    let well_known = custom_version::well_known();
+    assert!(well_known.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+    assert!(well_known.contains(&(
+        FEditorObjectVersion::GUID,
+        FEditorObjectVersion::FRIENDLY_NAME
+    )));
+    assert_eq!(
+        custom_version::friendly_name(&custom_version::ASSET_REGISTRY_VERSION_GUID),
+        Some("FAssetRegistryVersion")
+    );
+    assert_eq!(custom_version::friendly_name(&Default::default()), None);
+}
@@ -90,3 +90,61 @@ fn data_tables() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn data_table_rows_as_maps() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+
+    let data_table_export: &mut DataTableExport<_> =
+        cast!(Export, DataTableExport, &mut asset.asset_data.exports[0])
+            .expect("First export is not a DataTableExport");
+
+    let rows = data_table_export.table.rows_as_maps()?;
+    assert_eq!(rows.len(), data_table_export.table.data.len());
+    for ((row_name, columns), row) in rows.iter().zip(&data_table_export.table.data) {
+        assert_eq!(*row_name, row.name);
+        assert_eq!(columns.len(), row.value.len());
+        for property in &row.value {
+            let column = columns[&property.get_name().get_owned_content()];
+            assert_eq!(column, property);
+        }
+    }
+
+    let (_, first_columns) = &rows[0];
+    let test_column = first_columns
+        .get("AcceleratorANDDoubleJump")
+        .expect("Column missing from the first row");
+    assert!(cast!(Property, BoolProperty, *test_column).is_some());
+    let mut element = (*test_column).clone();
+
+    // rows with differing column sets only hold their own columns
+    let removed = data_table_export.table.data[1].value.remove(0);
+    let removed_name = removed.get_name().get_owned_content();
+
+    let rows = data_table_export.table.rows_as_maps()?;
+    assert!(rows[0].1.contains_key(&removed_name));
+    assert!(!rows[1].1.contains_key(&removed_name));
+    assert_eq!(rows[1].1.len(), rows[0].1.len() - 1);
+
+    // static array elements are keyed by their index
+    cast!(Property, BoolProperty, &mut element)
+        .expect("Column is not a BoolProperty")
+        .duplication_index = 1;
+    data_table_export.table.data[0].value.push(element);
+
+    let rows = data_table_export.table.rows_as_maps()?;
+    assert!(rows[0].1.contains_key("AcceleratorANDDoubleJump"));
+    assert!(rows[0].1.contains_key("AcceleratorANDDoubleJump[1]"));
+
+    // the same column twice can't be told apart
+    data_table_export.table.data[1].value.push(removed.clone());
+    data_table_export.table.data[1].value.push(removed);
+    assert!(data_table_export.table.rows_as_maps().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        delegate_property::{Delegate, DelegateProperty, MulticastInlineDelegateProperty},
+        PropertyTrait,
+    },
+    reader::{RawReader, RawWriter},
+    types::{FName, PackageIndex},
+    Error,
+};
+
+#[test]
+fn bind_delegate() -> Result<(), Error> {
+    let name_map = NameMap::new();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+
+    let mut property = DelegateProperty::default();
+    property.bind(&mut writer, PackageIndex::new(3), "OnClicked_Event_0");
+    assert_eq!(property.object(), PackageIndex::new(3));
+    assert_eq!(
+        property.function_name().get_owned_content(),
+        "OnClicked_Event"
+    );
+    assert_eq!(property.function_name().get_number(), 1);
+    property.write(&mut writer, false)?;
+
+    cursor.set_position(0);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(cursor, None),
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    let read = DelegateProperty::new(
+        &mut reader,
+        FName::default(),
+        Default::default(),
+        false,
+        0,
+        0,
+    )?;
+
+    assert_eq!(read.object(), PackageIndex::new(3));
+    assert_eq!(read.function_name(), property.function_name());
+
+    Ok(())
+}
+
+#[test]
+fn bind_multicast_delegate() -> Result<(), Error> {
+    let mut name_map = NameMap::new();
+    let existing = Delegate::new(
+        PackageIndex::new(1),
+        name_map.get_mut().add_fname("ExistingHandler"),
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+
+    let mut property = MulticastInlineDelegateProperty {
+        value: vec![existing.clone()],
+        ..Default::default()
+    };
+    property.bind(&mut writer, PackageIndex::new(2), "NewHandler");
+    // binding the same function again doesn't add a duplicate entry
+    property.bind(&mut writer, PackageIndex::new(2), "NewHandler");
+    assert_eq!(property.delegates().len(), 2);
+    property.write(&mut writer, false)?;
+
+    cursor.set_position(0);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(cursor, None),
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    let read = MulticastInlineDelegateProperty::new(
+        &mut reader,
+        FName::default(),
+        Default::default(),
+        false,
+        0,
+        0,
+    )?;
+
+    let delegates = read.delegates();
+    assert_eq!(delegates.len(), 2);
+    assert_eq!(delegates[0], existing);
+    assert_eq!(delegates[1].object(), PackageIndex::new(2));
+    assert_eq!(delegates[1].function_name(), "NewHandler");
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::io::Cursor;
+
+use miette::Diagnostic;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Versioned/Assault_M1A1Thompson_WW2_DrumSuppressor.uasset"
+));
+
+#[test]
+fn truncated_asset_diagnostic() {
+    let truncated = &TEST_ASSET[..TEST_ASSET.len() / 2];
+    let error = Asset::new(
+        Cursor::new(truncated),
+        None,
+        EngineVersion::VER_UE4_14,
+        None,
+    )
+    .expect_err("Truncated asset parsed");
+
+    let Error::Context { offset, .. } = error else {
+        panic!("Expected an error with context, got {error:?}");
+    };
+    assert!(offset > 0 && offset <= truncated.len() as u64);
+    assert_eq!(error.offset(), Some(offset));
+    // the export that is cut off is read as raw data, which is longer than the rest of the file
+    assert!(matches!(error.root(), Error::InvalidFile(_)));
+
+    let code = Diagnostic::code(&error).map(|e| e.to_string());
+    assert_eq!(code.as_deref(), Some("unreal_asset::invalid_file"));
+
+    let labels = error.labels().expect("No labels").collect::<Vec<_>>();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].offset() as u64, offset);
+}
+
+#[test]
+fn truncated_summary_io_error() {
+    let error = Asset::new(
+        Cursor::new(&TEST_ASSET[..64]),
+        None,
+        EngineVersion::VER_UE4_14,
+        None,
+    )
+    .expect_err("Truncated asset parsed");
+
+    // I/O errors get the offset attached, without the diagnostic feature they are returned as they are
+    assert!(matches!(error, Error::Context { .. }), "{error:?}");
+    assert!(error.offset().is_some());
+    assert!(matches!(error.root(), Error::Io(_)), "{error:?}");
+}
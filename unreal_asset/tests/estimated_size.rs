@@ -0,0 +1,130 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::Export,
+    properties::{Property, PropertyDataTrait, PropertyTrait},
+    reader::{ArchiveTrait, RawWriter},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+const RACE_SIM_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Misc_426/RaceSimDataAsset.uasset"
+));
+const RACE_SIM_BULK: &[u8] =
+    include_bytes!(concat!(assets_folder!(), "Misc_426/RaceSimDataAsset.uexp"));
+
+const COSTUME_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "StarlitSeason/CharacterCostume_chr0001_DataTable.uasset"
+));
+const COSTUME_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "StarlitSeason/CharacterCostume_chr0001_DataTable.uexp"
+));
+
+/// Serialize a single property value without its tag
+fn written_size(asset: &Asset<Cursor<&[u8]>>, property: &Property) -> Result<usize, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        asset.get_object_version(),
+        asset.get_object_version_ue5(),
+        asset.use_event_driven_loader(),
+        asset.get_name_map(),
+    );
+    property.write(&mut writer, false)?;
+    Ok(cursor.into_inner().len())
+}
+
+/// Check that no estimate exceeds the number of bytes actually written
+fn verify_estimates(asset: &Asset<Cursor<&[u8]>>) -> Result<(), Error> {
+    for (i, export) in asset.asset_data.exports.iter().enumerate() {
+        if let Some(normal_export) = cast!(Export, NormalExport, export) {
+            for property in &normal_export.properties {
+                let estimate = property.estimated_serialized_size();
+                let written = written_size(asset, property)?;
+                assert!(
+                    estimate <= written,
+                    "property {} estimated at {estimate} bytes but wrote {written}",
+                    property.get_name().get_owned_content()
+                );
+            }
+        }
+
+        let estimate = export.estimated_serialized_size();
+        let written = asset
+            .serialize_export(PackageIndex::new(i as i32 + 1))?
+            .len();
+        assert!(
+            estimate <= written,
+            "export {i} estimated at {estimate} bytes but wrote {written}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn estimated_size_prebulk() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    verify_estimates(&asset)
+}
+
+#[test]
+fn estimated_size_split_bulk() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(RACE_SIM_ASSET),
+        Some(Cursor::new(RACE_SIM_BULK)),
+        EngineVersion::VER_UE4_26,
+        None,
+    )?;
+    verify_estimates(&asset)?;
+
+    let asset = Asset::new(
+        Cursor::new(COSTUME_ASSET),
+        Some(Cursor::new(COSTUME_BULK)),
+        EngineVersion::VER_UE4_26,
+        None,
+    )?;
+    verify_estimates(&asset)
+}
+
+#[test]
+fn estimated_size_is_nonzero() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let total: usize = asset
+        .asset_data
+        .exports
+        .iter()
+        .map(|e| e.estimated_serialized_size())
+        .sum();
+    assert!(total > 0);
+
+    Ok(())
+}
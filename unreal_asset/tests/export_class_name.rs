@@ -0,0 +1,89 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{Export, ExportBaseTrait},
+    types::{PackageIndex, PackageIndexTrait},
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/BloodStained/"
+        )
+    };
+}
+
+const DATA_TABLE_ASSET: &[u8] =
+    include_bytes!(concat!(assets_folder!(), "PB_DT_ItemMaster.uasset"));
+const LEVEL_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "m05SAN_000_Gimmick.umap"));
+
+const BLUEPRINT_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const BLUEPRINT_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+/// Find the index of the first export matching `func`
+fn find_export<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+    func: impl Fn(&Export<PackageIndex>) -> bool,
+) -> PackageIndex {
+    let position = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(func)
+        .expect("No matching export");
+    PackageIndex::from_export(position as i32).unwrap()
+}
+
+#[test]
+fn export_class_name() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(DATA_TABLE_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let data_table = find_export(&asset, |e| cast!(Export, DataTableExport, e).is_some());
+    assert_eq!(
+        asset.export_class_name(data_table).as_deref(),
+        Some("DataTable")
+    );
+
+    let asset = Asset::new(
+        Cursor::new(LEVEL_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let level = find_export(&asset, |e| cast!(Export, LevelExport, e).is_some());
+    assert_eq!(asset.export_class_name(level).as_deref(), Some("Level"));
+
+    // the class default object's class is the blueprint generated class exported by the same asset
+    let asset = Asset::new(
+        Cursor::new(BLUEPRINT_ASSET),
+        Some(Cursor::new(BLUEPRINT_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    let default_object = find_export(&asset, |e| e.get_base_export().class_index.is_export());
+    assert_eq!(
+        asset.export_class_name(default_object).as_deref(),
+        Some("BP_DetPack_Charge_C")
+    );
+
+    // indexes that aren't exports of the asset don't resolve
+    assert_eq!(asset.export_class_name(PackageIndex::new(0)), None);
+    assert_eq!(asset.export_class_name(PackageIndex::new(-1)), None);
+    assert_eq!(asset.export_class_name(PackageIndex::new(i32::MAX)), None);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{Export, ExportNormalTrait},
+    parse_options::ParseOptions,
+    properties::Property,
+    types::PackageIndex,
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Zone_Library.umap"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "Zone_Library.uexp"));
+
+fn count_struct_properties(export: &Export<PackageIndex>) -> usize {
+    export
+        .get_normal_export()
+        .map(|e| {
+            e.properties
+                .iter()
+                .filter(|e| matches!(e, Property::StructProperty(_)))
+                .count()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn export_iter() -> Result<(), Error> {
+    let eager = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    let eager_count = eager
+        .asset_data
+        .exports
+        .iter()
+        .map(count_struct_properties)
+        .sum::<usize>();
+    assert_ne!(eager_count, 0);
+
+    let mut lazy = Asset::new_with_options(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+        ParseOptions {
+            skip_export_bodies: true,
+            ..Default::default()
+        },
+    )?;
+    assert_eq!(
+        lazy.asset_data.exports.len(),
+        eager.asset_data.exports.len()
+    );
+    assert!(lazy
+        .asset_data
+        .exports
+        .iter()
+        .all(|e| matches!(e, Export::BaseExport(_))));
+
+    let mut streamed = 0;
+    let mut lazy_count = 0;
+    for export in lazy.export_iter() {
+        let export = export?;
+        lazy_count += count_struct_properties(&export);
+        streamed += 1;
+    }
+    assert_eq!(streamed, eager.asset_data.exports.len());
+    assert_eq!(lazy_count, eager_count);
+
+    // export bodies are missing, so the asset can't be written back
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    assert!(lazy
+        .write_data(&mut cursor, Some(&mut bulk_cursor))
+        .is_err());
+    assert!(lazy
+        .write_verified(&mut cursor, Some(&mut bulk_cursor))
+        .is_err());
+
+    // until the bodies are loaded
+    lazy.reload_bulk_data(Cursor::new(TEST_BULK))?;
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    lazy.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    assert_eq!(cursor.into_inner(), TEST_ASSET);
+    assert_eq!(bulk_cursor.into_inner(), TEST_BULK);
+
+    Ok(())
+}
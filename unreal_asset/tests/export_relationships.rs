@@ -0,0 +1,133 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportBaseTrait,
+    types::{PackageIndex, PackageIndexTrait},
+    Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+fn relationships<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+    index: i32,
+) -> [PackageIndex; 4] {
+    let base_export = asset
+        .get_export(PackageIndex::new(index))
+        .expect("Missing export")
+        .get_base_export();
+    [
+        base_export.class_index(),
+        base_export.super_index(),
+        base_export.template_index(),
+        base_export.outer_index(),
+    ]
+}
+
+#[test]
+fn read_export_relationships() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let [class, super_struct, template, outer] = relationships(&asset, 1);
+    assert_eq!(
+        asset
+            .get_import(class)
+            .map(|e| e.object_name.get_owned_content()),
+        Some("BlueprintGeneratedClass".to_string())
+    );
+    assert!(super_struct.is_import());
+    assert_eq!(template, PackageIndex::new(0));
+    assert_eq!(outer, PackageIndex::new(0));
+
+    // the class default object is an instance of the generated class
+    assert_eq!(relationships(&asset, 2)[0], PackageIndex::new(1));
+    // the catalog data is nested in the class default object
+    assert_eq!(relationships(&asset, 3)[3], PackageIndex::new(2));
+
+    Ok(())
+}
+
+#[test]
+fn reparent_export_outer() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let export = PackageIndex::new(3);
+    asset.set_export_outer(export, PackageIndex::new(1))?;
+    asset.set_export_template(PackageIndex::new(2), PackageIndex::new(-1))?;
+
+    // exports can't be nested in themselves or in their own children
+    assert!(asset.set_export_outer(export, export).is_err());
+    assert!(asset
+        .set_export_outer(PackageIndex::new(1), export)
+        .is_err());
+    // targets have to exist
+    assert!(asset
+        .set_export_outer(export, PackageIndex::new(4))
+        .is_err());
+    assert!(asset
+        .set_export_class(export, PackageIndex::new(-23))
+        .is_err());
+    assert!(asset
+        .set_export_super(PackageIndex::new(4), PackageIndex::new(0))
+        .is_err());
+    assert_eq!(relationships(&asset, 3)[3], PackageIndex::new(1));
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let parsed = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(relationships(&parsed, 3)[3], PackageIndex::new(1));
+    assert_eq!(relationships(&parsed, 2)[2], PackageIndex::new(-1));
+
+    Ok(())
+}
+
+#[test]
+fn export_outer_existing_cycle() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // a malformed asset with exports 1 and 2 nested in each other
+    asset.asset_data.exports[0]
+        .get_base_export_mut()
+        .outer_index = PackageIndex::new(2);
+    asset.asset_data.exports[1]
+        .get_base_export_mut()
+        .outer_index = PackageIndex::new(1);
+
+    assert!(asset
+        .set_export_outer(PackageIndex::new(3), PackageIndex::new(1))
+        .is_err());
+    assert_eq!(relationships(&asset, 3)[3], PackageIndex::new(2));
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, reader::ArchiveTrait, types::FName, Asset, Error,
+};
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+#[test]
+fn split_display_string() {
+    assert_eq!(FName::split_display_string("Foo"), ("Foo", 0));
+    assert_eq!(FName::split_display_string("Foo_0"), ("Foo", 1));
+    assert_eq!(FName::split_display_string("Foo_3"), ("Foo", 4));
+    assert_eq!(FName::split_display_string("Foo_Bar"), ("Foo_Bar", 0));
+    assert_eq!(FName::split_display_string("Foo_Bar_12"), ("Foo_Bar", 13));
+
+    // not numbers the engine would have split off
+    assert_eq!(FName::split_display_string("Foo_"), ("Foo_", 0));
+    assert_eq!(FName::split_display_string("_3"), ("_3", 0));
+    assert_eq!(FName::split_display_string("Foo_03"), ("Foo_03", 0));
+    assert_eq!(FName::split_display_string("Foo_-3"), ("Foo_-3", 0));
+    assert_eq!(
+        FName::split_display_string("Foo_2147483647"),
+        ("Foo_2147483647", 0)
+    );
+}
+
+#[test]
+fn from_display_string() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    for (value, content, number) in [
+        ("Foo", "Foo", 0),
+        ("Foo_0", "Foo", 1),
+        ("Foo_3", "Foo", 4),
+        ("Foo_Bar", "Foo_Bar", 0),
+    ] {
+        let name = FName::from_display_string(&mut asset, value, true);
+        assert_eq!(name.get_owned_content(), content);
+        assert_eq!(name.get_number(), number);
+    }
+
+    // numbered names share the name map entry of their content
+    assert_eq!(
+        FName::from_display_string(&mut asset, "Foo_3", true),
+        asset.add_fname_with_number("Foo", 4)
+    );
+    assert!(
+        FName::from_display_string(&mut asset, "Foo_3", true).eq_content(&asset.add_fname("Foo"))
+    );
+
+    let name = FName::from_display_string(&mut asset, "Foo_3", false);
+    assert_eq!(name.get_owned_content(), "Foo_3");
+    assert_eq!(name.get_number(), 0);
+
+    Ok(())
+}
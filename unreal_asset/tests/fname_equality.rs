@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, types::FName, Asset, Error};
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+#[test]
+fn backed_dummy_equality() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let backed = asset.imports[0].object_name.clone();
+    assert!(matches!(backed, FName::Backed { .. }));
+
+    let content = backed.get_owned_content();
+    let dummy = FName::new_dummy(content.clone(), backed.get_number());
+    assert_eq!(backed, dummy);
+    assert_eq!(dummy, backed);
+
+    // the number is part of the name
+    let numbered = FName::new_dummy(content, backed.get_number() + 1);
+    assert_ne!(backed, numbered);
+    assert_ne!(numbered, backed);
+
+    assert_ne!(backed, FName::new_dummy("NotInTheNameMap".to_string(), 0));
+
+    Ok(())
+}
+
+#[test]
+// the name map isn't edited while the set is alive, so hashes stay stable
+#[allow(clippy::mutable_key_type)]
+fn backed_dummy_hash_set() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let mut names = HashSet::new();
+    for import in &asset.imports {
+        names.insert(import.object_name.clone());
+    }
+    let len = names.len();
+
+    // dummy names with the same content and number are already in the set
+    for import in &asset.imports {
+        let dummy = FName::new_dummy(
+            import.object_name.get_owned_content(),
+            import.object_name.get_number(),
+        );
+        assert!(names.contains(&dummy));
+        assert!(!names.insert(dummy));
+    }
+    assert_eq!(names.len(), len);
+
+    let dummy = FName::new_dummy("NotInTheNameMap".to_string(), 0);
+    assert!(names.insert(dummy.clone()));
+    assert!(names.contains(&dummy));
+    assert_eq!(names.len(), len + 1);
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    custom_version::FEditorObjectVersion,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        font_character_property::{FontCharacter, FontCharacterProperty},
+        slate_core::font_data_property::{EFontHinting, FontData, FontDataProperty},
+        Property, PropertyDataTrait,
+    },
+    types::PackageIndex,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+#[test]
+fn font_properties() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert!(
+        asset
+            .asset_data
+            .get_custom_version::<FEditorObjectVersion>()
+            .version
+            >= FEditorObjectVersion::AddedFontFaceAssets as i32
+    );
+
+    let mut font_data = FontData::default();
+    assert!(!font_data.is_cooked());
+    font_data.set_local_font_face_asset(PackageIndex::new(-1));
+    assert!(font_data.is_cooked());
+    assert_eq!(font_data.font_filename(), None);
+
+    // retarget the font to a file on disk
+    font_data.set_font_filename(Some("../../Content/Fonts/Mod.ttf".to_string()));
+    font_data.set_hinting(EFontHinting::AutoLight);
+    font_data.set_sub_face_index(1);
+    assert_eq!(font_data.local_font_face_asset(), PackageIndex::new(0));
+
+    let font_character = FontCharacter {
+        start_u: 12,
+        size_u: 24,
+        vertical_offset: -3,
+        ..Default::default()
+    };
+
+    let character_name = asset.add_fname("TestCharacter");
+    let font_data_name = asset.add_fname("TestFontData");
+    asset.add_fname("FontCharacter");
+    asset.add_fname("FontData");
+
+    let export = asset.asset_data.exports[32]
+        .get_normal_export_mut()
+        .expect("Export is not a normal export");
+    export
+        .properties
+        .push(Property::FontCharacterProperty(FontCharacterProperty {
+            name: character_name,
+            ancestry: Default::default(),
+            property_guid: Some(Default::default()),
+            duplication_index: 0,
+            value: font_character.clone(),
+        }));
+    export
+        .properties
+        .push(Property::FontDataProperty(FontDataProperty {
+            name: font_data_name,
+            ancestry: Default::default(),
+            property_guid: Some(Default::default()),
+            duplication_index: 0,
+            value: Some(font_data.clone()),
+        }));
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let mut asset = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        Some(Cursor::new(bulk_cursor.into_inner())),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    let properties = &mut asset.asset_data.exports[32]
+        .get_normal_export_mut()
+        .expect("Export is not a normal export")
+        .properties;
+
+    let read_character = properties
+        .iter_mut()
+        .find(|e| e.get_name() == "TestCharacter")
+        .and_then(|e| cast!(Property, FontCharacterProperty, e))
+        .expect("Failed to find TestCharacter");
+    assert_eq!(read_character.value, font_character);
+    assert_eq!(read_character.value.start_u, 12);
+    assert_eq!(read_character.value.vertical_offset, -3);
+
+    // adjust glyph metrics after reading
+    read_character.value.size_u = 32;
+    read_character.value.vertical_offset = 2;
+
+    let read_font_data = properties
+        .iter()
+        .find(|e| e.get_name() == "TestFontData")
+        .and_then(|e| cast!(Property, FontDataProperty, e))
+        .and_then(|e| e.value.as_ref())
+        .expect("Failed to find TestFontData");
+    assert_eq!(read_font_data, &font_data);
+    assert_eq!(
+        read_font_data.font_filename(),
+        Some("../../Content/Fonts/Mod.ttf")
+    );
+    assert_eq!(read_font_data.hinting(), Some(EFontHinting::AutoLight));
+    assert_eq!(read_font_data.sub_face_index(), Some(1));
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let asset_data = cursor.into_inner();
+    let bulk_data = bulk_cursor.into_inner();
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let read_character = asset.asset_data.exports[32]
+        .get_normal_export()
+        .and_then(|e| {
+            e.properties
+                .iter()
+                .find(|e| e.get_name() == "TestCharacter")
+        })
+        .and_then(|e| cast!(Property, FontCharacterProperty, e))
+        .expect("Failed to find TestCharacter");
+    assert_eq!(read_character.value.size_u, 32);
+    assert_eq!(read_character.value.vertical_offset, 2);
+
+    shared::verify_binary_equality(&asset_data, Some(&bulk_data), &mut asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::io::Cursor;
+
+use ordered_float::OrderedFloat;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    properties::vector_property::{Box2DProperty, BoxProperty, PlaneProperty},
+    reader::BufferReader,
+    types::{
+        vector::{Vector, Vector2},
+        FName,
+    },
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const UE4_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+const UE5_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "pseudoregalia/BP_looseWeapon.uasset"
+));
+const UE5_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "pseudoregalia/BP_looseWeapon.uexp"
+));
+
+fn vector(x: f64, y: f64, z: f64) -> Vector<OrderedFloat<f64>> {
+    Vector::new(OrderedFloat(x), OrderedFloat(y), OrderedFloat(z))
+}
+
+fn vector2(x: f64, y: f64) -> Vector2<OrderedFloat<f64>> {
+    Vector2::new(OrderedFloat(x), OrderedFloat(y))
+}
+
+/// Round-trip a box, a 2d box and a plane, returning the serialized size of the box value
+fn round_trip(asset: &mut Asset<Cursor<&[u8]>>) -> Result<usize, Error> {
+    let name = FName::new_dummy("Bounds".to_string(), 0);
+
+    let box_property = BoxProperty::from_min_max(
+        name.clone(),
+        Ancestry::default(),
+        vector(-1.0, -2.0, -3.0),
+        vector(3.0, 4.0, 5.0),
+        true,
+    );
+    assert_eq!(box_property.center(), vector(1.0, 1.0, 1.0));
+    assert_eq!(box_property.extent(), vector(2.0, 3.0, 4.0));
+    assert_eq!(box_property.v1.ancestry.get_parent().unwrap(), "Bounds");
+
+    let (box_size, data) = shared::write_property(asset, &box_property)?;
+    let mut reader = BufferReader::new(asset, &data);
+    let read_box = BoxProperty::new(&mut reader, name.clone(), Ancestry::default(), true, 0)?;
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(read_box, box_property);
+    assert_eq!(read_box.min(), vector(-1.0, -2.0, -3.0));
+    assert_eq!(read_box.max(), vector(3.0, 4.0, 5.0));
+
+    let box2d_property = Box2DProperty::from_min_max(
+        name.clone(),
+        Ancestry::default(),
+        vector2(0.0, -4.0),
+        vector2(8.0, 4.0),
+        false,
+    );
+    assert_eq!(box2d_property.center(), vector2(4.0, 0.0));
+    assert_eq!(box2d_property.extent(), vector2(4.0, 4.0));
+
+    let (_, data) = shared::write_property(asset, &box2d_property)?;
+    let mut reader = BufferReader::new(asset, &data);
+    let read_box2d = Box2DProperty::new(&mut reader, name.clone(), Ancestry::default(), true, 0)?;
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(read_box2d, box2d_property);
+
+    let plane_property = PlaneProperty::from_normal_distance(
+        name.clone(),
+        Ancestry::default(),
+        vector(0.0, 0.0, 1.0),
+        OrderedFloat(10.0),
+    );
+    assert_eq!(plane_property.normal(), vector(0.0, 0.0, 1.0));
+    assert_eq!(plane_property.distance(), OrderedFloat(10.0));
+    assert_eq!(
+        plane_property.distance_to(vector(5.0, 5.0, 15.0)),
+        OrderedFloat(5.0)
+    );
+    assert_eq!(
+        PlaneProperty::from_point_normal(
+            name.clone(),
+            Ancestry::default(),
+            vector(3.0, -7.0, 10.0),
+            vector(0.0, 0.0, 1.0),
+        ),
+        plane_property
+    );
+
+    let (_, data) = shared::write_property(asset, &plane_property)?;
+    let mut reader = BufferReader::new(asset, &data);
+    let read_plane = PlaneProperty::new(&mut reader, name, Ancestry::default(), true, 0)?;
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(read_plane, plane_property);
+
+    Ok(box_size)
+}
+
+#[test]
+fn geometry_properties_ue4() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(UE4_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    // two single precision vectors and the validity flag
+    assert_eq!(round_trip(&mut asset)?, 2 * 3 * 4 + 1);
+    Ok(())
+}
+
+#[test]
+fn geometry_properties_large_world_coordinates() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(UE5_ASSET),
+        Some(Cursor::new(UE5_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    // two double precision vectors and the validity flag
+    assert_eq!(round_trip(&mut asset)?, 2 * 3 * 8 + 1);
+    Ok(())
+}
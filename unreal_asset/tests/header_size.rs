@@ -0,0 +1,93 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, types::PackageIndex, Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+#[test]
+fn header_size_after_name_map_growth() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let original_size = asset.header_size();
+    assert_eq!(
+        asset.asset_data.exports[0].get_base_export().serial_offset,
+        original_size as i64
+    );
+
+    for i in 0..16 {
+        asset.add_fname(&format!("/Game/HeaderSizeTest/Name_{i}"));
+    }
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_verified(&mut cursor, None)?;
+    let data = cursor.into_inner();
+
+    let written = Asset::new(
+        Cursor::new(data.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let header_size = written.header_size();
+    assert!(header_size > original_size);
+
+    // the header offset points exactly at the serialized data of the first export
+    let first_export = written.serialize_export(PackageIndex::new(1))?;
+    let start = header_size as usize;
+    assert_eq!(&data[start..start + first_export.len()], first_export);
+
+    Ok(())
+}
+
+#[test]
+fn header_size_with_bulk_data() -> Result<(), Error> {
+    let data = include_bytes!(concat!(
+        assets_folder!(),
+        "pseudoregalia/BP_looseWeapon.uasset"
+    ));
+    let bulk = include_bytes!(concat!(
+        assets_folder!(),
+        "pseudoregalia/BP_looseWeapon.uexp"
+    ));
+
+    let mut asset = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    asset.add_fname("/Game/HeaderSizeTest/Name");
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_verified(&mut cursor, Some(&mut bulk_cursor))?;
+    let data = cursor.into_inner();
+    let bulk = bulk_cursor.into_inner();
+
+    // export data starts right after the header, in the .uexp file
+    let written = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert_eq!(written.header_size() as usize, data.len());
+    assert!(written.header_size() > asset.header_size());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error, Import};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/BloodStained/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "m05SAN_000_Gimmick.umap"));
+
+#[test]
+fn imports_by_package() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    let packages = asset.imports_by_package();
+
+    // every import is in the group of its class package, in import map order
+    assert_eq!(
+        packages.values().map(Vec::len).sum::<usize>(),
+        asset.imports.len()
+    );
+    for (package, imports) in &packages {
+        assert!(imports.iter().all(|e| e.class_package == package.as_str()));
+
+        let positions = imports
+            .iter()
+            .map(|e| asset.imports.iter().position(|i| std::ptr::eq(i, *e)))
+            .collect::<Option<Vec<_>>>()
+            .expect("Grouped import is not in the import map");
+        assert!(positions.windows(2).all(|e| e[0] < e[1]));
+    }
+
+    for package in [
+        "/Script/CoreUObject",
+        "/Script/Engine",
+        "/Script/ProjectBlood",
+    ] {
+        assert!(packages.contains_key(package), "{package} is missing");
+    }
+
+    // classes of a package all end up in the same group
+    let is_destructible = |e: &Import| e.class_name == "DestructibleComponent";
+    assert_eq!(
+        packages["/Script/ApexDestruction"]
+            .iter()
+            .filter(|e| is_destructible(e))
+            .count(),
+        asset.imports.iter().filter(|e| is_destructible(e)).count()
+    );
+
+    // blueprint default objects are grouped by their blueprint package
+    let area_door =
+        &packages["/Game/Core/Environment/Gimmick/NewGimmicks/Common/Door_New/BP_AreaDoor"];
+    assert_eq!(area_door.len(), 1);
+    assert_eq!(area_door[0].class_name, "BP_AreaDoor_C");
+    assert_eq!(area_door[0].object_name, "Default__BP_AreaDoor_C");
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
+    properties::PropertyDataTrait,
+    reader::{ArchiveReader, ArchiveWriter, RawReader, RawWriter},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+#[allow(clippy::duplicate_mod)]
+#[path = "shared.rs"]
+mod shared;
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+/// Serialize a name the way inline `FName`s are stored, a length-prefixed string followed by the number
+fn inline_name(data: &mut Vec<u8>, name: &str, number: i32) {
+    data.extend_from_slice(&(name.len() as i32 + 1).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.push(0);
+    data.extend_from_slice(&number.to_le_bytes());
+}
+
+#[test]
+fn inline_fnames() -> Result<(), Error> {
+    let mut data = Vec::new();
+    inline_name(&mut data, "StaticMeshComponent", 0);
+    inline_name(&mut data, "Mesh", 3);
+    inline_name(&mut data, "StaticMeshComponent", 1);
+
+    let parse_options = ParseOptions {
+        inline_fnames: true,
+        ..Default::default()
+    };
+
+    let name_map = NameMap::new();
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(Cursor::new(data.clone()), None),
+        ObjectVersion::VER_UE4_OLDEST_LOADABLE_PACKAGE,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+    reader.parse_options = parse_options;
+
+    let names = (0..3)
+        .map(|_| reader.read_fname())
+        .collect::<Result<Vec<_>, _>>()?;
+    let resolved = names
+        .iter()
+        .map(|e| (e.get_owned_content(), e.get_number()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        resolved,
+        [
+            ("StaticMeshComponent".to_string(), 0),
+            ("Mesh".to_string(), 3),
+            ("StaticMeshComponent".to_string(), 1),
+        ]
+    );
+
+    // names read inline are added to the name map once
+    assert_eq!(name_map.get_ref().get_name_map_index_list().len(), 2);
+    assert!(name_map.get_ref().search_name_reference("Mesh").is_some());
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::VER_UE4_OLDEST_LOADABLE_PACKAGE,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    writer.parse_options = parse_options;
+    for name in &names {
+        writer.write_fname(name)?;
+    }
+    assert_eq!(cursor.into_inner(), data);
+
+    Ok(())
+}
+
+#[test]
+fn inline_fnames_asset() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    let property_names = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| {
+            e.properties
+                .iter()
+                .map(|e| e.get_name().get_owned_content())
+        })
+        .collect::<Vec<_>>();
+
+    asset.asset_data.parse_options.inline_fnames = true;
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let data = cursor.into_inner();
+
+    let mut parsed = Asset::new_with_options(
+        Cursor::new(data.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        ParseOptions {
+            inline_fnames: true,
+            ..Default::default()
+        },
+    )?;
+    assert!(shared::verify_all_exports_parsed(&parsed));
+
+    let parsed_property_names = parsed
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| {
+            e.properties
+                .iter()
+                .map(|e| e.get_name().get_owned_content())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parsed_property_names, property_names);
+
+    shared::verify_binary_equality(&data, None, &mut parsed)?;
+
+    Ok(())
+}
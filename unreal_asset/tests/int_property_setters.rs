@@ -0,0 +1,65 @@
+use unreal_asset::{
+    error::PropertyError,
+    properties::int_property::{Int8Property, UInt16Property, UInt64Property},
+    Error,
+};
+
+#[test]
+fn try_set_in_range() -> Result<(), Error> {
+    let mut property = Int8Property::default();
+    property.try_set(-128)?;
+    assert_eq!(property.value, i8::MIN);
+    property.try_set(127)?;
+    assert_eq!(property.value, i8::MAX);
+
+    let mut property = UInt16Property::default();
+    property.try_set(65535)?;
+    assert_eq!(property.value, u16::MAX);
+
+    Ok(())
+}
+
+#[test]
+fn try_set_out_of_range() {
+    let mut property = Int8Property {
+        value: 5,
+        ..Default::default()
+    };
+    assert!(matches!(
+        property.try_set(128),
+        Err(Error::Property(PropertyError::ValueOutOfRange(_, 128, _)))
+    ));
+    // the value is left untouched on error
+    assert_eq!(property.value, 5);
+
+    let mut property = UInt16Property::default();
+    assert!(property.try_set(-1).is_err());
+    assert!(property.try_set(65536).is_err());
+
+    let mut property = UInt64Property::default();
+    assert!(property.try_set(-1).is_err());
+    assert_eq!(property.value, 0);
+}
+
+#[test]
+fn set_saturating() {
+    let mut property = Int8Property::default();
+    property.set_saturating(1000);
+    assert_eq!(property.value, i8::MAX);
+    property.set_saturating(-1000);
+    assert_eq!(property.value, i8::MIN);
+    property.set_saturating(42);
+    assert_eq!(property.value, 42);
+
+    let mut property = UInt16Property::default();
+    property.set_saturating(-5);
+    assert_eq!(property.value, 0);
+    property.set_saturating(70000);
+    assert_eq!(property.value, u16::MAX);
+
+    let mut property = UInt64Property::default();
+    property.set_saturating(i64::MAX);
+    assert_eq!(property.value, i64::MAX as u64);
+    property.set_saturating(i64::MIN);
+    assert_eq!(property.value, 0);
+}
@@ -0,0 +1,263 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        int_property::{FloatProperty, IntProperty},
+        Property, PropertyDataTrait,
+    },
+    reader::ArchiveTrait,
+    unversioned::Usmap,
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/")
+    };
+}
+
+const BULK_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "unknown_properties/BP_DetPack_Charge.uasset"
+));
+const BULK_ASSET_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "unknown_properties/BP_DetPack_Charge.uexp"
+));
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+const DUPLICATE_NAMES_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "duplicate_name_map_entries/BIOME_AzureWeald.uasset"
+));
+const DUPLICATE_NAMES_ASSET_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "duplicate_name_map_entries/BIOME_AzureWeald.uexp"
+));
+const DATA_TABLE_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "general/StarlitSeason/CharacterCostume_chr0001_DataTable.uasset"
+));
+const DATA_TABLE_ASSET_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "general/StarlitSeason/CharacterCostume_chr0001_DataTable.uexp"
+));
+
+fn write<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+) -> Result<(Vec<u8>, Option<Vec<u8>>), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = asset
+        .asset_data
+        .use_event_driven_loader
+        .then(|| Cursor::new(Vec::new()));
+    asset.write_data(&mut cursor, bulk_cursor.as_mut())?;
+    Ok((cursor.into_inner(), bulk_cursor.map(|e| e.into_inner())))
+}
+
+/// Convert to a JSON string and back, then check that the asset writes the same bytes
+fn assert_json_roundtrip<C: std::io::Read + std::io::Seek>(
+    asset: &Asset<C>,
+    engine_version: EngineVersion,
+    mappings: Option<Usmap>,
+) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let json = serde_json::to_string(&asset.to_json()?).expect("Failed to serialize JSON");
+    let value = serde_json::from_str(&json).expect("Failed to parse JSON");
+    let reconstructed = Asset::from_json(&value, engine_version, mappings)?;
+
+    assert!(write(asset)? == write(&reconstructed)?);
+    Ok(reconstructed)
+}
+
+fn check_asset(
+    data: &[u8],
+    bulk: Option<&[u8]>,
+    engine_version: EngineVersion,
+) -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(data),
+        bulk.map(Cursor::new),
+        engine_version,
+        None,
+    )?;
+    assert_json_roundtrip(&asset, engine_version, None)?;
+
+    // exports of every type store their properties, not raw data
+    let json = asset.to_json()?;
+    let exports = json["exports"].as_array().expect("Exports aren't an array");
+    assert!(exports
+        .iter()
+        .all(|e| e["type"] == "RawExport" || e.get("properties").is_some()));
+
+    Ok(())
+}
+
+#[test]
+fn json_roundtrip() -> Result<(), Error> {
+    check_asset(BULK_ASSET, Some(BULK_ASSET_BULK), EngineVersion::VER_UE4_25)?;
+    check_asset(PREBULK_ASSET, None, EngineVersion::VER_UE4_23)?;
+    check_asset(
+        DUPLICATE_NAMES_ASSET,
+        Some(DUPLICATE_NAMES_ASSET_BULK),
+        EngineVersion::VER_UE4_25,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn json_property_tags() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let guid = Guid::from(0x0123_4567_89ab_cdef_0011_2233_4455_6677u128);
+    let name = asset
+        .get_name_map()
+        .get_mut()
+        .add_fname_with_number("JsonTagged", 4);
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| e.get_normal_export().is_some())
+        .expect("No normal export");
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Not a normal export")
+        .properties
+        .insert(
+            0,
+            IntProperty {
+                name,
+                property_guid: Some(guid),
+                duplication_index: 2,
+                value: 7,
+                ..Default::default()
+            }
+            .into(),
+        );
+
+    let reconstructed = assert_json_roundtrip(&asset, EngineVersion::VER_UE4_23, None)?;
+
+    let property: &Property = &reconstructed.asset_data.exports[export_index]
+        .get_normal_export()
+        .expect("Not a normal export")
+        .properties[0];
+    assert_eq!(property.get_name().get_owned_content(), "JsonTagged");
+    assert_eq!(property.get_name().get_number(), 4);
+    assert_eq!(property.get_property_guid(), Some(guid));
+    assert_eq!(property.get_duplication_index(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn json_unsupported_property() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let name = asset.get_name_map().get_mut().add_fname("JsonNaN");
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| e.get_normal_export().is_some())
+        .expect("No normal export");
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Not a normal export")
+        .properties
+        .insert(
+            0,
+            FloatProperty {
+                name,
+                value: f32::NAN.into(),
+                ..Default::default()
+            }
+            .into(),
+        );
+
+    // only the property without a JSON representation is stored as hex
+    let json = asset.to_json()?;
+    let properties = &json["exports"][export_index]["properties"];
+    assert_eq!(properties[0]["type"], "FloatProperty");
+    assert!(properties[0].get("data").is_some());
+    assert!(properties[1].get("data").is_none());
+
+    let reconstructed = assert_json_roundtrip(&asset, EngineVersion::VER_UE4_23, None)?;
+    let property: &Property = &reconstructed.asset_data.exports[export_index]
+        .get_normal_export()
+        .expect("Not a normal export")
+        .properties[0];
+    match property {
+        Property::FloatProperty(e) => assert!(e.value.is_nan()),
+        property => panic!("Read {property:?} instead of a float property"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn json_unversioned() -> Result<(), Error> {
+    let mut tagged = Asset::new(
+        Cursor::new(DATA_TABLE_ASSET),
+        Some(Cursor::new(DATA_TABLE_ASSET_BULK)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+    let mappings = shared::mappings(&tagged);
+    tagged.set_unversioned(true, Some(&mappings))?;
+    let (data, bulk) = write(&tagged)?;
+
+    let asset = Asset::new(
+        Cursor::new(data),
+        bulk.map(Cursor::new),
+        EngineVersion::VER_UE4_24,
+        Some(mappings.clone()),
+    )?;
+    assert!(asset.has_unversioned_properties());
+    assert_json_roundtrip(&asset, EngineVersion::VER_UE4_24, Some(mappings))?;
+
+    // data table rows are stored as struct properties
+    let json = asset.to_json()?;
+    let rows = json["exports"][0]["rows"]
+        .as_array()
+        .expect("Rows aren't an array");
+    assert!(!rows.is_empty());
+    assert!(rows.iter().all(|e| e["type"] == "StructProperty"));
+
+    Ok(())
+}
+
+#[test]
+fn json_invalid() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let mut json = asset.to_json()?;
+    json["names"] = serde_json::Value::from(7);
+
+    let err = Asset::from_json(&json, EngineVersion::VER_UE4_23, None)
+        .expect_err("Read names from a number");
+    assert!(matches!(err, Error::InvalidFile(_)), "{err:?}");
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, types::PackageIndex, Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const FLAGGED_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "pseudoregalia/BP_looseWeapon.uasset"
+));
+const FLAGGED_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "pseudoregalia/BP_looseWeapon.uexp"
+));
+const UNFLAGGED_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+fn main_export_name<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Option<String> {
+    let main_export = asset.main_export()?;
+    asset
+        .get_export(main_export)
+        .map(|e| e.get_base_export().object_name.get_owned_content())
+}
+
+#[test]
+fn main_export_flagged() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(FLAGGED_ASSET),
+        Some(Cursor::new(FLAGGED_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let flagged = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter(|e| e.get_base_export().is_asset())
+        .count();
+    assert_eq!(flagged, 1);
+
+    assert_eq!(asset.main_export(), Some(PackageIndex::new(1)));
+    assert_eq!(
+        main_export_name(&asset).as_deref(),
+        Some("BP_looseWeapon_C")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn main_export_first_top_level() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(UNFLAGGED_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(asset
+        .asset_data
+        .exports
+        .iter()
+        .all(|e| !e.get_base_export().is_asset()));
+
+    assert_eq!(
+        main_export_name(&asset).as_deref(),
+        Some("Augment_BroadBrush_C")
+    );
+
+    // a flagged export takes precedence over the first top level one
+    asset.asset_data.exports[1].get_base_export_mut().is_asset = true;
+    assert_eq!(asset.main_export(), Some(PackageIndex::new(2)));
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    parse_options::ParseOptions,
+    properties::{struct_property::StructProperty, Property},
+    types::FName,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Misc_426/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "RaceSimDataAsset.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "RaceSimDataAsset.uexp"));
+
+/// Get the value struct types of the `RaceScalingOptions` map
+fn value_struct_types<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> Vec<String> {
+    let map = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| &e.properties)
+        .filter_map(|e| cast!(Property, MapProperty, e))
+        .find(|e| e.name == "RaceScalingOptions")
+        .expect("No RaceScalingOptions map");
+
+    map.value
+        .values()
+        .map(|e| {
+            let value: &StructProperty =
+                cast!(Property, StructProperty, e).expect("Map value is not a StructProperty");
+            assert!(!value.value.is_empty());
+            value.struct_type.as_ref().unwrap().get_owned_content()
+        })
+        .collect()
+}
+
+#[test]
+fn map_struct_type_overrides() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_26,
+        None,
+    )?;
+    // nothing tells the parser what struct the values are
+    assert_eq!(value_struct_types(&asset), ["Generic"]);
+
+    let mut asset = Asset::new_with_map_struct_type_overrides(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_26,
+        None,
+        ParseOptions::default(),
+        HashMap::from([(
+            "RaceScalingOptions".to_string(),
+            (
+                FName::from_slice("Generic"),
+                FName::from_slice("RaceScalingOption"),
+            ),
+        )]),
+    )?;
+    assert_eq!(value_struct_types(&asset), ["RaceScalingOption"]);
+    assert_eq!(
+        asset
+            .asset_data
+            .map_value_override
+            .get_by_key("RaceScalingOptions"),
+        Some(&"RaceScalingOption".to_string())
+    );
+
+    shared::verify_binary_equality(TEST_ASSET, Some(TEST_BULK), &mut asset)?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    Ok(())
+}
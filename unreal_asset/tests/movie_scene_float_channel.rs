@@ -0,0 +1,74 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        movies::movie_scene_float_channel_property::MovieSceneFloatChannel,
+        rich_curve_key_property::{RichCurveExtrapolation, RichCurveInterpMode},
+    },
+    reader::{RawReader, RawWriter},
+    types::PackageIndex,
+    Error,
+};
+
+#[test]
+fn movie_scene_float_channel() -> Result<(), Error> {
+    let mut channel = MovieSceneFloatChannel::default();
+    channel.set_extrapolation(
+        RichCurveExtrapolation::Cycle,
+        RichCurveExtrapolation::Linear,
+    );
+    channel.set_keys(
+        &[(30, 1.0), (0, 0.5), (60, -2.0)],
+        RichCurveInterpMode::Linear,
+    );
+
+    assert_eq!(
+        channel.keys().collect::<Vec<_>>(),
+        [(0, 0.5), (30, 1.0), (60, -2.0)]
+    );
+    assert_eq!(channel.times().len(), channel.values().len());
+    assert!(channel
+        .values()
+        .iter()
+        .all(|e| e.interp_mode == RichCurveInterpMode::Linear));
+
+    channel.set_interp_mode(RichCurveInterpMode::Cubic);
+
+    let name_map = NameMap::new();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+    channel.write(&mut writer)?;
+
+    cursor.set_position(0);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(cursor, None),
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    let read = MovieSceneFloatChannel::new(&mut reader)?;
+
+    assert_eq!(read, channel);
+    assert_eq!(read.pre_infinity_extrap, RichCurveExtrapolation::Cycle);
+    assert_eq!(read.post_infinity_extrap, RichCurveExtrapolation::Linear);
+    assert!(read
+        .values()
+        .iter()
+        .all(|e| e.interp_mode == RichCurveInterpMode::Cubic));
+
+    // rebuilding keys keeps extrapolation
+    channel.set_keys(&[(10, 3.0)], RichCurveInterpMode::Constant);
+    assert_eq!(channel.pre_infinity_extrap, RichCurveExtrapolation::Cycle);
+    assert_eq!(channel.keys().collect::<Vec<_>>(), [(10, 3.0)]);
+
+    Ok(())
+}
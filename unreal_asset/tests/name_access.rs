@@ -0,0 +1,86 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::io::Cursor;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportNormalTrait, properties::PropertyDataTrait,
+    Asset, Error,
+};
+
+/// Allocator counting every allocation, this file only has a single test so nothing else allocates in between
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(func: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = func();
+    (result, ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+#[test]
+fn borrowing_name_access() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let properties = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .collect::<Vec<_>>();
+    assert!(!properties.is_empty());
+
+    let (owned_count, owned_allocations) = count_allocations(|| {
+        properties
+            .iter()
+            .filter(|e| e.get_name().get_owned_content().starts_with("Coop"))
+            .count()
+    });
+    let (borrowed_count, borrowed_allocations) = count_allocations(|| {
+        properties
+            .iter()
+            .filter(|e| e.get_name_ref().get_content(|e| e.starts_with("Coop")))
+            .count()
+    });
+
+    assert_eq!(owned_count, borrowed_count);
+    assert!(owned_allocations >= properties.len());
+    assert_eq!(borrowed_allocations, 0);
+
+    // properties that store their name lend it out
+    assert!(properties
+        .iter()
+        .all(|e| matches!(e.get_name_ref(), Cow::Borrowed(_))));
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    parse_options::{ParseAnomaly, ParseOptions},
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+
+/// Copy of the test asset with the summary name count changed by `delta`
+fn with_name_count_delta(delta: i32) -> Result<(Vec<u8>, i32), Error> {
+    let name_count =
+        Asset::read_summary_with_version(&mut Cursor::new(TEST_ASSET), EngineVersion::VER_UE4_23)?
+            .name_count;
+
+    // the name count is the first field after the folder name and package flags
+    let position = TEST_ASSET
+        .windows(4)
+        .skip(24)
+        .position(|window| window == name_count.to_le_bytes())
+        .expect("Name count not found in the summary")
+        + 24;
+
+    let mut data = TEST_ASSET.to_vec();
+    data[position..position + 4].copy_from_slice(&(name_count + delta).to_le_bytes());
+    Ok((data, name_count))
+}
+
+fn parse(data: &[u8], lenient: bool) -> Result<Asset<Cursor<&[u8]>>, Error> {
+    Asset::new_with_options(
+        Cursor::new(data),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+        ParseOptions {
+            lenient,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn name_count_mismatch_strict() -> Result<(), Error> {
+    for delta in [-1, 1] {
+        let (data, name_count) = with_name_count_delta(delta)?;
+
+        let err = parse(&data, false).expect_err("Wrong name count was accepted");
+        match err.root() {
+            Error::NameCountMismatch { expected, actual } => {
+                assert_eq!(*expected, name_count + delta);
+                assert_eq!(*actual, name_count);
+            }
+            err => panic!("Unexpected error {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn name_count_mismatch_lenient() -> Result<(), Error> {
+    let original = parse(TEST_ASSET, false)?;
+    assert!(original.anomalies().is_empty());
+
+    for delta in [-1, 1] {
+        let (data, name_count) = with_name_count_delta(delta)?;
+
+        let asset = parse(&data, true)?;
+        assert_eq!(
+            asset.anomalies(),
+            [ParseAnomaly::NameCountMismatch {
+                expected: name_count + delta,
+                actual: name_count,
+            }]
+        );
+        assert_eq!(asset.asset_data.summary.name_count, name_count);
+        assert_eq!(
+            asset.get_name_map().get_ref().get_name_map_index_list(),
+            original.get_name_map().get_ref().get_name_map_index_list()
+        );
+        assert_eq!(asset.imports.len(), original.imports.len());
+        assert_eq!(
+            asset.asset_data.exports.len(),
+            original.asset_data.exports.len()
+        );
+
+        // writing uses the actual count, restoring the original asset
+        let mut cursor = Cursor::new(Vec::new());
+        asset.write_data(&mut cursor, None)?;
+        assert_eq!(cursor.into_inner(), TEST_ASSET);
+    }
+
+    Ok(())
+}
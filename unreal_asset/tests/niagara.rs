@@ -0,0 +1,240 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        int_property::BytePropertyValue,
+        niagara::niagara_variable_property::{NiagaraVariableProperty, NiagaraVariableValue},
+        Property,
+    },
+    types::PackageIndex,
+    Asset, Error, Import,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Zone_Caves.umap"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "Zone_Caves.uexp"));
+
+/// Get the first parameter store of a Niagara component with its variable and parameter data
+fn get_parameter_store<'a>(
+    asset: &'a mut Asset<Cursor<&[u8]>>,
+) -> (&'a mut NiagaraVariableProperty, &'a mut Vec<Property>) {
+    let store = asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| e.get_normal_export_mut())
+        .flat_map(|e| e.properties.iter_mut())
+        .filter_map(|e| cast!(Property, StructProperty, e))
+        .find(|e| e.name == "OverrideParameters" && !e.value.is_empty())
+        .expect("Failed to find a parameter store");
+
+    let mut variable = None;
+    let mut parameter_data = None;
+    for property in store.value.iter_mut() {
+        let Some(array) = cast!(Property, ArrayProperty, property) else {
+            continue;
+        };
+
+        if array.name == "SortedParameterOffsets" {
+            variable = array
+                .value
+                .iter_mut()
+                .filter_map(|e| cast!(Property, StructProperty, e))
+                .flat_map(|e| e.value.iter_mut())
+                .find_map(|e| cast!(Property, NiagaraVariableWithOffsetProperty, e))
+                .map(|e| &mut e.niagara_variable);
+        } else if array.name == "ParameterData" {
+            parameter_data = Some(&mut array.value);
+        }
+    }
+
+    (
+        variable.expect("Failed to find a variable"),
+        parameter_data.expect("Failed to find parameter data"),
+    )
+}
+
+fn to_bytes(parameter_data: &[Property]) -> Vec<u8> {
+    parameter_data
+        .iter()
+        .map(
+            |e| match cast!(Property, ByteProperty, e).map(|e| &e.value) {
+                Some(BytePropertyValue::Byte(value)) => *value,
+                _ => panic!("Parameter data is not a byte array"),
+            },
+        )
+        .collect()
+}
+
+fn from_bytes(parameter_data: &mut [Property], bytes: &[u8]) {
+    for (property, byte) in parameter_data.iter_mut().zip(bytes) {
+        if let Some(property) = cast!(Property, ByteProperty, property) {
+            property.value = BytePropertyValue::Byte(*byte);
+        }
+    }
+}
+
+fn roundtrip(asset: &Asset<Cursor<&[u8]>>) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    Ok((cursor.into_inner(), bulk_cursor.into_inner()))
+}
+
+#[test]
+fn niagara_variable_value_bytes() {
+    let value = NiagaraVariableValue::Float(0.25);
+    assert_eq!(
+        NiagaraVariableValue::from_bytes("NiagaraFloat", &value.to_bytes()),
+        Some(value)
+    );
+
+    let value = NiagaraVariableValue::Bool(true);
+    assert_eq!(value.to_bytes(), (-1i32).to_le_bytes());
+    assert_eq!(
+        NiagaraVariableValue::from_bytes("NiagaraBool", &value.to_bytes()),
+        Some(value)
+    );
+
+    assert_eq!(
+        NiagaraVariableValue::from_bytes("NiagaraFloat", &[0; 2]),
+        None
+    );
+    assert_eq!(
+        NiagaraVariableValue::from_bytes("NiagaraDataInterface", &[0; 4]),
+        None
+    );
+}
+
+#[test]
+fn niagara_linear_color() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let (variable, parameter_data) = get_parameter_store(&mut asset);
+    let variable = variable.clone();
+    let mut bytes = to_bytes(parameter_data);
+
+    assert_eq!(variable.variable_name, "User.Color");
+    assert_eq!(
+        variable
+            .get_type_name(&asset)
+            .map(|e| e.get_owned_content()),
+        Some("LinearColor".to_string())
+    );
+    let Some(NiagaraVariableValue::LinearColor(color)) = variable.read_value(&asset, &bytes) else {
+        panic!("User.Color is not a LinearColor");
+    };
+
+    let new_color = [1.0, 0.5, 0.25, color[3]];
+    variable.write_value(&mut bytes, NiagaraVariableValue::LinearColor(new_color))?;
+    from_bytes(get_parameter_store(&mut asset).1, &bytes);
+
+    let (asset_data, bulk_data) = roundtrip(&asset)?;
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let (variable, parameter_data) = get_parameter_store(&mut asset);
+    let variable = variable.clone();
+    let bytes = to_bytes(parameter_data);
+    assert_eq!(
+        variable.read_value(&asset, &bytes),
+        Some(NiagaraVariableValue::LinearColor(new_color))
+    );
+
+    shared::verify_binary_equality(&asset_data, Some(&bulk_data), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn niagara_float() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    // retype the variable to a float
+    let niagara_package = asset
+        .imports
+        .iter()
+        .position(|e| e.object_name == "/Script/Niagara")
+        .map(|e| PackageIndex::new(-(e as i32) - 1))
+        .expect("Failed to find /Script/Niagara");
+    let import = Import::new(
+        asset.add_fname("/Script/CoreUObject"),
+        asset.add_fname("ScriptStruct"),
+        niagara_package,
+        asset.add_fname("NiagaraFloat"),
+        false,
+    );
+    let float_type = asset.add_import(import);
+
+    let (variable, parameter_data) = get_parameter_store(&mut asset);
+    variable.set_type_definition(float_type)?;
+    let variable = variable.clone();
+    let mut bytes = to_bytes(parameter_data);
+
+    assert_eq!(variable.get_type_definition(), Some(float_type));
+    let Some(NiagaraVariableValue::Float(_)) = variable.read_value(&asset, &bytes) else {
+        panic!("Variable is not a float");
+    };
+
+    variable.write_value(&mut bytes, NiagaraVariableValue::Float(42.5))?;
+    from_bytes(get_parameter_store(&mut asset).1, &bytes);
+
+    let (asset_data, bulk_data) = roundtrip(&asset)?;
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let (variable, parameter_data) = get_parameter_store(&mut asset);
+    let variable = variable.clone();
+    let bytes = to_bytes(parameter_data);
+    assert_eq!(
+        variable
+            .get_type_name(&asset)
+            .map(|e| e.get_owned_content()),
+        Some("NiagaraFloat".to_string())
+    );
+    assert_eq!(
+        variable.read_value(&asset, &bytes),
+        Some(NiagaraVariableValue::Float(42.5))
+    );
+
+    // writing past the end of the parameter data fails
+    let mut short = vec![0u8; 2];
+    assert!(variable
+        .write_value(&mut short, NiagaraVariableValue::Float(1.0))
+        .is_err());
+
+    shared::verify_binary_equality(&asset_data, Some(&bulk_data), &mut asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, flags::EObjectFlags, Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+#[test]
+fn toggle_object_flags() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let base_export = asset.asset_data.exports[0].get_base_export_mut();
+    let original_flags = base_export.object_flags();
+    assert!(!original_flags.contains(EObjectFlags::RF_TRANSIENT));
+
+    base_export.set_object_flag(EObjectFlags::RF_TRANSIENT, true);
+    base_export.set_object_flag(EObjectFlags::RF_PUBLIC, false);
+
+    let expected_flags = (original_flags | EObjectFlags::RF_TRANSIENT) & !EObjectFlags::RF_PUBLIC;
+    assert_eq!(base_export.object_flags(), expected_flags);
+    assert_eq!(
+        base_export.object_flags().bits(),
+        (original_flags.bits() | 0x40) & !0x1
+    );
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let parsed = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(
+        parsed.asset_data.exports[0]
+            .get_base_export()
+            .object_flags(),
+        expected_flags
+    );
+
+    let base_export = asset.asset_data.exports[0].get_base_export_mut();
+    base_export.set_object_flag(EObjectFlags::RF_TRANSIENT, false);
+    base_export.set_object_flag(
+        EObjectFlags::RF_PUBLIC,
+        original_flags.contains(EObjectFlags::RF_PUBLIC),
+    );
+    assert_eq!(base_export.object_flags(), original_flags);
+
+    Ok(())
+}
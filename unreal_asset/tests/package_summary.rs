@@ -0,0 +1,129 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+const VERSIONED_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Versioned/Assault_M1A1Thompson_WW2_DrumSuppressor.uasset"
+));
+
+#[test]
+fn package_summary_accessors() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert_eq!(asset.package_source(), 0xb65c3d5f);
+    assert_eq!(asset.file_licensee_version(), 0);
+
+    let asset = Asset::new(
+        Cursor::new(VERSIONED_ASSET),
+        None,
+        EngineVersion::UNKNOWN,
+        None,
+    )?;
+    assert_eq!(asset.package_source(), 0x78ca8d76);
+    assert_eq!(asset.file_licensee_version(), 7);
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let asset = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::UNKNOWN,
+        None,
+    )?;
+    assert_eq!(asset.package_source(), 0x78ca8d76);
+    assert_eq!(asset.file_licensee_version(), 7);
+
+    Ok(())
+}
+
+#[test]
+fn not_an_asset() {
+    let mut not_an_asset = TEST_ASSET.to_vec();
+    not_an_asset[0] = 0;
+
+    for data in [&not_an_asset[..], b"PK\x03\x04"] {
+        let result = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_25, None);
+        assert!(matches!(result, Err(Error::NotAnAsset)));
+    }
+
+    // a file too short to hold the magic fails to read like any other truncated file
+    for data in [&b""[..], &TEST_ASSET[..2]] {
+        let result = Asset::new(Cursor::new(data), None, EngineVersion::VER_UE4_25, None);
+        assert!(!matches!(result, Err(Error::NotAnAsset)), "{result:?}");
+        assert!(matches!(result.unwrap_err().root(), Error::Io(_)));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "diagnostic"))]
+fn truncated_summary_io_error() {
+    let result = Asset::new(
+        Cursor::new(&VERSIONED_ASSET[..64]),
+        None,
+        EngineVersion::VER_UE4_14,
+        None,
+    );
+    assert!(matches!(result, Err(Error::Io(_))), "{result:?}");
+}
+
+#[test]
+fn read_summary() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(VERSIONED_ASSET),
+        None,
+        EngineVersion::UNKNOWN,
+        None,
+    )?;
+
+    let mut reader = Cursor::new(VERSIONED_ASSET);
+    let summary = Asset::read_summary(&mut reader)?;
+    assert_eq!(summary, asset.asset_data.summary);
+    // this object version doesn't belong to a retail engine version
+    assert_eq!(summary.engine_version, EngineVersion::UNKNOWN);
+    assert_eq!(
+        summary.name_count as usize,
+        asset
+            .get_name_map()
+            .get_ref()
+            .get_name_map_index_list()
+            .len()
+    );
+    assert_eq!(summary.import_count as usize, asset.imports.len());
+    assert_eq!(
+        summary.export_count as usize,
+        asset.asset_data.exports.len()
+    );
+
+    // nothing after the summary is read
+    assert!(reader.position() < asset.header_size() as u64);
+
+    // unversioned assets need an engine version, but never their bulk data
+    assert!(Asset::read_summary(&mut Cursor::new(TEST_ASSET)).is_err());
+
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    let summary =
+        Asset::read_summary_with_version(&mut Cursor::new(TEST_ASSET), EngineVersion::VER_UE4_25)?;
+    assert_eq!(summary, asset.asset_data.summary);
+    assert_eq!(summary.engine_version, EngineVersion::VER_UE4_25);
+
+    Ok(())
+}
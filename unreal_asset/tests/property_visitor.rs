@@ -0,0 +1,200 @@
+use unreal_asset::{
+    containers::IndexedMap,
+    properties::{
+        array_property::ArrayProperty,
+        empty_property::EmptyProperty,
+        int_property::IntProperty,
+        map_property::MapProperty,
+        niagara::niagara_variable_property::{
+            NiagaraVariableProperty, NiagaraVariableWithOffsetProperty,
+        },
+        object_property::ObjectProperty,
+        set_property::SetProperty,
+        struct_property::StructProperty,
+        unknown_property::UnknownProperty,
+        Property, PropertyDataTrait,
+    },
+    types::{FName, PackageIndex},
+};
+
+fn int(name: &str, value: i32) -> Property {
+    IntProperty {
+        name: FName::from_slice(name),
+        value,
+        ..Default::default()
+    }
+    .into()
+}
+
+fn object(name: &str, index: i32) -> Property {
+    ObjectProperty {
+        name: FName::from_slice(name),
+        value: PackageIndex::new(index),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn structure(name: &str, value: Vec<Property>) -> Property {
+    StructProperty {
+        name: FName::from_slice(name),
+        value,
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Root {
+///     Count: Int,
+///     Objects: [Object, Object],
+///     Lookup: { Int: Object, Int: Entry { Object, Empty } }, removed keys [Int],
+///     Ids: Set [Int], removed items [Unknown],
+/// }
+fn nested_property() -> Property {
+    let mut lookup = IndexedMap::new();
+    lookup.insert(int("Key", 1), object("Value", 1));
+    lookup.insert(
+        int("Key", 2),
+        structure(
+            "Entry",
+            vec![
+                object("Target", 2),
+                EmptyProperty {
+                    name: FName::from_slice("Zero"),
+                    ..Default::default()
+                }
+                .into(),
+            ],
+        ),
+    );
+
+    structure(
+        "Root",
+        vec![
+            int("Count", 3),
+            ArrayProperty {
+                name: FName::from_slice("Objects"),
+                value: vec![object("0", 3), object("1", 4)],
+                ..Default::default()
+            }
+            .into(),
+            MapProperty {
+                name: FName::from_slice("Lookup"),
+                value: lookup,
+                keys_to_remove: Some(vec![int("Key", 5)]),
+                ..Default::default()
+            }
+            .into(),
+            SetProperty {
+                name: FName::from_slice("Ids"),
+                value: ArrayProperty {
+                    value: vec![int("Id", 6)],
+                    ..Default::default()
+                },
+                removed_items: ArrayProperty {
+                    value: vec![UnknownProperty {
+                        name: FName::from_slice("Removed"),
+                        value: vec![1, 2, 3],
+                        ..Default::default()
+                    }
+                    .into()],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+            .into(),
+        ],
+    )
+}
+
+#[test]
+fn visit_nested_properties() {
+    let property = nested_property();
+
+    let mut names = Vec::new();
+    property.visit(&mut |property| names.push(property.get_name().get_owned_content()));
+    assert_eq!(
+        names,
+        [
+            "Root", "Count", "Objects", "0", "1", "Lookup", "Key", "Key", "Value", "Key", "Entry",
+            "Target", "Zero", "Ids", "Id", "Removed"
+        ]
+    );
+
+    let mut objects = Vec::new();
+    property.visit(&mut |property| {
+        if let Property::ObjectProperty(object) = property {
+            objects.push(object.value.index);
+        }
+    });
+    assert_eq!(objects, [3, 4, 1, 2]);
+}
+
+#[test]
+fn visit_mut_nested_properties() {
+    let mut property = nested_property();
+
+    // visiting without changes keeps maps equal, even though they are rebuilt
+    property.visit_mut(&mut |_| {});
+    assert_eq!(property, nested_property());
+
+    property.visit_mut(&mut |property| match property {
+        Property::ObjectProperty(object) => object.value = PackageIndex::new(-1),
+        Property::IntProperty(int) => int.value *= 10,
+        _ => {}
+    });
+
+    let mut values = Vec::new();
+    property.visit(&mut |property| match property {
+        Property::ObjectProperty(object) => values.push(object.value.index),
+        Property::IntProperty(int) => values.push(int.value),
+        _ => {}
+    });
+    assert_eq!(values, [30, -1, -1, 50, 10, -1, 20, -1, 60]);
+
+    // rewritten map keys can still be looked up
+    let Property::StructProperty(root) = &property else {
+        panic!("root is not a struct");
+    };
+    let Property::MapProperty(lookup) = &root.value[2] else {
+        panic!("lookup is not a map");
+    };
+    assert_eq!(
+        lookup.value.get_by_key(&int("Key", 10)),
+        Some(&object("Value", -1))
+    );
+}
+
+#[test]
+fn visit_niagara_variable() {
+    let mut property: Property = NiagaraVariableWithOffsetProperty {
+        niagara_variable: NiagaraVariableProperty {
+            struct_property: StructProperty {
+                name: FName::from_slice("Variable"),
+                value: vec![object("ClassStructOrEnum", 7), int("UnderlyingType", 0)],
+                ..Default::default()
+            },
+            variable_name: FName::from_slice("User.Color"),
+            variable_offset: 0,
+        },
+    }
+    .into();
+
+    let mut names = Vec::new();
+    property.visit(&mut |property| names.push(property.get_name().get_owned_content()));
+    assert_eq!(names, ["Variable", "ClassStructOrEnum", "UnderlyingType"]);
+
+    property.visit_mut(&mut |property| {
+        if let Property::ObjectProperty(object) = property {
+            object.value = PackageIndex::new(-2);
+        }
+    });
+
+    let Property::NiagaraVariableWithOffsetProperty(property) = &property else {
+        panic!("property is not a niagara variable");
+    };
+    assert_eq!(
+        property.niagara_variable.get_type_definition(),
+        Some(PackageIndex::new(-2))
+    );
+}
@@ -0,0 +1,107 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{base_export::BaseExport, raw_export::RawExport},
+    properties::{raw_struct_property::RawStructProperty, unknown_property::UnknownProperty},
+    reader::BufferReader,
+    types::{FName, PackageIndex},
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+
+const DATA: [u8; 16] = [7; 16];
+
+fn parse() -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )
+}
+
+fn unknown_property(
+    asset: &mut Asset<Cursor<&[u8]>>,
+    length: i64,
+) -> Result<UnknownProperty, Error> {
+    let mut reader = BufferReader::new(asset, &DATA);
+    UnknownProperty::new(
+        &mut reader,
+        FName::from_slice("Unknown"),
+        Ancestry::default(),
+        false,
+        length,
+        0,
+        FName::from_slice("HollowProperty"),
+    )
+}
+
+fn assert_invalid_file(err: Error, type_name: &str) {
+    assert_eq!(err.offset(), Some(0));
+    assert!(matches!(err.root(), Error::InvalidFile(_)), "{err:?}");
+    assert!(
+        err.to_string()
+            .starts_with(&format!("Failed to read {type_name} at offset 0")),
+        "{err}"
+    );
+}
+
+#[test]
+fn unknown_property_length() -> Result<(), Error> {
+    let mut asset = parse()?;
+
+    let property = unknown_property(&mut asset, DATA.len() as i64)?;
+    assert_eq!(property.value, DATA);
+
+    for length in [DATA.len() as i64 + 1, 0x7fff_ffff_ffff, -1] {
+        let err = unknown_property(&mut asset, length).expect_err("Read past the end of the data");
+        assert_invalid_file(err, "HollowProperty");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn raw_struct_property_length() -> Result<(), Error> {
+    let mut asset = parse()?;
+    let mut reader = BufferReader::new(&mut asset, &DATA);
+    let err = RawStructProperty::new(
+        &mut reader,
+        FName::from_slice("Raw"),
+        Ancestry::default(),
+        false,
+        0,
+        i64::MAX,
+    )
+    .expect_err("Read past the end of the data");
+    assert_invalid_file(err, "RawStructProperty");
+
+    Ok(())
+}
+
+#[test]
+fn raw_export_length() -> Result<(), Error> {
+    let mut asset = parse()?;
+    let mut reader = BufferReader::new(&mut asset, &DATA);
+    let base_export = BaseExport::<PackageIndex> {
+        serial_size: 0x7fff_ffff_ffff,
+        ..Default::default()
+    };
+    let err =
+        RawExport::from_base(base_export, &mut reader).expect_err("Read past the end of the data");
+    assert_invalid_file(err, "RawExport");
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    error::PropertyError,
+    exports::Export,
+    properties::{
+        raw_struct_property::RawStructProperty, struct_property::StructProperty, Property,
+        PropertyTrait,
+    },
+    reader::{ArchiveTrait, RawWriter},
+    types::PackageIndex,
+    Asset, Error,
+};
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+
+type TestAsset = Asset<Cursor<&'static [u8]>>;
+
+/// Find the `ConstructionRecipe` struct and turn it into a `RawStructProperty` holding its serialized value
+fn read_recipe() -> Result<(TestAsset, StructProperty, RawStructProperty), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let recipe = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| cast!(Export, NormalExport, e))
+        .flat_map(|e| e.properties.iter())
+        .find_map(|e| match e {
+            Property::StructProperty(e) if e.name == "ConstructionRecipe" => Some(e.clone()),
+            _ => None,
+        })
+        .expect("Failed to find ConstructionRecipe");
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        asset.get_object_version(),
+        asset.get_object_version_ue5(),
+        asset.use_event_driven_loader(),
+        asset.get_name_map(),
+    );
+    recipe.write(&mut writer, false)?;
+
+    let raw = RawStructProperty {
+        name: recipe.name.clone(),
+        ancestry: recipe.ancestry.clone(),
+        property_guid: recipe.property_guid,
+        duplication_index: recipe.duplication_index,
+        value: cursor.into_inner(),
+    };
+
+    Ok((asset, recipe, raw))
+}
+
+#[test]
+fn reinterpret_raw_struct() -> Result<(), Error> {
+    let (mut asset, recipe, raw) = read_recipe()?;
+    assert!(!recipe.value.is_empty());
+
+    let reinterpreted = raw.reinterpret(&mut asset, "Recipe")?;
+    assert_eq!(reinterpreted.name, recipe.name);
+    assert_eq!(reinterpreted.struct_type, recipe.struct_type);
+    assert_eq!(reinterpreted.value, recipe.value);
+
+    Ok(())
+}
+
+#[test]
+fn reinterpret_leftover_data() -> Result<(), Error> {
+    let (mut asset, _, mut raw) = read_recipe()?;
+    raw.value.extend_from_slice(&[0; 4]);
+
+    assert!(matches!(
+        raw.reinterpret(&mut asset, "Recipe"),
+        Err(Error::Property(PropertyError::InvalidStruct(_)))
+    ));
+
+    Ok(())
+}
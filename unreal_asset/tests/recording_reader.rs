@@ -0,0 +1,95 @@
+use std::io::Cursor;
+
+use byteorder::{ReadBytesExt, LE};
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::font_character_property::FontCharacter,
+    reader::{
+        recording_reader::ReadRecord, ArchiveReader, ArchiveTrait, ArchiveWriter, RawReader,
+        RawWriter, RecordingReader,
+    },
+    types::PackageIndex,
+    Error,
+};
+
+fn record(offset: u64, size: usize, label: Option<&str>) -> ReadRecord {
+    ReadRecord {
+        offset,
+        size,
+        label: label.map(str::to_string),
+    }
+}
+
+#[test]
+fn recording_reader() -> Result<(), Error> {
+    let font_character = FontCharacter {
+        start_u: 1,
+        start_v: 2,
+        size_u: 3,
+        size_v: 4,
+        texture_index: 5,
+        vertical_offset: -6,
+    };
+
+    let name_map = NameMap::new();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+    writer.write_fstring(Some("Glyph"))?;
+    font_character.write(&mut writer)?;
+    writer.write_bool(true)?;
+
+    cursor.set_position(0);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(cursor, None),
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    let mut recording = RecordingReader::new(&mut reader);
+
+    let name = recording.with_label("Name", |e| e.read_fstring())?;
+    assert_eq!(name.as_deref(), Some("Glyph"));
+    let read = recording.with_label("FontCharacter", FontCharacter::new)?;
+    assert_eq!(read, font_character);
+    assert!(recording.read_bool()?);
+
+    // the name is read as its length, its characters and its null terminator,
+    // the font character as four i32 coordinates, a u8 texture index and an i32 offset
+    let font_character_label = Some("FontCharacter");
+    assert_eq!(
+        recording.records(),
+        [
+            record(0, 4, Some("Name")),
+            record(4, 5, Some("Name")),
+            record(9, 1, Some("Name")),
+            record(10, 4, font_character_label),
+            record(14, 4, font_character_label),
+            record(18, 4, font_character_label),
+            record(22, 4, font_character_label),
+            record(26, 1, font_character_label),
+            record(27, 4, font_character_label),
+            record(31, 1, None),
+        ]
+    );
+    assert_eq!(recording.dump().lines().count(), 10);
+
+    // a read past the end is recorded as a short read
+    recording.set_label(Some("Overflow"));
+    assert!(recording.read_i32::<LE>().is_err());
+    assert_eq!(
+        recording.take_records().last(),
+        Some(&record(32, 0, Some("Overflow")))
+    );
+    assert!(recording.records().is_empty());
+    assert_eq!(recording.position(), 32);
+
+    Ok(())
+}
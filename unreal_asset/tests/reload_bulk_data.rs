@@ -0,0 +1,87 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{Property, PropertyDataTrait},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+#[test]
+fn reload_bulk_data() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    // edit the export body externally
+    let mut edited = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    let coop_use = edited.asset_data.exports[32]
+        .get_normal_export_mut()
+        .and_then(|e| e.properties.iter_mut().find(|e| e.get_name() == "CoopUse"))
+        .and_then(|e| cast!(Property, BoolProperty, e))
+        .expect("Failed to find CoopUse");
+    assert!(!coop_use.value);
+    coop_use.value = true;
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    edited.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    assert_eq!(cursor.into_inner(), TEST_ASSET);
+
+    let edited_bulk = bulk_cursor.into_inner();
+    asset.reload_bulk_data(Cursor::new(edited_bulk.as_slice()))?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    let coop_use = asset.asset_data.exports[32]
+        .get_normal_export()
+        .and_then(|e| e.properties.iter().find(|e| e.get_name() == "CoopUse"))
+        .and_then(|e| cast!(Property, BoolProperty, e))
+        .expect("Failed to find CoopUse");
+    assert!(coop_use.value);
+
+    shared::verify_binary_equality(TEST_ASSET, Some(&edited_bulk), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn reload_bulk_data_without_bulk() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    assert!(asset.reload_bulk_data(Cursor::new(TEST_BULK)).is_err());
+
+    Ok(())
+}
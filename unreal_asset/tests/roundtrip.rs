@@ -0,0 +1,85 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset::{assert_roundtrip, RoundtripError, RoundtripRegion},
+    engine_version::EngineVersion,
+    exports::ExportBaseTrait,
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+#[test]
+fn roundtrip() -> Result<(), RoundtripError> {
+    assert_roundtrip(TEST_ASSET, Some(TEST_BULK), EngineVersion::VER_UE4_25)?;
+    assert_roundtrip(PREBULK_ASSET, None, EngineVersion::VER_UE4_23)?;
+
+    Ok(())
+}
+
+#[test]
+fn roundtrip_divergence() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    // tag of the CoopUse BoolProperty: name, type, length and duplication index
+    let name_map = asset.get_name_map();
+    let name_map = name_map.get_ref();
+    let mut tag = Vec::new();
+    for name in ["CoopUse", "BoolProperty"] {
+        let index = name_map.search_name_reference(name).expect("Missing name");
+        tag.extend(index.to_le_bytes());
+        tag.extend(0i32.to_le_bytes());
+    }
+    tag.extend([0; 8]);
+
+    let export = asset.asset_data.exports[32].get_base_export();
+    let export_start = export.serial_offset as usize - TEST_ASSET.len();
+    let export_end = export_start + export.serial_size as usize;
+    let tag_offset = TEST_BULK[export_start..export_end]
+        .windows(tag.len())
+        .position(|e| e == tag)
+        .expect("Failed to find CoopUse");
+
+    // a bool stored as 2 is read as true, but written back as 1
+    let value_offset = export_start + tag_offset + tag.len();
+    assert_eq!(TEST_BULK[value_offset], 0);
+    let mut bulk = TEST_BULK.to_vec();
+    bulk[value_offset] = 2;
+
+    match assert_roundtrip(TEST_ASSET, Some(&bulk), EngineVersion::VER_UE4_25) {
+        Err(RoundtripError::Mismatch {
+            offset,
+            region,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(offset, (TEST_ASSET.len() + value_offset) as u64);
+            assert_eq!(region, RoundtripRegion::Export(32));
+            assert_eq!(expected, Some(2));
+            assert_eq!(actual, Some(1));
+        }
+        result => panic!("Expected a mismatch, got {result:?}"),
+    }
+
+    Ok(())
+}
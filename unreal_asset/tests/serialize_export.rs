@@ -0,0 +1,89 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, exports::ExportBaseTrait, types::PackageIndex, Asset, Error,
+};
+
+mod shared;
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+/// Compare every serialized export against the slice a full write places at its serial offset
+fn verify_serialized_exports(asset: &Asset<Cursor<&[u8]>>) -> Result<(), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    match asset.asset_data.use_event_driven_loader {
+        true => asset.write_data(&mut cursor, Some(&mut bulk_cursor))?,
+        false => asset.write_data(&mut cursor, None)?,
+    };
+
+    let mut data = cursor.into_inner();
+    data.extend(bulk_cursor.into_inner());
+
+    for (i, export) in asset.asset_data.exports.iter().enumerate() {
+        let base_export = export.get_base_export();
+        let start = base_export.serial_offset as usize;
+        let end = start + base_export.serial_size as usize;
+
+        let serialized = asset.serialize_export(PackageIndex::new(i as i32 + 1))?;
+        assert_eq!(serialized, &data[start..end], "export {i} differs");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn serialize_export() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    verify_serialized_exports(&asset)
+}
+
+#[test]
+fn serialize_export_without_bulk() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    verify_serialized_exports(&asset)
+}
+
+#[test]
+fn serialize_export_invalid_index() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    assert!(asset.serialize_export(PackageIndex::new(0)).is_err());
+    assert!(asset.serialize_export(PackageIndex::new(-1)).is_err());
+    assert!(asset
+        .serialize_export(PackageIndex::new(asset.asset_data.exports.len() as i32 + 1))
+        .is_err());
+
+    Ok(())
+}
@@ -1,6 +1,26 @@
 use std::io::{Cursor, Read, Seek};
 
-use unreal_asset::{cast, engine_version::EngineVersion, Asset, Error, Export};
+use unreal_asset::{
+    cast,
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{Property, PropertyDataTrait, PropertyTrait},
+    reader::{ArchiveTrait, RawWriter},
+    types::{fname::ToSerializedName, PackageIndex},
+    unversioned::{
+        properties::{
+            array_property::UsmapArrayPropertyData, enum_property::UsmapEnumPropertyData,
+            map_property::UsmapMapPropertyData, shallow_property::UsmapShallowPropertyData,
+            struct_property::UsmapStructPropertyData, EPropertyType, UsmapProperty,
+            UsmapPropertyData,
+        },
+        EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion, UsmapSchema,
+    },
+    Asset, Error, Export,
+};
 
 #[allow(dead_code)]
 pub(crate) fn verify_reparse<C: Read + Seek>(
@@ -20,6 +40,24 @@ pub(crate) fn verify_reparse<C: Read + Seek>(
     Ok(())
 }
 
+/// Serialize a property with its header
+#[allow(dead_code)]
+pub(crate) fn write_property<C: Read + Seek>(
+    asset: &Asset<C>,
+    property: &impl PropertyTrait,
+) -> Result<(usize, Vec<u8>), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        asset.get_object_version(),
+        asset.get_object_version_ue5(),
+        asset.use_event_driven_loader(),
+        asset.get_name_map(),
+    );
+    let size = property.write(&mut writer, true)?;
+    Ok((size, cursor.into_inner()))
+}
+
 #[allow(dead_code)]
 pub(crate) fn verify_binary_equality<C: Read + Seek>(
     data: &[u8],
@@ -61,3 +99,139 @@ pub(crate) fn verify_all_exports_parsed<C: Read + Seek>(asset: &Asset<C>) -> boo
 
     true
 }
+
+#[allow(dead_code)]
+fn shallow(property_type: &str) -> UsmapPropertyData {
+    let property_type = (0..=u8::MAX)
+        .filter_map(|e| EPropertyType::try_from(e).ok())
+        .find(|e| e.to_string() == property_type)
+        .expect("Unknown property type");
+    UsmapShallowPropertyData { property_type }.into()
+}
+
+/// Mapping type data describing a parsed property
+#[allow(dead_code)]
+fn property_data(property: &Property) -> UsmapPropertyData {
+    match property {
+        Property::StructProperty(property) => UsmapStructPropertyData {
+            struct_type: property.struct_type.as_ref().unwrap().get_owned_content(),
+        }
+        .into(),
+        Property::ArrayProperty(property) => UsmapArrayPropertyData {
+            inner_type: Box::new(match property.value.first() {
+                Some(element) => property_data(element),
+                None => shallow(&property.array_type.as_ref().unwrap().get_owned_content()),
+            }),
+        }
+        .into(),
+        Property::MapProperty(property) => {
+            let (_, key, value) = property.value.iter().next().expect("Empty map");
+            UsmapMapPropertyData {
+                inner_type: Box::new(property_data(key)),
+                value_type: Box::new(property_data(value)),
+            }
+            .into()
+        }
+        // enums are stored by name, tagged map keys don't carry their enum type
+        Property::EnumProperty(property) => UsmapEnumPropertyData {
+            inner_property: Box::new(shallow("NameProperty")),
+            name: property
+                .enum_type
+                .as_ref()
+                .map(|e| e.get_owned_content())
+                .unwrap_or_default(),
+        }
+        .into(),
+        _ => shallow(&property.to_serialized_name()),
+    }
+}
+
+/// Add schemas for `properties` and all properties nested in them
+#[allow(dead_code)]
+fn add_schemas(schemas: &mut IndexedMap<String, UsmapSchema>, properties: &[Property]) {
+    for property in properties {
+        let schema_name = property
+            .get_ancestry()
+            .get_parent()
+            .unwrap()
+            .get_owned_content();
+        if schemas.get_by_key(&schema_name).is_none() {
+            schemas.insert(
+                schema_name.clone(),
+                UsmapSchema {
+                    name: schema_name.clone(),
+                    super_type: String::new(),
+                    prop_count: 0,
+                    module_path: None,
+                    properties: IndexedMap::new(),
+                },
+            );
+        }
+        let schema = schemas.get_by_key_mut(&schema_name).unwrap();
+
+        let name = property.get_name().get_owned_content();
+        let array_index = property.get_duplication_index() as u32;
+        if schema.get_property(&name, array_index).is_none() {
+            schema.properties.insert(
+                (name.clone(), array_index),
+                UsmapProperty {
+                    name,
+                    schema_index: schema.prop_count,
+                    array_size: 1,
+                    array_index: array_index as u16,
+                    property_data: property_data(property),
+                },
+            );
+            schema.prop_count += 1;
+        }
+
+        match property {
+            Property::StructProperty(property)
+                if !property
+                    .struct_type
+                    .as_ref()
+                    .unwrap()
+                    .get_content(Property::has_custom_serialization) =>
+            {
+                add_schemas(schemas, &property.value)
+            }
+            Property::ArrayProperty(property) => add_schemas(schemas, &property.value),
+            Property::MapProperty(property) => {
+                for (_, key, value) in property.value.iter() {
+                    add_schemas(schemas, std::slice::from_ref(key));
+                    add_schemas(schemas, std::slice::from_ref(value));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Mappings covering every property in the asset
+#[allow(dead_code)]
+pub(crate) fn mappings<C: Read + Seek>(asset: &Asset<C>) -> Usmap {
+    let mut schemas = IndexedMap::new();
+    for export in &asset.asset_data.exports {
+        if let Some(normal_export) = export.get_normal_export() {
+            add_schemas(&mut schemas, &normal_export.properties);
+        }
+        if let Some(data_table) = cast!(Export, DataTableExport, export) {
+            for row in &data_table.table.data {
+                add_schemas(&mut schemas, &row.value);
+            }
+        }
+    }
+
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
@@ -0,0 +1,95 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    custom_version::FAnimPhysObjectVersion,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{smart_name_property::SmartNameProperty, Property, PropertyDataTrait},
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/unknown_properties/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_DetPack_Charge.uexp"));
+
+#[test]
+fn smart_name() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    // this asset predates the smart name refactor, so ids and guids are still serialized
+    assert!(
+        asset
+            .asset_data
+            .get_custom_version::<FAnimPhysObjectVersion>()
+            .version
+            < FAnimPhysObjectVersion::RemoveUIDFromSmartNameSerialize as i32
+    );
+
+    let mut smart_name = SmartNameProperty {
+        name: asset.add_fname("TestCurve"),
+        property_guid: Some(Default::default()),
+        display_name: asset.add_fname("None"),
+        ..Default::default()
+    };
+    assert_eq!(smart_name.get_display_name(), None);
+
+    smart_name.set_display_name(&mut asset, "ModdedCurve");
+    assert_eq!(
+        smart_name.get_display_name().as_deref(),
+        Some("ModdedCurve")
+    );
+    assert_eq!(smart_name.smart_name_id, Some(SmartNameProperty::MAX_UID));
+    assert_eq!(smart_name.temp_guid, Some(Default::default()));
+    assert!(asset.search_name_reference("ModdedCurve").is_some());
+
+    asset.add_fname("SmartName");
+    asset.asset_data.exports[32]
+        .get_normal_export_mut()
+        .expect("Export is not a normal export")
+        .properties
+        .push(Property::SmartNameProperty(smart_name));
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let asset_data = cursor.into_inner();
+    let bulk_data = bulk_cursor.into_inner();
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    let smart_name = asset.asset_data.exports[32]
+        .get_normal_export()
+        .and_then(|e| e.properties.iter().find(|e| e.get_name() == "TestCurve"))
+        .and_then(|e| cast!(Property, SmartNameProperty, e))
+        .expect("Failed to find TestCurve");
+    assert_eq!(
+        smart_name.get_display_name().as_deref(),
+        Some("ModdedCurve")
+    );
+    assert_eq!(smart_name.smart_name_id, Some(SmartNameProperty::MAX_UID));
+
+    shared::verify_binary_equality(&asset_data, Some(&bulk_data), &mut asset)?;
+
+    Ok(())
+}
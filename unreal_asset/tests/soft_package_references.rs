@@ -0,0 +1,108 @@
+use std::io::Cursor;
+
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+mod shared;
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+#[test]
+fn soft_package_references() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert!(asset.soft_package_references().is_empty());
+
+    asset.add_soft_package_reference("/Game/Mods/Relocated/BP_DetPack".to_string());
+    asset.add_soft_package_reference("/Game/Maps/ModdedMap".to_string());
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let asset_data = cursor.into_inner();
+    let bulk_data = bulk_cursor.into_inner();
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+    assert_eq!(
+        asset.soft_package_references(),
+        ["/Game/Mods/Relocated/BP_DetPack", "/Game/Maps/ModdedMap"]
+    );
+
+    // relocate an existing reference
+    asset.soft_package_references_mut()[1] = "/Game/Mods/Maps/ModdedMap".to_string();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    let asset_data = cursor.into_inner();
+    let bulk_data = bulk_cursor.into_inner();
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        Some(Cursor::new(bulk_data.as_slice())),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+    assert_eq!(
+        asset.soft_package_references(),
+        [
+            "/Game/Mods/Relocated/BP_DetPack",
+            "/Game/Mods/Maps/ModdedMap"
+        ]
+    );
+
+    shared::verify_binary_equality(&asset_data, Some(&bulk_data), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn soft_package_references_without_bulk() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(PREBULK_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(asset.soft_package_references().is_empty());
+
+    asset.add_soft_package_reference("/Game/Mods/Augment".to_string());
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+
+    let asset_data = cursor.into_inner();
+    let mut asset = Asset::new(
+        Cursor::new(asset_data.as_slice()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&asset));
+    assert_eq!(asset.soft_package_references(), ["/Game/Mods/Augment"]);
+
+    shared::verify_binary_equality(&asset_data, None, &mut asset)?;
+
+    Ok(())
+}
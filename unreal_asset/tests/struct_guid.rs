@@ -0,0 +1,149 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    properties::{
+        array_property::ArrayProperty, int_property::IntProperty, struct_property::StructProperty,
+        Property,
+    },
+    reader::BufferReader,
+    types::FName,
+    unversioned::Ancestry,
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "Augment_BroadBrush.uasset"));
+
+const STRUCT_GUID: Guid = Guid::from_ints(0x11223344, 0x55667788, 0x99AABBCC, 0xDDEEFF00);
+
+/// Size of a serialized `FName`
+const FNAME_SIZE: usize = 8;
+
+fn parse() -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )
+}
+
+/// Value of the int member of a struct from `user_struct`
+fn count(struct_property: &StructProperty) -> i32 {
+    assert_eq!(struct_property.value.len(), 1);
+    cast!(Property, IntProperty, &struct_property.value[0])
+        .expect("Struct member is not an IntProperty")
+        .value
+}
+
+/// A user defined struct with a single int member
+fn user_struct(asset: &mut Asset<Cursor<&[u8]>>, name: FName, value: i32) -> StructProperty {
+    let member = IntProperty {
+        name: asset.get_name_map().get_mut().add_fname("Count"),
+        value,
+        ..Default::default()
+    };
+
+    let mut struct_property = StructProperty::dummy(
+        name,
+        Ancestry::default(),
+        asset.get_name_map().get_mut().add_fname("ST_Custom"),
+        None,
+    );
+    struct_property.set_struct_guid(Some(STRUCT_GUID));
+    struct_property.value.push(member.into());
+    struct_property
+}
+
+#[test]
+fn struct_guid_in_property_tag() -> Result<(), Error> {
+    let mut asset = parse()?;
+    let name = asset.get_name_map().get_mut().add_fname("Stats");
+    let mut struct_property = user_struct(&mut asset, name.clone(), 7);
+    assert_eq!(struct_property.struct_guid(), Some(STRUCT_GUID));
+
+    // the guid directly follows the struct type and precedes the property guid flag
+    let (size, data) = shared::write_property(&asset, &struct_property)?;
+    assert_eq!(&data[FNAME_SIZE..FNAME_SIZE + 16], &STRUCT_GUID.0);
+    assert_eq!(data[FNAME_SIZE + 16], 0);
+
+    let mut reader = BufferReader::new(&mut asset, &data);
+    let read_struct = StructProperty::new(
+        &mut reader,
+        name.clone(),
+        Ancestry::default(),
+        true,
+        size as i64,
+        0,
+    )?;
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(read_struct.struct_guid(), Some(STRUCT_GUID));
+    assert_eq!(count(&read_struct), 7);
+
+    // a struct without a guid still writes one, native structs carry a zero guid
+    struct_property.set_struct_guid(None);
+    let (size, data) = shared::write_property(&asset, &struct_property)?;
+    assert_eq!(&data[FNAME_SIZE..FNAME_SIZE + 16], &[0; 16]);
+
+    let mut reader = BufferReader::new(&mut asset, &data);
+    let read_struct =
+        StructProperty::new(&mut reader, name, Ancestry::default(), true, size as i64, 0)?;
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(read_struct.struct_guid(), Some(Guid::default()));
+
+    Ok(())
+}
+
+#[test]
+fn struct_guid_in_inner_array_tag() -> Result<(), Error> {
+    let mut asset = parse()?;
+    let name = asset.get_name_map().get_mut().add_fname("StatsArray");
+    let array_type = asset.get_name_map().get_mut().add_fname("StructProperty");
+    let elements = [3, 5].map(|value| user_struct(&mut asset, name.clone(), value).into());
+    let array_property = ArrayProperty::from_arr(
+        name.clone(),
+        Ancestry::default(),
+        Some(array_type),
+        elements.to_vec(),
+    );
+
+    // array type, property guid flag, element count,
+    // then the inner tag with name, type, size and struct type before the struct guid
+    let (_, data) = shared::write_property(&asset, &array_property)?;
+    let guid_offset = FNAME_SIZE + 1 + 4 + FNAME_SIZE * 2 + 8 + FNAME_SIZE;
+    assert_eq!(&data[guid_offset..guid_offset + 16], &STRUCT_GUID.0);
+
+    let mut reader = BufferReader::new(&mut asset, &data);
+    let read_array = ArrayProperty::new(
+        &mut reader,
+        name,
+        Ancestry::default(),
+        true,
+        data.len() as i64,
+        0,
+        true,
+    )?;
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(read_array.value.len(), elements.len());
+    for (read_element, element) in read_array.value.iter().zip(&elements) {
+        let read_element = cast!(Property, StructProperty, read_element)
+            .expect("Array element is not a StructProperty");
+        let element = cast!(Property, StructProperty, element).unwrap();
+        assert_eq!(read_element.struct_guid(), Some(STRUCT_GUID));
+        assert_eq!(count(read_element), count(element));
+    }
+
+    Ok(())
+}
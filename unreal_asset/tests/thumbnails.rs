@@ -0,0 +1,119 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, thumbnail::AssetThumbnail, types::PackageIndex, Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+fn thumbnail(object_path: &str, is_jpeg: bool) -> AssetThumbnail {
+    AssetThumbnail {
+        object_class_name: String::from("BlueprintGeneratedClass"),
+        object_path: object_path.to_string(),
+        image_width: 4,
+        image_height: 2,
+        is_jpeg,
+        compressed_image_data: object_path.as_bytes().to_vec(),
+    }
+}
+
+#[test]
+fn thumbnails_roundtrip() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    // cooked assets don't have thumbnails
+    assert!(asset.thumbnails().is_empty());
+
+    asset.thumbnails_mut().extend([
+        thumbnail("Augment_BroadBrush_C", false),
+        thumbnail("Default__Augment_BroadBrush_C:ItemCatalogData_0", true),
+        // not an export of this asset
+        thumbnail("SomeOtherObject", false),
+    ]);
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_verified(&mut cursor, None)?;
+
+    let written = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert_eq!(written.thumbnails(), asset.thumbnails());
+
+    assert_eq!(
+        written.thumbnail_for_export(PackageIndex::new(1)),
+        Some(&asset.thumbnails()[0])
+    );
+    assert_eq!(written.thumbnail_for_export(PackageIndex::new(2)), None);
+    assert_eq!(
+        written.thumbnail_for_export(PackageIndex::new(3)),
+        Some(&asset.thumbnails()[1])
+    );
+    assert_eq!(written.thumbnail_for_export(PackageIndex::new(-1)), None);
+
+    Ok(())
+}
+
+/// Find the position of `pattern` in `data`
+fn find(data: &[u8], pattern: &[u8]) -> usize {
+    data.windows(pattern.len())
+        .position(|e| e == pattern)
+        .expect("Pattern not found")
+}
+
+#[test]
+fn thumbnails_corrupt_lengths() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    asset
+        .thumbnails_mut()
+        .push(thumbnail("Augment_BroadBrush_C", false));
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let data = cursor.into_inner();
+
+    // a count or image length larger than the file is an error, not a huge allocation
+    let mut table = 1i32.to_le_bytes().to_vec();
+    table.extend(24i32.to_le_bytes());
+    table.extend(b"BlueprintGeneratedClass\0");
+    let count_offset = find(&data, &table);
+
+    let mut image = [4i32, 2, 20].map(i32::to_le_bytes).concat();
+    image.extend(b"Augment_BroadBrush_C");
+    let length_offset = find(&data, &image) + 8;
+
+    for offset in [count_offset, length_offset] {
+        let mut corrupted = data.clone();
+        corrupted[offset..offset + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+
+        assert!(Asset::new(
+            Cursor::new(corrupted),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )
+        .is_err());
+    }
+
+    Ok(())
+}
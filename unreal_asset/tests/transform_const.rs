@@ -0,0 +1,137 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+use std::io::Cursor;
+
+use byteorder::{WriteBytesExt, LE};
+use ordered_float::OrderedFloat;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    reader::{ArchiveTrait, BufferReader, RawWriter},
+    types::{
+        vector::{Transform, Vector, Vector4},
+        PackageIndex,
+    },
+    Asset, Error, KismetExpression,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/general/")
+    };
+}
+
+const UE4_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+
+const UE5_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "pseudoregalia/BP_looseWeapon.uasset"
+));
+const UE5_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "pseudoregalia/BP_looseWeapon.uexp"
+));
+
+/// Rotation, translation and scale components in serialization order
+const COMPONENTS: [f64; 10] = [
+    0.0,
+    0.0,
+    FRAC_1_SQRT_2,
+    FRAC_1_SQRT_2,
+    100.5,
+    -2048.25,
+    1.0e10 + 0.5,
+    1.0,
+    2.0,
+    0.5,
+];
+
+/// Serialized `ExTransformConst` with every component written by `write`
+fn transform_const(write: impl Fn(&mut Vec<u8>, f64)) -> Vec<u8> {
+    let mut data = vec![0x2B];
+    for component in COMPONENTS {
+        write(&mut data, component);
+    }
+    data
+}
+
+fn expected(components: [f64; 10]) -> Transform<OrderedFloat<f64>> {
+    let [rx, ry, rz, rw, tx, ty, tz, sx, sy, sz] = components.map(OrderedFloat);
+    Transform::new(
+        Vector4::new(rx, ry, rz, rw),
+        Vector::new(tx, ty, tz),
+        Vector::new(sx, sy, sz),
+    )
+}
+
+/// Read a transform constant and check it writes back to the same bytes
+fn round_trip(
+    asset: &mut Asset<Cursor<&[u8]>>,
+    data: &[u8],
+) -> Result<Transform<OrderedFloat<f64>>, Error> {
+    let mut reader = BufferReader::new(asset, data);
+    let expression = KismetExpression::new(&mut reader)?;
+    assert_eq!(reader.remaining(), 0);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        asset.get_object_version(),
+        asset.get_object_version_ue5(),
+        asset.use_event_driven_loader(),
+        asset.get_name_map(),
+    );
+    let size = KismetExpression::write(&expression, &mut writer)?;
+    assert_eq!(size, data.len());
+    assert_eq!(cursor.into_inner(), data);
+
+    let transform = cast!(KismetExpression, ExTransformConst, expression)
+        .expect("Expected an ExTransformConst")
+        .value;
+    Ok(transform)
+}
+
+#[test]
+fn transform_const_single_precision() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(UE4_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let data = transform_const(|data, component| data.write_f32::<LE>(component as f32).unwrap());
+    let transform = round_trip(&mut asset, &data)?;
+
+    // values are widened from what fits in an f32
+    assert_eq!(transform, expected(COMPONENTS.map(|e| e as f32 as f64)));
+    assert_eq!(transform.to_f32().to_f64(), transform);
+    assert_ne!(transform, expected(COMPONENTS));
+
+    Ok(())
+}
+
+#[test]
+fn transform_const_double_precision() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(UE5_ASSET),
+        Some(Cursor::new(UE5_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let data = transform_const(|data, component| data.write_f64::<LE>(component).unwrap());
+    let transform = round_trip(&mut asset, &data)?;
+
+    assert_eq!(transform, expected(COMPONENTS));
+    assert_eq!(
+        transform.to_f32().translation.z,
+        OrderedFloat(1.0e10_f32),
+        "Narrowing rounds to the nearest f32"
+    );
+
+    Ok(())
+}
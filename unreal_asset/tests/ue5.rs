@@ -1,6 +1,20 @@
-use std::io::Cursor;
+use std::io::{Cursor, Read, Seek};
 
-use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+use byteorder::{WriteBytesExt, LE};
+
+use unreal_asset::{
+    cast,
+    containers::{Chain, NameMap},
+    engine_version::{get_object_versions, get_possible_versions, EngineVersion},
+    exports::{ExportBaseTrait, ExportNormalTrait},
+    flags::EPropertyTagFlags,
+    object_version::ObjectVersionUE5,
+    properties::{Property, PropertyDataTrait},
+    reader::{ArchiveTrait, RawReader, RawWriter},
+    types::{PackageIndex, PropertyTypeName},
+    unversioned::Ancestry,
+    Asset, Error,
+};
 
 mod shared;
 
@@ -36,3 +50,387 @@ fn ue5() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn ue5_object_versions() {
+    for (engine_version, object_version_ue5) in [
+        (EngineVersion::VER_UE5_2, ObjectVersionUE5::DATA_RESOURCES),
+        (
+            EngineVersion::VER_UE5_3,
+            ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION,
+        ),
+        (
+            EngineVersion::VER_UE5_4,
+            ObjectVersionUE5::ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES,
+        ),
+    ] {
+        let (object_version, ue5_version) = get_object_versions(engine_version);
+        assert_eq!(ue5_version, object_version_ue5);
+
+        // every UE5 version has its own object version, so they can be told apart
+        let possible_versions = get_possible_versions(object_version, object_version_ue5);
+        assert!(possible_versions.contains(&engine_version));
+        for other_version in [
+            EngineVersion::VER_UE5_2,
+            EngineVersion::VER_UE5_3,
+            EngineVersion::VER_UE5_4,
+        ] {
+            if other_version != engine_version {
+                assert!(!possible_versions.contains(&other_version));
+            }
+        }
+    }
+}
+
+#[test]
+fn ue5_newer_versions() -> Result<(), Error> {
+    for engine_version in [EngineVersion::VER_UE5_3, EngineVersion::VER_UE5_4] {
+        let (_, object_version_ue5) = get_object_versions(engine_version);
+
+        for (asset_data, bulk_data) in TEST_ASSETS {
+            let mut parsed = Asset::new(
+                Cursor::new(asset_data),
+                Some(Cursor::new(bulk_data)),
+                EngineVersion::VER_UE5_1,
+                None,
+            )?;
+            parsed.asset_data.engine_version = engine_version;
+            parsed.asset_data.object_version_ue5 = object_version_ue5;
+            for (i, export) in parsed.asset_data.exports.iter_mut().enumerate() {
+                let base_export = export.get_base_export_mut();
+                base_export.script_serialization_start_offset = i as i64;
+                base_export.script_serialization_end_offset = i as i64 * 2;
+            }
+
+            let mut cursor = Cursor::new(Vec::new());
+            let mut bulk_cursor = Cursor::new(Vec::new());
+            parsed.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+            let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+            let mut reparsed = Asset::new(
+                Cursor::new(data.as_slice()),
+                Some(Cursor::new(bulk.as_slice())),
+                engine_version,
+                None,
+            )?;
+            assert_eq!(reparsed.asset_data.object_version_ue5, object_version_ue5);
+            assert_eq!(
+                reparsed.asset_data.exports.len(),
+                parsed.asset_data.exports.len()
+            );
+            // script serialization offsets are stored from 5.3 on
+            let has_script_offsets =
+                object_version_ue5 >= ObjectVersionUE5::SCRIPT_SERIALIZATION_OFFSET;
+            for (i, export) in reparsed.asset_data.exports.iter().enumerate() {
+                let base_export = export.get_base_export();
+                let start_offset = if has_script_offsets { i as i64 } else { 0 };
+                assert_eq!(base_export.script_serialization_start_offset, start_offset);
+                assert_eq!(
+                    base_export.script_serialization_end_offset,
+                    start_offset * 2
+                );
+            }
+            assert_eq!(
+                shared::verify_all_exports_parsed(&reparsed),
+                shared::verify_all_exports_parsed(&parsed)
+            );
+
+            shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Assets with tagged properties, the UE5 assets above use unversioned properties
+const TAGGED_ASSETS: [(&[u8], &[u8]); 2] = [
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "../unknown_properties/BP_DetPack_Charge.uasset"
+        )),
+        include_bytes!(concat!(
+            assets_folder!(),
+            "../unknown_properties/BP_DetPack_Charge.uexp"
+        )),
+    ),
+    (
+        include_bytes!(concat!(
+            assets_folder!(),
+            "../general/StarlitSeason/CharacterCostume_chr0001_DataTable.uasset"
+        )),
+        include_bytes!(concat!(
+            assets_folder!(),
+            "../general/StarlitSeason/CharacterCostume_chr0001_DataTable.uexp"
+        )),
+    ),
+];
+
+#[test]
+fn ue5_newer_versions_tagged() -> Result<(), Error> {
+    for engine_version in [EngineVersion::VER_UE5_3, EngineVersion::VER_UE5_4] {
+        let (object_version, object_version_ue5) = get_object_versions(engine_version);
+
+        for (asset_data, bulk_data) in TAGGED_ASSETS {
+            let mut parsed = Asset::new(
+                Cursor::new(asset_data),
+                Some(Cursor::new(bulk_data)),
+                EngineVersion::VER_UE4_26,
+                None,
+            )?;
+            // the UE5 object version is only stored with newer legacy file versions
+            parsed.legacy_file_version = -8;
+            parsed.asset_data.engine_version = engine_version;
+            parsed.asset_data.object_version = object_version;
+            parsed.asset_data.object_version_ue5 = object_version_ue5;
+
+            let mut cursor = Cursor::new(Vec::new());
+            let mut bulk_cursor = Cursor::new(Vec::new());
+            parsed.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+            let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+            let mut reparsed = Asset::new(
+                Cursor::new(data.as_slice()),
+                Some(Cursor::new(bulk.as_slice())),
+                engine_version,
+                None,
+            )?;
+            assert!(shared::verify_all_exports_parsed(&reparsed));
+            assert_eq!(property_count(&reparsed), property_count(&parsed));
+
+            // type names are only stored in property tags from 5.4 on
+            assert_eq!(
+                reparsed.asset_data.property_tag_types.is_empty(),
+                object_version_ue5 < ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME
+            );
+
+            shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+
+            if object_version_ue5 >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+                check_stored_type_names(&mut reparsed)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Count all properties of an asset, including nested ones
+fn property_count<C: Read + Seek>(asset: &Asset<C>) -> usize {
+    let mut count = 0;
+    for export in &asset.asset_data.exports {
+        if let Some(normal_export) = export.get_normal_export() {
+            for property in &normal_export.properties {
+                property.visit(&mut |_| count += 1);
+            }
+        }
+    }
+    count
+}
+
+/// Check that type names read from UE5.4+ property tags are written back,
+/// including the parts that properties don't store
+fn check_stored_type_names(asset: &mut Asset<Cursor<&[u8]>>) -> Result<(), Error> {
+    let package = asset
+        .get_name_map()
+        .get_mut()
+        .add_fname("/Script/CoreUObject");
+    let struct_type = asset
+        .asset_data
+        .property_tag_types
+        .values_mut()
+        .find_map(|e| match e.type_name.name == "StructProperty" {
+            true => e.type_name.parameters.first_mut(),
+            false => None,
+        })
+        .expect("No struct property tag types");
+    struct_type
+        .parameters
+        .push(PropertyTypeName::new(package, Vec::new()));
+    let struct_type = struct_type.to_string();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE5_4,
+        None,
+    )?;
+    assert!(reparsed
+        .asset_data
+        .property_tag_types
+        .values()
+        .any(|e| e.type_name.name == "StructProperty"
+            && e.type_name.parameters[0].to_string() == struct_type));
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)
+}
+
+/// Write a UE5.4+ property tag
+fn write_tag(
+    data: &mut Vec<u8>,
+    name: i32,
+    type_name: &[(i32, i32)],
+    size: i32,
+    flags: EPropertyTagFlags,
+) -> std::io::Result<()> {
+    data.write_i64::<LE>(name as i64)?;
+    // the type name is stored as a pre-order list of names and their parameter counts
+    for (name, parameter_count) in type_name {
+        data.write_i64::<LE>(*name as i64)?;
+        data.write_i32::<LE>(*parameter_count)?;
+    }
+    data.write_i32::<LE>(size)?;
+    data.write_u8(flags.bits())
+}
+
+#[test]
+fn ue5_4_property_tags() -> Result<(), Error> {
+    let mut name_map = NameMap::new();
+    let names = [
+        "None",
+        "Location",
+        "StructProperty",
+        "Vector",
+        "/Script/CoreUObject",
+        "bHidden",
+        "BoolProperty",
+        "Tags",
+        "ArrayProperty",
+        "NameProperty",
+        "Points",
+    ]
+    .map(|name| {
+        name_map
+            .get_mut()
+            .add_name_reference(name.to_string(), false)
+    });
+    let [none, location, struct_property, vector, core_uobject, hidden, bool_property, tags, array_property, name_property, points] =
+        names;
+
+    let mut data = Vec::new();
+
+    // Location: StructProperty(Vector(/Script/CoreUObject))
+    write_tag(
+        &mut data,
+        location,
+        &[(struct_property, 1), (vector, 1), (core_uobject, 0)],
+        24,
+        EPropertyTagFlags::HAS_BINARY_OR_NATIVE_SERIALIZE,
+    )?;
+    for component in [1.0f64, 2.0, 3.0] {
+        data.write_f64::<LE>(component)?;
+    }
+
+    // bHidden: BoolProperty, the value is stored in the tag
+    write_tag(
+        &mut data,
+        hidden,
+        &[(bool_property, 0)],
+        0,
+        EPropertyTagFlags::BOOL_TRUE,
+    )?;
+
+    // Tags[1]: ArrayProperty(NameProperty)
+    write_tag(
+        &mut data,
+        tags,
+        &[(array_property, 1), (name_property, 0)],
+        4 + 8,
+        EPropertyTagFlags::HAS_ARRAY_INDEX,
+    )?;
+    data.write_i32::<LE>(1)?;
+    data.write_i32::<LE>(1)?;
+    data.write_i64::<LE>(vector as i64)?;
+
+    // Points: ArrayProperty(StructProperty(Vector(/Script/CoreUObject))), there is no inner tag
+    write_tag(
+        &mut data,
+        points,
+        &[
+            (array_property, 1),
+            (struct_property, 1),
+            (vector, 1),
+            (core_uobject, 0),
+        ],
+        4 + 24,
+        EPropertyTagFlags::NONE,
+    )?;
+    data.write_i32::<LE>(1)?;
+    for component in [4.0f64, 5.0, 6.0] {
+        data.write_f64::<LE>(component)?;
+    }
+
+    data.write_i64::<LE>(none as i64)?;
+
+    let (object_version, object_version_ue5) = get_object_versions(EngineVersion::VER_UE5_4);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(Cursor::new(data.as_slice()), None),
+        object_version,
+        object_version_ue5,
+        false,
+        name_map.clone(),
+    );
+
+    let mut properties = Vec::new();
+    while let Some(property) = Property::new(&mut reader, Ancestry::default(), None, true)? {
+        properties.push(property);
+    }
+    assert_eq!(reader.position(), data.len() as u64);
+    assert_eq!(properties.len(), 4);
+
+    let location = cast!(Property, StructProperty, &properties[0]).expect("Not a struct");
+    assert_eq!(location.struct_type.as_ref().unwrap(), "Vector");
+    let location = cast!(Property, VectorProperty, &location.value[0]).expect("Not a vector");
+    assert_eq!(location.value.z.0, 3.0);
+
+    let hidden = cast!(Property, BoolProperty, &properties[1]).expect("Not a bool");
+    assert!(hidden.value);
+
+    let tags = cast!(Property, ArrayProperty, &properties[2]).expect("Not an array");
+    assert_eq!(tags.get_duplication_index(), 1);
+    let tag = cast!(Property, NameProperty, &tags.value[0]).expect("Not a name");
+    assert_eq!(tag.value, "Vector");
+
+    let points = cast!(Property, ArrayProperty, &properties[3]).expect("Not an array");
+    let point = cast!(Property, StructProperty, &points.value[0]).expect("Not a struct");
+    assert_eq!(point.struct_type.as_ref().unwrap(), "Vector");
+    let point = cast!(Property, VectorProperty, &point.value[0]).expect("Not a vector");
+    assert_eq!(point.value.x.0, 4.0);
+
+    // without the type names read from the tags, they are derived from the properties,
+    // those don't know which package a struct is in
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        object_version,
+        object_version_ue5,
+        false,
+        name_map.clone(),
+    );
+    for property in &properties[1..3] {
+        Property::write(property, &mut writer, true)?;
+    }
+    let tags_end = data.len() - 8 - (8 + 4 * 12 + 4 + 1 + 4 + 24);
+    let hidden_start = 8 + 3 * 12 + 4 + 1 + 24;
+    assert_eq!(cursor.into_inner(), data[hidden_start..tags_end]);
+
+    let mut writer_cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut writer_cursor,
+        object_version,
+        object_version_ue5,
+        false,
+        name_map,
+    );
+    assert_eq!(
+        properties[3].complete_type_name(&mut writer).to_string(),
+        "ArrayProperty(StructProperty(Vector))"
+    );
+
+    Ok(())
+}
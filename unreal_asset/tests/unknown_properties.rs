@@ -2,8 +2,13 @@ use std::collections::HashMap;
 use std::io::Cursor;
 
 use unreal_asset::{
-    cast, engine_version::EngineVersion, exports::ExportNormalTrait, properties::Property, Asset,
-    Error,
+    cast,
+    engine_version::EngineVersion,
+    error::PropertyError,
+    exports::ExportNormalTrait,
+    parse_options::{ParseOptions, UnknownPropertyPolicy},
+    properties::Property,
+    Asset, Error,
 };
 
 mod shared;
@@ -59,3 +64,82 @@ fn unknown_properties() -> Result<(), Error> {
 
     Ok(())
 }
+
+fn count_unknown_properties<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> usize {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .filter(|e| cast!(Property, UnknownProperty, e).is_some())
+        .count()
+}
+
+fn parse_with_policy(
+    unknown_property_policy: UnknownPropertyPolicy,
+) -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    Asset::new_with_options(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+        ParseOptions {
+            unknown_property_policy,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn unknown_property_policy_raw() -> Result<(), Error> {
+    let mut asset = parse_with_policy(UnknownPropertyPolicy::Raw)?;
+
+    assert_ne!(count_unknown_properties(&asset), 0);
+    shared::verify_binary_equality(TEST_ASSET, Some(TEST_BULK), &mut asset)?;
+
+    Ok(())
+}
+
+#[test]
+fn unknown_property_policy_error_out() {
+    let err = parse_with_policy(UnknownPropertyPolicy::ErrorOut)
+        .expect_err("Unknown property types should error out");
+
+    assert!(matches!(
+        err,
+        Error::Property(PropertyError::UnknownType(..))
+    ));
+}
+
+#[test]
+fn unknown_property_policy_skip_by_length() -> Result<(), Error> {
+    let mut asset = parse_with_policy(UnknownPropertyPolicy::SkipByLength)?;
+
+    assert_eq!(count_unknown_properties(&asset), 0);
+    assert!(shared::verify_all_exports_parsed(&asset));
+
+    let raw_asset = parse_with_policy(UnknownPropertyPolicy::Raw)?;
+    for (skipped, raw) in asset
+        .asset_data
+        .exports
+        .iter()
+        .zip(raw_asset.asset_data.exports.iter())
+    {
+        let (Some(skipped), Some(raw)) = (skipped.get_normal_export(), raw.get_normal_export())
+        else {
+            continue;
+        };
+
+        let known = raw
+            .properties
+            .iter()
+            .filter(|e| cast!(Property, UnknownProperty, e).is_none())
+            .collect::<Vec<_>>();
+        assert_eq!(skipped.properties.iter().collect::<Vec<_>>(), known);
+    }
+
+    shared::verify_reparse(&mut asset, EngineVersion::VER_UE4_25)?;
+
+    Ok(())
+}
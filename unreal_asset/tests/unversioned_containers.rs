@@ -0,0 +1,239 @@
+use std::io::Cursor;
+
+use byteorder::{WriteBytesExt, LE};
+
+use unreal_asset::{
+    cast,
+    containers::IndexedMap,
+    custom_version::CustomVersion,
+    engine_version::EngineVersion,
+    flags::EPackageFlags,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{struct_property::StructProperty, Property, PropertyDataTrait},
+    reader::BufferReader,
+    types::FName,
+    unversioned::{
+        properties::{
+            array_property::UsmapArrayPropertyData, set_property::UsmapSetPropertyData,
+            shallow_property::UsmapShallowPropertyData, struct_property::UsmapStructPropertyData,
+            EPropertyType, UsmapProperty, UsmapPropertyData,
+        },
+        Ancestry, EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion,
+        UsmapSchema,
+    },
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_looseWeapon.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "BP_looseWeapon.uexp"));
+
+fn int_data() -> UsmapPropertyData {
+    UsmapShallowPropertyData {
+        property_type: EPropertyType::IntProperty,
+    }
+    .into()
+}
+
+fn struct_data(struct_type: &str) -> UsmapPropertyData {
+    UsmapStructPropertyData {
+        struct_type: struct_type.to_string(),
+    }
+    .into()
+}
+
+fn schema(name: &str, fields: Vec<(&str, UsmapPropertyData)>) -> UsmapSchema {
+    let mut properties = IndexedMap::new();
+    let prop_count = fields.len() as u16;
+    for (i, (field, property_data)) in fields.into_iter().enumerate() {
+        properties.insert(
+            (field.to_string(), 0),
+            UsmapProperty {
+                name: field.to_string(),
+                schema_index: i as u16,
+                array_size: 1,
+                array_index: 0,
+                property_data,
+            },
+        );
+    }
+
+    UsmapSchema {
+        name: name.to_string(),
+        super_type: String::new(),
+        prop_count,
+        module_path: None,
+        properties,
+    }
+}
+
+/// `Outer { Items: TArray<Inner>, Tags: TSet<int32> }`,
+/// `Inner { Value: int32, Children: TArray<Leaf> }` and `Leaf { Id: int32 }`
+fn mappings() -> Usmap {
+    let mut schemas = IndexedMap::new();
+    schemas.insert(
+        "Outer".to_string(),
+        schema(
+            "Outer",
+            vec![
+                (
+                    "Items",
+                    UsmapArrayPropertyData {
+                        inner_type: Box::new(struct_data("Inner")),
+                    }
+                    .into(),
+                ),
+                (
+                    "Tags",
+                    UsmapSetPropertyData {
+                        inner_type: Box::new(int_data()),
+                    }
+                    .into(),
+                ),
+            ],
+        ),
+    );
+    schemas.insert(
+        "Inner".to_string(),
+        schema(
+            "Inner",
+            vec![
+                ("Value", int_data()),
+                (
+                    "Children",
+                    UsmapArrayPropertyData {
+                        inner_type: Box::new(struct_data("Leaf")),
+                    }
+                    .into(),
+                ),
+            ],
+        ),
+    );
+    schemas.insert("Leaf".to_string(), schema("Leaf", vec![("Id", int_data())]));
+
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+/// Unversioned header with a single fragment serializing the first `value_num` properties
+fn write_header(data: &mut Vec<u8>, value_num: u16) {
+    data.write_u16::<LE>((value_num << 9) | 0x100).unwrap();
+}
+
+/// Serialized `Outer` value with two `Inner` items holding one and two leaves
+fn outer_data() -> Vec<u8> {
+    let mut data = Vec::new();
+    write_header(&mut data, 2);
+
+    // Items
+    data.write_i32::<LE>(2).unwrap();
+    for (value, ids) in [(10, vec![100]), (20, vec![200, 201])] {
+        write_header(&mut data, 2);
+        data.write_i32::<LE>(value).unwrap();
+        data.write_i32::<LE>(ids.len() as i32).unwrap();
+        for id in ids {
+            write_header(&mut data, 1);
+            data.write_i32::<LE>(id).unwrap();
+        }
+    }
+
+    // Tags
+    data.write_i32::<LE>(0).unwrap();
+    data.write_i32::<LE>(2).unwrap();
+    data.write_i32::<LE>(7).unwrap();
+    data.write_i32::<LE>(8).unwrap();
+
+    data
+}
+
+fn int_value(property: &Property) -> i32 {
+    cast!(Property, IntProperty, property)
+        .expect("Expected an IntProperty")
+        .value
+}
+
+fn struct_value(property: &Property) -> &StructProperty {
+    cast!(Property, StructProperty, property).expect("Expected a StructProperty")
+}
+
+#[test]
+fn nested_array_of_structs() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    asset.asset_data.mappings = Some(mappings());
+    asset.asset_data.summary.package_flags |= EPackageFlags::PKG_UNVERSIONED_PROPERTIES;
+
+    let data = outer_data();
+    let mut reader = BufferReader::new(&mut asset, &data);
+    let root = StructProperty::custom_header(
+        &mut reader,
+        FName::new_dummy("Root".to_string(), 0),
+        Ancestry::default(),
+        1,
+        0,
+        Some(FName::new_dummy("Outer".to_string(), 0)),
+        None,
+        None,
+    )?;
+    assert_eq!(reader.remaining(), 0);
+
+    assert_eq!(root.value.len(), 2);
+    let items = cast!(Property, ArrayProperty, &root.value[0]).expect("Items is not an array");
+    assert_eq!(items.name, "Items");
+    assert_eq!(items.ancestry.get_parent().unwrap(), "Outer");
+    assert_eq!(items.array_type.as_ref().unwrap(), "StructProperty");
+    assert_eq!(items.value.len(), 2);
+
+    let expected = [(10, vec![100]), (20, vec![200, 201])];
+    for (item, (value, ids)) in items.value.iter().zip(expected) {
+        let item = struct_value(item);
+        assert_eq!(item.struct_type.as_ref().unwrap(), "Inner");
+        assert_eq!(item.ancestry, items.element_ancestry());
+        assert_eq!(item.value.len(), 2);
+        assert_eq!(int_value(&item.value[0]), value);
+        assert_eq!(item.value[0].get_ancestry().get_parent().unwrap(), "Inner");
+
+        let children =
+            cast!(Property, ArrayProperty, &item.value[1]).expect("Children is not an array");
+        assert_eq!(children.value.len(), ids.len());
+        for (child, id) in children.value.iter().zip(ids) {
+            let child = struct_value(child);
+            assert_eq!(child.struct_type.as_ref().unwrap(), "Leaf");
+            assert_eq!(child.ancestry, children.element_ancestry());
+            assert_eq!(int_value(&child.value[0]), id);
+        }
+    }
+
+    let tags = cast!(Property, SetProperty, &root.value[1]).expect("Tags is not a set");
+    assert_eq!(tags.ancestry, tags.value.ancestry);
+    assert_eq!(tags.ancestry, tags.removed_items.ancestry);
+    assert!(tags.removed_items.value.is_empty());
+    assert_eq!(
+        tags.value.value.iter().map(int_value).collect::<Vec<_>>(),
+        [7, 8]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    error::PropertyError,
+    exports::Export,
+    properties::{mark_unversioned_zero, Property, PropertyDataTrait},
+    reader::ArchiveTrait,
+    unversioned::Usmap,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/StarlitSeason/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "CharacterCostume_chr0001_DataTable.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "CharacterCostume_chr0001_DataTable.uexp"
+));
+
+/// Names and duplication indices of `properties`
+fn names(properties: &[Property]) -> Vec<(String, i32)> {
+    properties
+        .iter()
+        .map(|e| (e.get_name().get_owned_content(), e.get_duplication_index()))
+        .collect()
+}
+
+fn write(asset: &Asset<Cursor<&[u8]>>) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut data = Cursor::new(Vec::new());
+    let mut bulk = Cursor::new(Vec::new());
+    asset.write_data(&mut data, Some(&mut bulk))?;
+    Ok((data.into_inner(), bulk.into_inner()))
+}
+
+#[test]
+fn tagged_to_unversioned() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+    assert!(!asset.has_unversioned_properties());
+
+    let err = asset
+        .set_unversioned(true, None)
+        .expect_err("Switched to unversioned properties without mappings");
+    assert!(matches!(err, Error::Property(_)));
+    assert!(!asset.has_unversioned_properties());
+
+    let mappings = shared::mappings(&asset);
+    asset.set_unversioned(true, Some(&mappings))?;
+    assert!(asset.has_unversioned_properties());
+    let (data, bulk) = write(&asset)?;
+    assert!(bulk.len() < TEST_BULK.len());
+
+    let mut unversioned = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE4_24,
+        Some(mappings),
+    )?;
+    assert!(unversioned.has_unversioned_properties());
+    assert!(shared::verify_all_exports_parsed(&unversioned));
+    shared::verify_binary_equality(&data, Some(&bulk), &mut unversioned)?;
+
+    let original = cast!(Export, DataTableExport, &asset.asset_data.exports[0]).unwrap();
+    let converted = cast!(Export, DataTableExport, &unversioned.asset_data.exports[0])
+        .expect("Converted export is not a DataTableExport");
+    assert_eq!(converted.table.data.len(), original.table.data.len());
+    for (converted, original) in converted.table.data.iter().zip(&original.table.data) {
+        assert_eq!(converted.name, original.name);
+        assert_eq!(names(&converted.value), names(&original.value));
+    }
+
+    // switching back writes the tagged asset we started with
+    asset.set_unversioned(false, None)?;
+    assert!(!asset.has_unversioned_properties());
+    let (data, bulk) = write(&asset)?;
+    assert_eq!(data, TEST_ASSET);
+    assert_eq!(bulk, TEST_BULK);
+
+    Ok(())
+}
+
+/// First data table row of `asset`
+fn first_row<C: std::io::Read + std::io::Seek>(asset: &Asset<C>) -> &[Property] {
+    let table = cast!(Export, DataTableExport, &asset.asset_data.exports[0])
+        .expect("Export is not a DataTableExport");
+    &table.table.data[0].value
+}
+
+/// Write the test asset with unversioned properties, storing the `zero` properties of the first row as zero values
+fn unversioned_with_zeros(zero: &[&str]) -> Result<(Vec<u8>, Vec<u8>, Usmap), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+    let mappings = shared::mappings(&asset);
+
+    let table = cast!(Export, DataTableExport, &mut asset.asset_data.exports[0]).unwrap();
+    for property in &mut table.table.data[0].value {
+        if zero.contains(&property.get_name().get_owned_content().as_str()) {
+            mark_unversioned_zero(property);
+        }
+    }
+
+    asset.set_unversioned(true, Some(&mappings))?;
+    let (data, bulk) = write(&asset)?;
+    Ok((data, bulk, mappings))
+}
+
+#[test]
+fn unversioned_to_tagged() -> Result<(), Error> {
+    let original = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+
+    let (data, bulk, mappings) = unversioned_with_zeros(&["CostumeID", "AccessoryScale1"])?;
+    let mut asset = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE4_24,
+        Some(mappings),
+    )?;
+    assert!(asset.has_unversioned_properties());
+    let zeros = first_row(&asset)
+        .iter()
+        .filter(|e| matches!(e, Property::EmptyProperty(_)))
+        .count();
+    assert_eq!(zeros, 2);
+
+    asset.set_unversioned(false, None)?;
+    assert!(!asset.has_unversioned_properties());
+    let (data, bulk) = write(&asset)?;
+
+    let tagged = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE4_24,
+        None,
+    )?;
+    assert!(!tagged.has_unversioned_properties());
+    assert!(shared::verify_all_exports_parsed(&tagged));
+
+    let converted = cast!(Export, DataTableExport, &tagged.asset_data.exports[0]).unwrap();
+    let expected = cast!(Export, DataTableExport, &original.asset_data.exports[0]).unwrap();
+    assert_eq!(converted.table.data.len(), expected.table.data.len());
+    assert_eq!(converted.table.data[1..], expected.table.data[1..]);
+
+    // zero values are written as the defaults of their types
+    for (converted, expected) in first_row(&tagged).iter().zip(first_row(&original)) {
+        match converted.get_name().get_owned_content().as_str() {
+            "CostumeID" => {
+                let name = cast!(Property, NameProperty, converted)
+                    .expect("Zero value is not a NameProperty");
+                assert_eq!(name.value.get_owned_content(), "None");
+            }
+            "AccessoryScale1" => {
+                let float = cast!(Property, FloatProperty, converted)
+                    .expect("Zero value is not a FloatProperty");
+                assert_eq!(float.value.0, 0.0);
+            }
+            _ => assert_eq!(converted, expected),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unversioned_to_tagged_without_default() -> Result<(), Error> {
+    let (data, bulk, mappings) = unversioned_with_zeros(&["AccessoryAttachPos1"])?;
+    let mut asset = Asset::new(
+        Cursor::new(data.as_slice()),
+        Some(Cursor::new(bulk.as_slice())),
+        EngineVersion::VER_UE4_24,
+        Some(mappings),
+    )?;
+
+    // zero structs have no known default, the asset is left as it is
+    let err = asset
+        .set_unversioned(false, None)
+        .expect_err("Switched to tagged properties with a zero struct");
+    assert!(matches!(
+        err,
+        Error::Property(PropertyError::NoZeroValue(..))
+    ));
+    assert!(asset.has_unversioned_properties());
+    assert!(first_row(&asset)
+        .iter()
+        .any(|e| matches!(e, Property::EmptyProperty(_))));
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    asset_archive_writer::AssetArchiveWriter,
+    asset_data::AssetData,
+    containers::{IndexedMap, NameMap},
+    custom_version::CustomVersion,
+    flags::EPackageFlags,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        empty_property::EmptyProperty, generate_unversioned_header, int_property::IntProperty,
+        mark_unversioned_zero, Property, PropertyDataTrait,
+    },
+    reader::RawWriter,
+    types::{FName, PackageIndex},
+    unversioned::{
+        properties::{shallow_property::UsmapShallowPropertyData, EPropertyType, UsmapProperty},
+        Ancestry, EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion,
+        UsmapSchema,
+    },
+    Error,
+};
+
+const SCHEMA: &str = "TestStruct";
+const FIELDS: [&str; 4] = ["A", "B", "C", "D"];
+
+fn mappings() -> Usmap {
+    let mut properties = IndexedMap::new();
+    for (i, name) in FIELDS.iter().enumerate() {
+        properties.insert(
+            (name.to_string(), 0),
+            UsmapProperty {
+                name: name.to_string(),
+                schema_index: i as u16,
+                array_size: 1,
+                array_index: 0,
+                property_data: UsmapShallowPropertyData {
+                    property_type: EPropertyType::IntProperty,
+                }
+                .into(),
+            },
+        );
+    }
+
+    let mut schemas = IndexedMap::new();
+    schemas.insert(
+        SCHEMA.to_string(),
+        UsmapSchema {
+            name: SCHEMA.to_string(),
+            super_type: String::new(),
+            prop_count: FIELDS.len() as u16,
+            module_path: None,
+            properties,
+        },
+    );
+
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas,
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::<CustomVersion>::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+fn int_property(name: &str, value: i32) -> Property {
+    IntProperty {
+        name: FName::new_dummy(name.to_string(), 0),
+        ancestry: Ancestry::new(FName::new_dummy(SCHEMA.to_string(), 0)),
+        value,
+        ..Default::default()
+    }
+    .into()
+}
+
+#[test]
+fn zero_mask() -> Result<(), Error> {
+    let mut asset_data = AssetData::<PackageIndex> {
+        mappings: Some(mappings()),
+        ..Default::default()
+    };
+    asset_data.summary.package_flags |= EPackageFlags::PKG_UNVERSIONED_PROPERTIES;
+
+    let mut zeroed = int_property("C", 5);
+    mark_unversioned_zero(&mut zeroed);
+    assert!(matches!(zeroed, Property::EmptyProperty(ref e) if e.name == "C"));
+
+    // A is left out, C and D are zero and B is the only serialized value
+    let properties = [
+        EmptyProperty::for_field(
+            "IntProperty",
+            "D",
+            Ancestry::new(FName::new_dummy(SCHEMA.to_string(), 0)),
+        )
+        .into(),
+        int_property("B", 1),
+        zeroed,
+    ];
+
+    let name_map = NameMap::new();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut raw_writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+    let mut writer = AssetArchiveWriter::new(&mut raw_writer, &asset_data, &[], name_map);
+
+    let (header, sorted_properties) = generate_unversioned_header(
+        &writer,
+        &properties,
+        &FName::new_dummy(SCHEMA.to_string(), 0),
+    )?
+    .expect("No header generated for unversioned properties");
+
+    assert_eq!(
+        sorted_properties
+            .iter()
+            .map(|e| e.get_name().get_owned_content())
+            .collect::<Vec<_>>(),
+        ["B", "C", "D"]
+    );
+
+    assert_eq!(header.fragments.len(), 1);
+    let fragment = header.fragments[0];
+    assert_eq!(fragment.skip_num, 1);
+    assert_eq!(fragment.value_num, 3);
+    assert_eq!(fragment.first_num, 1);
+    assert!(fragment.is_last);
+    assert!(fragment.has_zeros);
+    assert_eq!(header.zero_mask.as_raw_slice(), [0b110]);
+    assert!(header.has_non_zero_values);
+
+    header.write(&mut writer)?;
+    assert_eq!(cursor.into_inner(), [0x81, 0x07, 0b110]);
+
+    Ok(())
+}
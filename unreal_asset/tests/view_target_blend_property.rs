@@ -0,0 +1,73 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::{Chain, NameMap},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{
+        view_target_blend_property::{ViewTargetBlendFunction, ViewTargetBlendParamsProperty},
+        PropertyTrait,
+    },
+    reader::{RawReader, RawWriter},
+    types::PackageIndex,
+    unversioned::ancestry::Ancestry,
+    Error,
+};
+
+#[test]
+fn view_target_blend_params() -> Result<(), Error> {
+    let mut name_map = NameMap::new();
+    let name = name_map.get_mut().add_fname("BlendParams");
+
+    let mut property = ViewTargetBlendParamsProperty {
+        name: name.clone(),
+        ..Default::default()
+    };
+    property.set_blend_time(0.5);
+    assert_eq!(
+        property.blend_function(),
+        ViewTargetBlendFunction::VtBlendLinear
+    );
+
+    property.set_blend_function(ViewTargetBlendFunction::VtBlendEaseInOut);
+    property.set_blend_exp(2.5);
+    property.set_lock_outgoing(true);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+    let written = property.write(&mut writer, false)?;
+    assert_eq!(written, cursor.get_ref().len());
+
+    cursor.set_position(0);
+    let mut reader = RawReader::<PackageIndex, _>::new(
+        Chain::new(cursor, None),
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map,
+    );
+    let read = ViewTargetBlendParamsProperty::new(
+        &mut reader,
+        name,
+        Ancestry::default(),
+        false,
+        written as i64,
+        0,
+    )?;
+
+    assert_eq!(read, property);
+    assert_eq!(read.blend_time(), 0.5);
+    assert_eq!(
+        read.blend_function(),
+        ViewTargetBlendFunction::VtBlendEaseInOut
+    );
+    assert_eq!(read.blend_exp(), 2.5);
+    assert!(read.lock_outgoing());
+
+    Ok(())
+}
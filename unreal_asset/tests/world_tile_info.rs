@@ -0,0 +1,59 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion, properties::world_tile_property::FWorldTileInfo, Asset, Error,
+};
+
+mod shared;
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/BloodStained/m05SAN_000_Gimmick.umap"
+));
+
+#[test]
+fn world_tile_info() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+    shared::verify_binary_equality(TEST_ASSET, None, &mut asset)?;
+
+    let world_tile_info = asset.asset_data.world_tile_info.as_mut().unwrap();
+    let streaming_distance = world_tile_info.streaming_distance().unwrap();
+    let z_order = world_tile_info.z_order().unwrap();
+
+    world_tile_info.set_streaming_distance(streaming_distance + 5000)?;
+    world_tile_info.set_layer_name(Some(String::from("Retuned")));
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let asset = Asset::new(
+        Cursor::new(cursor.into_inner()),
+        None,
+        EngineVersion::VER_UE4_18,
+        None,
+    )?;
+
+    let world_tile_info = asset.asset_data.world_tile_info.as_ref().unwrap();
+    assert_eq!(
+        world_tile_info.streaming_distance(),
+        Some(streaming_distance + 5000)
+    );
+    assert_eq!(world_tile_info.layer_name(), Some("Retuned"));
+    assert_eq!(world_tile_info.z_order(), Some(z_order));
+
+    Ok(())
+}
+
+#[test]
+fn world_tile_info_missing_fields() {
+    // fields that are not serialized for older versions can't be set
+    let mut world_tile_info = FWorldTileInfo::default();
+    assert!(world_tile_info.set_streaming_distance(5000).is_err());
+    assert!(world_tile_info.set_z_order(1).is_err());
+    assert_eq!(world_tile_info.streaming_distance(), None);
+    assert_eq!(world_tile_info.z_order(), None);
+}
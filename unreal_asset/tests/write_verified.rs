@@ -0,0 +1,97 @@
+use std::io::Cursor;
+
+use byteorder::{WriteBytesExt, LE};
+use unreal_asset::{
+    containers::NameMap,
+    engine_version::EngineVersion,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    properties::{impl_property_data_trait, verify_written_length, PropertyTrait},
+    reader::{ArchiveWriter, RawWriter},
+    types::{FName, PackageIndex, PackageIndexTrait},
+    unversioned::Ancestry,
+    Asset, Error, Guid,
+};
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uasset"
+));
+const TEST_BULK: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+/// Property that reports more bytes than it writes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MiscountedProperty {
+    name: FName,
+    ancestry: Ancestry,
+    property_guid: Option<Guid>,
+    duplication_index: i32,
+}
+impl_property_data_trait!(MiscountedProperty);
+
+impl PropertyTrait for MiscountedProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        _include_header: bool,
+    ) -> Result<usize, Error> {
+        asset.write_i32::<LE>(0)?;
+        Ok(8)
+    }
+}
+
+#[test]
+fn write_verified() -> Result<(), Error> {
+    let asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_verified(&mut cursor, Some(&mut bulk_cursor))?;
+
+    assert_eq!(cursor.into_inner(), TEST_ASSET);
+    assert_eq!(bulk_cursor.into_inner(), TEST_BULK);
+
+    Ok(())
+}
+
+#[test]
+fn miscounted_write_is_caught() -> Result<(), Error> {
+    let name_map = NameMap::new();
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = RawWriter::<PackageIndex, _>::new(
+        &mut cursor,
+        ObjectVersion::UNKNOWN,
+        ObjectVersionUE5::UNKNOWN,
+        false,
+        name_map.clone(),
+    );
+
+    let property = MiscountedProperty {
+        name: FName::from_slice("Miscounted"),
+        ancestry: Ancestry::default(),
+        property_guid: None,
+        duplication_index: 0,
+    };
+
+    match verify_written_length(&property, &mut writer) {
+        Err(Error::SerializationLengthMismatch {
+            name,
+            reported,
+            actual,
+        }) => {
+            assert_eq!(&*name, "Miscounted");
+            assert_eq!(reported, 8);
+            assert_eq!(actual, 4);
+        }
+        other => panic!("expected a length mismatch, got {other:?}"),
+    }
+
+    Ok(())
+}
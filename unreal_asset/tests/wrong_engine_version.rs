@@ -0,0 +1,120 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    containers::IndexedMap,
+    engine_version::EngineVersion,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    unversioned::{EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion},
+    Asset, Error,
+};
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Misc_426/"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(assets_folder!(), "RaceSimDataAsset.uasset"));
+const TEST_BULK: &[u8] = include_bytes!(concat!(assets_folder!(), "RaceSimDataAsset.uexp"));
+
+/// Mappings generated for a 4.27 game, without any schemas
+fn mappings_4_27() -> Usmap {
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas: IndexedMap::new(),
+        extension_version: UsmapExtensionVersion::NONE,
+        object_version: ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+#[test]
+fn wrong_engine_version() {
+    let error = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_14,
+        Some(mappings_4_27()),
+    )
+    .expect_err("Asset parsed with the wrong engine version");
+
+    let Error::WrongEngineVersion {
+        tried,
+        suggestions,
+        source,
+    } = error
+    else {
+        panic!("Expected a WrongEngineVersion error, got {error:?}");
+    };
+
+    // the engine version is guessed from the versions of the mappings
+    assert_eq!(tried, EngineVersion::VER_UE4_14);
+    assert_eq!(suggestions, vec![EngineVersion::VER_UE4_27]);
+    assert!(matches!(*source, Error::Io(_)));
+
+    assert!(Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_27,
+        Some(mappings_4_27()),
+    )
+    .is_ok());
+}
+
+#[test]
+fn wrong_engine_version_without_mappings() {
+    let error = Asset::new(
+        Cursor::new(TEST_ASSET),
+        Some(Cursor::new(TEST_BULK)),
+        EngineVersion::VER_UE4_14,
+        None,
+    )
+    .expect_err("Asset parsed with the wrong engine version");
+
+    let Error::WrongEngineVersion {
+        tried, suggestions, ..
+    } = error
+    else {
+        panic!("Expected a WrongEngineVersion error, got {error:?}");
+    };
+
+    // the header only tells this is a UE4 asset, every UE4 version it parses with is suggested
+    assert_eq!(tried, EngineVersion::VER_UE4_14);
+    assert!(suggestions.contains(&EngineVersion::VER_UE4_27));
+    assert!(suggestions.iter().all(|e| *e < EngineVersion::VER_UE5_0));
+    for suggestion in suggestions {
+        assert!(Asset::new(
+            Cursor::new(TEST_ASSET),
+            Some(Cursor::new(TEST_BULK)),
+            suggestion,
+            None,
+        )
+        .is_ok());
+    }
+}
+
+#[test]
+fn versioned_asset_error_unchanged() {
+    // a truncated versioned asset is not a version mismatch
+    let test_asset = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/Versioned/Assault_M1A1Thompson_WW2_DrumSuppressor.uasset"
+    ));
+
+    let error = Asset::new(
+        Cursor::new(&test_asset[..test_asset.len() / 2]),
+        None,
+        EngineVersion::VER_UE4_14,
+        None,
+    )
+    .expect_err("Truncated asset parsed");
+    assert!(!matches!(error, Error::WrongEngineVersion { .. }));
+}
@@ -33,6 +33,26 @@ impl<C: Read + Seek> Chain<C> {
             pos: 0,
         }
     }
+
+    /// Replace the second `Read` + `Seek` implementation, rewinding the chain
+    pub fn set_second(&mut self, mut second: C) -> Result<()> {
+        self.second_len = second.seek(SeekFrom::End(0))?;
+        second.rewind()?;
+        self.first.rewind()?;
+        self.second = Some(second);
+        self.pos = 0;
+        Ok(())
+    }
+
+    /// Check if the chain has a second `Read` + `Seek` implementation
+    pub fn has_second(&self) -> bool {
+        self.second.is_some()
+    }
+
+    /// Get back the chained `Read` + `Seek` implementations
+    pub fn into_inner(self) -> (C, Option<C>) {
+        (self.first, self.second)
+    }
 }
 
 impl<C: Read + Seek> Read for Chain<C> {
@@ -63,7 +63,7 @@ lazy_static! {
         ( (0xAFE08691, 0x3A0D4952, 0xB673673B, 0x7CF22D1E).into(), (String::from("FPoseDriverCustomVersion"), None) ),
         ( (0xCB8AB0CD, 0xE78C4BDE, 0xA8621393, 0x14E9EF62).into(), (String::from("FTempCustomVersion"), None) ),
         ( (0x2EB5FDBD, 0x01AC4D10, 0x8136F38F, 0x3393A5DA).into(), (String::from("FAnimationCustomVersion"), None) ),
-        ( (0x717F9EE7, 0xE9B0493A, 0x88B39132, 0x1B388107).into(), (String::from("FAssetRegistryVersion"), None) ),
+        ( ASSET_REGISTRY_VERSION_GUID,                              (String::from("FAssetRegistryVersion"), None) ),
         ( (0xFB680AF2, 0x59EF4BA3, 0xBAA819B5, 0x73C8443D).into(), (String::from("FClothingAssetCustomVersion"), None) ),
         ( (0x4A56EB40, 0x10F511DC, 0x92D3347E, 0xB2C96AE7).into(), (String::from("FParticleSystemCustomVersion"), None) ),
         ( (0xD78A4A00, 0xE8584697, 0xBAA819B5, 0x487D46B4).into(), (String::from("FSkeletalMeshCustomVersion"), None) ),
@@ -80,6 +80,27 @@ lazy_static! {
         ( FReleaseObjectVersion::GUID,                              (String::from(FReleaseObjectVersion::FRIENDLY_NAME), Some(FReleaseObjectVersion::VERSION_MAPPINGS)) ),
         ( FSequencerObjectVersion::GUID,                            (String::from(FSequencerObjectVersion::FRIENDLY_NAME), Some(FSequencerObjectVersion::VERSION_MAPPINGS)) ),
     ]);
+
+    static ref WELL_KNOWN: Vec<(Guid, &'static str)> = {
+        let mut well_known = GUID_TO_VERSION_INFO
+            .iter()
+            .map(|(guid, (friendly_name, _))| (*guid, friendly_name.as_str()))
+            .collect::<Vec<_>>();
+        well_known.sort_by_key(|(_, friendly_name)| *friendly_name);
+        well_known
+    };
+}
+
+/// Get all well-known custom versions as guid and friendly name pairs, sorted by friendly name
+pub fn well_known() -> &'static [(Guid, &'static str)] {
+    &WELL_KNOWN
+}
+
+/// Look up the friendly name of a well-known custom version
+pub fn friendly_name(guid: &Guid) -> Option<&'static str> {
+    GUID_TO_VERSION_INFO
+        .get(guid)
+        .map(|(friendly_name, _)| friendly_name.as_str())
 }
 
 impl CustomVersion {
@@ -1331,7 +1352,8 @@ pub enum FAssetRegistryVersionType {
     VersionPlusOne,
 }
 
-const ASSET_REGISTRY_VERSION_GUID: Guid =
+/// Asset registry custom version guid
+pub const ASSET_REGISTRY_VERSION_GUID: Guid =
     Guid::from_ints(0x717F9EE7, 0xE9B0493A, 0x88B39132, 0x1B388107);
 
 impl FAssetRegistryVersionType {
@@ -11,12 +11,23 @@ use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 /// ['ObjectVersion']: object_version.html
 /// ['CustomVersion']: custom_version.html
 #[derive(
-    Debug, Hash, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, IntoPrimitive, TryFromPrimitive,
+    Debug,
+    Default,
+    Hash,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    IntoPrimitive,
+    TryFromPrimitive,
 )]
 #[repr(i32)]
 #[allow(non_camel_case_types)]
 pub enum EngineVersion {
     /// Unknown
+    #[default]
     UNKNOWN,
     /// Oldest loadable package
     VER_UE4_OLDEST_LOADABLE_PACKAGE,
@@ -84,6 +95,10 @@ pub enum EngineVersion {
     VER_UE5_1,
     /// 5.2
     VER_UE5_2,
+    /// 5.3
+    VER_UE5_3,
+    /// 5.4
+    VER_UE5_4,
 
     /// The newest specified version of the Unreal Engine.
     VER_UE4_AUTOMATIC_VERSION,
@@ -217,6 +232,14 @@ lazy_static! {
             ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
             EngineVersion::VER_UE5_2
         ),
+        (
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            EngineVersion::VER_UE5_3
+        ),
+        (
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            EngineVersion::VER_UE5_4
+        ),
     ]);
     static ref OBJECT_VERSION_TO_ENGINE_VERSION_UE5: Vec<(ObjectVersionUE5, EngineVersion)> =
         Vec::from([
@@ -228,10 +251,15 @@ lazy_static! {
                 ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST,
                 EngineVersion::VER_UE5_1
             ),
+            (ObjectVersionUE5::DATA_RESOURCES, EngineVersion::VER_UE5_2),
+            (
+                ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION,
+                EngineVersion::VER_UE5_3
+            ),
             (
-                ObjectVersionUE5::AUTOMATIC_VERSION,
-                EngineVersion::VER_UE5_2
-            )
+                ObjectVersionUE5::ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES,
+                EngineVersion::VER_UE5_4
+            ),
         ]);
 }
 
@@ -8,6 +8,7 @@ use thiserror::Error;
 use unreal_helpers::error::FStringError;
 
 use crate::custom_version::FAssetRegistryVersionType;
+use crate::engine_version::EngineVersion;
 use crate::reader::ArchiveType;
 use crate::unversioned::Ancestry;
 
@@ -129,6 +130,15 @@ pub enum PropertyError {
     /// An unversioned property schema was not found
     #[error("Unversioned property schema for {0} at index {1} was not found")]
     NoSchema(Box<str>, usize),
+    /// A property type was not recognized
+    #[error("Unknown property type {1} for property {0}")]
+    UnknownType(Box<str>, Box<str>),
+    /// A value does not fit into a property's type
+    #[error("Value {1} is out of range for {2} property {0}")]
+    ValueOutOfRange(Box<str>, i64, Box<str>),
+    /// An unversioned zero value has no known tagged equivalent
+    #[error("Cannot write zero value of {1} property {0} as a tagged property")]
+    NoZeroValue(Box<str>, Box<str>),
     /// Other
     #[error("{0}")]
     Other(Box<str>),
@@ -188,6 +198,31 @@ impl PropertyError {
         PropertyError::NoSchema(name.into_boxed_str(), index)
     }
 
+    /// Create a `PropertyError` for a property with an unrecognized type
+    pub fn unknown_type(name: &str, property_type: &str) -> Self {
+        PropertyError::UnknownType(
+            name.to_string().into_boxed_str(),
+            property_type.to_string().into_boxed_str(),
+        )
+    }
+
+    /// Create a `PropertyError` for a value that does not fit into a property's type
+    pub fn value_out_of_range(name: &str, value: i64, value_type: &str) -> Self {
+        PropertyError::ValueOutOfRange(
+            name.to_string().into_boxed_str(),
+            value,
+            value_type.to_string().into_boxed_str(),
+        )
+    }
+
+    /// Create a `PropertyError` for an unversioned zero value that can't be written as a tagged property
+    pub fn no_zero_value(name: &str, property_type: &str) -> Self {
+        PropertyError::NoZeroValue(
+            name.to_string().into_boxed_str(),
+            property_type.to_string().into_boxed_str(),
+        )
+    }
+
     /// Create a `PropertyError` for a field that was expected to have a value, but was None
     pub fn property_field_none(field_name: &str, expected: &str) -> Self {
         PropertyError::PropertyFieldNone(
@@ -323,6 +358,9 @@ pub enum Error {
     /// Hash mismatch when reading a name batch
     #[error("Hash mismatch when reading a name batch, expected hash {0}, got {1}, string {2}")]
     NameBatchHashMismatch(u64, u64, Box<str>),
+    /// The file does not start with the asset magic
+    #[error("File is not a valid uasset file")]
+    NotAnAsset,
     /// The file is invalid
     #[error("{0}")]
     InvalidFile(Box<str>),
@@ -367,9 +405,107 @@ pub enum Error {
     /// A `ZenError` occured
     #[error(transparent)]
     Zen(#[from] ZenError),
+
+    /// An unversioned asset failed to parse with the engine version it was given
+    #[error("Failed to parse asset with engine version {tried:?}, it might have been serialized with one of {suggestions:?}: {source}")]
+    WrongEngineVersion {
+        /// Engine version the asset failed to parse with
+        tried: EngineVersion,
+        /// Engine versions the asset parses with
+        suggestions: Vec<EngineVersion>,
+        /// Error the asset failed to parse with
+        source: Box<Error>,
+    },
+
+    /// A written length field does not match the size of the data that was serialized
+    #[error("Serialization length mismatch for {name}, reported {reported} bytes but {actual} were written")]
+    SerializationLengthMismatch {
+        /// Name of the property or export
+        name: Box<str>,
+        /// Length that was written to the length field
+        reported: u64,
+        /// Length of the serialized data
+        actual: u64,
+    },
+
+    /// The summary name count doesn't match the number of names in the name map
+    #[error(
+        "Name count mismatch, the summary lists {expected} names but the name map holds {actual}"
+    )]
+    NameCountMismatch {
+        /// Name count stored in the summary
+        expected: i32,
+        /// Number of names found before the next section
+        actual: i32,
+    },
+
+    /// An error occured at a known offset
+    #[error("{context} at offset {offset}: {source}")]
+    Context {
+        /// Offset the error occured at
+        offset: u64,
+        /// What was being done when the error occured
+        context: Box<str>,
+        /// Error that occured
+        source: Box<Error>,
+    },
 }
 
 impl Error {
+    /// Create an `Error` for an asset that failed to parse with the wrong engine version
+    pub fn wrong_engine_version(
+        tried: EngineVersion,
+        suggestions: Vec<EngineVersion>,
+        source: Error,
+    ) -> Self {
+        Error::WrongEngineVersion {
+            tried,
+            suggestions,
+            source: Box::new(source),
+        }
+    }
+
+    /// Create an `Error` for a length field that does not match the size of the serialized data
+    pub fn serialization_length_mismatch(name: String, reported: u64, actual: u64) -> Self {
+        Error::SerializationLengthMismatch {
+            name: name.into_boxed_str(),
+            reported,
+            actual,
+        }
+    }
+
+    /// Create an `Error` for a summary name count that doesn't match the name map
+    pub fn name_count_mismatch(expected: i32, actual: i32) -> Self {
+        Error::NameCountMismatch { expected, actual }
+    }
+
+    /// Attach the offset the error occured at and what was being done at the time
+    pub fn with_context(self, offset: u64, context: String) -> Self {
+        Error::Context {
+            offset,
+            context: context.into_boxed_str(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Get the offset this error occured at, if it is known
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            Error::Context { offset, .. } => Some(*offset),
+            Error::FString(FStringError::InvalidStringSize(_, offset))
+            | Error::FString(FStringError::InvalidStringTerminator(_, offset)) => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// Get the innermost error, skipping any attached context
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+
     /// Create an `Error` for a case where expected data was not found
     pub fn no_data(msg: String) -> Self {
         Error::NoData(msg.into_boxed_str())
@@ -416,3 +552,72 @@ impl<T: TryFromPrimitive> From<TryFromPrimitiveError<T>> for Error {
         Error::InvalidEnumValue(e.to_string().into_boxed_str())
     }
 }
+
+#[cfg(feature = "diagnostic")]
+impl Error {
+    /// Get the diagnostic code of this error
+    fn code(&self) -> &'static str {
+        match self {
+            Error::Io(e) if e.kind() == io::ErrorKind::UnexpectedEof => "unreal_asset::eof",
+            Error::Io(_) => "unreal_asset::io",
+            Error::FString(FStringError::InvalidStringSize(..)) => "unreal_asset::overflow",
+            Error::FString(_) | Error::Utf8(_) | Error::Utf16(_) => "unreal_asset::string",
+            Error::NoData(_) => "unreal_asset::no_data",
+            Error::FName(_) => "unreal_asset::fname",
+            Error::ArchiveTypeMismatch(..) => "unreal_asset::archive_type_mismatch",
+            Error::Cityhash64Collision(..) | Error::NameBatchHashMismatch(..) => {
+                "unreal_asset::name_hash"
+            }
+            Error::NotAnAsset => "unreal_asset::not_an_asset",
+            Error::InvalidFile(_) => "unreal_asset::invalid_file",
+            Error::InvalidPackageIndex(_) => "unreal_asset::invalid_package_index",
+            Error::InvalidEnumValue(_) => "unreal_asset::invalid_enum_value",
+            Error::Unimplemented(_) => "unreal_asset::unimplemented",
+            Error::Kismet(_) => "unreal_asset::kismet",
+            Error::Property(PropertyError::ValueOutOfRange(..)) => "unreal_asset::overflow",
+            Error::Property(_) => "unreal_asset::property",
+            Error::Registry(_) => "unreal_asset::registry",
+            Error::Usmap(_) => "unreal_asset::usmap",
+            Error::IoStore(_) => "unreal_asset::io_store",
+            Error::UnknownCompressionMethod(_)
+            | Error::Lz4(_)
+            | Error::Oodle
+            | Error::OodleNotInitialized => "unreal_asset::compression",
+            Error::Zen(_) => "unreal_asset::zen",
+            Error::WrongEngineVersion { .. } => "unreal_asset::wrong_engine_version",
+            Error::SerializationLengthMismatch { .. } => "unreal_asset::length_mismatch",
+            Error::NameCountMismatch { .. } => "unreal_asset::name_count_mismatch",
+            Error::Context { source, .. } => source.code(),
+        }
+    }
+}
+
+#[cfg(feature = "diagnostic")]
+impl miette::Diagnostic for Error {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        Some(Box::new(Error::code(self)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        match self.root() {
+            Error::WrongEngineVersion { suggestions, .. } => {
+                Some(Box::new(format!("try parsing with one of {suggestions:?}")))
+            }
+            Error::NameCountMismatch { .. } => Some(Box::new(
+                "parse with `ParseOptions::lenient` to use the actual name count",
+            )),
+            _ => None,
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let offset = self.offset()? as usize;
+        let label = match self {
+            Error::Context { context, .. } => context.to_string(),
+            _ => self.to_string(),
+        };
+        Some(Box::new(std::iter::once(miette::LabeledSpan::at_offset(
+            offset, label,
+        ))))
+    }
+}
@@ -472,6 +472,28 @@ bitflags! {
         /// Flags that are always computed; never loaded or done with code generation
         const COMPUTED_FLAGS = Self::NET_DELTA_SERIALIZE_NATIVE.bits() | Self::NET_SERIALIZE_NATIVE.bits() | Self::SERIALIZE_NATIVE.bits() | Self::POST_SERIALIZE_NATIVE.bits() | Self::COPY_NATIVE.bits() | Self::IS_PLAIN_OLD_DATA.bits() | Self::NO_DESTRUCTOR.bits() | Self::ZERO_CONSTRUCTOR.bits() | Self::IDENTICAL_NATIVE.bits() | Self::ADD_STRUCT_REFERENCED_OBJECTS.bits() | Self::EXPORT_TEXT_ITEM_NATIVE.bits() | Self::IMPORT_TEXT_ITEM_NATIVE.bits() | Self::SERIALIZE_FROM_MISMATCHED_TAG.bits() | Self::POST_SCRIPT_CONSTRUCT.bits() | Self::NET_SHARED_SERIALIZATION.bits();
     }
+
+    /// Property tag flags, serialized in property tags since UE5.4
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct EPropertyTagFlags : u8 {
+        /// No flags
+        const NONE = 0x00;
+
+        /// The tag stores an array index
+        const HAS_ARRAY_INDEX = 0x01;
+
+        /// The tag stores a property guid
+        const HAS_PROPERTY_GUID = 0x02;
+
+        /// The tag stores property extensions
+        const HAS_PROPERTY_EXTENSIONS = 0x04;
+
+        /// The property value uses binary or native serialization
+        const HAS_BINARY_OR_NATIVE_SERIALIZE = 0x08;
+
+        /// The value of a bool property is true
+        const BOOL_TRUE = 0x10;
+    }
 }
 
 impl Default for EObjectFlags {
@@ -509,3 +531,9 @@ impl Default for EStructFlags {
         Self::NO_FLAGS
     }
 }
+
+impl Default for EPropertyTagFlags {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
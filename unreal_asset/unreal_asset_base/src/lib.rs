@@ -18,6 +18,7 @@ pub mod flags;
 pub mod import;
 pub use import::Import;
 pub mod object_version;
+pub mod parse_options;
 pub mod reader;
 pub mod types;
 pub mod unversioned;
@@ -696,6 +696,18 @@ pub enum ObjectVersionUE5 {
     /// Added bulk/data resource table
     DATA_RESOURCES,
 
+    /// Added script property serialization offset to export table entries for saved, versioned packages
+    SCRIPT_SERIALIZATION_OFFSET,
+
+    /// Added property tag extensions, used to support overridable serialization on UObject and in containers
+    PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION,
+
+    /// Added property tag complete type name and serialization type
+    PROPERTY_TAG_COMPLETE_TYPE_NAME,
+
+    /// Changed UE::AssetRegistry::WritePackageData to include PackageBuildDependencies
+    ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES,
+
     /// -----<new versions can be added before this line>-------------------------------------------------
     AUTOMATIC_VERSION,
     /// Automatic version plus one
@@ -0,0 +1,50 @@
+//! Options controlling how assets are parsed
+
+/// Policy for handling properties with an unrecognized type
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum UnknownPropertyPolicy {
+    /// Read the property data into an `UnknownProperty`
+    #[default]
+    Raw,
+    /// Return an error
+    ErrorOut,
+    /// Skip the property using the length from its tag, it will not be present after parsing
+    ///
+    /// Only tagged properties have a length to skip by. Unversioned properties and
+    /// elements of containers are read as with [`UnknownPropertyPolicy::Raw`].
+    SkipByLength,
+}
+
+/// Options controlling how assets are parsed
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// Policy for handling properties with an unrecognized type
+    pub unknown_property_policy: UnknownPropertyPolicy,
+    /// Only read the export map and keep every export as a `BaseExport`
+    ///
+    /// Export bodies can then be parsed one at a time with `Asset::export_iter`.
+    /// An asset parsed this way can't be written back, as the export data is missing.
+    pub skip_export_bodies: bool,
+    /// `FName`s are stored inline as a string followed by their number instead of as a name map index
+    ///
+    /// Used by some very old or custom assets. Names read this way are added to the name map.
+    pub inline_fnames: bool,
+    /// Recover from inconsistencies in the package summary instead of failing
+    ///
+    /// Every inconsistency that was recovered from is recorded as a [`ParseAnomaly`],
+    /// see `Asset::anomalies`.
+    pub lenient: bool,
+}
+
+/// An inconsistency in an asset that was recovered from while parsing with [`ParseOptions::lenient`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ParseAnomaly {
+    /// The summary name count doesn't match the number of names in the name map,
+    /// the name map was read with the actual count
+    NameCountMismatch {
+        /// Name count stored in the summary
+        expected: i32,
+        /// Number of names found before the next section
+        actual: i32,
+    },
+}
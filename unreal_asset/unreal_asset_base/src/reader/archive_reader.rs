@@ -10,25 +10,61 @@ use crate::custom_version::CustomVersion;
 use crate::enums;
 use crate::enums::ECustomVersionSerializationFormat;
 use crate::error::{Error, FNameError};
-use crate::object_version::ObjectVersion;
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 use crate::reader::ArchiveTrait;
-use crate::types::{FName, PackageIndexTrait, SerializedNameHeader};
+use crate::types::{
+    property_type_name::PropertyTagType, FName, PackageIndexTrait, SerializedNameHeader,
+};
+use crate::unversioned::Ancestry;
 use crate::Guid;
 
 /// A trait that allows reading from an archive in an asset-specific way
 pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
     /// Read a `Guid` property
     fn read_property_guid(&mut self) -> Result<Option<Guid>, Error> {
+        let mut property_guid = None;
         if self.get_object_version() >= ObjectVersion::VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG {
             let has_property_guid = self.read_bool()?;
             if has_property_guid {
-                return Ok(Some(self.read_guid()?));
+                property_guid = Some(self.read_guid()?);
             }
         }
-        Ok(None)
+
+        if self.get_object_version_ue5()
+            >= ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+        {
+            // overridable serialization is editor-only, cooked assets don't set any extensions
+            let extensions = self.read_u8()?;
+            if extensions != 0 {
+                return Err(Error::unimplemented(format!(
+                    "Property tag extensions {extensions:#x} are not supported"
+                )));
+            }
+        }
+        Ok(property_guid)
+    }
+    /// Remember the UE5.4+ property tag type of a property, so it can be written back
+    fn add_property_tag_type(
+        &mut self,
+        _name: FName,
+        _ancestry: Ancestry,
+        _tag_type: PropertyTagType,
+    ) {
     }
     /// Read an `FName`
     fn read_fname(&mut self) -> Result<FName, Error> {
+        if self.get_parse_options().inline_fnames {
+            let value = self
+                .read_fstring()?
+                .ok_or_else(|| Error::invalid_file("Inline FName has no content".to_string()))?;
+            let number = self.read_i32::<LE>()?;
+
+            return Ok(self
+                .get_name_map()
+                .get_mut()
+                .add_fname_with_number(&value, number));
+        }
+
         let index = self.read_i32::<LE>()?;
         let number = self.read_i32::<LE>()?;
 
@@ -214,6 +250,25 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         self.read_array_with_length(length, getter)
     }
 
+    /// Read `length` bytes of raw data of a `type_name` value
+    ///
+    /// Lengths that are negative or longer than the rest of the archive are an invalid file error
+    /// with the offset attached, so a corrupted length can't cause a huge allocation.
+    fn read_raw_data(&mut self, length: i64, type_name: &str) -> Result<Vec<u8>, Error> {
+        let offset = self.position();
+        let remaining = self.data_length()?.saturating_sub(offset);
+        if length < 0 || length as u64 > remaining {
+            return Err(Error::invalid_file(format!(
+                "Invalid length {length}, only {remaining} bytes remain"
+            ))
+            .with_context(offset, format!("Failed to read {type_name}")));
+        }
+
+        let mut data = vec![0u8; length as usize];
+        self.read_exact(&mut data)?;
+        Ok(data)
+    }
+
     /// Read an FString
     fn read_fstring(&mut self) -> Result<Option<String>, Error>;
     /// Read an FString with a `SerializedNameHeader`
@@ -7,8 +7,11 @@ use crate::containers::{IndexedMap, NameMap, SharedResource};
 use crate::custom_version::{CustomVersion, CustomVersionTrait};
 use crate::engine_version::EngineVersion;
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
-use crate::types::{FName, PackageIndex, PackageIndexTrait};
-use crate::unversioned::Usmap;
+use crate::parse_options::ParseOptions;
+use crate::types::{
+    property_type_name::PropertyTagType, FName, PackageIndex, PackageIndexTrait,
+};
+use crate::unversioned::{Ancestry, Usmap};
 
 
 /// An enum to help identify current archive type
@@ -108,6 +111,10 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
     fn get_map_key_override(&self) -> &IndexedMap<String, String>;
     /// Get map value overrides for a `MapProperty`
     fn get_map_value_override(&self) -> &IndexedMap<String, String>;
+    /// Get the UE5.4+ property tag type of a property
+    fn get_property_tag_type(&self, _name: &FName, _ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        None
+    }
 
     /// Get archive's engine version
     fn get_engine_version(&self) -> EngineVersion;
@@ -119,6 +126,11 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
     /// Get .usmap mappings
     fn get_mappings(&self) -> Option<&Usmap>;
 
+    /// Get parse options
+    fn get_parse_options(&self) -> ParseOptions {
+        ParseOptions::default()
+    }
+
     /// Get parent class export name
     fn get_parent_class_export_name(&self) -> Option<FName>;
 
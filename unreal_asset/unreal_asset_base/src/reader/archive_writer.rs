@@ -5,7 +5,7 @@ use std::io::{self, Write};
 use byteorder::{WriteBytesExt, LE};
 
 use crate::error::{Error, FNameError};
-use crate::object_version::ObjectVersion;
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 use crate::reader::ArchiveTrait;
 use crate::types::{FName, PackageIndexTrait};
 use crate::Guid;
@@ -21,10 +21,22 @@ pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
             }
         }
 
+        if self.get_object_version_ue5()
+            >= ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+        {
+            self.write_u8(0)?;
+        }
+
         Ok(())
     }
     /// Write an `FName`
     fn write_fname(&mut self, fname: &FName) -> Result<(), Error> {
+        if self.get_parse_options().inline_fnames {
+            self.write_fstring(Some(&fname.get_owned_content()))?;
+            self.write_i32::<LE>(fname.get_number())?;
+            return Ok(());
+        }
+
         match fname {
             FName::Backed {
                 index,
@@ -0,0 +1,192 @@
+//! Archive reader that reads data from a buffer using another reader's context
+
+use std::io::{self, Cursor, Read, Seek};
+
+use unreal_helpers::{read_ext::read_fstring_len, Guid, UnrealReadExt};
+
+use crate::containers::{IndexedMap, NameMap, SharedResource};
+use crate::custom_version::{CustomVersion, CustomVersionTrait};
+use crate::engine_version::EngineVersion;
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
+use crate::parse_options::ParseOptions;
+use crate::reader::{
+    archive_trait::{ArchiveTrait, ArchiveType},
+    ArchiveReader,
+};
+use crate::types::{
+    property_type_name::PropertyTagType, FName, PackageIndex, PackageIndexTrait,
+    SerializedNameHeader,
+};
+use crate::unversioned::{Ancestry, Usmap};
+use crate::Error;
+
+/// A reader that reads data from an in-memory buffer,
+/// while taking versions, names, mappings and imports from a parent reader
+///
+/// This is useful for parsing bytes that were kept around raw, after the asset they came from was read.
+/// Positions are relative to the start of the buffer.
+pub struct BufferReader<'parent_reader, 'data, Index: PackageIndexTrait, R: ArchiveReader<Index>> {
+    /// Parent reader
+    parent_reader: &'parent_reader mut R,
+    /// Buffer cursor
+    cursor: Cursor<&'data [u8]>,
+    /// Marker
+    _marker: std::marker::PhantomData<Index>,
+}
+
+impl<'parent_reader, 'data, Index: PackageIndexTrait, R: ArchiveReader<Index>>
+    BufferReader<'parent_reader, 'data, Index, R>
+{
+    /// Create a new `BufferReader` instance
+    pub fn new(parent_reader: &'parent_reader mut R, data: &'data [u8]) -> Self {
+        BufferReader {
+            parent_reader,
+            cursor: Cursor::new(data),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the amount of bytes left in the buffer
+    pub fn remaining(&self) -> u64 {
+        (self.cursor.get_ref().len() as u64).saturating_sub(self.cursor.position())
+    }
+}
+
+impl<'parent_reader, 'data, Index: PackageIndexTrait, R: ArchiveReader<Index>> ArchiveTrait<Index>
+    for BufferReader<'parent_reader, 'data, Index, R>
+{
+    #[inline(always)]
+    fn get_archive_type(&self) -> ArchiveType {
+        self.parent_reader.get_archive_type()
+    }
+
+    fn get_custom_version<T>(&self) -> CustomVersion
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        self.parent_reader.get_custom_version::<T>()
+    }
+
+    fn has_unversioned_properties(&self) -> bool {
+        self.parent_reader.has_unversioned_properties()
+    }
+
+    fn use_event_driven_loader(&self) -> bool {
+        self.parent_reader.use_event_driven_loader()
+    }
+
+    fn position(&mut self) -> u64 {
+        self.cursor.position()
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.parent_reader.get_name_map()
+    }
+
+    fn get_array_struct_type_override(&self) -> &IndexedMap<String, String> {
+        self.parent_reader.get_array_struct_type_override()
+    }
+
+    fn get_map_key_override(&self) -> &IndexedMap<String, String> {
+        self.parent_reader.get_map_key_override()
+    }
+
+    fn get_map_value_override(&self) -> &IndexedMap<String, String> {
+        self.parent_reader.get_map_value_override()
+    }
+
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.parent_reader.get_property_tag_type(name, ancestry)
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.parent_reader.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.parent_reader.get_object_version()
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.parent_reader.get_object_version_ue5()
+    }
+
+    fn get_mappings(&self) -> Option<&Usmap> {
+        self.parent_reader.get_mappings()
+    }
+
+    fn get_parse_options(&self) -> ParseOptions {
+        self.parent_reader.get_parse_options()
+    }
+
+    fn get_parent_class_export_name(&self) -> Option<FName> {
+        self.parent_reader.get_parent_class_export_name()
+    }
+
+    fn get_object_name(&self, index: Index) -> Option<FName> {
+        self.parent_reader.get_object_name(index)
+    }
+
+    fn get_object_name_packageindex(&self, index: PackageIndex) -> Option<FName> {
+        self.parent_reader.get_object_name_packageindex(index)
+    }
+}
+
+impl<'parent_reader, 'data, Index: PackageIndexTrait, R: ArchiveReader<Index>> ArchiveReader<Index>
+    for BufferReader<'parent_reader, 'data, Index, R>
+{
+    fn read_fstring(&mut self) -> Result<Option<String>, Error> {
+        Ok(self.cursor.read_fstring()?)
+    }
+
+    fn read_fstring_name_header(
+        &mut self,
+        serialized_name_header: SerializedNameHeader,
+    ) -> Result<Option<String>, Error> {
+        if serialized_name_header.len == 0 {
+            return Ok(None);
+        }
+
+        Ok(read_fstring_len(
+            &mut self.cursor,
+            serialized_name_header.len,
+            serialized_name_header.is_wide,
+        )?)
+    }
+
+    fn read_guid(&mut self) -> io::Result<Guid> {
+        self.cursor.read_guid()
+    }
+
+    fn read_bool(&mut self) -> io::Result<bool> {
+        self.cursor.read_bool()
+    }
+
+    fn add_property_tag_type(
+        &mut self,
+        name: FName,
+        ancestry: Ancestry,
+        tag_type: PropertyTagType,
+    ) {
+        self.parent_reader
+            .add_property_tag_type(name, ancestry, tag_type)
+    }
+}
+
+impl<'parent_reader, 'data, Index: PackageIndexTrait, R: ArchiveReader<Index>> Read
+    for BufferReader<'parent_reader, 'data, Index, R>
+{
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl<'parent_reader, 'data, Index: PackageIndexTrait, R: ArchiveReader<Index>> Seek
+    for BufferReader<'parent_reader, 'data, Index, R>
+{
+    #[inline(always)]
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.cursor.seek(pos)
+    }
+}
@@ -10,8 +10,14 @@ pub use archive_trait::ArchiveType;
 pub mod archive_writer;
 pub use archive_writer::ArchiveWriter;
 
+pub mod buffer_reader;
+pub use buffer_reader::BufferReader;
+
 pub mod raw_reader;
 pub use raw_reader::RawReader;
 
 pub mod raw_writer;
 pub use raw_writer::RawWriter;
+
+pub mod recording_reader;
+pub use recording_reader::RecordingReader;
@@ -9,12 +9,16 @@ use crate::containers::{Chain, IndexedMap, NameMap, SharedResource};
 use crate::custom_version::{CustomVersion, CustomVersionTrait};
 use crate::engine_version::{guess_engine_version, EngineVersion};
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
+use crate::parse_options::ParseOptions;
 use crate::reader::{
     archive_trait::{ArchiveTrait, ArchiveType},
     ArchiveReader,
 };
-use crate::types::{FName, PackageIndex, PackageIndexTrait, SerializedNameHeader};
-use crate::unversioned::Usmap;
+use crate::types::{
+    property_type_name::{PropertyTagType, PropertyTagTypes},
+    FName, PackageIndex, PackageIndexTrait, SerializedNameHeader,
+};
+use crate::unversioned::{Ancestry, Usmap};
 use crate::Error;
 
 
@@ -30,8 +34,12 @@ pub struct RawReader<Index: PackageIndexTrait, C: Read + Seek> {
     pub use_event_driven_loader: bool,
     /// Name map
     pub name_map: SharedResource<NameMap>,
+    /// Parse options
+    pub parse_options: ParseOptions,
     /// Empty map
     empty_map: IndexedMap<String, String>,
+    /// UE5.4+ property tag types read so far
+    property_tag_types: PropertyTagTypes,
 
     /// Marker
     _marker: PhantomData<Index>,
@@ -52,10 +60,22 @@ impl<Index: PackageIndexTrait, C: Read + Seek> RawReader<Index, C> {
             object_version_ue5,
             use_event_driven_loader,
             name_map,
+            parse_options: ParseOptions::default(),
             empty_map: IndexedMap::new(),
+            property_tag_types: PropertyTagTypes::new(),
             _marker: PhantomData,
         }
     }
+
+    /// Replace the bulk data (.uexp) this reader reads from, rewinding the reader
+    pub fn set_bulk_data(&mut self, bulk_data: C) -> io::Result<()> {
+        self.cursor.set_second(bulk_data)
+    }
+
+    /// Get back the chain this reader reads from
+    pub fn into_inner(self) -> Chain<C> {
+        self.cursor
+    }
 }
 
 impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveTrait<Index> for RawReader<Index, C> {
@@ -99,6 +119,11 @@ impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveTrait<Index> for RawReader
         &self.empty_map
     }
 
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.property_tag_types
+            .get_by_key(&(ancestry.clone(), name.clone()))
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         guess_engine_version(self.object_version, self.object_version_ue5, &[])
     }
@@ -115,6 +140,10 @@ impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveTrait<Index> for RawReader
         None
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.parse_options
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         None
     }
@@ -129,6 +158,10 @@ impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveTrait<Index> for RawReader
 }
 
 impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveReader<Index> for RawReader<Index, C> {
+    fn add_property_tag_type(&mut self, name: FName, ancestry: Ancestry, tag_type: PropertyTagType) {
+        self.property_tag_types.insert((ancestry, name), tag_type);
+    }
+
     fn read_fstring(&mut self) -> Result<Option<String>, Error> {
         Ok(self.cursor.read_fstring()?)
     }
@@ -9,6 +9,7 @@ use crate::containers::{IndexedMap, NameMap, SharedResource};
 use crate::custom_version::{CustomVersion, CustomVersionTrait};
 use crate::engine_version::{guess_engine_version, EngineVersion};
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
+use crate::parse_options::ParseOptions;
 use crate::reader::{
     archive_trait::{ArchiveTrait, ArchiveType},
     ArchiveWriter,
@@ -30,6 +31,8 @@ pub struct RawWriter<'cursor, Index: PackageIndexTrait, W: Write + Seek> {
     use_event_driven_loader: bool,
     /// Name map
     name_map: SharedResource<NameMap>,
+    /// Parse options
+    pub parse_options: ParseOptions,
     /// Empty map
     empty_map: IndexedMap<String, String>,
     /// Marker
@@ -51,6 +54,7 @@ impl<'cursor, Index: PackageIndexTrait, W: Write + Seek> RawWriter<'cursor, Inde
             object_version_ue5,
             use_event_driven_loader,
             name_map,
+            parse_options: ParseOptions::default(),
             empty_map: IndexedMap::new(),
             _marker: PhantomData,
         }
@@ -116,6 +120,10 @@ impl<'cursor, Index: PackageIndexTrait, W: Write + Seek> ArchiveTrait<Index>
         None
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.parse_options
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         None
     }
@@ -0,0 +1,247 @@
+//! Archive reader that records every read for debugging
+
+use std::fmt::Write;
+use std::io::{self, Read, Seek};
+
+use unreal_helpers::{read_ext::read_fstring_len, Guid, UnrealReadExt};
+
+use crate::containers::{IndexedMap, NameMap, SharedResource};
+use crate::custom_version::{CustomVersion, CustomVersionTrait};
+use crate::engine_version::EngineVersion;
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
+use crate::parse_options::ParseOptions;
+use crate::reader::{
+    archive_trait::{ArchiveTrait, ArchiveType},
+    ArchiveReader,
+};
+use crate::types::{
+    property_type_name::PropertyTagType, FName, PackageIndex, PackageIndexTrait,
+    SerializedNameHeader,
+};
+use crate::unversioned::{Ancestry, Usmap};
+use crate::Error;
+
+/// A single read recorded by a [`RecordingReader`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadRecord {
+    /// Offset the read started at
+    pub offset: u64,
+    /// Amount of bytes read
+    pub size: usize,
+    /// Label that was set when the read happened
+    pub label: Option<String>,
+}
+
+/// A reader that forwards to another reader while recording every read
+///
+/// Reads are recorded as they reach the underlying `Read` implementation,
+/// so a value that is read with multiple calls, like an `FString`, produces multiple records.
+pub struct RecordingReader<'parent_reader, Index: PackageIndexTrait, R: ArchiveReader<Index>> {
+    /// Parent reader
+    parent_reader: &'parent_reader mut R,
+    /// Label attached to new records
+    label: Option<String>,
+    /// Recorded reads
+    records: Vec<ReadRecord>,
+    /// Marker
+    _marker: std::marker::PhantomData<Index>,
+}
+
+impl<'parent_reader, Index: PackageIndexTrait, R: ArchiveReader<Index>>
+    RecordingReader<'parent_reader, Index, R>
+{
+    /// Create a new `RecordingReader` instance
+    pub fn new(parent_reader: &'parent_reader mut R) -> Self {
+        RecordingReader {
+            parent_reader,
+            label: None,
+            records: Vec::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Set the label attached to following reads, `None` clears it
+    pub fn set_label(&mut self, label: Option<&str>) {
+        self.label = label.map(str::to_string);
+    }
+
+    /// Run a closure with a label attached to every read it does
+    pub fn with_label<T>(&mut self, label: &str, func: impl FnOnce(&mut Self) -> T) -> T {
+        let previous = self.label.replace(label.to_string());
+        let result = func(self);
+        self.label = previous;
+        result
+    }
+
+    /// Get the recorded reads
+    pub fn records(&self) -> &[ReadRecord] {
+        &self.records
+    }
+
+    /// Take the recorded reads, clearing them
+    pub fn take_records(&mut self) -> Vec<ReadRecord> {
+        std::mem::take(&mut self.records)
+    }
+
+    /// Dump the recorded reads as one `offset size label` line per read
+    pub fn dump(&self) -> String {
+        let mut dump = String::new();
+        for record in &self.records {
+            let _ = writeln!(
+                dump,
+                "{:#010x} {:>6} {}",
+                record.offset,
+                record.size,
+                record.label.as_deref().unwrap_or_default()
+            );
+        }
+        dump
+    }
+}
+
+impl<'parent_reader, Index: PackageIndexTrait, R: ArchiveReader<Index>> ArchiveTrait<Index>
+    for RecordingReader<'parent_reader, Index, R>
+{
+    #[inline(always)]
+    fn get_archive_type(&self) -> ArchiveType {
+        self.parent_reader.get_archive_type()
+    }
+
+    fn get_custom_version<T>(&self) -> CustomVersion
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        self.parent_reader.get_custom_version::<T>()
+    }
+
+    fn has_unversioned_properties(&self) -> bool {
+        self.parent_reader.has_unversioned_properties()
+    }
+
+    fn use_event_driven_loader(&self) -> bool {
+        self.parent_reader.use_event_driven_loader()
+    }
+
+    fn position(&mut self) -> u64 {
+        self.parent_reader.position()
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.parent_reader.get_name_map()
+    }
+
+    fn get_array_struct_type_override(&self) -> &IndexedMap<String, String> {
+        self.parent_reader.get_array_struct_type_override()
+    }
+
+    fn get_map_key_override(&self) -> &IndexedMap<String, String> {
+        self.parent_reader.get_map_key_override()
+    }
+
+    fn get_map_value_override(&self) -> &IndexedMap<String, String> {
+        self.parent_reader.get_map_value_override()
+    }
+
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.parent_reader.get_property_tag_type(name, ancestry)
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.parent_reader.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.parent_reader.get_object_version()
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.parent_reader.get_object_version_ue5()
+    }
+
+    fn get_mappings(&self) -> Option<&Usmap> {
+        self.parent_reader.get_mappings()
+    }
+
+    fn get_parse_options(&self) -> ParseOptions {
+        self.parent_reader.get_parse_options()
+    }
+
+    fn get_parent_class_export_name(&self) -> Option<FName> {
+        self.parent_reader.get_parent_class_export_name()
+    }
+
+    fn get_object_name(&self, index: Index) -> Option<FName> {
+        self.parent_reader.get_object_name(index)
+    }
+
+    fn get_object_name_packageindex(&self, index: PackageIndex) -> Option<FName> {
+        self.parent_reader.get_object_name_packageindex(index)
+    }
+}
+
+// strings, guids and bools are read through this reader instead of being passed through,
+// so that they get recorded too
+impl<'parent_reader, Index: PackageIndexTrait, R: ArchiveReader<Index>> ArchiveReader<Index>
+    for RecordingReader<'parent_reader, Index, R>
+{
+    fn read_fstring(&mut self) -> Result<Option<String>, Error> {
+        Ok(UnrealReadExt::read_fstring(self)?)
+    }
+
+    fn read_fstring_name_header(
+        &mut self,
+        serialized_name_header: SerializedNameHeader,
+    ) -> Result<Option<String>, Error> {
+        if serialized_name_header.len == 0 {
+            return Ok(None);
+        }
+
+        Ok(read_fstring_len(
+            self,
+            serialized_name_header.len,
+            serialized_name_header.is_wide,
+        )?)
+    }
+
+    fn read_guid(&mut self) -> io::Result<Guid> {
+        UnrealReadExt::read_guid(self)
+    }
+
+    fn read_bool(&mut self) -> io::Result<bool> {
+        UnrealReadExt::read_bool(self)
+    }
+
+    fn add_property_tag_type(
+        &mut self,
+        name: FName,
+        ancestry: Ancestry,
+        tag_type: PropertyTagType,
+    ) {
+        self.parent_reader
+            .add_property_tag_type(name, ancestry, tag_type)
+    }
+}
+
+impl<'parent_reader, Index: PackageIndexTrait, R: ArchiveReader<Index>> Read
+    for RecordingReader<'parent_reader, Index, R>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let offset = self.parent_reader.position();
+        let size = self.parent_reader.read(buf)?;
+        self.records.push(ReadRecord {
+            offset,
+            size,
+            label: self.label.clone(),
+        });
+        Ok(size)
+    }
+}
+
+impl<'parent_reader, Index: PackageIndexTrait, R: ArchiveReader<Index>> Seek
+    for RecordingReader<'parent_reader, Index, R>
+{
+    #[inline(always)]
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.parent_reader.seek(pos)
+    }
+}
@@ -7,6 +7,8 @@ use std::hash::Hash;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::containers::{IndexedMap, NameMap, SharedResource};
+use crate::reader::ArchiveTrait;
+use crate::types::PackageIndexTrait;
 
 /// FName name type
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
@@ -126,6 +128,44 @@ impl FName {
         FName::new_dummy(value.to_string(), 0)
     }
 
+    /// Create a new `FName` from a display string like `Name_3`, adding it to the archive's name map
+    ///
+    /// The same way as the engine does it, a numeric `_N` suffix is split off into the instance number,
+    /// which is serialized as `N + 1`. Passing `split_number: false` stores the whole string as content.
+    pub fn from_display_string<Index: PackageIndexTrait>(
+        archive: &mut impl ArchiveTrait<Index>,
+        value: &str,
+        split_number: bool,
+    ) -> Self {
+        let (content, number) = match split_number {
+            true => Self::split_display_string(value),
+            false => (value, 0),
+        };
+        archive.add_fname_with_number(content, number)
+    }
+
+    /// Split a display string like `Name_3` into its content and serialized instance number
+    ///
+    /// Suffixes with leading zeros or that don't fit an instance number are kept as content.
+    pub fn split_display_string(value: &str) -> (&str, i32) {
+        let Some((content, suffix)) = value.rsplit_once('_') else {
+            return (value, 0);
+        };
+
+        if content.is_empty()
+            || suffix.is_empty()
+            || !suffix.bytes().all(|e| e.is_ascii_digit())
+            || (suffix.len() > 1 && suffix.starts_with('0'))
+        {
+            return (value, 0);
+        }
+
+        match suffix.parse::<i32>().ok().and_then(|e| e.checked_add(1)) {
+            Some(number) => (content, number),
+            None => (value, 0),
+        }
+    }
+
     /// Get access to this `FName`'s content
     pub fn get_content<T>(&self, func: impl FnOnce(&str) -> T) -> T {
         match self {
@@ -169,6 +209,7 @@ impl FName {
 }
 
 impl PartialEq for FName {
+    /// `FName`s are equal when their content and number are equal, regardless of variant
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (
@@ -176,15 +217,17 @@ impl PartialEq for FName {
                     index: a_index,
                     number: a_number,
                     ty: _,
-                    name_map: _,
+                    name_map: a_name_map,
                 },
                 FName::Backed {
                     index: b_index,
                     number: b_number,
                     ty: _,
-                    name_map: _,
+                    name_map: b_name_map,
                 },
-            ) => a_index == b_index && a_number == b_number,
+            ) if a_index == b_index && std::ptr::eq(&**a_name_map, &**b_name_map) => {
+                a_number == b_number
+            }
             (
                 FName::Dummy {
                     value: a_value,
@@ -195,30 +238,27 @@ impl PartialEq for FName {
                     number: b_number,
                 },
             ) => a_value == b_value && a_number == b_number,
-            _ => false,
+            _ => self.get_number() == other.get_number() && self.eq_content(other),
         }
     }
 }
 
 impl Eq for FName {}
 
+/// Hashes the content and number, consistent with [`PartialEq`] for `FName`.
+///
+/// This compares by content, so backed names with different name map indices
+/// but the same content and number are equal and hash the same.
+///
+/// Hashing a backed name borrows its name map, so it panics (or, with the `threading`
+/// feature, deadlocks) while the name map is held by `get_mut()`.
+///
+/// The hash of a backed name changes when its name map entry is edited,
+/// so names already stored in a `HashSet` or `HashMap` can't be found after renaming them.
 impl Hash for FName {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        match self {
-            FName::Backed {
-                index,
-                number,
-                ty: _,
-                name_map: _,
-            } => {
-                index.hash(state);
-                number.hash(state);
-            }
-            FName::Dummy { value, number } => {
-                value.hash(state);
-                number.hash(state);
-            }
-        }
+        self.get_content(|content| content.hash(state));
+        self.get_number().hash(state);
     }
 }
 
@@ -5,6 +5,8 @@ use byteorder::{ReadBytesExt, WriteBytesExt};
 pub use fname::FName;
 
 pub mod movie;
+pub mod property_type_name;
+pub use property_type_name::PropertyTypeName;
 pub mod vector;
 
 use std::hash::Hash;
@@ -0,0 +1,114 @@
+//! Property type names
+
+use std::fmt::{self, Display};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::containers::IndexedMap;
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::types::{FName, PackageIndexTrait};
+use crate::unversioned::Ancestry;
+use crate::Error;
+use crate::FNameContainer;
+use crate::Guid;
+
+// silly `FNameContainer` fix
+mod unreal_asset_base {
+    pub use crate::types;
+}
+
+/// Complete type name of a property, stored in property tags since UE5.4
+///
+/// Parameters describe the rest of the type, e.g. `ArrayProperty(StructProperty(Vector(/Script/CoreUObject)))`
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[container_nobounds]
+pub struct PropertyTypeName {
+    /// Type name
+    pub name: FName,
+    /// Type parameters
+    pub parameters: Vec<PropertyTypeName>,
+}
+
+impl PropertyTypeName {
+    /// Create a new `PropertyTypeName` instance
+    pub fn new(name: FName, parameters: Vec<PropertyTypeName>) -> Self {
+        PropertyTypeName { name, parameters }
+    }
+
+    /// Read a `PropertyTypeName` from an archive
+    pub fn read<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        reader: &mut Reader,
+    ) -> Result<Self, Error> {
+        let name = reader.read_fname()?;
+        let parameter_count = reader.read_i32::<LE>()?;
+
+        let mut parameters = Vec::with_capacity(parameter_count.max(0) as usize);
+        for _ in 0..parameter_count {
+            parameters.push(PropertyTypeName::read(reader)?);
+        }
+
+        Ok(PropertyTypeName { name, parameters })
+    }
+
+    /// Write a `PropertyTypeName` to an archive
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        writer: &mut Writer,
+    ) -> Result<(), Error> {
+        writer.write_fname(&self.name)?;
+        writer.write_i32::<LE>(self.parameters.len() as i32)?;
+
+        for parameter in &self.parameters {
+            parameter.write(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get a type parameter
+    pub fn get_parameter(&self, index: usize) -> Option<&PropertyTypeName> {
+        self.parameters.get(index)
+    }
+
+    /// Get the name of a type parameter
+    pub fn get_parameter_name(&self, index: usize) -> Option<FName> {
+        self.get_parameter(index).map(|e| e.name.clone())
+    }
+
+    /// Get a type parameter parsed as a guid, struct types store their guid this way
+    pub fn get_parameter_guid(&self, index: usize) -> Option<Guid> {
+        self.get_parameter(index)
+            .and_then(|e| e.name.get_content(|name| name.parse().ok()))
+    }
+}
+
+impl Display for PropertyTypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.get_content(|name| write!(f, "{name}"))?;
+
+        if !self.parameters.is_empty() {
+            write!(f, "(")?;
+            for (i, parameter) in self.parameters.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{parameter}")?;
+            }
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Property type information read from a UE5.4+ property tag
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyTagType {
+    /// Complete type name
+    pub type_name: PropertyTypeName,
+    /// Does the property value use binary or native serialization
+    pub binary_or_native: bool,
+}
+
+/// Property tag types by property ancestry and name
+pub type PropertyTagTypes = IndexedMap<(Ancestry, FName), PropertyTagType>;
@@ -1,6 +1,31 @@
 //! Vector/Quat/etc. types
 //!
 
+use ordered_float::OrderedFloat;
+
+/// Implement `to_f64`/`to_f32` conversions between single and double precision for a vector type
+macro_rules! impl_precision_conversions {
+    ($name:ident, $($field:ident),+) => {
+        impl $name<OrderedFloat<f32>> {
+            #[doc = concat!("Convert to a double precision `", stringify!($name), "`")]
+            pub fn to_f64(&self) -> $name<OrderedFloat<f64>> {
+                $name {
+                    $($field: OrderedFloat(self.$field.0 as f64)),+
+                }
+            }
+        }
+
+        impl $name<OrderedFloat<f64>> {
+            #[doc = concat!("Convert to a single precision `", stringify!($name), "`, rounding every component")]
+            pub fn to_f32(&self) -> $name<OrderedFloat<f32>> {
+                $name {
+                    $($field: OrderedFloat(self.$field.0 as f32)),+
+                }
+            }
+        }
+    };
+}
+
 /// Vector
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Vector<T> {
@@ -19,6 +44,8 @@ impl<T> Vector<T> {
     }
 }
 
+impl_precision_conversions!(Vector, x, y, z);
+
 /// Vector2
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Vector2<T> {
@@ -35,6 +62,8 @@ impl<T> Vector2<T> {
     }
 }
 
+impl_precision_conversions!(Vector2, x, y);
+
 /// Vector4
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Vector4<T> {
@@ -55,6 +84,8 @@ impl<T> Vector4<T> {
     }
 }
 
+impl_precision_conversions!(Vector4, x, y, z, w);
+
 /// RGBA Color
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Color<T> {
@@ -114,6 +145,28 @@ impl<T> Transform<T> {
     }
 }
 
+impl Transform<OrderedFloat<f32>> {
+    /// Convert to a double precision `Transform`, as used with large world coordinates
+    pub fn to_f64(&self) -> Transform<OrderedFloat<f64>> {
+        Transform::new(
+            self.rotation.to_f64(),
+            self.translation.to_f64(),
+            self.scale.to_f64(),
+        )
+    }
+}
+
+impl Transform<OrderedFloat<f64>> {
+    /// Convert to a single precision `Transform`, as used before large world coordinates
+    pub fn to_f32(&self) -> Transform<OrderedFloat<f32>> {
+        Transform::new(
+            self.rotation.to_f32(),
+            self.translation.to_f32(),
+            self.scale.to_f32(),
+        )
+    }
+}
+
 /// Plane
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Plane<T> {
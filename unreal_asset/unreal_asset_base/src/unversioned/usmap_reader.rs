@@ -11,15 +11,16 @@ use crate::{
     engine_version::EngineVersion,
     error::{Error, UsmapError},
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
     passthrough_archive_reader,
     reader::{
         archive_reader::ArchiveReader,
         archive_trait::{ArchiveTrait, ArchiveType},
     },
-    types::{FName, PackageIndex},
+    types::{property_type_name::PropertyTagType, FName, PackageIndex},
 };
 
-use super::Usmap;
+use super::{Ancestry, Usmap};
 
 /// Usmap file reader
 pub struct UsmapReader<'parent_reader, 'asset, R: ArchiveReader<PackageIndex>> {
@@ -105,6 +106,10 @@ impl<'parent_reader, 'asset, R: ArchiveReader<PackageIndex>> ArchiveTrait<Packag
         self.parent_reader.get_map_value_override()
     }
 
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.parent_reader.get_property_tag_type(name, ancestry)
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.parent_reader.get_engine_version()
     }
@@ -121,6 +126,10 @@ impl<'parent_reader, 'asset, R: ArchiveReader<PackageIndex>> ArchiveTrait<Packag
         None
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.parent_reader.get_parse_options()
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.parent_reader.get_parent_class_export_name()
     }
@@ -138,6 +147,16 @@ impl<'parent_reader, 'asset, R: ArchiveReader<PackageIndex>> ArchiveReader<Packa
     for UsmapReader<'parent_reader, 'asset, R>
 {
     passthrough_archive_reader!(parent_reader);
+
+    fn add_property_tag_type(
+        &mut self,
+        name: FName,
+        ancestry: Ancestry,
+        tag_type: PropertyTagType,
+    ) {
+        self.parent_reader
+            .add_property_tag_type(name, ancestry, tag_type)
+    }
 }
 
 impl<'parent_reader, 'asset, R: ArchiveReader<PackageIndex>> Read
@@ -8,15 +8,16 @@ use crate::{
     engine_version::EngineVersion,
     error::Error,
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
     passthrough_archive_writer,
     reader::{
         archive_trait::{ArchiveTrait, ArchiveType},
         archive_writer::ArchiveWriter,
     },
-    types::{FName, PackageIndex},
+    types::{property_type_name::PropertyTagType, FName, PackageIndex},
 };
 
-use super::Usmap;
+use super::{Ancestry, Usmap};
 
 /// Usmap file writer
 pub struct UsmapWriter<'parent_writer, 'asset, W: ArchiveWriter<PackageIndex>> {
@@ -83,6 +84,10 @@ impl<'parent_writer, 'asset, W: ArchiveWriter<PackageIndex>> ArchiveTrait<Packag
         self.parent_writer.get_map_value_override()
     }
 
+    fn get_property_tag_type(&self, name: &FName, ancestry: &Ancestry) -> Option<&PropertyTagType> {
+        self.parent_writer.get_property_tag_type(name, ancestry)
+    }
+
     fn get_engine_version(&self) -> EngineVersion {
         self.parent_writer.get_engine_version()
     }
@@ -99,6 +104,10 @@ impl<'parent_writer, 'asset, W: ArchiveWriter<PackageIndex>> ArchiveTrait<Packag
         None
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.parent_writer.get_parse_options()
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.parent_writer.get_parent_class_export_name()
     }
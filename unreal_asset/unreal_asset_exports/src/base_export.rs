@@ -72,6 +72,10 @@ pub struct BaseExport<Index: PackageIndexTrait> {
     pub public_export_hash: u64,
     /// First dependency serialized offset
     pub first_export_dependency_offset: i32,
+    /// Offset relative to the serial offset where script serialization of this export starts
+    pub script_serialization_start_offset: i64,
+    /// Offset relative to the serial offset where script serialization of this export ends
+    pub script_serialization_end_offset: i64,
     /// Dependencies that should be serialized before this export is serialized
     #[container_ignore]
     pub serialization_before_serialization_dependencies: Vec<PackageIndex>,
@@ -98,6 +102,46 @@ impl<Index: PackageIndexTrait> BaseExport<Index> {
         }
         .unwrap_or_default()
     }
+
+    /// Gets the class of this export, null for exports that are classes themselves
+    pub fn class_index(&self) -> Index {
+        self.class_index
+    }
+
+    /// Gets the struct this export inherits from, null if it is not a struct or has no parent
+    pub fn super_index(&self) -> Index {
+        self.super_index
+    }
+
+    /// Gets the archetype this export was created from
+    ///
+    /// Always null for assets older than `VER_UE4_TemplateIndex_IN_COOKED_EXPORTS`,
+    /// which don't serialize the template index.
+    pub fn template_index(&self) -> Index {
+        self.template_index
+    }
+
+    /// Gets the object this export is nested in, null for top level exports
+    pub fn outer_index(&self) -> Index {
+        self.outer_index
+    }
+
+    /// Checks if this export is the main asset object of its package
+    ///
+    /// Only serialized since `VER_UE4_COOKED_ASSETS_IN_EDITOR_SUPPORT`, and not set by every cooker.
+    pub fn is_asset(&self) -> bool {
+        self.is_asset
+    }
+
+    /// Gets this export's object flags
+    pub fn object_flags(&self) -> EObjectFlags {
+        self.object_flags
+    }
+
+    /// Sets or clears a single object flag, leaving all other flags untouched
+    pub fn set_object_flag(&mut self, flag: EObjectFlags, value: bool) {
+        self.object_flags.set(flag, value);
+    }
 }
 
 impl<Index: PackageIndexTrait> ExportNormalTrait<Index> for BaseExport<Index> {
@@ -78,8 +78,9 @@ impl<Index: PackageIndexTrait> ClassExport<Index> {
     ) -> Result<Self, Error> {
         let struct_export = StructExport::from_base(base, asset)?;
 
+        // not preallocated, a corrupt count fails on the first entry that can't be read
         let num_func_index_entries = asset.read_i32::<LE>()? as usize;
-        let mut func_map = IndexedMap::with_capacity(num_func_index_entries);
+        let mut func_map = IndexedMap::new();
         for _i in 0..num_func_index_entries {
             let name = asset.read_fname()?;
             let function_export = PackageIndex::new(asset.read_i32::<LE>()?);
@@ -118,7 +119,7 @@ impl<Index: PackageIndexTrait> ClassExport<Index> {
             asset.seek(SeekFrom::Start(interfaces_start.unwrap()))?;
         }
         let num_interfaces = asset.read_i32::<LE>()? as usize;
-        let mut interfaces = Vec::with_capacity(num_interfaces);
+        let mut interfaces = Vec::new();
         for _i in 0..num_interfaces {
             interfaces.push(SerializedInterfaceReference::new(
                 PackageIndex::new(asset.read_i32::<LE>()?),
@@ -134,7 +135,7 @@ impl<Index: PackageIndexTrait> ClassExport<Index> {
         }
 
         let deprecated_force_script_order = asset.read_i32::<LE>()? == 1;
-        asset.read_i64::<LE>()?; // none
+        asset.read_fname()?; // none
 
         let cooked = match asset.get_object_version() >= ObjectVersion::VER_UE4_ADD_COOKED_TO_UCLASS
         {
@@ -236,7 +237,8 @@ impl<Index: PackageIndexTrait> ExportTrait<Index> for ClassExport<Index> {
             true => 1,
             false => 0,
         })?;
-        asset.write_fname(&asset.get_name_map().get_mut().add_fname("None"))?;
+        let none_fname = asset.add_fname("None");
+        asset.write_fname(&none_fname)?;
 
         if asset.get_object_version() >= ObjectVersion::VER_UE4_ADD_COOKED_TO_UCLASS {
             asset.write_i32::<LE>(
@@ -1,8 +1,11 @@
 //! Data table export
 
+use std::collections::HashMap;
+
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_asset_base::{
+    error::PropertyError,
     reader::{ArchiveReader, ArchiveWriter},
     types::{FName, PackageIndexTrait},
     unversioned::Ancestry,
@@ -15,6 +18,9 @@ use crate::ExportTrait;
 
 use crate::{BaseExport, NormalExport};
 
+/// Row name and the row's columns keyed by field name, see [`DataTable::rows_as_maps`]
+pub type DataTableRowMap<'a> = (FName, HashMap<String, &'a Property>);
+
 /// Data table
 #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct DataTable {
@@ -27,6 +33,38 @@ impl DataTable {
     pub fn new(data: Vec<StructProperty>) -> Self {
         DataTable { data }
     }
+
+    /// Get every row name with the row's columns keyed by field name
+    ///
+    /// Each map only holds the columns present in that row, rows don't need to share a column set.
+    /// Elements of static arrays after the first are keyed as `Name[index]`.
+    /// A row with the same column twice is an error.
+    pub fn rows_as_maps(&self) -> Result<Vec<DataTableRowMap<'_>>, Error> {
+        self.data
+            .iter()
+            .map(|row| {
+                let mut columns = HashMap::with_capacity(row.value.len());
+                for column in &row.value {
+                    let name = column.get_name_ref().get_owned_content();
+                    let key = match column.get_duplication_index() {
+                        0 => name,
+                        index => format!("{name}[{index}]"),
+                    };
+
+                    if columns.contains_key(&key) {
+                        return Err(PropertyError::invalid_struct(format!(
+                            "Row {} has the column {} more than once",
+                            row.name.get_owned_content(),
+                            key
+                        ))
+                        .into());
+                    }
+                    columns.insert(key, column);
+                }
+                Ok((row.name.clone(), columns))
+            })
+            .collect()
+    }
 }
 
 /// Data table export
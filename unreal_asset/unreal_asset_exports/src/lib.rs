@@ -6,6 +6,7 @@
 use std::fmt::Debug;
 
 use unreal_asset_base::{reader::ArchiveWriter, types::PackageIndexTrait, Error, FNameContainer};
+use unreal_asset_properties::Property;
 
 pub mod properties;
 
@@ -192,4 +193,26 @@ manual_dispatch! {
     WorldExport
 }
 
+impl<Index: PackageIndexTrait> Export<Index> {
+    /// Get a cheap lower bound estimate of the serialized size of this export
+    ///
+    /// Only property values, raw export data and extras are counted,
+    /// see [`Property::estimated_serialized_size`].
+    pub fn estimated_serialized_size(&self) -> usize {
+        let data = match self {
+            Export::RawExport(raw_export) => raw_export.data.len(),
+            _ => 0,
+        };
+
+        data + self.get_normal_export().map_or(0, |normal_export| {
+            normal_export
+                .properties
+                .iter()
+                .map(Property::estimated_serialized_size)
+                .sum::<usize>()
+                + normal_export.extras.len()
+        })
+    }
+}
+
 // todo: impl hash for export
@@ -1,7 +1,10 @@
 //! Normal export
 
+use byteorder::{ReadBytesExt, WriteBytesExt};
+
 use unreal_asset_base::{
-    reader::{ArchiveReader, ArchiveWriter},
+    object_version::ObjectVersionUE5,
+    reader::{ArchiveReader, ArchiveTrait, ArchiveWriter},
     types::PackageIndexTrait,
     unversioned::{header::UnversionedHeader, Ancestry},
     Error, FNameContainer,
@@ -52,6 +55,16 @@ impl<Index: PackageIndexTrait> NormalExport<Index> {
     ) -> Result<Self, Error> {
         let mut properties = Vec::new();
 
+        if has_serialization_control(asset) {
+            // overridable serialization is editor-only, cooked assets don't set any extensions
+            let serialization_control = asset.read_u8()?;
+            if serialization_control != 0 {
+                return Err(Error::unimplemented(format!(
+                    "Class serialization control extensions {serialization_control:#x} are not supported"
+                )));
+            }
+        }
+
         let mut unversioned_header = UnversionedHeader::new(asset)?;
         let ancestry = Ancestry::new(base.get_class_type_for_ancestry(asset));
         while let Some(e) =
@@ -80,6 +93,10 @@ impl<Index: PackageIndexTrait> ExportTrait<Index> for NormalExport<Index> {
             None => (None, None),
         };
 
+        if has_serialization_control(asset) {
+            asset.write_u8(0)?;
+        }
+
         if let Some(unversioned_header) = unversioned_header {
             unversioned_header.write(asset)?;
         }
@@ -97,3 +114,10 @@ impl<Index: PackageIndexTrait> ExportTrait<Index> for NormalExport<Index> {
         Ok(())
     }
 }
+
+/// Check if tagged properties of an object are preceded by class serialization control extensions
+fn has_serialization_control<Index: PackageIndexTrait>(asset: &impl ArchiveTrait<Index>) -> bool {
+    !asset.has_unversioned_properties()
+        && asset.get_object_version_ue5()
+            >= ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+}
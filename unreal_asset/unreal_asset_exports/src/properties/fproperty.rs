@@ -192,29 +192,28 @@ impl FProperty {
         asset: &mut Reader,
     ) -> Result<Self, Error> {
         let serialized_type = asset.read_fname()?;
-        serialized_type.get_content(|ty| {
-            Ok::<FProperty, Error>(match ty {
-                "EnumProperty" => FEnumProperty::new(asset)?.into(),
-                "ArrayProperty" => FArrayProperty::new(asset)?.into(),
-                "SetProperty" => FSetProperty::new(asset)?.into(),
-                "ObjectProperty" => FObjectProperty::new(asset)?.into(),
-                "SoftObjectProperty" => FSoftObjectProperty::new(asset)?.into(),
-                "ClassProperty" => FClassProperty::new(asset)?.into(),
-                "SoftClassProperty" => FSoftClassProperty::new(asset)?.into(),
-                "DelegateProperty" => FDelegateProperty::new(asset)?.into(),
-                "MulticastDelegateProperty" => FMulticastDelegateProperty::new(asset)?.into(),
-                "MulticastInlineDelegateProperty" => {
-                    FMulticastInlineDelegateProperty::new(asset)?.into()
-                }
-                "InterfaceProperty" => FInterfaceProperty::new(asset)?.into(),
-                "MapProperty" => FMapProperty::new(asset)?.into(),
-                "BoolProperty" => FBoolProperty::new(asset)?.into(),
-                "ByteProperty" => FByteProperty::new(asset)?.into(),
-                "StructProperty" => FStructProperty::new(asset)?.into(),
-                "NumericProperty" => FNumericProperty::new(asset)?.into(),
-                _ => FGenericProperty::with_serialized_type(asset, Some(serialized_type.clone()))?
-                    .into(),
-            })
+        Ok(match serialized_type.get_owned_content().as_str() {
+            "EnumProperty" => FEnumProperty::new(asset)?.into(),
+            "ArrayProperty" => FArrayProperty::new(asset)?.into(),
+            "SetProperty" => FSetProperty::new(asset)?.into(),
+            "ObjectProperty" => FObjectProperty::new(asset)?.into(),
+            "SoftObjectProperty" => FSoftObjectProperty::new(asset)?.into(),
+            "ClassProperty" => FClassProperty::new(asset)?.into(),
+            "SoftClassProperty" => FSoftClassProperty::new(asset)?.into(),
+            "DelegateProperty" => FDelegateProperty::new(asset)?.into(),
+            "MulticastDelegateProperty" => FMulticastDelegateProperty::new(asset)?.into(),
+            "MulticastInlineDelegateProperty" => {
+                FMulticastInlineDelegateProperty::new(asset)?.into()
+            }
+            "InterfaceProperty" => FInterfaceProperty::new(asset)?.into(),
+            "MapProperty" => FMapProperty::new(asset)?.into(),
+            "BoolProperty" => FBoolProperty::new(asset)?.into(),
+            "ByteProperty" => FByteProperty::new(asset)?.into(),
+            "StructProperty" => FStructProperty::new(asset)?.into(),
+            "NumericProperty" => FNumericProperty::new(asset)?.into(),
+            _ => {
+                FGenericProperty::with_serialized_type(asset, Some(serialized_type.clone()))?.into()
+            }
         })
     }
 
@@ -224,12 +223,8 @@ impl FProperty {
         asset: &mut Writer,
     ) -> Result<(), Error> {
         let property_serialized_name = property.to_serialized_name();
-        asset.write_fname(
-            &asset
-                .get_name_map()
-                .get_mut()
-                .add_fname(&property_serialized_name),
-        )?;
+        let serialized_name = asset.add_fname(&property_serialized_name);
+        asset.write_fname(&serialized_name)?;
         property.write(asset)
     }
 }
@@ -1334,22 +1334,22 @@ impl ExTransformConst {
                 }
                 false => {
                     let rotation = Vector4::new(
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
                     );
                     let translation = Vector::new(
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
                     );
                     let scale = Vector::new(
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
-                        OrderedFloat(asset.read_f32::<LE>()? as f64),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
+                        OrderedFloat(asset.read_f32::<LE>()?),
                     );
-                    Transform::new(rotation, translation, scale)
+                    Transform::new(rotation, translation, scale).to_f64()
                 }
             };
 
@@ -1380,16 +1380,17 @@ impl KismetExpressionTrait for ExTransformConst {
                 Ok(size_of::<f64>() * 10)
             }
             false => {
-                asset.write_f32::<LE>(self.value.rotation.x.0 as f32)?;
-                asset.write_f32::<LE>(self.value.rotation.y.0 as f32)?;
-                asset.write_f32::<LE>(self.value.rotation.z.0 as f32)?;
-                asset.write_f32::<LE>(self.value.rotation.w.0 as f32)?;
-                asset.write_f32::<LE>(self.value.translation.x.0 as f32)?;
-                asset.write_f32::<LE>(self.value.translation.y.0 as f32)?;
-                asset.write_f32::<LE>(self.value.translation.z.0 as f32)?;
-                asset.write_f32::<LE>(self.value.scale.x.0 as f32)?;
-                asset.write_f32::<LE>(self.value.scale.y.0 as f32)?;
-                asset.write_f32::<LE>(self.value.scale.z.0 as f32)?;
+                let value = self.value.to_f32();
+                asset.write_f32::<LE>(value.rotation.x.0)?;
+                asset.write_f32::<LE>(value.rotation.y.0)?;
+                asset.write_f32::<LE>(value.rotation.z.0)?;
+                asset.write_f32::<LE>(value.rotation.w.0)?;
+                asset.write_f32::<LE>(value.translation.x.0)?;
+                asset.write_f32::<LE>(value.translation.y.0)?;
+                asset.write_f32::<LE>(value.translation.z.0)?;
+                asset.write_f32::<LE>(value.scale.x.0)?;
+                asset.write_f32::<LE>(value.scale.y.0)?;
+                asset.write_f32::<LE>(value.scale.z.0)?;
 
                 Ok(size_of::<f32>() * 10)
             }
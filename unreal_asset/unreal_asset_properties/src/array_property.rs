@@ -68,6 +68,11 @@ impl ArrayProperty {
         }
     }
 
+    /// Ancestry of the array elements, this array's ancestry with the array as the immediate parent
+    pub fn element_ancestry(&self) -> Ancestry {
+        self.ancestry.with_parent(self.name.clone())
+    }
+
     /// Read an `ArrayProperty` from an asset without reading the property header
     #[allow(clippy::too_many_arguments)]
     pub fn new_no_header<Reader: ArchiveReader<impl PackageIndexTrait>>(
@@ -92,19 +97,24 @@ impl ArrayProperty {
 
         let mut array_struct_type = None;
         if array_type.is_none() {
-            if let Some(struct_data) = asset
+            // sets are read as arrays, so their mappings describe the element type as well
+            if let Some(inner_type) = asset
                 .get_mappings()
                 .and_then(|e| e.get_property(&name, &ancestry))
-                .and_then(|e| cast!(UsmapPropertyData, UsmapArrayPropertyData, &e.property_data))
+                .and_then(|e| match &e.property_data {
+                    UsmapPropertyData::UsmapArrayPropertyData(data) => Some(&data.inner_type),
+                    UsmapPropertyData::UsmapSetPropertyData(data) => Some(&data.inner_type),
+                    _ => None,
+                })
             {
                 array_type = Some(FName::new_dummy(
-                    struct_data.inner_type.get_property_type().to_string(),
+                    inner_type.get_property_type().to_string(),
                     0,
                 ));
                 if let Some(inner_struct_data) = cast!(
                     UsmapPropertyData,
                     UsmapStructPropertyData,
-                    struct_data.inner_type.as_ref()
+                    inner_type.as_ref()
                 ) {
                     array_struct_type =
                         Some(FName::new_dummy(inner_struct_data.struct_type.clone(), 0));
@@ -112,6 +122,16 @@ impl ArrayProperty {
             }
         }
 
+        // UE5.4+ property tags store the complete array type, including the struct type of the elements
+        if let Some(inner_type) = asset
+            .get_property_tag_type(&name, &ancestry)
+            .and_then(|e| e.type_name.get_parameter(0))
+        {
+            array_type.get_or_insert_with(|| inner_type.name.clone());
+            array_struct_type = inner_type.get_parameter_name(0);
+            struct_guid = inner_type.get_parameter_guid(1);
+        }
+
         if asset.has_unversioned_properties() && array_type.is_none() {
             return name.get_content(|name| Err(PropertyError::no_type(name, &ancestry).into()));
         }
@@ -123,7 +143,12 @@ impl ArrayProperty {
             && !asset.has_unversioned_properties()
         {
             let mut full_type = FName::from_slice("Generic");
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
+            if asset.get_object_version_ue5() >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+                // the complete type name in the property tag replaces the inner tag
+                if let Some(array_struct_type) = array_struct_type.clone() {
+                    full_type = array_struct_type;
+                }
+            } else if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
                 name = asset.read_fname()?;
                 if name == "None" {
                     return Ok(ArrayProperty::default());
@@ -161,7 +186,7 @@ impl ArrayProperty {
             if num_entries == 0 {
                 dummy_struct = Some(StructProperty::dummy(
                     name.clone(),
-                    ancestry.with_parent(name.clone()),
+                    new_ancestry.clone(),
                     full_type.clone(),
                     struct_guid,
                 ));
@@ -191,11 +216,16 @@ impl ArrayProperty {
                         Some(ref e) => Some(e.clone()),
                         None => Some(FName::from_slice("Generic")),
                     };
+                    // unversioned lengths don't describe the data, like maps read the struct fully
+                    let struct_length = match asset.has_unversioned_properties() {
+                        true => 1,
+                        false => size_est_1,
+                    };
                     StructProperty::custom_header(
                         asset,
                         FName::new_dummy(i.to_string(), i32::MIN),
                         new_ancestry.clone(),
-                        size_est_1,
+                        struct_length,
                         0,
                         struct_type,
                         None,
@@ -207,7 +237,7 @@ impl ArrayProperty {
                         asset,
                         array_type,
                         FName::new_dummy(i.to_string(), i32::MIN),
-                        ancestry.clone(),
+                        new_ancestry.clone(),
                         false,
                         size_est_1,
                         size_est_2,
@@ -256,6 +286,7 @@ impl ArrayProperty {
 
         if array_type.as_ref().is_some_and(|ty| ty == "StructProperty")
             && serialize_structs_differently
+            && !asset.has_unversioned_properties()
         {
             let property: &StructProperty = match !self.value.is_empty() {
                 true => match &self.value[0] {
@@ -274,9 +305,13 @@ impl ArrayProperty {
             }?;
 
             let mut length_loc = None;
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
+            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO
+                && asset.get_object_version_ue5()
+                    < ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME
+            {
                 asset.write_fname(&property.name)?;
-                asset.write_fname(&asset.get_name_map().get_mut().add_fname("StructProperty"))?;
+                let struct_property_fname = asset.add_fname("StructProperty");
+                asset.write_fname(&struct_property_fname)?;
                 length_loc = Some(asset.position());
                 asset.write_i64::<LE>(0)?;
                 asset.write_fname(
@@ -286,14 +321,11 @@ impl ArrayProperty {
                 )?;
                 if asset.get_object_version() >= ObjectVersion::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG
                 {
-                    asset.write_guid(&property.property_guid.unwrap_or_default())?;
-                }
-                if asset.get_object_version()
-                    >= ObjectVersion::VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG
-                {
-                    asset.write_u8(0)?;
+                    asset.write_guid(&property.struct_guid.unwrap_or_default())?;
                 }
+                asset.write_property_guid(None)?;
             }
+            let data_begin = asset.position();
 
             for property in &self.value {
                 let struct_property: &StructProperty = match property {
@@ -306,17 +338,10 @@ impl ArrayProperty {
                 struct_property.write(asset, false)?;
             }
 
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
-                let length_loc = length_loc.expect("Corrupted memory");
-                let full_len = asset.position() - length_loc;
+            if let Some(length_loc) = length_loc {
                 let new_loc = asset.position();
                 asset.seek(SeekFrom::Start(length_loc))?;
-                let length = full_len
-                    - 32
-                    - match include_header {
-                        true => 1,
-                        false => 0,
-                    };
+                let length = new_loc - data_begin;
 
                 asset.write_i32::<LE>(length as i32)?;
                 asset.seek(SeekFrom::Start(new_loc))?;
@@ -158,6 +158,10 @@ impl PropertyDataTrait for ClothLodDataProperty {
         self.struct_property.get_name()
     }
 
+    fn get_name_ref(&self) -> Cow<'_, FName> {
+        self.struct_property.get_name_ref()
+    }
+
     fn get_name_mut(&mut self) -> &mut FName {
         self.struct_property.get_name_mut()
     }
@@ -170,6 +174,10 @@ impl PropertyDataTrait for ClothLodDataProperty {
         self.struct_property.get_property_guid()
     }
 
+    fn set_property_guid(&mut self, property_guid: Option<Guid>) {
+        self.struct_property.set_property_guid(property_guid)
+    }
+
     fn get_ancestry(&self) -> &Ancestry {
         self.struct_property.get_ancestry()
     }
@@ -1,5 +1,7 @@
 //! Delegate properties
 
+use unreal_asset_base::reader::ArchiveTrait;
+
 use crate::property_prelude::*;
 
 /// Delegate
@@ -17,6 +19,35 @@ impl Delegate {
     pub fn new(object: PackageIndex, delegate: FName) -> Self {
         Delegate { object, delegate }
     }
+
+    /// Create a new `Delegate` bound to a function, adding the function name to the archive's name map
+    pub fn bound<Index: PackageIndexTrait>(
+        archive: &mut impl ArchiveTrait<Index>,
+        object: PackageIndex,
+        function: &str,
+    ) -> Self {
+        Delegate::new(object, FName::from_display_string(archive, function, true))
+    }
+
+    /// Get the object the delegate is bound to
+    pub fn object(&self) -> PackageIndex {
+        self.object
+    }
+
+    /// Get the name of the function the delegate is bound to
+    pub fn function_name(&self) -> &FName {
+        &self.delegate
+    }
+
+    /// Bind the delegate to a function, adding the function name to the archive's name map
+    pub fn bind<Index: PackageIndexTrait>(
+        &mut self,
+        archive: &mut impl ArchiveTrait<Index>,
+        object: PackageIndex,
+        function: &str,
+    ) {
+        *self = Delegate::bound(archive, object, function);
+    }
 }
 
 /// Delegate property
@@ -58,6 +89,26 @@ impl DelegateProperty {
             ),
         })
     }
+
+    /// Get the object the delegate is bound to
+    pub fn object(&self) -> PackageIndex {
+        self.value.object()
+    }
+
+    /// Get the name of the function the delegate is bound to
+    pub fn function_name(&self) -> &FName {
+        self.value.function_name()
+    }
+
+    /// Bind the delegate to a function, adding the function name to the archive's name map
+    pub fn bind<Index: PackageIndexTrait>(
+        &mut self,
+        archive: &mut impl ArchiveTrait<Index>,
+        object: PackageIndex,
+        function: &str,
+    ) {
+        self.value.bind(archive, object, function);
+    }
 }
 
 impl PropertyTrait for DelegateProperty {
@@ -122,6 +173,26 @@ macro_rules! impl_multicast {
                     value,
                 })
             }
+
+            /// Get the delegates bound to this property
+            pub fn delegates(&self) -> &[Delegate] {
+                &self.value
+            }
+
+            /// Add a binding to a function, adding the function name to the archive's name map
+            ///
+            /// Nothing is added if the function is already bound on the same object.
+            pub fn bind<Index: PackageIndexTrait>(
+                &mut self,
+                archive: &mut impl ArchiveTrait<Index>,
+                object: PackageIndex,
+                function: &str,
+            ) {
+                let delegate = Delegate::bound(archive, object, function);
+                if !self.value.contains(&delegate) {
+                    self.value.push(delegate);
+                }
+            }
         }
 
         impl PropertyTrait for $property_name {
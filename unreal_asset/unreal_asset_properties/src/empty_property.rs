@@ -1,6 +1,18 @@
 //! Empty unversioned property
 
+use unreal_asset_base::unversioned::{properties::EPropertyType, Usmap};
+
+use crate::array_property::ArrayProperty;
+use crate::enum_property::EnumProperty;
+use crate::int_property::{
+    BoolProperty, ByteProperty, BytePropertyValue, DoubleProperty, FloatProperty, Int16Property,
+    Int64Property, Int8Property, IntProperty, UInt16Property, UInt32Property, UInt64Property,
+};
+use crate::map_property::MapProperty;
+use crate::object_property::ObjectProperty;
 use crate::property_prelude::*;
+use crate::set_property::SetProperty;
+use crate::str_property::NameProperty;
 
 /// Empty unversioned property
 #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
@@ -22,6 +34,206 @@ impl EmptyProperty {
             ancestry,
         }
     }
+
+    /// Create a zero value for a mapped field, the same way unversioned parsing does
+    ///
+    /// `type_name` is the usmap property type, e.g. `IntProperty`, and `ancestry`
+    /// has the schema containing the field as its parent.
+    pub fn for_field(type_name: &str, name: &str, ancestry: Ancestry) -> Self {
+        EmptyProperty {
+            type_name: FName::new_dummy(type_name.to_string(), 0),
+            name: FName::new_dummy(name.to_string(), 0),
+            ancestry,
+        }
+    }
+
+    /// Create the default value this zero stands for
+    ///
+    /// Unversioned properties holding their default value are only marked in the zero mask,
+    /// tagged properties have to store the value, so its type is looked up in `mappings`.
+    /// Struct, text and other property types without an obvious default return an error.
+    pub fn to_default_property(&self, mappings: &Usmap) -> Result<Property, Error> {
+        let no_zero_value = || -> Error {
+            self.name.get_content(|name| {
+                self.type_name
+                    .get_content(|ty| PropertyError::no_zero_value(name, ty).into())
+            })
+        };
+
+        let Some((mapping, _)) =
+            mappings.get_property_with_duplication_index(&self.name, &self.ancestry, 0)
+        else {
+            return self
+                .name
+                .get_content(|name| Err(PropertyError::no_mapping(name, &self.ancestry).into()));
+        };
+
+        let name = self.name.clone();
+        let ancestry = self.ancestry.clone();
+        let type_name =
+            |data: &UsmapPropertyData| FName::new_dummy(data.get_property_type().to_string(), 0);
+
+        let property: Property = match &mapping.property_data {
+            UsmapPropertyData::UsmapEnumPropertyData(data) => {
+                let value = mappings
+                    .enum_map
+                    .get_by_key(&data.name)
+                    .and_then(|values| values.first())
+                    .ok_or_else(no_zero_value)?;
+                EnumProperty {
+                    name,
+                    ancestry,
+                    enum_type: Some(FName::new_dummy(data.name.clone(), 0)),
+                    inner_type: Some(type_name(&data.inner_property)),
+                    value: Some(FName::new_dummy(value.clone(), 0)),
+                    ..Default::default()
+                }
+                .into()
+            }
+            UsmapPropertyData::UsmapArrayPropertyData(data) => {
+                // empty struct arrays still write the struct type in their inner tag
+                let dummy_property = match data.inner_type.as_ref() {
+                    UsmapPropertyData::UsmapStructPropertyData(inner) => Some(StructProperty {
+                        name: name.clone(),
+                        ancestry: ancestry.with_parent(name.clone()),
+                        struct_type: Some(FName::new_dummy(inner.struct_type.clone(), 0)),
+                        ..Default::default()
+                    }),
+                    _ => None,
+                };
+                ArrayProperty {
+                    name,
+                    ancestry,
+                    array_type: Some(type_name(&data.inner_type)),
+                    dummy_property,
+                    ..Default::default()
+                }
+                .into()
+            }
+            UsmapPropertyData::UsmapSetPropertyData(data) => {
+                let array_type = Some(type_name(&data.inner_type));
+                let items = ArrayProperty {
+                    name: name.clone(),
+                    ancestry: ancestry.clone(),
+                    array_type: array_type.clone(),
+                    ..Default::default()
+                };
+                SetProperty {
+                    name,
+                    ancestry,
+                    array_type,
+                    value: items.clone(),
+                    removed_items: items,
+                    ..Default::default()
+                }
+                .into()
+            }
+            UsmapPropertyData::UsmapMapPropertyData(data) => MapProperty {
+                name,
+                ancestry,
+                key_type: type_name(&data.inner_type),
+                value_type: type_name(&data.value_type),
+                ..Default::default()
+            }
+            .into(),
+            UsmapPropertyData::UsmapShallowPropertyData(data) => match data.property_type {
+                EPropertyType::BoolProperty => BoolProperty {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::ByteProperty => ByteProperty {
+                    name,
+                    ancestry,
+                    enum_type: Some(FName::new_dummy(String::from("None"), 0)),
+                    value: BytePropertyValue::Byte(0),
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::Int8Property => Int8Property {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::Int16Property => Int16Property {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::IntProperty => IntProperty {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::Int64Property => Int64Property {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::UInt16Property => UInt16Property {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::UInt32Property => UInt32Property {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::UInt64Property => UInt64Property {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::FloatProperty => FloatProperty {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::DoubleProperty => DoubleProperty {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::ObjectProperty => ObjectProperty {
+                    name,
+                    ancestry,
+                    ..Default::default()
+                }
+                .into(),
+                EPropertyType::NameProperty => NameProperty {
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index: 0,
+                    value: FName::new_dummy(String::from("None"), 0),
+                }
+                .into(),
+                EPropertyType::StrProperty => StrProperty {
+                    name,
+                    ancestry,
+                    property_guid: None,
+                    duplication_index: 0,
+                    value: None,
+                }
+                .into(),
+                _ => return Err(no_zero_value()),
+            },
+            UsmapPropertyData::UsmapStructPropertyData(_) => return Err(no_zero_value()),
+        };
+
+        Ok(property)
+    }
 }
 
 impl PropertyDataTrait for EmptyProperty {
@@ -29,6 +241,10 @@ impl PropertyDataTrait for EmptyProperty {
         self.name.clone()
     }
 
+    fn get_name_ref(&self) -> Cow<'_, FName> {
+        Cow::Borrowed(&self.name)
+    }
+
     fn get_name_mut(&mut self) -> &mut FName {
         &mut self.name
     }
@@ -41,6 +257,8 @@ impl PropertyDataTrait for EmptyProperty {
         None
     }
 
+    fn set_property_guid(&mut self, _: Option<Guid>) {}
+
     fn get_ancestry(&self) -> &Ancestry {
         &self.ancestry
     }
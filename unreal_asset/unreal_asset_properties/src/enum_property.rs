@@ -1,5 +1,6 @@
 //! Enum property
 
+use crate::int_property::{ByteProperty, BytePropertyValue};
 use crate::property_prelude::*;
 
 /// Enum property
@@ -95,6 +96,31 @@ impl EnumProperty {
     }
 }
 
+impl EnumProperty {
+    /// Convert this property to an equivalent byte enum `ByteProperty`
+    ///
+    /// Returns `None` if the enum type or value is missing, or if the enum
+    /// is known to be backed by a type other than a byte.
+    pub fn to_byte_property(&self) -> Option<ByteProperty> {
+        if self
+            .inner_type
+            .as_ref()
+            .is_some_and(|e| e != "ByteProperty")
+        {
+            return None;
+        }
+
+        Some(ByteProperty {
+            name: self.name.clone(),
+            ancestry: self.ancestry.clone(),
+            property_guid: self.property_guid,
+            duplication_index: self.duplication_index,
+            enum_type: Some(self.enum_type.clone()?),
+            value: BytePropertyValue::FName(self.value.clone()?),
+        })
+    }
+}
+
 impl PropertyTrait for EnumProperty {
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
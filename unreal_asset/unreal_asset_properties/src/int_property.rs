@@ -1,5 +1,6 @@
 //! Int properties
 
+use crate::enum_property::EnumProperty;
 use crate::property_prelude::*;
 
 /// Implement a simple integer property
@@ -31,6 +32,30 @@ macro_rules! impl_int_property {
     };
 }
 
+/// Implement checked and saturating setters for an integer property
+macro_rules! impl_int_property_setters {
+    ($property_type:ident, $ty:ty) => {
+        impl $property_type {
+            /// Set the value, returning an error if it is out of range for this property's type
+            pub fn try_set(&mut self, value: i64) -> Result<(), Error> {
+                self.value = <$ty>::try_from(value).map_err(|_| {
+                    PropertyError::value_out_of_range(
+                        &self.name.get_owned_content(),
+                        value,
+                        stringify!($ty),
+                    )
+                })?;
+                Ok(())
+            }
+
+            /// Set the value, clamping it to the range of this property's type
+            pub fn set_saturating(&mut self, value: i64) {
+                self.value = (value as i128).clamp(<$ty>::MIN as i128, <$ty>::MAX as i128) as $ty;
+            }
+        }
+    };
+}
+
 /// Int8 property
 #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Int8Property {
@@ -359,6 +384,37 @@ impl ByteProperty {
             value,
         })
     }
+
+    /// Check if this property stores an enum value
+    ///
+    /// Plain bytes are tagged with the enum type `None`.
+    pub fn is_enum(&self) -> bool {
+        self.enum_type.as_ref().is_some_and(|e| e != "None")
+    }
+
+    /// Convert this property to an equivalent `EnumProperty`
+    ///
+    /// Returns `None` if this is not an enum or the value is stored as a raw byte,
+    /// which can't be resolved to an enum value name without mappings.
+    pub fn to_enum_property(&self) -> Option<EnumProperty> {
+        if !self.is_enum() {
+            return None;
+        }
+
+        let BytePropertyValue::FName(ref value) = self.value else {
+            return None;
+        };
+
+        Some(EnumProperty {
+            name: self.name.clone(),
+            ancestry: self.ancestry.clone(),
+            property_guid: self.property_guid,
+            duplication_index: self.duplication_index,
+            enum_type: self.enum_type.clone(),
+            inner_type: Some(FName::new_dummy(String::from("ByteProperty"), 0)),
+            value: Some(value.clone()),
+        })
+    }
 }
 
 impl PropertyTrait for ByteProperty {
@@ -463,3 +519,10 @@ impl_int_property!(Int64Property, read_i64, write_i64, i64);
 impl_int_property!(UInt16Property, read_u16, write_u16, u16);
 impl_int_property!(UInt32Property, read_u32, write_u32, u32);
 impl_int_property!(UInt64Property, read_u64, write_u64, u64);
+
+impl_int_property_setters!(Int8Property, i8);
+impl_int_property_setters!(Int16Property, i16);
+impl_int_property_setters!(IntProperty, i32);
+impl_int_property_setters!(UInt16Property, u16);
+impl_int_property_setters!(UInt32Property, u32);
+impl_int_property_setters!(UInt64Property, u64);
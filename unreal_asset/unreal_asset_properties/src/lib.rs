@@ -3,10 +3,12 @@
 
 //! Unreal asset properties
 
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::io::SeekFrom;
+use std::mem::size_of;
 
 use bitvec::{order::Lsb0, vec::BitVec};
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
@@ -19,8 +21,12 @@ pub use unreal_asset_base::unversioned::Ancestry;
 pub use unreal_asset_base::Guid;
 
 use unreal_asset_base::error::{Error, PropertyError};
-use unreal_asset_base::reader::{ArchiveReader, ArchiveWriter};
+use unreal_asset_base::flags::EPropertyTagFlags;
+use unreal_asset_base::object_version::ObjectVersionUE5;
+use unreal_asset_base::parse_options::UnknownPropertyPolicy;
+use unreal_asset_base::reader::{ArchiveReader, ArchiveTrait, ArchiveWriter};
 use unreal_asset_base::types::fname::ToSerializedName;
+use unreal_asset_base::types::property_type_name::{PropertyTagType, PropertyTypeName};
 use unreal_asset_base::unversioned::header::UnversionedHeaderFragment;
 use unreal_asset_base::unversioned::{
     header::UnversionedHeader, properties::UsmapPropertyDataTrait,
@@ -132,6 +138,7 @@ use vector_property::{
 use view_target_blend_property::ViewTargetBlendParamsProperty;
 
 mod property_prelude {
+    pub use std::borrow::Cow;
     pub use std::io::SeekFrom;
     pub use std::mem::size_of;
 
@@ -222,6 +229,10 @@ macro_rules! impl_property_data_trait {
                 self.name.clone()
             }
 
+            fn get_name_ref(&self) -> std::borrow::Cow<'_, $crate::FName> {
+                std::borrow::Cow::Borrowed(&self.name)
+            }
+
             fn get_name_mut(&mut self) -> &mut FName {
                 &mut self.name
             }
@@ -234,6 +245,10 @@ macro_rules! impl_property_data_trait {
                 self.property_guid.clone()
             }
 
+            fn set_property_guid(&mut self, property_guid: Option<$crate::Guid>) {
+                self.property_guid = property_guid;
+            }
+
             fn get_ancestry(&self) -> &$crate::Ancestry {
                 &self.ancestry
             }
@@ -311,12 +326,20 @@ const CUSTOM_SERIALIZATION: [&str; 57] = [
 pub trait PropertyDataTrait {
     /// Get property's name
     fn get_name(&self) -> FName;
+    /// Get property's name, borrowed if the property stores it
+    ///
+    /// Avoids cloning the name for read-only access, the default implementation clones it.
+    fn get_name_ref(&self) -> Cow<'_, FName> {
+        Cow::Owned(self.get_name())
+    }
     /// Get a mutable reference to property's name
     fn get_name_mut(&mut self) -> &mut FName;
     /// Get property's duplication index
     fn get_duplication_index(&self) -> i32;
     /// Get property's guid
     fn get_property_guid(&self) -> Option<Guid>;
+    /// Set property's guid
+    fn set_property_guid(&mut self, property_guid: Option<Guid>);
     /// Get property's ancestry
     fn get_ancestry(&self) -> &Ancestry;
     /// Get a mutable reference to property's ancestry
@@ -525,101 +548,249 @@ impl Property {
         unversioned_header: Option<&mut UnversionedHeader>,
         include_header: bool,
     ) -> Result<Option<Self>, Error> {
-        let name: FName;
-        let property_type: FName;
-        let length: i32;
-        let duplication_index: i32;
+        if !asset.has_unversioned_properties() {
+            return Property::new_tagged(asset, ancestry, include_header);
+        }
+
         let mut is_zero = false;
 
-        if asset.has_unversioned_properties() {
-            let header = unversioned_header.ok_or_else(PropertyError::no_unversioned_header)?;
-            let mappings = asset
-                .get_mappings()
-                .ok_or_else(PropertyError::no_mappings)?;
-            let parent_name = ancestry.get_parent().ok_or_else(PropertyError::no_parent)?;
-
-            loop {
-                let current_fragment = header.fragments[header.current_fragment_index];
-                if header.unversioned_property_index > current_fragment.get_last_num() as usize {
-                    break;
-                }
+        let header = unversioned_header.ok_or_else(PropertyError::no_unversioned_header)?;
+        let mappings = asset
+            .get_mappings()
+            .ok_or_else(PropertyError::no_mappings)?;
+        let parent_name = ancestry.get_parent().ok_or_else(PropertyError::no_parent)?;
+
+        // move on to the fragment containing the next serialized property
+        loop {
+            let current_fragment = header.fragments[header.current_fragment_index];
+            if current_fragment.value_num > 0
+                && header.unversioned_property_index <= current_fragment.get_last_num() as usize
+            {
+                break;
+            }
 
-                header.current_fragment_index += 1;
-                header.unversioned_property_index =
-                    header.fragments[header.current_fragment_index].first_num as usize;
+            if current_fragment.is_last {
+                return Ok(None);
             }
 
-            let mut practicing_unversioned_property_index = header.unversioned_property_index;
-            let mut schema = parent_name.get_content(|name| {
-                mappings.schemas.get_by_key(name).ok_or_else(|| {
+            header.current_fragment_index += 1;
+            header.unversioned_property_index =
+                header.fragments[header.current_fragment_index].first_num as usize;
+        }
+
+        let mut practicing_unversioned_property_index = header.unversioned_property_index;
+        let mut schema = parent_name.get_content(|name| {
+            mappings.schemas.get_by_key(name).ok_or_else(|| {
+                PropertyError::no_schema(name.to_string(), practicing_unversioned_property_index)
+            })
+        })?;
+
+        while practicing_unversioned_property_index >= schema.prop_count as usize {
+            practicing_unversioned_property_index -= schema.prop_count as usize;
+
+            let new_schema = mappings
+                .schemas
+                .get_by_key(&schema.super_type)
+                .ok_or_else(|| {
                     PropertyError::no_schema(
-                        name.to_string(),
+                        parent_name.get_owned_content(),
                         practicing_unversioned_property_index,
                     )
-                })
-            })?;
-
-            while practicing_unversioned_property_index >= schema.prop_count as usize {
-                practicing_unversioned_property_index -= schema.prop_count as usize;
-
-                let new_schema =
-                    mappings
-                        .schemas
-                        .get_by_key(&schema.super_type)
-                        .ok_or_else(|| {
-                            PropertyError::no_schema(
-                                parent_name.get_owned_content(),
-                                practicing_unversioned_property_index,
-                            )
-                        })?;
-
-                schema = new_schema;
-            }
-
-            let property = schema
-                .properties
-                .get_by_index(practicing_unversioned_property_index)
-                .unwrap();
-            header.unversioned_property_index += 1;
-
-            name = FName::new_dummy(property.name.clone(), 0);
-            property_type =
-                FName::new_dummy(property.property_data.get_property_type().to_string(), 0);
-            length = 1;
-            duplication_index = property.array_index as i32;
+                })?;
 
-            let current_fragment = header.fragments[header.current_fragment_index];
-            if current_fragment.has_zeros {
-                is_zero = match header.zero_mask_index < header.zero_mask.len() {
-                    true => header.zero_mask[header.zero_mask_index],
-                    false => false,
-                };
+            schema = new_schema;
+        }
 
-                header.zero_mask_index += 1;
-            }
-        } else {
-            name = asset.read_fname()?;
-            if name == "None" {
-                return Ok(None);
-            }
+        let property = schema
+            .properties
+            .get_by_index(practicing_unversioned_property_index)
+            .unwrap();
+        header.unversioned_property_index += 1;
+
+        let name = FName::new_dummy(property.name.clone(), 0);
+        let property_type =
+            FName::new_dummy(property.property_data.get_property_type().to_string(), 0);
+        let duplication_index = property.array_index as i32;
+
+        let current_fragment = header.fragments[header.current_fragment_index];
+        if current_fragment.has_zeros {
+            is_zero = match header.zero_mask_index < header.zero_mask.len() {
+                true => header.zero_mask[header.zero_mask_index],
+                false => false,
+            };
 
-            property_type = asset.read_fname()?;
-            length = asset.read_i32::<LE>()?;
-            duplication_index = asset.read_i32::<LE>()?;
+            header.zero_mask_index += 1;
         }
 
-        Property::from_type(
+        // unversioned properties are serialized without a tag
+        let property = Property::from_type(
             asset,
             &property_type,
             name,
             ancestry,
-            include_header,
-            length as i64,
+            false,
+            1,
             0,
             duplication_index,
             is_zero,
-        )
-        .map(Some)
+        )?;
+
+        Ok(Some(property))
+    }
+
+    /// Reads a tagged property, skipping unknown properties with [`UnknownPropertyPolicy::SkipByLength`]
+    fn new_tagged<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        mut ancestry: Ancestry,
+        include_header: bool,
+    ) -> Result<Option<Self>, Error> {
+        let skip_unknown = asset.get_parse_options().unknown_property_policy
+            == UnknownPropertyPolicy::SkipByLength;
+
+        loop {
+            let name = asset.read_fname()?;
+            if name == "None" {
+                return Ok(None);
+            }
+
+            // the ancestry is only needed again if this property is skipped
+            let property_ancestry = match skip_unknown {
+                true => ancestry.clone(),
+                false => std::mem::take(&mut ancestry),
+            };
+
+            if asset.get_object_version_ue5() >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+                let property =
+                    Property::read_complete_type_tag(asset, name, property_ancestry, skip_unknown)?;
+
+                if let Some(property) = property {
+                    return Ok(Some(property));
+                }
+                continue;
+            }
+
+            let property_type = asset.read_fname()?;
+            let length = asset.read_i32::<LE>()?;
+            let duplication_index = asset.read_i32::<LE>()?;
+
+            let property = Property::read_type(
+                asset,
+                &property_type,
+                name,
+                property_ancestry,
+                include_header,
+                length as i64,
+                0,
+                duplication_index,
+                false,
+                skip_unknown,
+            )?;
+
+            if let Some(property) = property {
+                return Ok(Some(property));
+            }
+        }
+    }
+
+    /// Reads the rest of a UE5.4+ property tag and the property it describes
+    ///
+    /// These tags store the complete type name instead of type specific header data.
+    fn read_complete_type_tag<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        skip_unknown: bool,
+    ) -> Result<Option<Self>, Error> {
+        let type_name = PropertyTypeName::read(asset)?;
+        let length = asset.read_i32::<LE>()?;
+        let flags = EPropertyTagFlags::from_bits_retain(asset.read_u8()?);
+
+        let duplication_index = match flags.contains(EPropertyTagFlags::HAS_ARRAY_INDEX) {
+            true => asset.read_i32::<LE>()?,
+            false => 0,
+        };
+        let property_guid = match flags.contains(EPropertyTagFlags::HAS_PROPERTY_GUID) {
+            true => Some(asset.read_guid()?),
+            false => None,
+        };
+        if flags.contains(EPropertyTagFlags::HAS_PROPERTY_EXTENSIONS) {
+            // overridable serialization is editor-only, cooked assets don't set any extensions
+            let extensions = asset.read_u8()?;
+            if extensions != 0 {
+                return Err(Error::unimplemented(format!(
+                    "Property tag extensions {extensions:#x} are not supported"
+                )));
+            }
+        }
+
+        // containers read the types of their elements from the stored tag type
+        asset.add_property_tag_type(
+            name.clone(),
+            ancestry.clone(),
+            PropertyTagType {
+                type_name: type_name.clone(),
+                binary_or_native: flags.contains(EPropertyTagFlags::HAS_BINARY_OR_NATIVE_SERIALIZE),
+            },
+        );
+
+        let mut property = match type_name.name.get_owned_content().as_str() {
+            "StructProperty" => Some(
+                StructProperty::custom_header(
+                    asset,
+                    name.clone(),
+                    ancestry.clone(),
+                    length as i64,
+                    duplication_index,
+                    type_name.get_parameter_name(0),
+                    type_name.get_parameter_guid(1),
+                    property_guid,
+                )?
+                .into(),
+            ),
+            // bool values are stored in the tag
+            "BoolProperty" => Some(
+                BoolProperty {
+                    name: name.clone(),
+                    ancestry: ancestry.clone(),
+                    property_guid,
+                    duplication_index,
+                    value: flags.contains(EPropertyTagFlags::BOOL_TRUE),
+                }
+                .into(),
+            ),
+            _ => None,
+        };
+
+        if property.is_none() {
+            property = Property::read_type(
+                asset,
+                &type_name.name,
+                name,
+                ancestry,
+                false,
+                length as i64,
+                0,
+                duplication_index,
+                false,
+                skip_unknown,
+            )?;
+        }
+
+        if let Some(ref mut property) = property {
+            property.set_property_guid(property_guid);
+            match property {
+                Property::EnumProperty(enum_property) => {
+                    enum_property.enum_type = type_name.get_parameter_name(0);
+                    enum_property.inner_type = type_name.get_parameter_name(1);
+                }
+                Property::ByteProperty(byte_property) => {
+                    byte_property.enum_type = type_name.get_parameter_name(0);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(property)
     }
 
     /// Tries to read a property from an ArchiveReader while specified a type and length
@@ -635,718 +806,811 @@ impl Property {
         duplication_index: i32,
         is_zero: bool,
     ) -> Result<Self, Error> {
+        let property = Property::read_type(
+            asset,
+            type_name,
+            name,
+            ancestry,
+            include_header,
+            length,
+            fallback_length,
+            duplication_index,
+            is_zero,
+            false,
+        )?;
+        // unknown properties are only skipped when asked to
+        property.ok_or_else(|| {
+            Error::invalid_file(format!(
+                "Property of type {} was skipped",
+                type_name.get_owned_content()
+            ))
+        })
+    }
+
+    /// Reads a property of a given type, returns `None` if the type is unknown and `skip_unknown` is set
+    ///
+    /// Skipping seeks past the property data using its tag length, so it is only valid for tagged properties.
+    #[allow(clippy::too_many_arguments)]
+    fn read_type<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        type_name: &FName,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        length: i64,
+        fallback_length: i64,
+        duplication_index: i32,
+        is_zero: bool,
+        skip_unknown: bool,
+    ) -> Result<Option<Self>, Error> {
         if is_zero {
-            return Ok(EmptyProperty::new(type_name.clone(), name, ancestry).into());
+            return Ok(Some(
+                EmptyProperty::new(type_name.clone(), name, ancestry).into(),
+            ));
         }
 
-        type_name.get_content(|ty| {
-            Ok::<Property, Error>(match ty {
-                "BoolProperty" => BoolProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "UInt16Property" => UInt16Property::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "UInt32Property" => UInt32Property::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "UInt64Property" => UInt64Property::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "FloatProperty" => FloatProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "Int16Property" => Int16Property::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "Int64Property" => Int64Property::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "Int8Property" => Int8Property::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "IntProperty" => IntProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "ByteProperty" => ByteProperty::new(
+        // owned, reading inline names adds them to the name map
+        let ty = type_name.get_owned_content();
+        Ok(Some(match ty.as_str() {
+            "BoolProperty" => BoolProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "UInt16Property" => UInt16Property::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "UInt32Property" => UInt32Property::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "UInt64Property" => UInt64Property::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "FloatProperty" => FloatProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "Int16Property" => Int16Property::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "Int64Property" => Int64Property::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "Int8Property" => Int8Property::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "IntProperty" => IntProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "ByteProperty" => ByteProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                fallback_length,
+                duplication_index,
+            )?
+            .into(),
+            "DoubleProperty" => DoubleProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+
+            "NameProperty" => {
+                NameProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "StrProperty" => {
+                StrProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "TextProperty" => {
+                TextProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+
+            "ObjectProperty" => {
+                ObjectProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "AssetObjectProperty" => {
+                AssetObjectProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "SoftObjectProperty" => {
+                SoftObjectProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+
+            "IntPoint" => {
+                IntPointProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Vector" => {
+                VectorProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Vector4" => {
+                Vector4Property::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Vector2D" => {
+                Vector2DProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Box" => {
+                BoxProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "Box2D" => {
+                Box2DProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "Quat" => {
+                QuatProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "Rotator" => {
+                RotatorProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Plane" => {
+                PlaneProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "LinearColor" => {
+                LinearColorProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Color" => {
+                ColorProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+            "Timespan" => {
+                TimeSpanProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "DateTime" => {
+                DateTimeProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "Guid" => {
+                GuidProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+
+            "SetProperty" => SetProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "ArrayProperty" => ArrayProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+                true,
+            )?
+            .into(),
+            "MapProperty" => {
+                MapProperty::new(asset, name, ancestry, include_header, duplication_index)?.into()
+            }
+
+            "PerPlatformBool" => PerPlatformBoolProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "PerPlatformInt" => PerPlatformIntProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "PerPlatformFloat" => PerPlatformFloatProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+
+            "MaterialAttributesInput" => MaterialAttributesInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "ExpressionInput" => ExpressionInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "ColorMaterialInput" => ColorMaterialInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "ScalarMaterialInput" => ScalarMaterialInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "ShadingModelMaterialInput" => ShadingModelMaterialInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "VectorMaterialInput" => VectorMaterialInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "Vector2MaterialInput" => Vector2MaterialInputProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+
+            "WeightedRandomSampler" => WeightedRandomSamplerProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "SkeletalMeshAreaWeightedTriangleSampler" => {
+                SkeletalMeshAreaWeightedTriangleSampler::new(
                     asset,
                     name,
                     ancestry,
                     include_header,
                     length,
-                    fallback_length,
                     duplication_index,
                 )?
-                .into(),
-                "DoubleProperty" => DoubleProperty::new(
+                .into()
+            }
+            "SkeletalMeshSamplingLODBuiltData" => SkeletalMeshSamplingLODBuiltDataProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "SoftAssetPath" => SoftAssetPathProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "SoftObjectPath" => SoftObjectPathProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "SoftClassPath" => SoftClassPathProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "StringAssetReference" => StringAssetReferenceProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+
+            "DelegateProperty" => DelegateProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "MulticastDelegateProperty" => MulticastDelegateProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "MulticastSparseDelegateProperty" => MulticastSparseDelegateProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "MulticastInlineDelegateProperty" => MulticastInlineDelegateProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "RichCurveKey" => RichCurveKeyProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "ViewTargetBlendParams" => ViewTargetBlendParamsProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "GameplayTagContainer" => GameplayTagContainerProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "SmartName" => SmartNameProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+
+            "StructProperty" => StructProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "EnumProperty" => EnumProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "ClothLODData" => cloth_lod_property::ClothLodDataProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+
+            "FontCharacter" => FontCharacterProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "UniqueNetIdRepl" => UniqueNetIdProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "NiagaraVariable" => NiagaraVariableProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "NiagaraVariableWithOffset" => NiagaraVariableWithOffsetProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "FontData" => FontDataProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+            )?
+            .into(),
+            "FloatRange" => {
+                FloatRangeProperty::new(asset, name, ancestry, include_header, duplication_index)?
+                    .into()
+            }
+            "RawStructProperty" => RawStructProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+                length,
+            )?
+            .into(),
+
+            "MovieSceneEvalTemplatePtr" => MovieSceneEvalTemplatePtrProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneTrackImplementationPtr" => MovieSceneTrackImplementationPtrProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneEvaluationFieldEntityTree" => {
+                MovieSceneEvaluationFieldEntityTreeProperty::new(
                     asset,
                     name,
                     ancestry,
                     include_header,
-                    length,
                     duplication_index,
                 )?
-                .into(),
-
-                "NameProperty" => {
-                    NameProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "StrProperty" => {
-                    StrProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
+                .into()
+            }
+            "MovieSceneSubSequenceTree" => MovieSceneSubSequenceTreeProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneSequenceInstanceDataPtr" => MovieSceneSequenceInstanceDataPtrProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "SectionEvaluationDataTree" => SectionEvaluationDataTreeProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneTrackFieldData" => MovieSceneTrackFieldDataProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneEventParameters" => MovieSceneEventParametersProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneFloatChannel" => MovieSceneFloatChannelProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneFloatValue" => MovieSceneFloatValueProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneFrameRange" => MovieSceneFrameRangeProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneSegment" => MovieSceneSegmentProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneSegmentIdentifier" => MovieSceneSegmentIdentifierProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneTrackIdentifier" => MovieSceneTrackIdentifierProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneSequenceId" => MovieSceneSequenceIdProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+            "MovieSceneEvaluationKey" => MovieSceneEvaluationKeyProperty::new(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                duplication_index,
+            )?
+            .into(),
+
+            _ if skip_unknown => {
+                let _property_guid = optional_guid!(asset, include_header);
+                asset.seek(SeekFrom::Current(length))?;
+                return Ok(None);
+            }
+            _ => match asset.get_parse_options().unknown_property_policy {
+                UnknownPropertyPolicy::ErrorOut => {
+                    return Err(PropertyError::unknown_type(&name.get_owned_content(), &ty).into());
                 }
-                "TextProperty" => {
-                    TextProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
+                UnknownPropertyPolicy::Raw | UnknownPropertyPolicy::SkipByLength => {
+                    UnknownProperty::new(
+                        asset,
+                        name,
+                        ancestry,
+                        include_header,
+                        length,
+                        duplication_index,
+                        type_name.clone(),
+                    )?
+                    .into()
                 }
+            },
+        }))
+    }
 
-                "ObjectProperty" => {
-                    ObjectProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "AssetObjectProperty" => AssetObjectProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "SoftObjectProperty" => SoftObjectProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
+    /// Writes a property to an ArchiveWriter
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        property: &Property,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        // unversioned properties are serialized without a tag,
+        // their name and type come from the mappings
+        if asset.has_unversioned_properties() {
+            return property.write(asset, false);
+        }
 
-                "IntPoint" => {
-                    IntPointProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Vector" => {
-                    VectorProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Vector4" => {
-                    Vector4Property::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Vector2D" => {
-                    Vector2DProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Box" => {
-                    BoxProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Box2D" => {
-                    Box2DProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Quat" => {
-                    QuatProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Rotator" => {
-                    RotatorProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Plane" => {
-                    PlaneProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "LinearColor" => LinearColorProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "Color" => {
-                    ColorProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Timespan" => {
-                    TimeSpanProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "DateTime" => {
-                    DateTimeProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-                "Guid" => {
-                    GuidProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-
-                "SetProperty" => SetProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "ArrayProperty" => ArrayProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                    true,
-                )?
-                .into(),
-                "MapProperty" => {
-                    MapProperty::new(asset, name, ancestry, include_header, duplication_index)?
-                        .into()
-                }
-
-                "PerPlatformBool" => PerPlatformBoolProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "PerPlatformInt" => PerPlatformIntProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "PerPlatformFloat" => PerPlatformFloatProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-
-                "MaterialAttributesInput" => MaterialAttributesInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "ExpressionInput" => ExpressionInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "ColorMaterialInput" => ColorMaterialInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "ScalarMaterialInput" => ScalarMaterialInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "ShadingModelMaterialInput" => ShadingModelMaterialInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "VectorMaterialInput" => VectorMaterialInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "Vector2MaterialInput" => Vector2MaterialInputProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-
-                "WeightedRandomSampler" => WeightedRandomSamplerProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "SkeletalMeshAreaWeightedTriangleSampler" => {
-                    SkeletalMeshAreaWeightedTriangleSampler::new(
-                        asset,
-                        name,
-                        ancestry,
-                        include_header,
-                        length,
-                        duplication_index,
-                    )?
-                    .into()
-                }
-                "SkeletalMeshSamplingLODBuiltData" => {
-                    SkeletalMeshSamplingLODBuiltDataProperty::new(
-                        asset,
-                        name,
-                        ancestry,
-                        include_header,
-                        length,
-                        duplication_index,
-                    )?
-                    .into()
-                }
-                "SoftAssetPath" => SoftAssetPathProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "SoftObjectPath" => SoftObjectPathProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "SoftClassPath" => SoftClassPathProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "StringAssetReference" => StringAssetReferenceProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-
-                "DelegateProperty" => DelegateProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "MulticastDelegateProperty" => MulticastDelegateProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "MulticastSparseDelegateProperty" => MulticastSparseDelegateProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "MulticastInlineDelegateProperty" => MulticastInlineDelegateProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "RichCurveKey" => RichCurveKeyProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "ViewTargetBlendParams" => ViewTargetBlendParamsProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "GameplayTagContainer" => GameplayTagContainerProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "SmartName" => SmartNameProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-
-                "StructProperty" => StructProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "EnumProperty" => EnumProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "ClothLODData" => cloth_lod_property::ClothLodDataProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-
-                "FontCharacter" => FontCharacterProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "UniqueNetIdRepl" => UniqueNetIdProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "NiagaraVariable" => NiagaraVariableProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "NiagaraVariableWithOffset" => NiagaraVariableWithOffsetProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "FontData" => FontDataProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                )?
-                .into(),
-                "FloatRange" => FloatRangeProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "RawStructProperty" => RawStructProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                    length,
-                )?
-                .into(),
-
-                "MovieSceneEvalTemplatePtr" => MovieSceneEvalTemplatePtrProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneTrackImplementationPtr" => {
-                    MovieSceneTrackImplementationPtrProperty::new(
-                        asset,
-                        name,
-                        ancestry,
-                        include_header,
-                        duplication_index,
-                    )?
-                    .into()
-                }
-                "MovieSceneEvaluationFieldEntityTree" => {
-                    MovieSceneEvaluationFieldEntityTreeProperty::new(
-                        asset,
-                        name,
-                        ancestry,
-                        include_header,
-                        duplication_index,
-                    )?
-                    .into()
-                }
-                "MovieSceneSubSequenceTree" => MovieSceneSubSequenceTreeProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneSequenceInstanceDataPtr" => {
-                    MovieSceneSequenceInstanceDataPtrProperty::new(
-                        asset,
-                        name,
-                        ancestry,
-                        include_header,
-                        duplication_index,
-                    )?
-                    .into()
-                }
-                "SectionEvaluationDataTree" => SectionEvaluationDataTreeProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneTrackFieldData" => MovieSceneTrackFieldDataProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneEventParameters" => MovieSceneEventParametersProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneFloatChannel" => MovieSceneFloatChannelProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneFloatValue" => MovieSceneFloatValueProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneFrameRange" => MovieSceneFrameRangeProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneSegment" => MovieSceneSegmentProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneSegmentIdentifier" => MovieSceneSegmentIdentifierProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneTrackIdentifier" => MovieSceneTrackIdentifierProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneSequenceId" => MovieSceneSequenceIdProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
-                "MovieSceneEvaluationKey" => MovieSceneEvaluationKeyProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    duplication_index,
-                )?
-                .into(),
+        asset.write_fname(&property.get_name())?;
 
-                _ => UnknownProperty::new(
-                    asset,
-                    name,
-                    ancestry,
-                    include_header,
-                    length,
-                    duplication_index,
-                    type_name.clone(),
-                )?
-                .into(),
-            })
-        })
+        if asset.get_object_version_ue5() >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+            return Property::write_complete_type_tag(property, asset);
+        }
+
+        let property_serialized_name = property.to_serialized_name();
+        let serialized_name = asset.add_fname(&property_serialized_name);
+        asset.write_fname(&serialized_name)?;
+
+        let begin = asset.position();
+        asset.write_i32::<LE>(0)?; // initial length
+        asset.write_i32::<LE>(property.get_duplication_index())?;
+        let len = property.write(asset, include_header)?;
+        let end = asset.position();
+
+        asset.seek(SeekFrom::Start(begin))?;
+        asset.write_i32::<LE>(len as i32)?;
+        asset.seek(SeekFrom::Start(end))?;
+        Ok(begin as usize)
     }
 
-    /// Writes a property to an ArchiveWriter
-    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    /// Writes the rest of a UE5.4+ property tag and the property
+    ///
+    /// The type name read with the property is written back, if there is none it is derived from the property.
+    fn write_complete_type_tag<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         property: &Property,
         asset: &mut Writer,
-        include_header: bool,
     ) -> Result<usize, Error> {
-        asset.write_fname(&property.get_name())?;
-
         let property_serialized_name = property.to_serialized_name();
-        asset.write_fname(
-            &asset
-                .get_name_map()
-                .get_mut()
-                .add_fname(&property_serialized_name),
-        )?;
+        let tag_type = asset
+            .get_property_tag_type(&property.get_name_ref(), property.get_ancestry())
+            .filter(|e| e.type_name.name == property_serialized_name.as_str())
+            .cloned();
+        let tag_type = match tag_type {
+            Some(tag_type) => tag_type,
+            None => PropertyTagType {
+                type_name: property.complete_type_name(asset),
+                binary_or_native: match property {
+                    Property::StructProperty(struct_property) => struct_property
+                        .struct_type
+                        .as_ref()
+                        .is_some_and(|e| e.get_content(Property::has_custom_serialization)),
+                    _ => false,
+                },
+            },
+        };
+        tag_type.type_name.write(asset)?;
 
         let begin = asset.position();
         asset.write_i32::<LE>(0)?; // initial length
-        asset.write_i32::<LE>(property.get_duplication_index())?;
-        let len = property.write(asset, include_header)?;
+
+        let duplication_index = property.get_duplication_index();
+        let property_guid = property.get_property_guid();
+
+        let mut flags = EPropertyTagFlags::NONE;
+        flags.set(EPropertyTagFlags::HAS_ARRAY_INDEX, duplication_index != 0);
+        flags.set(
+            EPropertyTagFlags::HAS_PROPERTY_GUID,
+            property_guid.is_some(),
+        );
+        flags.set(
+            EPropertyTagFlags::HAS_BINARY_OR_NATIVE_SERIALIZE,
+            tag_type.binary_or_native,
+        );
+        if let Property::BoolProperty(bool_property) = property {
+            flags.set(EPropertyTagFlags::BOOL_TRUE, bool_property.value);
+        }
+        asset.write_u8(flags.bits())?;
+
+        if duplication_index != 0 {
+            asset.write_i32::<LE>(duplication_index)?;
+        }
+        if let Some(ref property_guid) = property_guid {
+            asset.write_guid(property_guid)?;
+        }
+
+        // bool values are stored in the tag
+        let len = match property {
+            Property::BoolProperty(_) => 0,
+            _ => property.write(asset, false)?,
+        };
         let end = asset.position();
 
         asset.seek(SeekFrom::Start(begin))?;
@@ -1355,12 +1619,383 @@ impl Property {
         Ok(begin as usize)
     }
 
+    /// Get the complete type name of this property, as stored in UE5.4+ property tags
+    ///
+    /// Only the parts of the type name this property knows about are included,
+    /// e.g. struct types are missing the package they are in.
+    pub fn complete_type_name<Index: PackageIndexTrait>(
+        &self,
+        asset: &mut impl ArchiveTrait<Index>,
+    ) -> PropertyTypeName {
+        let name = asset.add_fname(&self.to_serialized_name());
+        let leaf = |name: &FName| PropertyTypeName::new(name.clone(), Vec::new());
+
+        let parameters = match self {
+            Property::StructProperty(struct_property) => {
+                let mut parameters = Vec::new();
+                if let Some(ref struct_type) = struct_property.struct_type {
+                    parameters.push(leaf(struct_type));
+                }
+                if let Some(struct_guid) = struct_property.struct_guid.filter(|e| !e.is_zero()) {
+                    let struct_guid = asset.add_fname(&struct_guid.to_string().replace('-', ""));
+                    parameters.push(leaf(&struct_guid));
+                }
+                parameters
+            }
+            Property::ArrayProperty(array_property) => {
+                match (
+                    array_property.value.first(),
+                    array_property.dummy_property.as_ref(),
+                ) {
+                    (Some(element), _) => vec![element.complete_type_name(asset)],
+                    (None, Some(dummy_property)) => {
+                        vec![Property::StructProperty(dummy_property.clone())
+                            .complete_type_name(asset)]
+                    }
+                    (None, None) => array_property.array_type.iter().map(leaf).collect(),
+                }
+            }
+            Property::SetProperty(set_property) => match set_property.value.value.first() {
+                Some(element) => vec![element.complete_type_name(asset)],
+                None => set_property.array_type.iter().map(leaf).collect(),
+            },
+            Property::MapProperty(map_property) => match map_property.value.iter().next() {
+                Some((_, key, value)) => vec![
+                    key.complete_type_name(asset),
+                    value.complete_type_name(asset),
+                ],
+                None => vec![leaf(&map_property.key_type), leaf(&map_property.value_type)],
+            },
+            Property::EnumProperty(enum_property) => enum_property
+                .enum_type
+                .iter()
+                .chain(enum_property.inner_type.iter())
+                .map(leaf)
+                .collect(),
+            Property::ByteProperty(byte_property) if byte_property.is_enum() => {
+                byte_property.enum_type.iter().map(leaf).collect()
+            }
+            _ => Vec::new(),
+        };
+
+        PropertyTypeName::new(name, parameters)
+    }
+
+    /// Check that the length a property reports when written matches the bytes it writes
+    ///
+    /// Tagged properties inside of structs, including structs stored in containers, are checked as well.
+    /// The property is written to `asset`, so this should be a scratch writer.
+    pub fn verify_length<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        property: &Property,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        Self::verify_length_inner(property, asset, true)
+    }
+
+    /// Check property lengths, `tagged` is false for container elements which have no length field
+    fn verify_length_inner<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        property: &Property,
+        asset: &mut Writer,
+        tagged: bool,
+    ) -> Result<(), Error> {
+        // bool properties store their value in the tag and always have a length of 0
+        if tagged && !matches!(property, Property::BoolProperty(_)) {
+            verify_written_length(property, asset)?;
+        }
+
+        match property {
+            Property::StructProperty(struct_property) => {
+                for entry in &struct_property.value {
+                    Self::verify_length_inner(entry, asset, true)?;
+                }
+            }
+            Property::ArrayProperty(array_property) => {
+                for entry in &array_property.value {
+                    Self::verify_length_inner(entry, asset, false)?;
+                }
+            }
+            Property::SetProperty(set_property) => {
+                for entry in set_property
+                    .value
+                    .value
+                    .iter()
+                    .chain(set_property.removed_items.value.iter())
+                {
+                    Self::verify_length_inner(entry, asset, false)?;
+                }
+            }
+            Property::MapProperty(map_property) => {
+                for (_, key, value) in &map_property.value {
+                    Self::verify_length_inner(key, asset, false)?;
+                    Self::verify_length_inner(value, asset, false)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Get a cheap lower bound estimate of the serialized size of this property's value
+    ///
+    /// The property tag is not counted, and neither is anything that depends on the engine version,
+    /// so this is always at most the number of bytes [`PropertyTrait::write`] writes.
+    /// Useful as a capacity hint for write buffers.
+    pub fn estimated_serialized_size(&self) -> usize {
+        fn array_size(values: &[Property]) -> usize {
+            size_of::<i32>()
+                + values
+                    .iter()
+                    .map(Property::estimated_serialized_size)
+                    .sum::<usize>()
+        }
+
+        match self {
+            Property::Int8Property(_) | Property::ByteProperty(_) | Property::EnumProperty(_) => 1,
+            Property::Int16Property(_) | Property::UInt16Property(_) => 2,
+            Property::IntProperty(_)
+            | Property::UInt32Property(_)
+            | Property::FloatProperty(_)
+            | Property::ObjectProperty(_)
+            | Property::ColorProperty(_) => 4,
+            Property::Int64Property(_)
+            | Property::UInt64Property(_)
+            | Property::DoubleProperty(_)
+            | Property::NameProperty(_)
+            | Property::IntPointProperty(_)
+            | Property::Vector2DProperty(_)
+            | Property::TimeSpanProperty(_)
+            | Property::DateTimeProperty(_) => 8,
+            // vectors are at least single precision
+            Property::VectorProperty(_) | Property::RotatorProperty(_) => 12,
+            Property::Vector4Property(_)
+            | Property::QuatProperty(_)
+            | Property::PlaneProperty(_)
+            | Property::LinearColorProperty(_)
+            | Property::GuidProperty(_) => 16,
+            // an fstring is a length followed by at least one byte per character and a terminator
+            Property::StrProperty(property) => {
+                size_of::<i32>()
+                    + property
+                        .value
+                        .as_ref()
+                        .map_or(0, |value| value.chars().count() + 1)
+            }
+            Property::StructProperty(property) => property
+                .value
+                .iter()
+                .map(Property::estimated_serialized_size)
+                .sum(),
+            Property::ArrayProperty(property) => array_size(&property.value),
+            Property::SetProperty(property) => {
+                array_size(&property.removed_items.value) + array_size(&property.value.value)
+            }
+            Property::MapProperty(property) => {
+                size_of::<i32>()
+                    + property.keys_to_remove.as_deref().map_or(0, |keys| {
+                        keys.iter().map(Property::estimated_serialized_size).sum()
+                    })
+                    + size_of::<i32>()
+                    + property
+                        .value
+                        .iter()
+                        .map(|(_, key, value)| {
+                            key.estimated_serialized_size() + value.estimated_serialized_size()
+                        })
+                        .sum::<usize>()
+            }
+            Property::RawStructProperty(property) => property.value.len(),
+            Property::UnknownProperty(property) => property.value.len(),
+            _ => 0,
+        }
+    }
+
+    /// Call `f` with this property and every property nested in it, parents before their children
+    ///
+    /// Nested properties are the fields of structs, the elements of arrays and sets including removed set items,
+    /// the keys and values of maps including removed keys, the fields of Niagara variables and cloth lod data,
+    /// and the properties stored in movie scene properties.
+    /// [`EmptyProperty`] and [`UnknownProperty`] values are passed to `f` like any other property.
+    pub fn visit<F: FnMut(&Property)>(&self, f: &mut F) {
+        f(self);
+
+        match self {
+            Property::StructProperty(property) => {
+                for entry in &property.value {
+                    entry.visit(f);
+                }
+            }
+            Property::ArrayProperty(property) => {
+                for entry in &property.value {
+                    entry.visit(f);
+                }
+            }
+            Property::SetProperty(property) => {
+                for entry in property
+                    .value
+                    .value
+                    .iter()
+                    .chain(property.removed_items.value.iter())
+                {
+                    entry.visit(f);
+                }
+            }
+            Property::MapProperty(property) => {
+                for key in property.keys_to_remove.iter().flatten() {
+                    key.visit(f);
+                }
+                for (_, key, value) in &property.value {
+                    key.visit(f);
+                    value.visit(f);
+                }
+            }
+            Property::MovieSceneEvalTemplatePtrProperty(property) => {
+                for entry in &property.value {
+                    entry.visit(f);
+                }
+            }
+            Property::MovieSceneTrackImplementationPtrProperty(property) => {
+                for entry in &property.value {
+                    entry.visit(f);
+                }
+            }
+            Property::MovieSceneSegmentProperty(property) => {
+                for entry in property.value.impls.iter().flatten() {
+                    entry.visit(f);
+                }
+            }
+            Property::SectionEvaluationDataTreeProperty(property) => {
+                for entry in property.value.tree.data.items.iter().flatten() {
+                    entry.visit(f);
+                }
+            }
+            Property::NiagaraVariableProperty(property) => {
+                for entry in &property.struct_property.value {
+                    entry.visit(f);
+                }
+            }
+            Property::NiagaraVariableWithOffsetProperty(property) => {
+                for entry in &property.niagara_variable.struct_property.value {
+                    entry.visit(f);
+                }
+            }
+            Property::ClothLodDataProperty(property) => {
+                for entry in &property.struct_property.value {
+                    entry.visit(f);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `f` with this property and every property nested in it, parents before their children
+    ///
+    /// Visits the same properties as [`Property::visit`], children are visited after `f` changed their parent.
+    /// Map keys can't be changed in place, so maps are rebuilt in their original order after their keys were visited,
+    /// if two keys become equal only the later value is kept.
+    pub fn visit_mut<F: FnMut(&mut Property)>(&mut self, f: &mut F) {
+        f(self);
+
+        match self {
+            Property::StructProperty(property) => {
+                for entry in &mut property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::ArrayProperty(property) => {
+                for entry in &mut property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::SetProperty(property) => {
+                for entry in property
+                    .value
+                    .value
+                    .iter_mut()
+                    .chain(property.removed_items.value.iter_mut())
+                {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::MapProperty(property) => {
+                for key in property.keys_to_remove.iter_mut().flatten() {
+                    key.visit_mut(f);
+                }
+                property.value = std::mem::take(&mut property.value)
+                    .into_iter()
+                    .map(|(_, mut key, mut value)| {
+                        key.visit_mut(f);
+                        value.visit_mut(f);
+                        (key, value)
+                    })
+                    .collect();
+            }
+            Property::MovieSceneEvalTemplatePtrProperty(property) => {
+                for entry in &mut property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::MovieSceneTrackImplementationPtrProperty(property) => {
+                for entry in &mut property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::MovieSceneSegmentProperty(property) => {
+                for entry in property.value.impls.iter_mut().flatten() {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::SectionEvaluationDataTreeProperty(property) => {
+                for entry in property.value.tree.data.items.iter_mut().flatten() {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::NiagaraVariableProperty(property) => {
+                for entry in &mut property.struct_property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::NiagaraVariableWithOffsetProperty(property) => {
+                for entry in &mut property.niagara_variable.struct_property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            Property::ClothLodDataProperty(property) => {
+                for entry in &mut property.struct_property.value {
+                    entry.visit_mut(f);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Check if a property type has custom serialization
     pub fn has_custom_serialization(name: &str) -> bool {
         CUSTOM_SERIALIZATION.contains(&name)
     }
 }
 
+/// Check that the length `property` returns from [`PropertyTrait::write`] matches the bytes it writes
+///
+/// The property is written without a header to `asset`, so this should be a scratch writer.
+pub fn verify_written_length<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    property: &impl PropertyTrait,
+    asset: &mut Writer,
+) -> Result<(), Error> {
+    let begin = asset.position();
+    let reported = property.write(asset, false)? as u64;
+    let actual = asset.position() - begin;
+
+    if reported != actual {
+        return Err(Error::serialization_length_mismatch(
+            property.get_name_ref().get_owned_content(),
+            reported,
+            actual,
+        ));
+    }
+    Ok(())
+}
+
 /// Implements `ToSerializedName` trait for properties
 macro_rules! property_inner_serialized_name {
     ($($inner:ident : $name:expr),*) => {
@@ -1469,7 +2104,27 @@ property_inner_serialized_name! {
     MovieSceneEvaluationKeyProperty: "MovieSceneEvaluationKey"
 }
 
+/// Mark a property as zero for [`generate_unversioned_header`]
+///
+/// The property is replaced by an [`EmptyProperty`] with the same name and ancestry,
+/// which is written as a set bit in the zero mask instead of being serialized.
+pub fn mark_unversioned_zero(property: &mut Property) {
+    if matches!(property, Property::EmptyProperty(_)) {
+        return;
+    }
+
+    *property = EmptyProperty::new(
+        FName::new_dummy(property.to_serialized_name(), 0),
+        property.get_name(),
+        property.get_ancestry().clone(),
+    )
+    .into();
+}
+
 /// Generate property unversioned header
+///
+/// Properties are sorted by their index in the mappings, [`EmptyProperty`] values are
+/// marked in the zero mask, see [`mark_unversioned_zero`].
 pub fn generate_unversioned_header<W: ArchiveWriter<impl PackageIndexTrait>>(
     archive: &W,
     properties: &[Property],
@@ -1483,13 +2138,11 @@ pub fn generate_unversioned_header<W: ArchiveWriter<impl PackageIndexTrait>>(
         return Ok(None);
     };
 
-    let mut first_global_index = u32::MAX;
-    let mut last_global_index = u32::MIN;
-
-    let mut properties_to_process = HashSet::new();
+    // global index -> index in `properties`
+    let mut properties_to_process = BTreeMap::new();
     let mut zero_properties: HashSet<u32> = HashSet::new();
 
-    for property in properties {
+    for (i, property) in properties.iter().enumerate() {
         let Some((_, global_index)) = mappings.get_property_with_duplication_index(
             &property.get_name(),
             property.get_ancestry(),
@@ -1504,82 +2157,63 @@ pub fn generate_unversioned_header<W: ArchiveWriter<impl PackageIndexTrait>>(
             zero_properties.insert(global_index);
         }
 
-        first_global_index = first_global_index.min(global_index);
-        last_global_index = last_global_index.max(global_index);
-        properties_to_process.insert(global_index);
+        properties_to_process.insert(global_index, i);
     }
 
     // Sort properties and generate header fragments
-    let mut sorted_properties = Vec::new();
+    let mut sorted_properties = Vec::with_capacity(properties_to_process.len());
 
     let mut fragments: Vec<UnversionedHeaderFragment> = Vec::new();
-    let mut last_num_before_fragment = 0;
-
-    if !properties_to_process.is_empty() {
-        loop {
-            let mut has_zeros = false;
-
-            // Find next contiguous properties chunk
-            let mut start_index = last_num_before_fragment;
-            while !properties_to_process.contains(&start_index) && start_index <= last_global_index
-            {
-                start_index += 1;
-            }
+    // first index not covered by a fragment yet
+    let mut next_num = 0u32;
+
+    let mut global_indices = properties_to_process.keys().copied().peekable();
+    while let Some(start_index) = global_indices.next() {
+        // Find next contiguous properties chunk
+        let mut end_index = start_index + 1;
+        while global_indices.next_if_eq(&end_index).is_some() {
+            end_index += 1;
+        }
 
-            if start_index > last_global_index {
-                break;
-            }
+        let mut skip_num = start_index - next_num;
+        while skip_num > i8::MAX as u32 {
+            fragments.push(UnversionedHeaderFragment {
+                skip_num: i8::MAX as u8,
+                value_num: 0,
+                first_num: (next_num + i8::MAX as u32) as u8,
+                is_last: false,
+                has_zeros: false,
+            });
+            skip_num -= i8::MAX as u32;
+            next_num += i8::MAX as u32;
+        }
 
-            // Process contiguous properties chunk
-            let mut end_index = start_index;
-            while properties_to_process.contains(&end_index) {
-                if zero_properties.contains(&end_index) {
-                    has_zeros = true;
-                }
+        // Split the chunk into fragments of at most i8::MAX values
+        let mut first_num = start_index;
+        while first_num < end_index {
+            let value_num = (end_index - first_num).min(i8::MAX as u32);
+            let has_zeros =
+                (first_num..first_num + value_num).any(|e| zero_properties.contains(&e));
 
-                // todo: clone might not be needed
-                sorted_properties.push(properties[end_index as usize].clone());
-                end_index += 1;
-            }
-
-            // Create extra fragments for this chunk
-            let mut skip_num = start_index - last_num_before_fragment - 1;
-            let mut value_num = (end_index - 1) - start_index + 1;
-
-            while skip_num > i8::MAX as u32 {
-                fragments.push(UnversionedHeaderFragment {
-                    skip_num: i8::MAX as u8,
-                    value_num: 0,
-                    first_num: 0,
-                    is_last: false,
-                    has_zeros: false,
-                });
-                skip_num -= i8::MAX as u32;
-            }
-            while value_num > i8::MAX as u32 {
-                fragments.push(UnversionedHeaderFragment {
-                    skip_num: 0,
-                    value_num: i8::MAX as u8,
-                    first_num: 0,
-                    is_last: false,
-                    has_zeros: false,
-                });
-                value_num -= i8::MAX as u32;
-            }
-
-            // Create the main fragment for this chunk
-            let fragment = UnversionedHeaderFragment {
+            fragments.push(UnversionedHeaderFragment {
                 skip_num: skip_num as u8,
                 value_num: value_num as u8,
-                first_num: start_index as u8,
+                first_num: first_num as u8,
                 is_last: false,
                 has_zeros,
-            };
+            });
+            skip_num = 0;
+            first_num += value_num;
+        }
 
-            fragments.push(fragment);
-            last_num_before_fragment = end_index - 1;
+        for global_index in start_index..end_index {
+            // todo: clone might not be needed
+            sorted_properties.push(properties[properties_to_process[&global_index]].clone());
         }
-    } else {
+        next_num = end_index;
+    }
+
+    if fragments.is_empty() {
         fragments.push(parent_name.get_content(|name| UnversionedHeaderFragment {
             skip_num: usize::min(mappings.get_all_properties(name).len(), i8::MAX as usize) as u8,
             value_num: 0,
@@ -1598,7 +2232,7 @@ pub fn generate_unversioned_header<W: ArchiveWriter<impl PackageIndexTrait>>(
 
     for fragment in fragments.iter().filter(|e| e.has_zeros) {
         for i in 0..fragment.value_num {
-            let is_zero = zero_properties.contains(&((fragment.first_num + i) as u32));
+            let is_zero = zero_properties.contains(&(fragment.first_num as u32 + i as u32));
             if !is_zero {
                 has_non_zero_values = true;
             }
@@ -1606,6 +2240,16 @@ pub fn generate_unversioned_header<W: ArchiveWriter<impl PackageIndexTrait>>(
         }
     }
 
+    // the zero mask is read as one or two bytes, or as whole dwords for larger masks
+    if !zero_mask.is_empty() {
+        let mask_bits = match zero_mask.len() {
+            0..=8 => 8,
+            9..=16 => 16,
+            len => len.div_ceil(32) * 32,
+        };
+        zero_mask.resize(mask_bits, false);
+    }
+
     let unversioned_property_index =
         fragments.first().map(|e| e.first_num).unwrap_or_default() as usize;
 
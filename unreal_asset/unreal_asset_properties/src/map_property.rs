@@ -51,7 +51,7 @@ impl MapProperty {
         is_key: bool,
     ) -> Result<Property, Error> {
         let new_ancestry = ancestry.with_parent(name.clone());
-        type_name.get_content(|ty| match ty {
+        match type_name.get_owned_content().as_str() {
             "StructProperty" => {
                 let mut struct_type = None;
 
@@ -75,7 +75,19 @@ impl MapProperty {
                     }
                 }
 
+                // UE5.4+ property tags store the complete map type, including the struct types
                 let struct_type = struct_type
+                    .or_else(|| {
+                        asset
+                            .get_property_tag_type(&name, ancestry)
+                            .and_then(|e| {
+                                e.type_name.get_parameter(match is_key {
+                                    true => 0,
+                                    false => 1,
+                                })
+                            })
+                            .and_then(|e| e.get_parameter_name(0))
+                    })
                     .or_else(|| {
                         name.get_content(|name| match is_key {
                             true => asset
@@ -113,7 +125,7 @@ impl MapProperty {
                 0,
                 false,
             ),
-        })
+        }
     }
 
     /// Read a `MapProperty` from an asset
@@ -149,6 +161,13 @@ impl MapProperty {
             }
         }
 
+        if type_1.is_none() && type_2.is_none() {
+            if let Some(tag_type) = asset.get_property_tag_type(&name, &ancestry) {
+                type_1 = tag_type.type_name.get_parameter_name(0);
+                type_2 = tag_type.type_name.get_parameter_name(1);
+            }
+        }
+
         let num_keys_to_remove = asset.read_i32::<LE>()?;
         let mut keys_to_remove = None;
 
@@ -205,6 +224,11 @@ impl MapProperty {
             keys_to_remove,
         })
     }
+
+    /// Ancestry of the map keys and values, this map's ancestry with the map as the immediate parent
+    pub fn element_ancestry(&self) -> Ancestry {
+        self.ancestry.with_parent(self.name.clone())
+    }
 }
 
 impl PropertyTrait for MapProperty {
@@ -3,7 +3,9 @@
 use unreal_asset_base::types::movie::{FrameNumber, FrameRate};
 
 use crate::property_prelude::*;
-use crate::rich_curve_key_property::RichCurveExtrapolation;
+use crate::rich_curve_key_property::{
+    RichCurveExtrapolation, RichCurveInterpMode, RichCurveTangentMode,
+};
 
 use super::movie_scene_float_value_property::MovieSceneFloatValue;
 
@@ -34,6 +36,76 @@ pub struct MovieSceneFloatChannel {
 }
 
 impl MovieSceneFloatChannel {
+    /// Serialized size of a `FrameNumber`
+    pub const TIMES_STRUCT_LENGTH: i32 = 4;
+    /// Serialized size of a `MovieSceneFloatValue`
+    pub const VALUES_STRUCT_LENGTH: i32 = 28;
+
+    /// Get keyframe times, parallel to [`MovieSceneFloatChannel::values`]
+    pub fn times(&self) -> &[FrameNumber] {
+        &self.times
+    }
+
+    /// Get keyframe values, parallel to [`MovieSceneFloatChannel::times`]
+    pub fn values(&self) -> &[MovieSceneFloatValue] {
+        &self.values
+    }
+
+    /// Get keyframes as `(frame, value)` pairs
+    pub fn keys(&self) -> impl Iterator<Item = (i32, f32)> + '_ {
+        self.times
+            .iter()
+            .zip(self.values.iter())
+            .map(|(time, value)| (time.value, value.value.0))
+    }
+
+    /// Rebuild this channel from `(frame, value)` keyframes
+    ///
+    /// Keys are sorted by frame, every key gets the given interpolation mode and default tangents.
+    /// Extrapolation, default value and tick resolution are left untouched.
+    pub fn set_keys(&mut self, keys: &[(i32, f32)], interp_mode: RichCurveInterpMode) {
+        let mut keys = keys.to_vec();
+        keys.sort_by_key(|(frame, _)| *frame);
+
+        self.times = keys
+            .iter()
+            .map(|(frame, _)| FrameNumber::new(*frame))
+            .collect();
+        self.values = keys
+            .iter()
+            .map(|(_, value)| MovieSceneFloatValue {
+                value: OrderedFloat(*value),
+                interp_mode,
+                tangent_mode: RichCurveTangentMode::Auto,
+                ..Default::default()
+            })
+            .collect();
+
+        if self.times_struct_length == 0 {
+            self.times_struct_length = Self::TIMES_STRUCT_LENGTH;
+        }
+        if self.values_struct_length == 0 {
+            self.values_struct_length = Self::VALUES_STRUCT_LENGTH;
+        }
+    }
+
+    /// Set interpolation mode for all keys
+    pub fn set_interp_mode(&mut self, interp_mode: RichCurveInterpMode) {
+        for value in &mut self.values {
+            value.interp_mode = interp_mode;
+        }
+    }
+
+    /// Set pre and post infinity extrapolation
+    pub fn set_extrapolation(
+        &mut self,
+        pre_infinity_extrap: RichCurveExtrapolation,
+        post_infinity_extrap: RichCurveExtrapolation,
+    ) {
+        self.pre_infinity_extrap = pre_infinity_extrap;
+        self.post_infinity_extrap = post_infinity_extrap;
+    }
+
     /// Read a `MovieSceneFloatChannel` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
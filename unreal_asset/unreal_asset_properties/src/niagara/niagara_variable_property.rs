@@ -1,7 +1,87 @@
 //! Niagara variable property
 
+use unreal_asset_base::reader::ArchiveTrait;
+
 use crate::property_prelude::*;
 
+/// Typed value of a Niagara variable
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NiagaraVariableValue {
+    /// `NiagaraFloat`
+    Float(f32),
+    /// `NiagaraInt32`
+    Int(i32),
+    /// `NiagaraBool`
+    Bool(bool),
+    /// `Vector2D`/`Vector2f`
+    Vector2([f32; 2]),
+    /// `Vector`/`Vector3f`
+    Vector([f32; 3]),
+    /// `Vector4`/`Vector4f`
+    Vector4([f32; 4]),
+    /// `LinearColor`
+    LinearColor([f32; 4]),
+    /// `Quat`/`Quat4f`
+    Quat([f32; 4]),
+}
+
+impl NiagaraVariableValue {
+    /// Niagara stores true as -1
+    const NIAGARA_BOOL_TRUE: i32 = -1;
+
+    /// Read a value of the given Niagara type from the start of `data`
+    ///
+    /// Returns `None` for unsupported types or if `data` is too short.
+    pub fn from_bytes(type_name: &str, data: &[u8]) -> Option<Self> {
+        let float = |i: usize| -> Option<f32> {
+            Some(f32::from_le_bytes(
+                data.get(i * 4..i * 4 + 4)?.try_into().ok()?,
+            ))
+        };
+        let int = || -> Option<i32> { Some(i32::from_le_bytes(data.get(0..4)?.try_into().ok()?)) };
+
+        Some(match type_name {
+            "NiagaraFloat" => NiagaraVariableValue::Float(float(0)?),
+            "NiagaraInt32" => NiagaraVariableValue::Int(int()?),
+            "NiagaraBool" => NiagaraVariableValue::Bool(int()? != 0),
+            "Vector2D" | "Vector2f" => NiagaraVariableValue::Vector2([float(0)?, float(1)?]),
+            "Vector" | "Vector3f" => {
+                NiagaraVariableValue::Vector([float(0)?, float(1)?, float(2)?])
+            }
+            "Vector4" | "Vector4f" => {
+                NiagaraVariableValue::Vector4([float(0)?, float(1)?, float(2)?, float(3)?])
+            }
+            "LinearColor" => {
+                NiagaraVariableValue::LinearColor([float(0)?, float(1)?, float(2)?, float(3)?])
+            }
+            "Quat" | "Quat4f" => {
+                NiagaraVariableValue::Quat([float(0)?, float(1)?, float(2)?, float(3)?])
+            }
+            _ => return None,
+        })
+    }
+
+    /// Serialize this value the way Niagara stores it in parameter data
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let floats =
+            |values: &[f32]| -> Vec<u8> { values.iter().flat_map(|e| e.to_le_bytes()).collect() };
+
+        match self {
+            NiagaraVariableValue::Float(value) => value.to_le_bytes().to_vec(),
+            NiagaraVariableValue::Int(value) => value.to_le_bytes().to_vec(),
+            NiagaraVariableValue::Bool(value) => match value {
+                true => Self::NIAGARA_BOOL_TRUE.to_le_bytes().to_vec(),
+                false => 0i32.to_le_bytes().to_vec(),
+            },
+            NiagaraVariableValue::Vector2(value) => floats(value),
+            NiagaraVariableValue::Vector(value) => floats(value),
+            NiagaraVariableValue::Vector4(value)
+            | NiagaraVariableValue::LinearColor(value)
+            | NiagaraVariableValue::Quat(value) => floats(value),
+        }
+    }
+}
+
 /// Niagara variable property
 #[derive(FNameContainer, Debug, Hash, Clone, Default, PartialEq, Eq)]
 pub struct NiagaraVariableProperty {
@@ -14,6 +94,86 @@ pub struct NiagaraVariableProperty {
 }
 
 impl NiagaraVariableProperty {
+    /// Get the struct, class or enum defining this variable's type
+    pub fn get_type_definition(&self) -> Option<PackageIndex> {
+        self.struct_property
+            .value
+            .iter()
+            .find_map(|property| match property {
+                Property::ObjectProperty(object) if object.name == "ClassStructOrEnum" => {
+                    Some(object.value)
+                }
+                _ => None,
+            })
+    }
+
+    /// Set the struct, class or enum defining this variable's type
+    pub fn set_type_definition(&mut self, type_definition: PackageIndex) -> Result<(), Error> {
+        self.struct_property
+            .value
+            .iter_mut()
+            .find_map(|property| match property {
+                Property::ObjectProperty(object) if object.name == "ClassStructOrEnum" => {
+                    Some(object)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                PropertyError::property_field_none("ClassStructOrEnum", "ObjectProperty")
+            })?
+            .value = type_definition;
+        Ok(())
+    }
+
+    /// Get the name of this variable's type, e.g. `NiagaraFloat`
+    pub fn get_type_name<Index: PackageIndexTrait>(
+        &self,
+        asset: &impl ArchiveTrait<Index>,
+    ) -> Option<FName> {
+        asset.get_object_name_packageindex(self.get_type_definition()?)
+    }
+
+    /// Read this variable's value from the parameter data of the parameter store it belongs to
+    ///
+    /// The value is located at `variable_offset`.
+    pub fn read_value<Index: PackageIndexTrait>(
+        &self,
+        asset: &impl ArchiveTrait<Index>,
+        parameter_data: &[u8],
+    ) -> Option<NiagaraVariableValue> {
+        let type_name = self.get_type_name(asset)?;
+        let data = parameter_data.get(usize::try_from(self.variable_offset).ok()?..)?;
+        type_name.get_content(|type_name| NiagaraVariableValue::from_bytes(type_name, data))
+    }
+
+    /// Write a value for this variable into the parameter data of the parameter store it belongs to
+    ///
+    /// The value is written at `variable_offset`, the parameter data is not resized.
+    pub fn write_value(
+        &self,
+        parameter_data: &mut [u8],
+        value: NiagaraVariableValue,
+    ) -> Result<(), Error> {
+        let bytes = value.to_bytes();
+        let start = usize::try_from(self.variable_offset).map_err(|_| {
+            PropertyError::other(format!("Invalid offset {}", self.variable_offset))
+        })?;
+        let parameter_data_len = parameter_data.len();
+
+        parameter_data
+            .get_mut(start..start + bytes.len())
+            .ok_or_else(|| {
+                PropertyError::other(format!(
+                    "Parameter data of length {} can't fit {} bytes at offset {}",
+                    parameter_data_len,
+                    bytes.len(),
+                    start
+                ))
+            })?
+            .copy_from_slice(&bytes);
+        Ok(())
+    }
+
     /// Read a `NiagaraVariableProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
@@ -61,6 +221,10 @@ impl PropertyDataTrait for NiagaraVariableProperty {
         self.struct_property.get_name()
     }
 
+    fn get_name_ref(&self) -> Cow<'_, FName> {
+        self.struct_property.get_name_ref()
+    }
+
     fn get_name_mut(&mut self) -> &mut FName {
         self.struct_property.get_name_mut()
     }
@@ -73,6 +237,10 @@ impl PropertyDataTrait for NiagaraVariableProperty {
         self.struct_property.get_property_guid()
     }
 
+    fn set_property_guid(&mut self, property_guid: Option<Guid>) {
+        self.struct_property.set_property_guid(property_guid)
+    }
+
     fn get_ancestry(&self) -> &Ancestry {
         self.struct_property.get_ancestry()
     }
@@ -113,7 +281,8 @@ impl PropertyTrait for NiagaraVariableProperty {
         }
 
         if !asset.has_unversioned_properties() {
-            asset.write_fname(&asset.get_name_map().get_mut().add_fname("None"))?;
+            let none_fname = asset.add_fname("None");
+            asset.write_fname(&none_fname)?;
         }
         asset.write_i32::<LE>(self.variable_offset)?;
 
@@ -156,6 +325,10 @@ impl PropertyDataTrait for NiagaraVariableWithOffsetProperty {
         self.niagara_variable.get_name()
     }
 
+    fn get_name_ref(&self) -> Cow<'_, FName> {
+        self.niagara_variable.get_name_ref()
+    }
+
     fn get_name_mut(&mut self) -> &mut FName {
         self.niagara_variable.get_name_mut()
     }
@@ -168,6 +341,10 @@ impl PropertyDataTrait for NiagaraVariableWithOffsetProperty {
         self.niagara_variable.get_property_guid()
     }
 
+    fn set_property_guid(&mut self, property_guid: Option<Guid>) {
+        self.niagara_variable.set_property_guid(property_guid)
+    }
+
     fn get_ancestry(&self) -> &Ancestry {
         self.niagara_variable.get_ancestry()
     }
@@ -19,6 +19,9 @@ pub struct ObjectProperty {
 }
 impl_property_data_trait!(ObjectProperty);
 
+/// Separates the asset path from the subobject path in an asset reference
+const SUBOBJECT_DELIMITER: char = ':';
+
 /// Asset object property
 #[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct AssetObjectProperty {
@@ -197,6 +200,36 @@ impl AssetObjectProperty {
             value,
         })
     }
+
+    /// Get the path of the referenced asset, e.g. `/Game/Path/Asset.Asset`
+    ///
+    /// Returns an empty string if the reference is empty.
+    pub fn asset_path(&self) -> &str {
+        let value = self.value.as_deref().unwrap_or_default();
+        match value.split_once(SUBOBJECT_DELIMITER) {
+            Some((asset_path, _)) => asset_path,
+            None => value,
+        }
+    }
+
+    /// Get the path of the referenced subobject within the asset, if any
+    pub fn sub_path(&self) -> Option<&str> {
+        self.value
+            .as_deref()?
+            .split_once(SUBOBJECT_DELIMITER)
+            .map(|(_, sub_path)| sub_path)
+    }
+
+    /// Retarget this reference to another asset and optional subobject
+    ///
+    /// An empty asset path without a sub path clears the reference.
+    pub fn set_path(&mut self, asset_path: &str, sub_path: Option<&str>) {
+        self.value = match sub_path {
+            Some(sub_path) => Some(format!("{asset_path}{SUBOBJECT_DELIMITER}{sub_path}")),
+            None if asset_path.is_empty() => None,
+            None => Some(asset_path.to_string()),
+        };
+    }
 }
 
 impl PropertyTrait for AssetObjectProperty {
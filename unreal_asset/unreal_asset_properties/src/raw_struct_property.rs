@@ -1,5 +1,7 @@
 //! Raw struct property
 
+use unreal_asset_base::reader::BufferReader;
+
 use crate::property_prelude::*;
 
 /// Raw struct property
@@ -30,8 +32,7 @@ impl RawStructProperty {
     ) -> Result<Self, Error> {
         let property_guid = optional_guid!(asset, include_header);
 
-        let mut value = vec![0u8; length as usize];
-        asset.read_exact(&mut value)?;
+        let value = asset.read_raw_data(length, "RawStructProperty")?;
 
         Ok(RawStructProperty {
             name,
@@ -41,6 +42,42 @@ impl RawStructProperty {
             value,
         })
     }
+
+    /// Parse the raw data as a `StructProperty` of the given struct type
+    ///
+    /// `asset` provides the versions, name map and mappings the data is parsed with,
+    /// it should be the asset this property was read from.
+    /// Fails if the struct doesn't consume all of the raw data.
+    pub fn reinterpret<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Reader,
+        struct_name: &str,
+    ) -> Result<StructProperty, Error> {
+        let struct_type = asset.add_fname(struct_name);
+
+        let mut reader = BufferReader::new(asset, &self.value);
+        let property = StructProperty::custom_header(
+            &mut reader,
+            self.name.clone(),
+            self.ancestry.clone(),
+            self.value.len() as i64,
+            self.duplication_index,
+            Some(struct_type),
+            None,
+            self.property_guid,
+        )?;
+
+        let remaining = reader.remaining();
+        if remaining != 0 {
+            return Err(PropertyError::invalid_struct(format!(
+                "{struct_name} left {remaining} of {} raw bytes unread",
+                self.value.len()
+            ))
+            .into());
+        }
+
+        Ok(property)
+    }
 }
 
 impl PropertyTrait for RawStructProperty {
@@ -33,15 +33,20 @@ impl SetProperty {
         length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let (array_type, property_guid) = match include_header {
+        let (mut array_type, property_guid) = match include_header {
             true => (Some(asset.read_fname()?), asset.read_property_guid()?),
             false => (None, None),
         };
+        if array_type.is_none() {
+            array_type = asset
+                .get_property_tag_type(&name, &ancestry)
+                .and_then(|e| e.type_name.get_parameter_name(0));
+        }
 
         let removed_items = ArrayProperty::new_no_header(
             asset,
             name.clone(),
-            ancestry.with_parent(name.clone()),
+            ancestry.clone(),
             false,
             length,
             0,
@@ -72,6 +77,11 @@ impl SetProperty {
             removed_items,
         })
     }
+
+    /// Ancestry of the set elements, this set's ancestry with the set as the immediate parent
+    pub fn element_ancestry(&self) -> Ancestry {
+        self.ancestry.with_parent(self.name.clone())
+    }
 }
 
 impl PropertyTrait for SetProperty {
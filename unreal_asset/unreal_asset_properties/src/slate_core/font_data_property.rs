@@ -121,9 +121,94 @@ impl FontData {
                     PropertyError::property_field_none("loading_policy", "Some(ELoadingPolicy)")
                 })? as u8)?;
             }
+
+            asset.write_i32::<LE>(self.sub_face_index.ok_or_else(|| {
+                PropertyError::property_field_none("sub_face_index", "Some(i32)")
+            })?)?;
         }
         Ok(())
     }
+
+    /// Is this font data stored in its cooked form
+    ///
+    /// Only cooked font data serializes the fields below, editor font data serializes nothing.
+    /// All setters switch the font data to its cooked form.
+    pub fn is_cooked(&self) -> bool {
+        self.is_cooked
+    }
+
+    /// Get the font face asset, a null index means the font is loaded from `font_filename`
+    pub fn local_font_face_asset(&self) -> PackageIndex {
+        self.local_font_face_asset
+    }
+
+    /// Set the font face asset, clearing the filename data if the index isn't null
+    pub fn set_local_font_face_asset(&mut self, local_font_face_asset: PackageIndex) {
+        self.set_cooked();
+        self.local_font_face_asset = local_font_face_asset;
+
+        if local_font_face_asset.index != 0 {
+            self.font_filename = None;
+            self.hinting = None;
+            self.loading_policy = None;
+        }
+    }
+
+    /// Get the font filename
+    pub fn font_filename(&self) -> Option<&str> {
+        self.font_filename.as_deref()
+    }
+
+    /// Set the font filename, this makes the font data stop referencing a font face asset
+    pub fn set_font_filename(&mut self, font_filename: Option<String>) {
+        self.local_font_face_asset = PackageIndex::new(0);
+        self.set_cooked();
+        self.font_filename = font_filename;
+    }
+
+    /// Get the font hinting
+    pub fn hinting(&self) -> Option<EFontHinting> {
+        self.hinting
+    }
+
+    /// Set the font hinting, only serialized when no font face asset is referenced
+    pub fn set_hinting(&mut self, hinting: EFontHinting) {
+        self.set_cooked();
+        self.hinting = Some(hinting);
+    }
+
+    /// Get the font loading policy
+    pub fn loading_policy(&self) -> Option<EFontLoadingPolicy> {
+        self.loading_policy
+    }
+
+    /// Set the font loading policy, only serialized when no font face asset is referenced
+    pub fn set_loading_policy(&mut self, loading_policy: EFontLoadingPolicy) {
+        self.set_cooked();
+        self.loading_policy = Some(loading_policy);
+    }
+
+    /// Get the sub face index
+    pub fn sub_face_index(&self) -> Option<i32> {
+        self.sub_face_index
+    }
+
+    /// Set the sub face index
+    pub fn set_sub_face_index(&mut self, sub_face_index: i32) {
+        self.set_cooked();
+        self.sub_face_index = Some(sub_face_index);
+    }
+
+    /// Switch to the cooked representation, filling in fields required for serialization
+    fn set_cooked(&mut self) {
+        self.is_cooked = true;
+        self.sub_face_index.get_or_insert(0);
+        if self.local_font_face_asset.index == 0 {
+            self.hinting.get_or_insert_with(EFontHinting::default);
+            self.loading_policy
+                .get_or_insert_with(EFontLoadingPolicy::default);
+        }
+    }
 }
 
 /// Font data property
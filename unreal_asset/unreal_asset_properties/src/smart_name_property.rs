@@ -1,6 +1,7 @@
 //! Smart name property
 
 use unreal_asset_base::custom_version::FAnimPhysObjectVersion;
+use unreal_asset_base::reader::ArchiveTrait;
 
 use crate::property_prelude::*;
 
@@ -25,6 +26,45 @@ pub struct SmartNameProperty {
 impl_property_data_trait!(SmartNameProperty);
 
 impl SmartNameProperty {
+    /// Smart name id which makes the engine look up the smart name by its display name
+    pub const MAX_UID: u16 = u16::MAX;
+
+    /// Get the display name this smart name refers to
+    ///
+    /// Smart names are resolved by their display name, the id stored by older versions is only
+    /// a cache into the skeleton's container. Returns `None` for unset smart names.
+    pub fn get_display_name(&self) -> Option<String> {
+        let display_name = self.display_name.get_owned_content();
+        match display_name.as_str() {
+            "None" | "" => None,
+            _ => Some(display_name),
+        }
+    }
+
+    /// Set the display name, adding it to the asset's name map
+    ///
+    /// For versions which still serialize a smart name id the id is reset to [`SmartNameProperty::MAX_UID`],
+    /// so that the engine resolves the new name against the container when loading.
+    pub fn set_display_name<Index: PackageIndexTrait>(
+        &mut self,
+        asset: &mut impl ArchiveTrait<Index>,
+        display_name: &str,
+    ) {
+        self.display_name = asset.add_fname(display_name);
+
+        let custom_version = asset.get_custom_version::<FAnimPhysObjectVersion>().version;
+
+        self.smart_name_id = (custom_version
+            < FAnimPhysObjectVersion::RemoveUIDFromSmartNameSerialize as i32)
+            .then_some(Self::MAX_UID);
+        self.temp_guid = match custom_version
+            < FAnimPhysObjectVersion::SmartNameRefactorForDeterministicCooking as i32
+        {
+            true => Some(self.temp_guid.unwrap_or_default()),
+            false => None,
+        };
+    }
+
     /// Read a `SmartNameProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
@@ -12,6 +12,9 @@ pub struct StructProperty {
     /// Struct type
     pub struct_type: Option<FName>,
     /// Struct guid
+    ///
+    /// Part of the property tag since `VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG` for every struct type,
+    /// native structs store a zero guid, user defined structs their own guid.
     pub struct_guid: Option<Guid>,
     /// Property guid
     pub property_guid: Option<Guid>,
@@ -44,6 +47,16 @@ impl StructProperty {
         }
     }
 
+    /// Get the struct guid, `None` if it wasn't read from a property tag
+    pub fn struct_guid(&self) -> Option<Guid> {
+        self.struct_guid
+    }
+
+    /// Set the struct guid, a missing guid is written as a zero guid
+    pub fn set_struct_guid(&mut self, struct_guid: Option<Guid>) {
+        self.struct_guid = struct_guid;
+    }
+
     /// Read a `StructProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
@@ -240,7 +253,7 @@ impl StructProperty {
         if include_header {
             asset.write_fname(struct_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
             if asset.get_object_version() >= ObjectVersion::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG {
-                asset.write_guid(&self.struct_guid.ok_or_else(PropertyError::headerless)?)?;
+                asset.write_guid(&self.struct_guid.unwrap_or_default())?;
             }
             asset.write_property_guid(self.property_guid.as_ref())?;
         }
@@ -319,7 +332,8 @@ impl StructProperty {
             }
 
             if !asset.has_unversioned_properties() {
-                asset.write_fname(&asset.get_name_map().get_mut().add_fname("None"))?;
+                let none_fname = asset.add_fname("None");
+                asset.write_fname(&none_fname)?;
             }
             Ok((asset.position() - begin) as usize)
         }
@@ -539,6 +539,64 @@ impl BoxProperty {
             is_valid,
         })
     }
+
+    /// Create a `BoxProperty` from its minimum and maximum corners
+    pub fn from_min_max(
+        name: FName,
+        ancestry: Ancestry,
+        min: Vector<OrderedFloat<f64>>,
+        max: Vector<OrderedFloat<f64>>,
+        is_valid: bool,
+    ) -> Self {
+        let corner_ancestry = ancestry.with_parent(name.clone());
+        let corner = |value| VectorProperty {
+            name: name.clone(),
+            ancestry: corner_ancestry.clone(),
+            property_guid: None,
+            duplication_index: 0,
+            value,
+        };
+
+        BoxProperty {
+            v1: corner(min),
+            v2: corner(max),
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index: 0,
+            is_valid,
+        }
+    }
+
+    /// Minimum corner of the box
+    pub fn min(&self) -> Vector<OrderedFloat<f64>> {
+        self.v1.value.clone()
+    }
+
+    /// Maximum corner of the box
+    pub fn max(&self) -> Vector<OrderedFloat<f64>> {
+        self.v2.value.clone()
+    }
+
+    /// Center of the box
+    pub fn center(&self) -> Vector<OrderedFloat<f64>> {
+        let (min, max) = (self.min(), self.max());
+        Vector::new(
+            (min.x + max.x) / 2.0,
+            (min.y + max.y) / 2.0,
+            (min.z + max.z) / 2.0,
+        )
+    }
+
+    /// Half of the size of the box along each axis
+    pub fn extent(&self) -> Vector<OrderedFloat<f64>> {
+        let (min, max) = (self.min(), self.max());
+        Vector::new(
+            (max.x - min.x) / 2.0,
+            (max.y - min.y) / 2.0,
+            (max.z - min.z) / 2.0,
+        )
+    }
 }
 
 impl PropertyTrait for BoxProperty {
@@ -548,8 +606,8 @@ impl PropertyTrait for BoxProperty {
         include_header: bool,
     ) -> Result<usize, Error> {
         optional_guid_write!(self, asset, include_header);
-        let total_size =
-            self.v1.write(asset, include_header)? + self.v2.write(asset, include_header)?;
+        // the corners are serialized without a header of their own
+        let total_size = self.v1.write(asset, false)? + self.v2.write(asset, false)?;
         asset.write_bool(self.is_valid)?;
         Ok(total_size + size_of::<bool>())
     }
@@ -577,6 +635,56 @@ impl Box2DProperty {
             is_valid: asset.read_bool()?,
         })
     }
+
+    /// Create a `Box2DProperty` from its minimum and maximum corners
+    pub fn from_min_max(
+        name: FName,
+        ancestry: Ancestry,
+        min: Vector2<OrderedFloat<f64>>,
+        max: Vector2<OrderedFloat<f64>>,
+        is_valid: bool,
+    ) -> Self {
+        let corner_ancestry = ancestry.with_parent(name.clone());
+        let corner = |value| Vector2DProperty {
+            name: name.clone(),
+            ancestry: corner_ancestry.clone(),
+            property_guid: None,
+            duplication_index: 0,
+            value,
+        };
+
+        Box2DProperty {
+            v1: corner(min),
+            v2: corner(max),
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index: 0,
+            is_valid,
+        }
+    }
+
+    /// Minimum corner of the box
+    pub fn min(&self) -> Vector2<OrderedFloat<f64>> {
+        self.v1.value.clone()
+    }
+
+    /// Maximum corner of the box
+    pub fn max(&self) -> Vector2<OrderedFloat<f64>> {
+        self.v2.value.clone()
+    }
+
+    /// Center of the box
+    pub fn center(&self) -> Vector2<OrderedFloat<f64>> {
+        let (min, max) = (self.min(), self.max());
+        Vector2::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0)
+    }
+
+    /// Half of the size of the box along each axis
+    pub fn extent(&self) -> Vector2<OrderedFloat<f64>> {
+        let (min, max) = (self.min(), self.max());
+        Vector2::new((max.x - min.x) / 2.0, (max.y - min.y) / 2.0)
+    }
 }
 
 impl PropertyTrait for Box2DProperty {
@@ -587,8 +695,8 @@ impl PropertyTrait for Box2DProperty {
     ) -> Result<usize, Error> {
         optional_guid_write!(self, asset, include_header);
 
-        let total_size =
-            self.v1.write(asset, include_header)? + self.v2.write(asset, include_header)?;
+        // the corners are serialized without a header of their own
+        let total_size = self.v1.write(asset, false)? + self.v2.write(asset, false)?;
 
         asset.write_bool(self.is_valid)?;
         Ok(total_size + size_of::<bool>())
@@ -633,6 +741,53 @@ impl PlaneProperty {
             value,
         })
     }
+
+    /// Create a `PlaneProperty` from its normal and its distance from the origin along the normal
+    ///
+    /// Points on the plane satisfy `dot(normal, point) == distance`.
+    pub fn from_normal_distance(
+        name: FName,
+        ancestry: Ancestry,
+        normal: Vector<OrderedFloat<f64>>,
+        distance: OrderedFloat<f64>,
+    ) -> Self {
+        PlaneProperty {
+            name,
+            ancestry,
+            property_guid: None,
+            duplication_index: 0,
+            value: Plane::new(normal.x, normal.y, normal.z, distance),
+        }
+    }
+
+    /// Create a `PlaneProperty` going through `point` with the given normal
+    pub fn from_point_normal(
+        name: FName,
+        ancestry: Ancestry,
+        point: Vector<OrderedFloat<f64>>,
+        normal: Vector<OrderedFloat<f64>>,
+    ) -> Self {
+        let distance = normal.x * point.x + normal.y * point.y + normal.z * point.z;
+        PlaneProperty::from_normal_distance(name, ancestry, normal, distance)
+    }
+
+    /// Normal of the plane
+    pub fn normal(&self) -> Vector<OrderedFloat<f64>> {
+        Vector::new(self.value.x, self.value.y, self.value.z)
+    }
+
+    /// Distance of the plane from the origin along its normal
+    pub fn distance(&self) -> OrderedFloat<f64> {
+        self.value.w
+    }
+
+    /// Signed distance of a point from the plane, positive on the side the normal points to
+    ///
+    /// This is only a true distance for planes with a normalized normal.
+    pub fn distance_to(&self, point: Vector<OrderedFloat<f64>>) -> OrderedFloat<f64> {
+        let normal = self.normal();
+        normal.x * point.x + normal.y * point.y + normal.z * point.z - self.distance()
+    }
 }
 
 impl PropertyTrait for PlaneProperty {
@@ -72,6 +72,46 @@ impl ViewTargetBlendParamsProperty {
             lock_outgoing,
         })
     }
+
+    /// Get the blend time in seconds
+    pub fn blend_time(&self) -> f32 {
+        self.blend_time.0
+    }
+
+    /// Set the blend time in seconds
+    pub fn set_blend_time(&mut self, blend_time: f32) {
+        self.blend_time = OrderedFloat(blend_time);
+    }
+
+    /// Get the blend function
+    pub fn blend_function(&self) -> ViewTargetBlendFunction {
+        self.blend_function
+    }
+
+    /// Set the blend function
+    pub fn set_blend_function(&mut self, blend_function: ViewTargetBlendFunction) {
+        self.blend_function = blend_function;
+    }
+
+    /// Get the blend exponent, used by the ease blend functions
+    pub fn blend_exp(&self) -> f32 {
+        self.blend_exp.0
+    }
+
+    /// Set the blend exponent, used by the ease blend functions
+    pub fn set_blend_exp(&mut self, blend_exp: f32) {
+        self.blend_exp = OrderedFloat(blend_exp);
+    }
+
+    /// Get whether the outgoing view target is locked to its last frame while blending
+    pub fn lock_outgoing(&self) -> bool {
+        self.lock_outgoing
+    }
+
+    /// Set whether the outgoing view target is locked to its last frame while blending
+    pub fn set_lock_outgoing(&mut self, lock_outgoing: bool) {
+        self.lock_outgoing = lock_outgoing;
+    }
 }
 
 impl PropertyTrait for ViewTargetBlendParamsProperty {
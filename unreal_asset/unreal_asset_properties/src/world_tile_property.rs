@@ -154,6 +154,57 @@ pub struct FWorldTileInfo {
 }
 
 impl FWorldTileInfo {
+    /// Get the tile layer name
+    pub fn layer_name(&self) -> Option<&str> {
+        self.layer.name.as_deref()
+    }
+
+    /// Set the tile layer name
+    pub fn set_layer_name(&mut self, name: Option<String>) {
+        self.layer.name = name;
+    }
+
+    /// Get the tile layer streaming distance
+    ///
+    /// `None` if the asset is older than `VER_UE4_WORLD_LEVEL_INFO_UPDATED`
+    pub fn streaming_distance(&self) -> Option<i32> {
+        self.layer.streaming_distance
+    }
+
+    /// Set the tile layer streaming distance
+    ///
+    /// Fails if the tile was read from an asset that doesn't serialize the streaming distance
+    pub fn set_streaming_distance(&mut self, streaming_distance: i32) -> Result<(), Error> {
+        let Some(ref mut current) = self.layer.streaming_distance else {
+            return Err(Error::no_data(
+                "streaming_distance is not present before VER_UE4_WORLD_LEVEL_INFO_UPDATED"
+                    .to_string(),
+            ));
+        };
+        *current = streaming_distance;
+        Ok(())
+    }
+
+    /// Get the tile Z-order
+    ///
+    /// `None` if the asset is older than `VER_UE4_WORLD_LEVEL_INFO_ZORDER`
+    pub fn z_order(&self) -> Option<i32> {
+        self.z_order
+    }
+
+    /// Set the tile Z-order
+    ///
+    /// Fails if the tile was read from an asset that doesn't serialize the Z-order
+    pub fn set_z_order(&mut self, z_order: i32) -> Result<(), Error> {
+        let Some(ref mut current) = self.z_order else {
+            return Err(Error::no_data(
+                "z_order is not present before VER_UE4_WORLD_LEVEL_INFO_ZORDER".to_string(),
+            ));
+        };
+        *current = z_order;
+        Ok(())
+    }
+
     /// Read `FWorldTileInfo` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
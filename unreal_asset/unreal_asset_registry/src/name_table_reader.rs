@@ -8,6 +8,7 @@ use unreal_asset_base::{
     custom_version::{CustomVersion, CustomVersionTrait},
     engine_version::EngineVersion,
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
     passthrough_archive_reader,
     reader::{ArchiveReader, ArchiveTrait, ArchiveType},
     types::{FName, PackageIndex},
@@ -121,6 +122,10 @@ impl<'reader, Reader: ArchiveReader<PackageIndex>> ArchiveTrait<PackageIndex>
         self.reader.get_mappings()
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.reader.get_parse_options()
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.reader.get_parent_class_export_name()
     }
@@ -6,6 +6,7 @@ use unreal_asset_base::{
     custom_version::{CustomVersion, CustomVersionTrait},
     engine_version::EngineVersion,
     object_version::{ObjectVersion, ObjectVersionUE5},
+    parse_options::ParseOptions,
     passthrough_archive_writer,
     reader::{ArchiveTrait, ArchiveType, ArchiveWriter},
     types::{FName, PackageIndex},
@@ -92,6 +93,10 @@ impl<'writer, Writer: ArchiveWriter<PackageIndex>> ArchiveTrait<PackageIndex>
         self.writer.get_mappings()
     }
 
+    fn get_parse_options(&self) -> ParseOptions {
+        self.writer.get_parse_options()
+    }
+
     fn get_parent_class_export_name(&self) -> Option<FName> {
         self.writer.get_parent_class_export_name()
     }
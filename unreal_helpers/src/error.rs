@@ -6,7 +6,7 @@ use std::{
     string::{FromUtf16Error, FromUtf8Error},
 };
 
-#[cfg(feature = "read_write")]
+#[cfg(any(feature = "read_write", feature = "path"))]
 use thiserror::Error;
 
 /// Gets thrown when there is an error reading/writing an FString.
@@ -29,3 +29,15 @@ pub enum FStringError {
     #[error("Io Error {0}")]
     Io(#[from] io::Error),
 }
+
+/// Gets thrown when an archive entry name can't be safely turned into a path.
+#[cfg(feature = "path")]
+#[derive(Error, Debug)]
+pub enum PathError {
+    /// Entry name is an absolute path
+    #[error("Entry name {0:?} is an absolute path")]
+    AbsolutePath(String),
+    /// Entry name uses `..` to leave the output directory
+    #[error("Entry name {0:?} points outside of the output directory")]
+    Traversal(String),
+}
@@ -8,7 +8,7 @@
 //!
 //! - `read_write`: Enables extension Traits [`UnrealReadExt`] and [`UnrealWriteExt`]
 //!                 which help with parsing Unreal data formats.
-//! - `path`: Enables [`game_to_absolute`] function.
+//! - `path`: Enables [`game_to_absolute`] and [`sanitize_extract_path`] functions.
 //! - `guid`: Enables [`Guid`] type.
 //! - `serde`: Enables `serde` support for [`Guid`] type.
 //! - `bitvec`: Enables extension Trait [`BitVecExt`].
@@ -28,7 +28,7 @@ pub use guid::Guid;
 #[cfg(feature = "path")]
 pub mod path;
 #[cfg(feature = "path")]
-pub use path::game_to_absolute;
+pub use path::{game_to_absolute, sanitize_extract_path};
 
 #[cfg(feature = "read_write")]
 pub mod read_ext;
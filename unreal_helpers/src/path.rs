@@ -1,10 +1,12 @@
 //! Functions for working with Unreal paths
 
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::error::PathError;
+
 lazy_static! {
     static ref GAME_REGEX: Regex = Regex::new(r"^/Game/").unwrap();
 }
@@ -30,3 +32,51 @@ pub fn game_to_absolute(game_name: &str, path: &str) -> Option<String> {
             .map(|e| e.to_string()),
     }
 }
+
+/// Join an archive entry name onto an extraction directory without leaving it.
+///
+/// Both `/` and `\` are accepted as separators. Absolute entry names, including
+/// Windows drive prefixes like `C:`, and names containing `..` components are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use unreal_helpers::{error::PathError, sanitize_extract_path};
+///
+/// let base = Path::new("out");
+/// assert_eq!(
+///     sanitize_extract_path(base, "Game/Content/Map.umap").unwrap(),
+///     Path::new("out/Game/Content/Map.umap")
+/// );
+/// assert!(matches!(
+///     sanitize_extract_path(base, "../../evil"),
+///     Err(PathError::Traversal(_))
+/// ));
+/// ```
+pub fn sanitize_extract_path(base: &Path, entry_name: &str) -> Result<PathBuf, PathError> {
+    let normalized = entry_name.replace('\\', "/");
+    let is_drive = normalized
+        .split('/')
+        .next()
+        .is_some_and(|first| first.contains(':'));
+
+    if normalized.starts_with('/') || is_drive {
+        return Err(PathError::AbsolutePath(entry_name.to_string()));
+    }
+
+    let mut path = base.to_path_buf();
+    for component in Path::new(&normalized).components() {
+        match component {
+            Component::Normal(component) => path.push(component),
+            Component::CurDir => {}
+            Component::ParentDir => return Err(PathError::Traversal(entry_name.to_string())),
+            Component::RootDir | Component::Prefix(_) => {
+                return Err(PathError::AbsolutePath(entry_name.to_string()))
+            }
+        }
+    }
+
+    Ok(path)
+}
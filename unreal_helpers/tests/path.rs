@@ -1,6 +1,8 @@
 #![cfg(feature = "path")]
 
-use unreal_helpers::game_to_absolute;
+use std::path::Path;
+
+use unreal_helpers::{error::PathError, game_to_absolute, sanitize_extract_path};
 
 #[test]
 fn test_game_to_absolute() {
@@ -27,3 +29,35 @@ fn test_game_to_absolute() {
     let no_game_name = "/Content/Vehicle";
     assert_eq!(game_to_absolute(game_name, no_game_name), None);
 }
+
+#[test]
+fn test_sanitize_extract_path() {
+    let base = Path::new("out");
+
+    assert_eq!(
+        sanitize_extract_path(base, "Game/Content/Maps/Map.umap").expect("Failed to sanitize path"),
+        base.join("Game")
+            .join("Content")
+            .join("Maps")
+            .join("Map.umap")
+    );
+    assert_eq!(
+        sanitize_extract_path(base, "./Game\\Content/Item.uasset")
+            .expect("Failed to sanitize path"),
+        base.join("Game").join("Content").join("Item.uasset")
+    );
+
+    for absolute in ["/etc/passwd", "\\Windows\\evil.dll", "C:/Windows/evil.dll"] {
+        assert!(matches!(
+            sanitize_extract_path(base, absolute),
+            Err(PathError::AbsolutePath(_))
+        ));
+    }
+
+    for traversal in ["../../evil", "Game/../../evil", "Game\\..\\..\\evil"] {
+        assert!(matches!(
+            sanitize_extract_path(base, traversal),
+            Err(PathError::Traversal(_))
+        ));
+    }
+}
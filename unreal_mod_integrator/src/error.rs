@@ -1,10 +1,29 @@
 use std::{fmt::Display, io};
 
+use unreal_asset::engine_version::EngineVersion;
+
 #[derive(Debug)]
 pub enum IntegrationError {
     GameNotFound,
     AssetNotFound(String),
     CorruptedStarterPak,
+    UnexpectedExportCount {
+        found: usize,
+        expected: usize,
+    },
+    MissingExport {
+        name: String,
+    },
+    EngineVersionMismatch {
+        found: EngineVersion,
+        expected: EngineVersion,
+    },
+    InvalidModAsset {
+        name: String,
+        // boxed, asset errors are large
+        source: Box<unreal_asset::error::Error>,
+    },
+    Cancelled,
 }
 
 impl IntegrationError {
@@ -19,6 +38,29 @@ impl IntegrationError {
     pub fn corrupted_starter_pak() -> Self {
         Self::CorruptedStarterPak
     }
+
+    pub fn unexpected_export_count(found: usize, expected: usize) -> Self {
+        Self::UnexpectedExportCount { found, expected }
+    }
+
+    pub fn missing_export(name: String) -> Self {
+        Self::MissingExport { name }
+    }
+
+    pub fn engine_version_mismatch(found: EngineVersion, expected: EngineVersion) -> Self {
+        Self::EngineVersionMismatch { found, expected }
+    }
+
+    pub fn invalid_mod_asset(name: String, source: unreal_asset::error::Error) -> Self {
+        Self::InvalidModAsset {
+            name,
+            source: Box::new(source),
+        }
+    }
+
+    pub fn cancelled() -> Self {
+        Self::Cancelled
+    }
 }
 
 impl Display for IntegrationError {
@@ -27,6 +69,25 @@ impl Display for IntegrationError {
             Self::GameNotFound => write!(f, "Game not found"),
             Self::AssetNotFound(ref name) => write!(f, "Asset {name:?} not found"),
             Self::CorruptedStarterPak => write!(f, "Corrupted starter pak"),
+            Self::UnexpectedExportCount { found, expected } => write!(
+                f,
+                "Corrupted starter pak, found {found} exports in template, expected {expected}"
+            ),
+            Self::MissingExport { ref name } => {
+                write!(
+                    f,
+                    "Corrupted starter pak, template is missing export {name:?}"
+                )
+            }
+            Self::EngineVersionMismatch { found, expected } => write!(
+                f,
+                "Starter pak template was made for engine version {found:?}, expected {expected:?}"
+            ),
+            Self::InvalidModAsset {
+                ref name,
+                ref source,
+            } => write!(f, "Failed to parse mod asset {name:?}: {source}"),
+            Self::Cancelled => write!(f, "Integration was cancelled"),
         }
     }
 }
@@ -67,6 +128,14 @@ impl Error {
             code: ErrorCode::Other(error),
         }
     }
+
+    /// Checks if the integration was cancelled instead of failing
+    pub fn is_cancelled(&self) -> bool {
+        matches!(
+            self.code,
+            ErrorCode::Integration(IntegrationError::Cancelled)
+        )
+    }
 }
 
 impl From<IntegrationError> for Error {
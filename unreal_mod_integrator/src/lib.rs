@@ -1,13 +1,18 @@
 use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufReader, BufWriter, Cursor, Write};
+use std::io::{BufReader, BufWriter, Cursor, Read, Seek, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
 
 use error::IntegrationError;
-use log::debug;
+use log::{debug, warn};
 use serde_json::Value;
 
-use unreal_asset::engine_version::EngineVersion;
+use unreal_asset::engine_version::{get_object_versions, guess_engine_version, EngineVersion};
+use unreal_asset::object_version::ObjectVersion;
 use unreal_asset::properties::int_property::BytePropertyValue;
 use unreal_asset::types::FName;
 use unreal_asset::unversioned::Ancestry;
@@ -19,6 +24,7 @@ use unreal_asset::{
         struct_property::StructProperty,
         Property, PropertyDataTrait,
     },
+    reader::ArchiveTrait,
     Asset,
 };
 use unreal_mod_metadata::{Metadata, SyncMode};
@@ -26,6 +32,8 @@ use unreal_pak::{pakversion::PakVersion, PakMemory, PakReader};
 
 mod assets;
 pub mod error;
+pub mod metrics;
+pub use metrics::IntegrationMetrics;
 mod handlers;
 pub mod helpers;
 pub mod macros;
@@ -168,12 +176,22 @@ pub trait IntegratorConfig<'data, D, E: std::error::Error + 'static> {
 
     fn get_baked_mods(&self) -> Vec<IntegratorMod<E>>;
 
+    /// Abort integration on the first mod that fails to load instead of skipping it
+    fn fail_fast(&self) -> bool {
+        false
+    }
+
+    /// Maximum number of game and mod paks opened and indexed at once, 1 loads them one by one
+    fn pak_load_concurrency(&self) -> usize {
+        thread::available_parallelism().map_or(1, |e| e.get())
+    }
+
     const GAME_NAME: &'static str;
     const INTEGRATOR_VERSION: &'static str;
     const ENGINE_VERSION: EngineVersion;
 }
 
-fn bake_mod_data(asset: &mut Asset<Cursor<&[u8]>>, mods: &Vec<Metadata>) -> Result<(), Error> {
+fn bake_mod_data(asset: &mut Asset<Cursor<&[u8]>>, mods: &[Metadata]) -> Result<(), Error> {
     let data_table_export = asset
         .asset_data
         .exports
@@ -313,13 +331,94 @@ fn bake_mod_data(asset: &mut Asset<Cursor<&[u8]>>, mods: &Vec<Metadata>) -> Resu
     Ok(())
 }
 
+/// Create the ListOfMods data table asset with one row per mod
+///
+/// This is the asset the integrator writes to `Integrator/ListOfMods.uasset`,
+/// returned before writing so the generated rows can be inspected.
+pub fn bake_list_of_mods(
+    mods: &[Metadata],
+    engine_version: EngineVersion,
+) -> Result<Asset<Cursor<&'static [u8]>>, Error> {
+    #[cfg(not(feature = "no_bulk_data"))]
+    let list_of_mods_bulk = Some(LIST_OF_MODS_BULK);
+    #[cfg(feature = "no_bulk_data")]
+    let list_of_mods_bulk = None;
+
+    let mut list_of_mods = Asset::new(
+        Cursor::new(LIST_OF_MODS_ASSET),
+        list_of_mods_bulk.map(Cursor::new),
+        engine_version,
+        None,
+    )?;
+    check_template_engine_version(&list_of_mods, engine_version)?;
+    bake_mod_data(&mut list_of_mods, mods)?;
+
+    Ok(list_of_mods)
+}
+
+/// Check that a baked template was serialized for the engine version it was parsed with
+///
+/// Versioned templates are checked by the object versions they store. Unversioned templates
+/// are checked against the object versions of their mappings, or without versioned mappings by
+/// every export parsing, which only catches engine versions that serialize exports differently.
+fn check_template_engine_version<C: Read + Seek>(
+    asset: &Asset<C>,
+    engine_version: EngineVersion,
+) -> Result<(), Error> {
+    let expected_object_versions = get_object_versions(engine_version);
+
+    let mismatched = match asset.get_mappings() {
+        _ if !asset.asset_data.summary.unversioned => {
+            (asset.get_object_version(), asset.get_object_version_ue5()) != expected_object_versions
+        }
+        Some(mappings) if mappings.object_version != ObjectVersion::UNKNOWN => {
+            (mappings.object_version, mappings.object_version_ue5) != expected_object_versions
+        }
+        _ => asset
+            .asset_data
+            .exports
+            .iter()
+            .any(|export| matches!(export, Export::RawExport(_))),
+    };
+
+    if mismatched {
+        return Err(IntegrationError::engine_version_mismatch(
+            template_engine_version(asset),
+            engine_version,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Guess the engine version a template was made for
+fn template_engine_version<C: Read + Seek>(asset: &Asset<C>) -> EngineVersion {
+    match asset.get_mappings() {
+        _ if !asset.asset_data.summary.unversioned => asset.get_engine_version(),
+        Some(mappings) => guess_engine_version(
+            mappings.object_version,
+            mappings.object_version_ue5,
+            &mappings.custom_versions,
+        ),
+        None => EngineVersion::UNKNOWN,
+    }
+}
+
 fn bake_integrator_data(
     asset: &mut Asset<Cursor<&[u8]>>,
     integrator_version: String,
     refuse_mismatched_connections: bool,
 ) -> Result<(), Error> {
-    if asset.asset_data.exports.len() != 4 {
-        return Err(IntegrationError::corrupted_starter_pak().into());
+    const EXPECTED_EXPORT_COUNT: usize = 4;
+    const STATICS_EXPORT_NAME: &str = "Default__IntegratorStatics_BP_C";
+
+    if asset.asset_data.exports.len() != EXPECTED_EXPORT_COUNT {
+        return Err(IntegrationError::unexpected_export_count(
+            asset.asset_data.exports.len(),
+            EXPECTED_EXPORT_COUNT,
+        )
+        .into());
     }
 
     let properties: Vec<Property> = Vec::from([
@@ -345,12 +444,9 @@ fn bake_integrator_data(
         .asset_data
         .exports
         .iter_mut()
-        .find(|e| e.get_base_export().object_name == "Default__IntegratorStatics_BP_C");
-    if export.is_none() {
-        return Err(IntegrationError::corrupted_starter_pak().into());
-    }
+        .find(|e| e.get_base_export().object_name == STATICS_EXPORT_NAME)
+        .ok_or_else(|| IntegrationError::missing_export(STATICS_EXPORT_NAME.to_string()))?;
 
-    let export = export.unwrap();
     match export {
         Export::NormalExport(e) => {
             e.properties = properties;
@@ -360,6 +456,172 @@ fn bake_integrator_data(
     }
 }
 
+/// A mod that was left out of an integration because it failed to load
+#[derive(Debug)]
+pub struct SkippedMod {
+    /// Id of the skipped mod
+    pub mod_id: String,
+    /// Why the mod could not be loaded
+    pub reason: Error,
+}
+
+/// Result of a finished integration run
+#[derive(Debug, Default)]
+pub struct IntegrationOutcome {
+    /// Time spent in each integration phase
+    pub metrics: IntegrationMetrics,
+    /// Mods that failed to load and were skipped, see [`IntegratorConfig::fail_fast`]
+    pub skipped_mods: Vec<SkippedMod>,
+}
+
+/// Open a mod pak, read its metadata and check that the assets the integrator reads from it parse
+fn read_mod_pak(
+    mod_file: Result<File, Error>,
+    game_name: &str,
+    engine_version: EngineVersion,
+    metrics: &mut IntegrationMetrics,
+) -> Result<(PakReader<BufReader<File>>, Metadata), Error> {
+    let phase_start = Instant::now();
+    let mut pak = PakReader::new(BufReader::new(mod_file?));
+    pak.load_index()?;
+    metrics.pak_discovery += phase_start.elapsed();
+
+    let phase_start = Instant::now();
+    let record = pak.read_entry(&String::from("metadata.json"))?;
+    let metadata = unreal_mod_metadata::from_slice(&record)?;
+    metrics.metadata_read += phase_start.elapsed();
+
+    let phase_start = Instant::now();
+    check_mod_assets(
+        &mut pak,
+        &integrated_mod_assets(&metadata, game_name),
+        engine_version,
+    )?;
+    metrics.pak_discovery += phase_start.elapsed();
+
+    Ok((pak, metadata))
+}
+
+/// Names of the assets the integrator reads from a mod, its persistent actors and persistent actor maps
+///
+/// Assets only read by game specific handlers aren't known here and are not included.
+fn integrated_mod_assets(metadata: &Metadata, game_name: &str) -> Vec<String> {
+    let entries = |key: &str| {
+        metadata
+            .integrator
+            .get(key)
+            .and_then(|e| e.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.as_str())
+    };
+
+    // actors can be given as an object path, the asset is the package before the object name
+    let actors = entries("persistent_actors").filter_map(|actor| {
+        let package = actor.split_once('.').map_or(actor, |(package, _)| package);
+        unreal_helpers::game_to_absolute(game_name, package)
+    });
+    let maps = entries("persistent_actor_maps").map(|map| map.to_string());
+
+    actors.chain(maps).collect()
+}
+
+/// Parse the given assets of a mod pak, so a broken asset skips the mod instead of failing a handler later on
+///
+/// Assets that aren't in the pak are left to the game paks and are not checked.
+fn check_mod_assets(
+    pak: &mut PakReader<BufReader<File>>,
+    asset_names: &[String],
+    engine_version: EngineVersion,
+) -> Result<(), Error> {
+    for name in asset_names {
+        if !pak.contains_entry(name) {
+            continue;
+        }
+
+        let bulk_name = Path::new(name)
+            .with_extension("uexp")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let bulk = match pak.contains_entry(&bulk_name) {
+            true => Some(pak.read_entry(&bulk_name)?),
+            false => None,
+        };
+        let data = pak.read_entry(name)?;
+
+        Asset::new(
+            Cursor::new(data),
+            bulk.map(Cursor::new),
+            engine_version,
+            None,
+        )
+        .map_err(|err| IntegrationError::invalid_mod_asset(name.clone(), err))?;
+    }
+
+    Ok(())
+}
+
+/// Open a game pak and load its index
+fn read_game_pak(game_file: File) -> Result<PakReader<BufReader<File>>, Error> {
+    let mut pak = PakReader::new(BufReader::new(game_file));
+    pak.load_index()?;
+    Ok(pak)
+}
+
+/// Map `items` on up to `concurrency` threads, results are in the same order as `items`
+fn map_concurrent<I, R, F>(items: Vec<I>, concurrency: usize, f: F) -> Vec<R>
+where
+    I: Send,
+    R: Send,
+    F: Fn(I) -> R + Sync,
+{
+    let threads = concurrency.min(items.len());
+    if threads <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let len = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let mut results = thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        // the lock is released before running `f` so other workers can take items
+                        let next = queue.lock().unwrap().next();
+                        match next {
+                            Some((index, item)) => results.push((index, f(item))),
+                            None => break results,
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(len);
+        for worker in workers {
+            match worker.join() {
+                Ok(worker_results) => results.extend(worker_results),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+        results
+    });
+
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Return a cancelled error if cancellation was requested
+fn check_cancelled(cancel: &AtomicBool) -> Result<(), Error> {
+    match cancel.load(Ordering::Acquire) {
+        true => Err(IntegrationError::cancelled().into()),
+        false => Ok(()),
+    }
+}
+
 pub fn integrate_mods<
     'data,
     T: 'data,
@@ -371,7 +633,43 @@ pub fn integrate_mods<
     paks_path: &Path,
     game_path: &Path,
     refuse_mismatched_connections: bool,
-) -> Result<(), Error> {
+) -> Result<Vec<SkippedMod>, Error> {
+    integrate_mods_cancellable(
+        integrator_config,
+        mods,
+        paks_path,
+        game_path,
+        refuse_mismatched_connections,
+        &AtomicBool::new(false),
+    )
+    .map(|outcome| outcome.skipped_mods)
+}
+
+/// Integrate mods, stopping with a cancelled error once `cancel` is set
+///
+/// Cancellation is checked between mods and between integration steps.
+/// The integrator pak is only written once everything else is done,
+/// so a cancelled integration never leaves a partial integrator pak behind.
+///
+/// Mods whose pak or metadata can't be read, or whose persistent actors or maps don't parse,
+/// are skipped and returned with the phase timings, unless [`IntegratorConfig::fail_fast`] is set.
+pub fn integrate_mods_cancellable<
+    'data,
+    T: 'data,
+    E: 'static + std::error::Error + Send,
+    C: IntegratorConfig<'data, T, E>,
+>(
+    integrator_config: &C,
+    mods: &[IntegratorMod<E>],
+    paks_path: &Path,
+    game_path: &Path,
+    refuse_mismatched_connections: bool,
+    cancel: &AtomicBool,
+) -> Result<IntegrationOutcome, Error> {
+    let start = Instant::now();
+    let mut metrics = IntegrationMetrics::default();
+    let mut skipped_mods = Vec::new();
+
     debug!(
         "Integrating {} mods, refuse_mismatched_connections: {}",
         mods.len(),
@@ -386,11 +684,16 @@ pub fn integrate_mods<
             .any(|provided_mod| provided_mod.get_mod_id() == e.get_mod_id())
     });
 
-    let game_dir = fs::read_dir(game_path)?;
-    let game_files: Vec<File> = game_dir
+    // directory listing order is platform dependent, sort it to keep the game pak order stable
+    let mut game_pak_paths: Vec<PathBuf> = fs::read_dir(game_path)?
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().map(|e| e == "pak").unwrap_or(false))
-        .filter_map(|e| File::open(e.path()).ok())
+        .map(|e| e.path())
+        .filter(|e| e.extension().map(|e| e == "pak").unwrap_or(false))
+        .collect();
+    game_pak_paths.sort();
+    let game_files: Vec<File> = game_pak_paths
+        .iter()
+        .filter_map(|e| File::open(e).ok())
         .collect();
     if game_files.is_empty() {
         return Err(IntegrationError::game_not_found().into());
@@ -400,23 +703,51 @@ pub fn integrate_mods<
         .iter()
         .chain(core_mods)
         .chain(enabled_baked_mods)
-        .filter_map(|e| match e {
-            IntegratorMod::File(file_mod) => File::open(&file_mod.path).ok(),
-            IntegratorMod::Baked(baked_mod) => baked_mod.write(paks_path).ok(),
-            _ => None,
+        .filter_map(|e| {
+            let file = match e {
+                IntegratorMod::File(file_mod) => File::open(&file_mod.path).map_err(Error::from),
+                IntegratorMod::Baked(baked_mod) => baked_mod.write(paks_path),
+                _ => return None,
+            };
+            Some((e.get_mod_id(), file))
         })
         .collect::<Vec<_>>();
 
+    metrics.pak_discovery += start.elapsed();
+
+    let pak_load_concurrency = integrator_config.pak_load_concurrency();
+    let phase_start = Instant::now();
+    let loaded_mods = map_concurrent(mod_files, pak_load_concurrency, |(mod_id, mod_file)| {
+        let mut mod_metrics = IntegrationMetrics::default();
+        let mod_pak = check_cancelled(cancel).and_then(|_| {
+            read_mod_pak(mod_file, C::GAME_NAME, C::ENGINE_VERSION, &mut mod_metrics)
+        });
+        (mod_id, mod_pak, mod_metrics)
+    });
+    metrics.add_concurrent(
+        loaded_mods.iter().map(|(_, _, mod_metrics)| mod_metrics),
+        phase_start.elapsed(),
+    );
+
     let mut mod_paks = Vec::new();
     let mut read_mods = Vec::new();
     let mut optional_mods_data = HashMap::new();
 
-    for mod_file in mod_files {
-        let mut pak = PakReader::new(BufReader::new(mod_file));
-        pak.load_index()?;
-
-        let record = pak.read_entry(&String::from("metadata.json"))?;
-        let metadata = unreal_mod_metadata::from_slice(&record)?;
+    for (mod_id, mod_pak, _) in loaded_mods {
+        check_cancelled(cancel)?;
+
+        let (pak, metadata) = match mod_pak {
+            Ok(mod_pak) => mod_pak,
+            Err(err) if integrator_config.fail_fast() => return Err(err),
+            Err(err) => {
+                warn!("Skipping mod {mod_id}, failed to load: {err}");
+                skipped_mods.push(SkippedMod {
+                    mod_id,
+                    reason: err,
+                });
+                continue;
+            }
+        };
         read_mods.push(metadata.clone());
 
         debug!(
@@ -435,20 +766,10 @@ pub fn integrate_mods<
     }
 
     if !mods.is_empty() {
+        let phase_start = Instant::now();
         let mut generated_pak = PakMemory::new(PakVersion::FnameBasedCompressionMethod);
 
-        #[cfg(not(feature = "no_bulk_data"))]
-        let list_of_mods_bulk = Some(LIST_OF_MODS_BULK);
-        #[cfg(feature = "no_bulk_data")]
-        let list_of_mods_bulk = None;
-
-        let mut list_of_mods = Asset::new(
-            Cursor::new(LIST_OF_MODS_ASSET),
-            list_of_mods_bulk.map(Cursor::new),
-            C::ENGINE_VERSION,
-            None,
-        )?;
-        bake_mod_data(&mut list_of_mods, &read_mods)?;
+        let list_of_mods = bake_list_of_mods(&read_mods, C::ENGINE_VERSION)?;
         write_asset(
             &mut generated_pak,
             &list_of_mods,
@@ -466,6 +787,7 @@ pub fn integrate_mods<
             C::ENGINE_VERSION,
             None,
         )?;
+        check_template_engine_version(&integrator_statics, C::ENGINE_VERSION)?;
 
         bake_integrator_data(
             &mut integrator_statics,
@@ -487,12 +809,13 @@ pub fn integrate_mods<
             );
         }
 
-        let mut game_paks = Vec::new();
-        for game_file in game_files {
-            let mut pak = PakReader::new(BufReader::new(game_file));
-            pak.load_index()?;
-            game_paks.push(pak);
-        }
+        metrics.asset_baking = phase_start.elapsed();
+
+        let phase_start = Instant::now();
+        let mut game_paks = map_concurrent(game_files, pak_load_concurrency, read_game_pak)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        metrics.pak_discovery += phase_start.elapsed();
 
         let empty_vec: Vec<Value> = Vec::new();
 
@@ -508,6 +831,8 @@ pub fn integrate_mods<
             .get("persistent_actors")
             .unwrap_or(&empty_vec);
 
+        check_cancelled(cancel)?;
+        let phase_start = Instant::now();
         handle_persistent_actors(
             C::GAME_NAME,
             &persistent_actor_maps,
@@ -516,18 +841,28 @@ pub fn integrate_mods<
             &mut mod_paks,
             persistent_actors,
         )?;
+        metrics
+            .handlers
+            .push(("persistent_actors".to_owned(), phase_start.elapsed()));
 
         for dynamic_mod in mods.iter() {
             if let IntegratorMod::Dynamic(dynamic_mod) = dynamic_mod {
+                check_cancelled(cancel)?;
+                let phase_start = Instant::now();
                 dynamic_mod
                     .integrate(&mut generated_pak, &mut game_paks, &mut mod_paks)
                     .map_err(|e| Error::other(Box::new(e)))?;
+                metrics
+                    .handlers
+                    .push((dynamic_mod.get_mod_id(), phase_start.elapsed()));
             }
         }
 
         for (name, mut exec) in integrator_config.get_handlers() {
+            check_cancelled(cancel)?;
             let all_mods = optional_mods_data.get(&name).unwrap_or(&empty_vec);
 
+            let phase_start = Instant::now();
             exec(
                 integrator_config.get_data(),
                 &mut generated_pak,
@@ -536,18 +871,711 @@ pub fn integrate_mods<
                 all_mods,
             )
             .map_err(|e| Error::other(Box::new(e)))?;
+            metrics.handlers.push((name, phase_start.elapsed()));
         }
 
+        check_cancelled(cancel)?;
+
+        let phase_start = Instant::now();
+
+        // write to a temporary file first, so a failed write doesn't leave a partial integrator pak
         let path = Path::new(paks_path).join(INTEGRATOR_PAK_FILE_NAME);
-        let file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(path)?;
+        let temp_path = path.with_extension("pak.tmp");
+        let write_pak = || -> Result<(), Error> {
+            let file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(&temp_path)?;
+
+            let mut writer = BufWriter::new(file);
+            generated_pak.write(&mut writer)?;
+            writer.flush()?;
+            Ok(())
+        };
 
-        let mut writer = BufWriter::new(file);
-        generated_pak.write(&mut writer)?;
+        if let Err(err) = write_pak() {
+            let _ = fs::remove_file(&temp_path);
+            return Err(err);
+        }
+        fs::rename(&temp_path, path)?;
+        metrics.pak_write = phase_start.elapsed();
     }
 
-    Ok(())
+    metrics.total = start.elapsed();
+    Ok(IntegrationOutcome {
+        metrics,
+        skipped_mods,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs::{self, File};
+    use std::io::{self, BufReader, Cursor};
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Barrier;
+    use std::thread;
+    use std::time::Duration;
+
+    use unreal_asset::containers::IndexedMap;
+    use unreal_asset::engine_version::EngineVersion;
+    use unreal_asset::exports::Export;
+    use unreal_asset::object_version::{ObjectVersion, ObjectVersionUE5};
+    use unreal_asset::properties::int_property::{ByteProperty, BytePropertyValue};
+    use unreal_asset::properties::Property;
+    use unreal_asset::reader::ArchiveTrait;
+    use unreal_asset::unversioned::{
+        EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion,
+    };
+    use unreal_asset::Asset;
+    use unreal_pak::{pakversion::PakVersion, PakReader, PakWriter};
+
+    use crate::error::{Error, IntegrationError};
+    use crate::{FileMod, HandlerFn, IntegratorConfig, IntegratorMod, INTEGRATOR_PAK_FILE_NAME};
+
+    use super::{
+        bake_integrator_data, bake_list_of_mods, check_template_engine_version,
+        integrate_mods_cancellable, map_concurrent, read_mod_pak,
+    };
+    use crate::metrics::IntegrationMetrics;
+
+    macro_rules! test_asset {
+        ($path:literal) => {
+            include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../unreal_asset/tests/assets/",
+                $path
+            ))
+        };
+    }
+
+    // an unversioned 4.26 asset
+    const UNVERSIONED_ASSET: &[u8] = test_asset!("general/Misc_426/RaceSimDataAsset.uasset");
+    const UNVERSIONED_BULK: &[u8] = test_asset!("general/Misc_426/RaceSimDataAsset.uexp");
+
+    const VERSIONED_ASSET: &[u8] =
+        test_asset!("general/Versioned/Assault_M1A1Thompson_WW2_DrumSuppressor.uasset");
+
+    // a 4.23 actor blueprint without bulk data
+    const ACTOR_ASSET: &[u8] = test_asset!("general/Astroneer_prebulk/Augment_BroadBrush.uasset");
+
+    struct TestConfig;
+
+    impl<'data> IntegratorConfig<'data, (), io::Error> for TestConfig {
+        fn get_data(&self) -> &'data () {
+            &()
+        }
+
+        fn get_handlers(&self) -> HashMap<String, Box<HandlerFn<(), io::Error>>> {
+            HashMap::new()
+        }
+
+        fn get_baked_mods(&self) -> Vec<IntegratorMod<io::Error>> {
+            Vec::new()
+        }
+
+        const GAME_NAME: &'static str = "TestGame";
+        const INTEGRATOR_VERSION: &'static str = "0.1.0";
+        const ENGINE_VERSION: EngineVersion = EngineVersion::VER_UE4_23;
+    }
+
+    /// [`TestConfig`] loading paks with the given concurrency
+    struct ConcurrencyConfig(usize);
+
+    impl<'data> IntegratorConfig<'data, (), io::Error> for ConcurrencyConfig {
+        fn get_data(&self) -> &'data () {
+            &()
+        }
+
+        fn get_handlers(&self) -> HashMap<String, Box<HandlerFn<(), io::Error>>> {
+            HashMap::new()
+        }
+
+        fn get_baked_mods(&self) -> Vec<IntegratorMod<io::Error>> {
+            Vec::new()
+        }
+
+        fn pak_load_concurrency(&self) -> usize {
+            self.0
+        }
+
+        const GAME_NAME: &'static str = TestConfig::GAME_NAME;
+        const INTEGRATOR_VERSION: &'static str = TestConfig::INTEGRATOR_VERSION;
+        const ENGINE_VERSION: EngineVersion = TestConfig::ENGINE_VERSION;
+    }
+
+    fn mappings(object_version: ObjectVersion) -> Usmap {
+        Usmap {
+            version: EUsmapVersion::Latest,
+            name_map: Vec::new(),
+            enum_map: IndexedMap::new(),
+            schemas: IndexedMap::new(),
+            extension_version: UsmapExtensionVersion::NONE,
+            object_version,
+            object_version_ue5: ObjectVersionUE5::UNKNOWN,
+            custom_versions: Vec::new(),
+            compression_method: EUsmapCompressionMethod::None,
+            net_cl: 0,
+        }
+    }
+
+    fn unversioned_asset(
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Asset<Cursor<&'static [u8]>> {
+        Asset::new(
+            Cursor::new(UNVERSIONED_ASSET),
+            Some(Cursor::new(UNVERSIONED_BULK)),
+            engine_version,
+            mappings,
+        )
+        .unwrap()
+    }
+
+    fn assert_mismatch(result: Result<(), Error>, found: EngineVersion, expected: EngineVersion) {
+        assert_eq!(
+            result.map_err(|e| e.to_string()),
+            Err(IntegrationError::engine_version_mismatch(found, expected).to_string())
+        );
+    }
+
+    #[test]
+    fn versioned_template_engine_version() {
+        let asset = Asset::new(
+            Cursor::new(VERSIONED_ASSET),
+            None,
+            EngineVersion::UNKNOWN,
+            None,
+        )
+        .unwrap();
+
+        assert_mismatch(
+            check_template_engine_version(&asset, EngineVersion::VER_UE4_15),
+            asset.get_engine_version(),
+            EngineVersion::VER_UE4_15,
+        );
+    }
+
+    #[test]
+    fn unversioned_template_engine_version_from_mappings() {
+        let asset = unversioned_asset(
+            EngineVersion::VER_UE4_27,
+            Some(mappings(ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG)),
+        );
+        assert!(check_template_engine_version(&asset, EngineVersion::VER_UE4_27).is_ok());
+
+        // mappings of a 4.23 game, the asset itself parses with 4.23 as well
+        let asset = unversioned_asset(
+            EngineVersion::VER_UE4_23,
+            Some(mappings(ObjectVersion::VER_UE4_FIX_WIDE_STRING_CRC)),
+        );
+        assert!(check_template_engine_version(&asset, EngineVersion::VER_UE4_23).is_ok());
+
+        let asset = unversioned_asset(
+            EngineVersion::VER_UE4_23,
+            Some(mappings(ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG)),
+        );
+        assert_mismatch(
+            check_template_engine_version(&asset, EngineVersion::VER_UE4_23),
+            EngineVersion::VER_UE4_27,
+            EngineVersion::VER_UE4_23,
+        );
+    }
+
+    #[test]
+    fn unversioned_template_engine_version_without_mappings() {
+        let asset = unversioned_asset(EngineVersion::VER_UE4_27, None);
+        assert!(check_template_engine_version(&asset, EngineVersion::VER_UE4_27).is_ok());
+
+        // 4.15 reads the export differently, so it can't be parsed
+        let asset = unversioned_asset(EngineVersion::VER_UE4_15, None);
+        assert_mismatch(
+            check_template_engine_version(&asset, EngineVersion::VER_UE4_15),
+            EngineVersion::UNKNOWN,
+            EngineVersion::VER_UE4_15,
+        );
+    }
+
+    fn actor_asset() -> Asset<Cursor<&'static [u8]>> {
+        Asset::new(
+            Cursor::new(ACTOR_ASSET),
+            None,
+            TestConfig::ENGINE_VERSION,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bake_integrator_data_export_count() {
+        let mut asset = actor_asset();
+        asset.asset_data.exports.truncate(1);
+
+        let result = bake_integrator_data(&mut asset, "0.1.0".to_string(), false);
+        assert_eq!(
+            result.map_err(|e| e.to_string()),
+            Err(IntegrationError::unexpected_export_count(1, 4).to_string())
+        );
+    }
+
+    #[test]
+    fn bake_integrator_data_missing_export() {
+        // the right number of exports, but none of them is the statics CDO
+        let mut asset = actor_asset();
+        let export = asset.asset_data.exports[0].clone();
+        asset.asset_data.exports.resize(4, export);
+
+        let result = bake_integrator_data(&mut asset, "0.1.0".to_string(), false);
+        assert_eq!(
+            result.map_err(|e| e.to_string()),
+            Err(
+                IntegrationError::missing_export("Default__IntegratorStatics_BP_C".to_string())
+                    .to_string()
+            )
+        );
+    }
+
+    /// Create empty game and mods directories for an integration test, the game directory has one game pak
+    fn integration_dirs(name: &str) -> (PathBuf, PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "unreal_mod_integrator_{name}_{}",
+            std::process::id()
+        ));
+        let game_path = dir.join("Game");
+        let paks_path = dir.join("Mods");
+        fs::create_dir_all(&game_path).unwrap();
+        fs::create_dir_all(&paks_path).unwrap();
+
+        let mut writer = PakWriter::new(
+            File::create(game_path.join("TestGame-WindowsNoEditor.pak")).unwrap(),
+            PakVersion::FnameBasedCompressionMethod,
+        );
+        writer
+            .write_entry(
+                &"TestGame/Content/A.uasset".to_string(),
+                &vec![1u8; 16],
+                false,
+            )
+            .unwrap();
+        writer.finish_write().unwrap();
+
+        (dir, game_path, paks_path)
+    }
+
+    #[test]
+    fn integration_metrics() {
+        let (dir, game_path, paks_path) = integration_dirs("metrics");
+
+        let outcome = integrate_mods_cancellable(
+            &TestConfig,
+            &[],
+            &paks_path,
+            &game_path,
+            false,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        // without mods only the game paks are discovered, nothing is baked or written
+        let metrics = outcome.metrics;
+        assert!(!metrics.pak_discovery.is_zero());
+        assert!(metrics.asset_baking.is_zero());
+        assert!(metrics.handlers.is_empty());
+        assert!(metrics.pak_write.is_zero());
+        assert!(metrics.total >= metrics.pak_discovery);
+        assert_eq!(metrics.phases().len(), 4);
+    }
+
+    fn write_mod_pak(
+        path: &Path,
+        mod_id: &str,
+        persistent_actors: &[&str],
+        assets: &[(&str, &[u8])],
+    ) {
+        let metadata = serde_json::json!({
+            "schema_version": 2,
+            "name": mod_id,
+            "mod_id": mod_id,
+            "version": "1.0.0",
+            "integrator": { "persistent_actors": persistent_actors },
+        });
+
+        let mut writer = PakWriter::new(
+            File::create(path).unwrap(),
+            PakVersion::FnameBasedCompressionMethod,
+        );
+        writer
+            .write_entry(
+                &"metadata.json".to_string(),
+                &serde_json::to_vec(&metadata).unwrap(),
+                false,
+            )
+            .unwrap();
+        for (name, data) in assets {
+            writer
+                .write_entry(&name.to_string(), &data.to_vec(), false)
+                .unwrap();
+        }
+        writer.finish_write().unwrap();
+    }
+
+    /// Write a mod with one persistent actor, the actor asset is cut in half if `broken` is set
+    fn write_actor_mod(paks_path: &Path, mod_id: &str, broken: bool) -> FileMod {
+        let path = paks_path.join(format!("000-{mod_id}-1.0.0_P.pak"));
+        let actor = match broken {
+            true => &ACTOR_ASSET[..ACTOR_ASSET.len() / 2],
+            false => ACTOR_ASSET,
+        };
+        write_mod_pak(
+            &path,
+            mod_id,
+            &[&format!("/Game/Mods/{mod_id}/Actor")],
+            &[(
+                &format!("TestGame/Content/Mods/{mod_id}/Actor.uasset"),
+                actor,
+            )],
+        );
+
+        FileMod {
+            path,
+            mod_id: mod_id.to_string(),
+            priority: 0,
+        }
+    }
+
+    /// Read the ids of the mods in the ListOfMods table of an integrator pak
+    fn integrated_mod_ids(paks_path: &Path) -> Vec<String> {
+        let mut pak = PakReader::new(BufReader::new(
+            File::open(paks_path.join(INTEGRATOR_PAK_FILE_NAME)).unwrap(),
+        ));
+        pak.load_index().unwrap();
+        let name = "TestGame/Content/Integrator/ListOfMods".to_string();
+        let asset = Asset::new(
+            Cursor::new(pak.read_entry(&(name.clone() + ".uasset")).unwrap()),
+            Some(Cursor::new(pak.read_entry(&(name + ".uexp")).unwrap())),
+            TestConfig::ENGINE_VERSION,
+            None,
+        )
+        .unwrap();
+
+        asset
+            .asset_data
+            .exports
+            .iter()
+            .find_map(|e| match e {
+                Export::DataTableExport(e) => Some(&e.table.data),
+                _ => None,
+            })
+            .unwrap()
+            .iter()
+            .map(|row| row.name.get_owned_content())
+            .collect()
+    }
+
+    #[test]
+    fn read_mod_pak_checks_assets() {
+        let dir = std::env::temp_dir().join(format!(
+            "unreal_mod_integrator_read_mod_pak_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+
+        let valid = write_actor_mod(&dir, "ValidMod", false);
+        let broken = write_actor_mod(&dir, "BrokenMod", true);
+        // the same broken asset, but not read by the integrator
+        let unused_path = dir.join("002-UnusedAssetMod-1.0.0_P.pak");
+        write_mod_pak(
+            &unused_path,
+            "UnusedAssetMod",
+            &[],
+            &[(
+                "TestGame/Content/Asset.uasset",
+                &ACTOR_ASSET[..ACTOR_ASSET.len() / 2],
+            )],
+        );
+
+        let mut metrics = IntegrationMetrics::default();
+        let (_, metadata) = read_mod_pak(
+            File::open(&valid.path).map_err(Error::from),
+            TestConfig::GAME_NAME,
+            TestConfig::ENGINE_VERSION,
+            &mut metrics,
+        )
+        .unwrap();
+        assert_eq!(metadata.mod_id, "ValidMod");
+
+        // the mod is skipped because of its broken asset, not the whole integration
+        let err = read_mod_pak(
+            File::open(&broken.path).map_err(Error::from),
+            TestConfig::GAME_NAME,
+            TestConfig::ENGINE_VERSION,
+            &mut metrics,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Failed to parse mod asset \"TestGame/Content/Mods/BrokenMod/Actor.uasset\""
+        ));
+
+        // assets the integrator doesn't read are left alone
+        let (_, metadata) = read_mod_pak(
+            File::open(&unused_path).map_err(Error::from),
+            TestConfig::GAME_NAME,
+            TestConfig::ENGINE_VERSION,
+            &mut metrics,
+        )
+        .unwrap();
+        assert_eq!(metadata.mod_id, "UnusedAssetMod");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn integrate_skips_broken_mod() {
+        let (dir, game_path, paks_path) = integration_dirs("skip_broken_mod");
+
+        let mods: Vec<IntegratorMod<io::Error>> = vec![
+            write_actor_mod(&paks_path, "FirstMod", false).into(),
+            write_actor_mod(&paks_path, "BrokenMod", true).into(),
+            write_actor_mod(&paks_path, "ThirdMod", false).into(),
+        ];
+
+        let outcome = integrate_mods_cancellable(
+            &TestConfig,
+            &mods,
+            &paks_path,
+            &game_path,
+            false,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let mod_ids = integrated_mod_ids(&paks_path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let skipped = outcome
+            .skipped_mods
+            .iter()
+            .map(|e| e.mod_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(skipped, ["BrokenMod"]);
+        assert_eq!(mod_ids, ["FirstMod", "ThirdMod"]);
+    }
+
+    #[test]
+    fn integrate_concurrency_keeps_order() {
+        let (dir, game_path, paks_path) = integration_dirs("concurrency");
+
+        let mod_ids = (0..8).map(|i| format!("Mod{i}")).collect::<Vec<_>>();
+        let mods: Vec<IntegratorMod<io::Error>> = mod_ids
+            .iter()
+            .map(|mod_id| write_actor_mod(&paks_path, mod_id, false).into())
+            .collect();
+
+        let mut outputs = Vec::new();
+        for concurrency in [1, 2, mods.len()] {
+            let outcome = integrate_mods_cancellable(
+                &ConcurrencyConfig(concurrency),
+                &mods,
+                &paks_path,
+                &game_path,
+                false,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+
+            // concurrently loaded paks don't add up to more than the whole run took
+            let metrics = outcome.metrics;
+            let phases = metrics
+                .phases()
+                .into_iter()
+                .map(|(_, e)| e)
+                .sum::<Duration>();
+            assert!(phases <= metrics.total, "{phases:?} > {:?}", metrics.total);
+
+            outputs.push((
+                integrated_mod_ids(&paks_path),
+                fs::read(paks_path.join(INTEGRATOR_PAK_FILE_NAME)).unwrap(),
+            ));
+        }
+        fs::remove_dir_all(&dir).unwrap();
+
+        // mods are integrated in the order they were passed in, no matter how many paks load at once
+        assert_eq!(outputs[0].0, mod_ids);
+        for output in &outputs[1..] {
+            assert_eq!(output.0, outputs[0].0);
+            assert!(output.1 == outputs[0].1, "integrator paks differ");
+        }
+    }
+
+    #[test]
+    fn integrate_cancelled() {
+        let (dir, game_path, paks_path) = integration_dirs("cancelled");
+
+        let mods: Vec<IntegratorMod<io::Error>> =
+            vec![write_actor_mod(&paks_path, "FirstMod", false).into()];
+
+        let result = integrate_mods_cancellable(
+            &TestConfig,
+            &mods,
+            &paks_path,
+            &game_path,
+            false,
+            &AtomicBool::new(true),
+        );
+        let written = fs::read_dir(&paks_path)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(result.unwrap_err().is_cancelled());
+        // only the mod pak is left, neither an integrator pak nor its temporary file was written
+        assert_eq!(written, ["000-FirstMod-1.0.0_P.pak"]);
+    }
+
+    #[test]
+    fn bake_list_of_mods_rows() {
+        let mods = [
+            r#"{"schema_version": 2, "name": "First Mod", "mod_id": "FirstMod", "author": "Someone", "version": "1.0.0", "sync": "serverclient"}"#,
+            r#"{"schema_version": 2, "name": "Second Mod", "mod_id": "SecondMod", "version": "0.2.0", "sync": "client"}"#,
+        ]
+        .map(|metadata| unreal_mod_metadata::from_slice(metadata.as_bytes()).unwrap());
+
+        let asset = bake_list_of_mods(&mods, TestConfig::ENGINE_VERSION).unwrap();
+
+        // the baked rows survive writing and re-reading the asset
+        let mut asset_cursor = Cursor::new(Vec::new());
+        let mut bulk_cursor = Cursor::new(Vec::new());
+        asset
+            .write_data(&mut asset_cursor, Some(&mut bulk_cursor))
+            .unwrap();
+        let asset = Asset::new(
+            Cursor::new(asset_cursor.into_inner()),
+            Some(Cursor::new(bulk_cursor.into_inner())),
+            TestConfig::ENGINE_VERSION,
+            None,
+        )
+        .unwrap();
+
+        let table = asset
+            .asset_data
+            .exports
+            .iter()
+            .find_map(|e| match e {
+                Export::DataTableExport(e) => Some(&e.table.data),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(table.len(), 2);
+
+        let rows = table
+            .iter()
+            .map(|row| {
+                let values = row
+                    .value
+                    .iter()
+                    .map(|property| match property {
+                        Property::StrProperty(e) => e.value.clone().unwrap_or_default(),
+                        Property::ByteProperty(ByteProperty {
+                            value: BytePropertyValue::FName(e),
+                            ..
+                        }) => e.get_owned_content(),
+                        Property::BoolProperty(e) => e.value.to_string(),
+                        _ => panic!("Unexpected property {property:?}"),
+                    })
+                    .collect::<Vec<_>>();
+                (row.name.get_owned_content(), values)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    "FirstMod".to_owned(),
+                    vec![
+                        "First Mod",
+                        "Someone",
+                        "",
+                        "1.0.0",
+                        "",
+                        "SyncMode::NewEnumerator3",
+                        "",
+                        "true"
+                    ]
+                    .into_iter()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+                ),
+                (
+                    "SecondMod".to_owned(),
+                    vec![
+                        "Second Mod",
+                        "",
+                        "",
+                        "0.2.0",
+                        "",
+                        "SyncMode::NewEnumerator1",
+                        "",
+                        "true"
+                    ]
+                    .into_iter()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn map_concurrent_keeps_order() {
+        // later items finish first
+        let items = (0..8u64).collect::<Vec<_>>();
+        let results = map_concurrent(items, 4, |item| {
+            thread::sleep(Duration::from_millis((8 - item) * 5));
+            item * 2
+        });
+        assert_eq!(results, (0..8).map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn map_concurrent_without_threads() {
+        // 0 and 1 both run every item on the calling thread
+        let caller = thread::current().id();
+        for concurrency in [0, 1] {
+            let results = map_concurrent(vec![1, 2, 3], concurrency, |item| {
+                assert_eq!(thread::current().id(), caller);
+                item + 1
+            });
+            assert_eq!(results, vec![2, 3, 4]);
+        }
+
+        let results = map_concurrent(Vec::<i32>::new(), 4, |item| item);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn map_concurrent_runs_in_parallel() {
+        // both items have to run at the same time to get past the barrier
+        let barrier = Barrier::new(2);
+        let results = map_concurrent(vec![1, 2], 2, |item| {
+            barrier.wait();
+            item
+        });
+        assert_eq!(results, vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "worker panic")]
+    fn map_concurrent_worker_panic() {
+        map_concurrent(vec![1, 2, 3, 4], 2, |item| {
+            if item == 3 {
+                panic!("worker panic");
+            }
+            item
+        });
+    }
 }
@@ -0,0 +1,128 @@
+//! Timings of the individual integration phases
+
+use std::time::Duration;
+
+/// Time spent in each phase of an integration run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IntegrationMetrics {
+    /// Listing game paks, opening mod files, loading pak indices and checking that mod assets parse
+    ///
+    /// Mod paks are loaded concurrently, their load times are summed up and scaled down
+    /// to the time loading took if they overlap.
+    pub pak_discovery: Duration,
+    /// Reading `metadata.json` of every mod, summed up over all mods like the mod pak load times
+    pub metadata_read: Duration,
+    /// Baking the list of mods and the integrator statics assets
+    pub asset_baking: Duration,
+    /// Time spent in each handler, in the order they ran
+    pub handlers: Vec<(String, Duration)>,
+    /// Writing the integrator pak
+    pub pak_write: Duration,
+    /// Whole integration run
+    pub total: Duration,
+}
+
+impl IntegrationMetrics {
+    /// All phases with their name, handlers are listed individually
+    pub fn phases(&self) -> Vec<(String, Duration)> {
+        let mut phases = vec![
+            ("pak_discovery".to_owned(), self.pak_discovery),
+            ("metadata_read".to_owned(), self.metadata_read),
+            ("asset_baking".to_owned(), self.asset_baking),
+        ];
+        phases.extend(
+            self.handlers
+                .iter()
+                .map(|(name, duration)| (format!("handler:{name}"), *duration)),
+        );
+        phases.push(("pak_write".to_owned(), self.pak_write));
+        phases
+    }
+
+    /// Adds the pak discovery and metadata read times of mods that were loaded concurrently in `wall_time`
+    pub(crate) fn add_concurrent<'a>(
+        &mut self,
+        mods: impl IntoIterator<Item = &'a IntegrationMetrics>,
+        wall_time: Duration,
+    ) {
+        let (mut pak_discovery, mut metadata_read) = (Duration::ZERO, Duration::ZERO);
+        for metrics in mods {
+            pak_discovery += metrics.pak_discovery;
+            metadata_read += metrics.metadata_read;
+        }
+
+        // overlapping loads would add up to more than the phases took
+        let summed = pak_discovery + metadata_read;
+        if summed > wall_time {
+            pak_discovery = pak_discovery
+                .mul_f64(wall_time.as_secs_f64() / summed.as_secs_f64())
+                .min(wall_time);
+            metadata_read = wall_time - pak_discovery;
+        }
+
+        self.pak_discovery += pak_discovery;
+        self.metadata_read += metadata_read;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::IntegrationMetrics;
+
+    #[test]
+    fn phases() {
+        let metrics = IntegrationMetrics {
+            pak_discovery: Duration::from_millis(1),
+            metadata_read: Duration::from_millis(2),
+            asset_baking: Duration::from_millis(3),
+            handlers: vec![
+                ("persistent_actors".to_owned(), Duration::from_millis(4)),
+                ("mission_trailheads".to_owned(), Duration::from_millis(5)),
+            ],
+            pak_write: Duration::from_millis(6),
+            total: Duration::from_millis(21),
+        };
+
+        assert_eq!(
+            metrics.phases(),
+            vec![
+                ("pak_discovery".to_owned(), Duration::from_millis(1)),
+                ("metadata_read".to_owned(), Duration::from_millis(2)),
+                ("asset_baking".to_owned(), Duration::from_millis(3)),
+                (
+                    "handler:persistent_actors".to_owned(),
+                    Duration::from_millis(4)
+                ),
+                (
+                    "handler:mission_trailheads".to_owned(),
+                    Duration::from_millis(5)
+                ),
+                ("pak_write".to_owned(), Duration::from_millis(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn add_concurrent() {
+        let loaded = |pak_discovery, metadata_read| IntegrationMetrics {
+            pak_discovery: Duration::from_millis(pak_discovery),
+            metadata_read: Duration::from_millis(metadata_read),
+            ..Default::default()
+        };
+        let mods = [loaded(30, 10), loaded(30, 10)];
+
+        // sequential loads are added as they are
+        let mut metrics = IntegrationMetrics::default();
+        metrics.add_concurrent(&mods, Duration::from_millis(100));
+        assert_eq!(metrics.pak_discovery, Duration::from_millis(60));
+        assert_eq!(metrics.metadata_read, Duration::from_millis(20));
+
+        // overlapping loads are scaled down to the time loading took
+        let mut metrics = IntegrationMetrics::default();
+        metrics.add_concurrent(&mods, Duration::from_millis(40));
+        assert_eq!(metrics.pak_discovery, Duration::from_millis(30));
+        assert_eq!(metrics.metadata_read, Duration::from_millis(10));
+    }
+}
@@ -17,8 +17,8 @@ use log::info;
 use parking_lot::Mutex;
 use semver::Version;
 
-use crate::egui::ViewportCommand;
 use crate::background_work::BackgroundThreadMessage;
+use crate::egui::ViewportCommand;
 use crate::error::{ModLoaderError, ModLoaderWarning};
 use crate::game_mod::{GameMod, SelectedVersion};
 use crate::mod_processing::dependencies::DependencyGraph;
@@ -190,7 +190,7 @@ impl App for ModLoaderApp {
                             self.platform_selector_open = false;
                             let _ = self
                                 .background_tx
-                                .send(BackgroundThreadMessage::integrate());
+                                .send(BackgroundThreadMessage::integrate(&data.integration_cancel));
                             ctx.request_repaint();
                         }
                     }
@@ -229,9 +229,9 @@ impl App for ModLoaderApp {
                     .background_tx
                     .send(BackgroundThreadMessage::RemoveMod(id.clone()));
                 self.selected_mod_id = None;
-                let _ = self
-                    .background_tx
-                    .send(BackgroundThreadMessage::integrate());
+                let _ = self.background_tx.send(BackgroundThreadMessage::integrate(
+                    &self.data.lock().integration_cancel,
+                ));
             }
         }
 
@@ -241,7 +241,7 @@ impl App for ModLoaderApp {
             if self.ready_exit.load(Ordering::Acquire) {
                 info!("Exiting...");
             }
-    
+
             self.ready_exit.load(Ordering::Acquire);
         }
 
@@ -365,6 +365,7 @@ impl ModLoaderApp {
                 // this is safe because we are getting mut references to different struct fields
                 let dependency_graph = &data.dependency_graph as *const Option<DependencyGraph>;
                 let warnings = &mut data.warnings as *mut Vec<ModLoaderWarning>;
+                let integration_cancel = data.integration_cancel.clone();
 
                 for (mod_id, game_mod) in data.game_mods.iter_mut() {
                     body.row(18.0, |mut row| {
@@ -387,7 +388,7 @@ impl ModLoaderApp {
                                 }
                                 let _ = self
                                     .background_tx
-                                    .send(BackgroundThreadMessage::integrate());
+                                    .send(BackgroundThreadMessage::integrate(&integration_cancel));
                             };
                         });
                         row.col(|ui| {
@@ -402,9 +403,9 @@ impl ModLoaderApp {
 
                                 // this may look dumb but is what is needed
                                 if prev_selected != game_mod.selected_version {
-                                    let _ = self
-                                        .background_tx
-                                        .send(BackgroundThreadMessage::integrate());
+                                    let _ = self.background_tx.send(
+                                        BackgroundThreadMessage::integrate(&integration_cancel),
+                                    );
                                 }
                             });
                         });
@@ -445,7 +446,10 @@ impl ModLoaderApp {
             .show_ui(ui, |ui| {
                 // for when there is an Index file show force latest version, this to diecrtly indicate that there
                 // is the possibility of an auto update vie an index file.
-                if game_mod.download.is_some() && game_mod.download.as_ref().unwrap().download_mode == unreal_mod_metadata::DownloadMode::IndexFile {
+                if game_mod.download.is_some()
+                    && game_mod.download.as_ref().unwrap().download_mode
+                        == unreal_mod_metadata::DownloadMode::IndexFile
+                {
                     let latest_version = game_mod.latest_version.clone().unwrap();
                     ui.selectable_value(
                         &mut game_mod.selected_version,
@@ -551,7 +555,29 @@ impl ModLoaderApp {
                     {
                         let _ = self
                             .background_tx
-                            .send(BackgroundThreadMessage::integrate());
+                            .send(BackgroundThreadMessage::integrate(&data.integration_cancel));
+                    };
+
+                    #[cfg(feature = "cpp_loader")]
+                    if ui
+                        .checkbox(&mut data.cpp_loader_enabled, "Load C++ mods")
+                        .on_hover_text("Disable if DLL injection is blocked, e.g. by anti-cheat")
+                        .changed()
+                    {
+                        let _ = self
+                            .background_tx
+                            .send(BackgroundThreadMessage::integrate(&data.integration_cancel));
+                    };
+
+                    if ui
+                        .checkbox(&mut data.write_mod_report, "Write mod report")
+                        .on_hover_text("Write mod_report.json next to the integrated pak")
+                        .changed()
+                    {
+                        // the report is written by the next integration, no need to start one now
+                        let _ = self
+                            .background_tx
+                            .send(BackgroundThreadMessage::WriteConfig);
                     };
 
                     ui.label(format!(
@@ -654,9 +680,9 @@ impl ModLoaderApp {
             let _ = self
                 .background_tx
                 .send(BackgroundThreadMessage::Import(files_to_import));
-            let _ = self
-                .background_tx
-                .send(BackgroundThreadMessage::integrate());
+            let _ = self.background_tx.send(BackgroundThreadMessage::integrate(
+                &self.data.lock().integration_cancel,
+            ));
         }
     }
 
@@ -872,9 +898,11 @@ impl ModLoaderApp {
                                                         }
                                                     }
 
-                                                    let _ = self
-                                                        .background_tx
-                                                        .send(BackgroundThreadMessage::integrate());
+                                                    let _ = self.background_tx.send(
+                                                        BackgroundThreadMessage::integrate(
+                                                            &data.integration_cancel,
+                                                        ),
+                                                    );
                                                 }
 
                                                 if ui.button("Delete").clicked() {
@@ -958,8 +986,8 @@ impl ModLoaderApp {
                     egui::ScrollArea::vertical().show(ui, |ui| {
                         for untrusted_mod in &data.untrusted_mods {
                             ui.label(format!(
-                                "{} version {}",
-                                untrusted_mod.name, untrusted_mod.version
+                                "{} version {} ({})",
+                                untrusted_mod.name, untrusted_mod.version, untrusted_mod.reason
                             ));
                         }
                     });
@@ -975,10 +1003,15 @@ impl ModLoaderApp {
                             let untrusted_mods = data
                                 .untrusted_mods
                                 .iter()
-                                .map(|e| e.hash.clone())
+                                .map(|e| (e.hash.clone(), e.name.clone()))
                                 .collect::<Vec<_>>();
 
-                            data.trusted_mods.extend(untrusted_mods);
+                            for (hash, mod_id) in untrusted_mods {
+                                data.trusted_mods.push(hash);
+                                if !data.trusted_mod_ids.contains(&mod_id) {
+                                    data.trusted_mod_ids.push(mod_id);
+                                }
+                            }
                             data.untrusted_mods.clear();
 
                             let _ = self
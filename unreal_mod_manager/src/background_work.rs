@@ -11,14 +11,14 @@ use std::sync::{
 };
 use std::time::Instant;
 
-use directories::BaseDirs;
 use log::{debug, error, warn};
 use parking_lot::Mutex;
 use semver::Version;
 use sha2::{Digest, Sha256};
 
 use unreal_mod_integrator::{
-    integrate_mods, FileMod, IntegratorConfig, IntegratorModInfo, INTEGRATOR_PAK_FILE_NAME,
+    error::IntegrationError, integrate_mods_cancellable, FileMod, IntegratorConfig,
+    IntegratorModInfo, INTEGRATOR_PAK_FILE_NAME,
 };
 use unreal_mod_metadata::Metadata;
 use unreal_pak::PakReader;
@@ -26,12 +26,15 @@ use unreal_pak::PakReader;
 use crate::config;
 use crate::error::{ModLoaderError, ModLoaderWarning};
 use crate::game_mod::{self, GameModVersion};
-use crate::mod_config::{load_config, write_config};
+use crate::mod_config::{load_config, write_config, FileConfigStore, CONFIG_FILE_NAME};
 use crate::mod_processing::{
     dependencies::{DependencyGraph, ModWithDependencies},
     index_file::{download_index_file, IndexFileModVersion},
+    preflight::clear_paks_dir,
     process_modfiles,
+    trust::evaluate_trust,
 };
+use crate::mod_report::{write_mod_report, ModReport, ModReportEntry};
 use crate::update_info::UpdateInfo;
 use crate::FileToProcess;
 use crate::ModLoaderAppData;
@@ -61,11 +64,64 @@ pub(crate) enum BackgroundThreadMessage {
 }
 
 impl BackgroundThreadMessage {
-    pub fn integrate() -> Self {
+    /// Request an integration
+    ///
+    /// `integration_cancel` is the flag of the running integration, see [`ModLoaderAppData::start_integration`].
+    /// A newer integration makes the result of the running one obsolete, so it stops at its next mod or step.
+    pub fn integrate(integration_cancel: &AtomicBool) -> Self {
+        integration_cancel.store(true, Ordering::Release);
         BackgroundThreadMessage::Integrate(Instant::now())
     }
 }
 
+fn check_integration_cancelled(integration_cancel: &AtomicBool) -> Result<(), ModLoaderWarning> {
+    match integration_cancel.load(Ordering::Acquire) {
+        true => {
+            Err(unreal_mod_integrator::error::Error::from(IntegrationError::cancelled()).into())
+        }
+        false => Ok(()),
+    }
+}
+
+/// Checks if the game was updated since mods were last integrated
+///
+/// The integrator pak of the previous build is removed, it might not work with the new build.
+/// Returns the integration to run for the new build if the game was updated.
+fn check_game_updated(data: &mut ModLoaderAppData) -> Option<BackgroundThreadMessage> {
+    let (Some(previous), Some(current)) = (data.integrated_game_build, data.game_build) else {
+        return None;
+    };
+    if previous == current {
+        return None;
+    }
+
+    debug!("Game updated from {} to {}", previous, current);
+    data.warnings
+        .push(ModLoaderWarning::game_updated(previous, current));
+
+    if let Some(ref paks_path) = data.paks_path {
+        if let Err(err) = fs::remove_file(paks_path.join(INTEGRATOR_PAK_FILE_NAME)) {
+            if !matches!(err.kind(), io::ErrorKind::NotFound) {
+                data.warnings.push(ModLoaderWarning::io_error_with_message(
+                    "Removing outdated integrator pak failed".to_owned(),
+                    err,
+                ));
+            }
+        }
+    }
+
+    // no integration is running yet that would have to be cancelled
+    Some(BackgroundThreadMessage::Integrate(Instant::now()))
+}
+
+/// Sha256 hash of a mod file, mod files with C++ code are trusted by it
+fn hash_file(path: &Path) -> io::Result<Vec<u8>> {
+    let mut hasher = Sha256::new();
+    let mut file = File::open(path)?;
+    io::copy(&mut file, &mut hasher)?;
+    Ok(hasher.finalize()[..].to_vec())
+}
+
 fn download_mod(
     mods_path: &Path,
     mod_version: &IndexFileModVersion,
@@ -115,6 +171,60 @@ fn download_mods(mods_path: &Path, files_to_download: &[GameModVersion]) -> Vec<
     warnings
 }
 
+/// Run the game specific step after mods were integrated
+///
+/// A failing step is returned as a warning, the integrated mods are kept.
+fn run_post_integrate<'data, GC, IC, D: 'data, E: 'static + std::error::Error + Send>(
+    config: &GC,
+    paks_path: &Path,
+) -> Option<ModLoaderWarning>
+where
+    IC: 'static + IntegratorConfig<'data, D, E>,
+    GC: 'static + config::GameConfig<'data, IC, D, E>,
+{
+    match config.post_integrate(paks_path) {
+        Ok(()) => None,
+        Err(err) => {
+            warn!("Post integration step failed: {}", err);
+            Some(ModLoaderWarning::generic(Box::new(err)))
+        }
+    }
+}
+
+/// Record the result of an integration and mark the background thread as idle
+fn finish_integration(
+    background_thread_data: &BackgroundThreadData,
+    result: Result<Vec<ModLoaderWarning>, ModLoaderWarning>,
+) {
+    match result {
+        Ok(warnings) => {
+            if warnings.is_empty() {
+                debug!("Integration work was successful.");
+            } else {
+                debug!("Integration work finished with the following warnings:");
+                for warning in &warnings {
+                    warn!("{}", warning);
+                }
+                let mut data_guard = background_thread_data.data.lock();
+                data_guard.warnings.extend(warnings);
+            }
+        }
+        Err(err) if err.is_integration_cancelled() => {
+            debug!("Integration was cancelled by a newer integration");
+        }
+        Err(err) => {
+            error!("Mayor Integration work error: {}", err);
+            let mut data_guard = background_thread_data.data.lock();
+            data_guard.warnings.push(err);
+            data_guard.failed = true;
+        }
+    }
+
+    background_thread_data
+        .working
+        .store(false, Ordering::Release);
+}
+
 pub(crate) fn background_work<'data, GC, IC, D: 'data, E: 'static + std::error::Error + Send>(
     config: GC,
     mut background_thread_data: BackgroundThreadData,
@@ -131,15 +241,21 @@ where
         .working
         .store(true, Ordering::Release);
 
-    let startup_work = || -> Result<(), ModLoaderError> {
-        let mods_path = BaseDirs::new()
-            .ok_or_else(ModLoaderError::no_base_path)?
-            .data_local_dir()
-            .join(IC::GAME_NAME)
-            .join("Saved")
-            .join("Mods");
-
-        background_thread_data.data.lock().mods_path = Some(mods_path.clone());
+    // returns the integration to run if the game was updated since mods were last integrated
+    let startup_work = || -> Result<Option<BackgroundThreadMessage>, ModLoaderError> {
+        let mods_path = config
+            .get_mods_path()
+            .ok_or_else(ModLoaderError::no_base_path)?;
+
+        {
+            let mut data_guard = background_thread_data.data.lock();
+            data_guard.mods_path = Some(mods_path.clone());
+            if data_guard.config_store.is_none() {
+                data_guard.config_store = Some(Box::new(FileConfigStore::new(
+                    mods_path.join(CONFIG_FILE_NAME),
+                )));
+            }
+        }
 
         // ensure the base_path/Mods directory exists
         fs::create_dir_all(&mods_path).map_err(|err| {
@@ -169,13 +285,24 @@ where
         // load config
         load_config(&mut data_guard);
 
+        // an explicitly set install path is used instead of the detected installs
+        data_guard.apply_custom_game_path(IC::GAME_NAME, |game_install_path| {
+            config.get_game_build(game_install_path)
+        });
+
         // debug!("{:#?}", data_guard.game_mods);
-        Ok(())
+        Ok(check_game_updated(&mut data_guard))
+    };
+    // mods integrated for an older game build are integrated again right away,
+    // even if the integration on startup was skipped
+    let mut pending_message = match startup_work() {
+        Ok(pending_message) => pending_message,
+        Err(err) => {
+            warn!("Startup work error: {}", err);
+            background_thread_data.data.lock().error = Some(err);
+            None
+        }
     };
-    if let Err(err) = startup_work() {
-        warn!("Startup work error: {}", err);
-        background_thread_data.data.lock().error = Some(err);
-    }
 
     background_thread_data
         .working
@@ -195,7 +322,7 @@ where
 
     let mut last_integration_time = None;
 
-    while let Ok(message) = receiver.recv() {
+    while let Some(message) = pending_message.take().or_else(|| receiver.recv().ok()) {
         match message {
             BackgroundThreadMessage::Import(files_to_process) => {
                 background_thread_data
@@ -288,6 +415,7 @@ where
                 last_integration_time = Some(Instant::now());
 
                 let mut data_guard = background_thread_data.data.lock();
+                let integration_cancel = data_guard.start_integration();
 
                 let paks_path = data_guard.paks_path.as_ref().unwrap().clone();
                 let install_path = match data_guard.game_install_path {
@@ -296,23 +424,41 @@ where
                 };
                 #[cfg(feature = "cpp_loader")]
                 let cpp_loader_extract_path = data_guard.cpp_loader_extract_path.clone();
+                let cpp_loader_enabled = data_guard.cpp_loader_enabled;
 
                 data_guard.failed = false;
 
+                // gather mods to be installed
+                let mut mods_to_install = data_guard
+                    .game_mods
+                    .iter()
+                    .filter(|(_, m)| m.enabled)
+                    .map(|(_, m)| {
+                        m.versions
+                            .get(&m.selected_version.clone().unwrap())
+                            .unwrap()
+                            .clone()
+                    })
+                    .collect::<Vec<_>>();
+
+                // fail early with a clear error instead of running out of space halfway through,
+                // mods that still have to be downloaded aren't counted
+                let mods_size = mods_to_install
+                    .iter()
+                    .filter_map(|e| fs::metadata(mods_path.join(e.file_name.as_str())).ok())
+                    .map(|e| e.len())
+                    .sum::<u64>();
+
                 // remove all old files
-                if let Err(err) = fs::remove_dir_all(&paks_path) {
-                    if !matches!(err.kind(), io::ErrorKind::NotFound) {
-                        data_guard
-                            .warnings
-                            .push(ModLoaderWarning::io_error_with_message(
-                                "Removing old paks directory failed".to_owned(),
-                                err,
-                            ));
+                match clear_paks_dir(&paks_path, mods_size) {
+                    Ok(clear_warnings) => data_guard.warnings.extend(clear_warnings),
+                    Err(err) => {
+                        error!("Cannot install mods: {}", err);
+                        data_guard.warnings.push(err.into());
+                        data_guard.failed = true;
+                        continue;
                     }
-                };
-                if let Err(err) = fs::create_dir_all(&paks_path) {
-                    data_guard.warnings.push(err.into());
-                };
+                }
 
                 // cpp loader cleanup
                 #[cfg(feature = "cpp_loader")]
@@ -330,19 +476,6 @@ where
                 let mut warnings = Vec::new();
                 background_thread_data.use_cpp_loader = false;
 
-                // gather mods to be installed
-                let mut mods_to_install = data_guard
-                    .game_mods
-                    .iter()
-                    .filter(|(_, m)| m.enabled)
-                    .map(|(_, m)| {
-                        m.versions
-                            .get(&m.selected_version.clone().unwrap())
-                            .unwrap()
-                            .clone()
-                    })
-                    .collect::<Vec<_>>();
-
                 drop(data_guard);
 
                 debug!(
@@ -398,6 +531,7 @@ where
                             mod_id.clone(),
                             Vec::from([selected_version.clone()]),
                             enabled_mod.dependencies.clone(),
+                            enabled_mod.conflicts.clone(),
                         ));
                     }
 
@@ -438,6 +572,7 @@ where
                                         mod_id.clone(),
                                         entry.keys().cloned().collect(),
                                         metadata.dependencies,
+                                        metadata.conflicts,
                                     );
                                     next_round.push(mod_info);
                                 }
@@ -516,51 +651,95 @@ where
                     }
 
                     let trusted_mods = data_guard.trusted_mods.clone();
+                    let trusted_mod_ids = data_guard.trusted_mod_ids.clone();
                     let mut untrusted_mods = Vec::new();
 
                     // copy new files
                     let mods_to_install = mods_to_install
                         .into_iter()
                         .map(|e| {
+                            let game_mod = data_guard.game_mods.get(&e.mod_id).unwrap();
+                            let other_version_files = game_mod
+                                .versions
+                                .values()
+                                .filter(|version| version.file_name != e.file_name)
+                                .map(|version| version.file_name.clone())
+                                .collect::<Vec<_>>();
+
                             (
-                                data_guard
-                                    .game_mods
-                                    .get(&e.mod_id)
-                                    .unwrap()
-                                    .selected_version
-                                    .to_string(),
+                                game_mod.selected_version.to_string(),
                                 e,
+                                other_version_files,
                             )
                         })
                         .collect::<Vec<_>>();
 
+                    let write_report = data_guard.write_mod_report;
                     drop(data_guard);
 
-                    for (version_string, mod_version) in mods_to_install {
+                    let mut mod_report = ModReport::default();
+
+                    for (version_string, mod_version, other_version_files) in mods_to_install {
+                        check_integration_cancelled(&integration_cancel)?;
+
                         let dst_path = paks_path.join(mod_version.file_name.as_str());
                         fs::copy(mods_path.join(mod_version.file_name.as_str()), &dst_path)
                             .map(|_| ())?;
 
                         // extract DLLS
+                        let mut untrusted_reason = None;
                         if let Some(ref metadata) = mod_version.metadata {
                             if !metadata.cpp_loader_dlls.is_empty() {
-                                let mut hasher = Sha256::new();
-                                let mut file = File::open(&dst_path)?;
-                                let _ = io::copy(&mut file, &mut hasher)?;
-                                let hash = hasher.finalize()[..].to_vec();
-
-                                if !trusted_mods.contains(&hash) {
+                                let hash = hash_file(&dst_path)?;
+
+                                untrusted_reason = evaluate_trust(
+                                    &mod_version.mod_id,
+                                    &hash,
+                                    &trusted_mods,
+                                    &trusted_mod_ids,
+                                    || {
+                                        other_version_files
+                                            .iter()
+                                            .filter_map(|file_name| {
+                                                hash_file(&mods_path.join(file_name)).ok()
+                                            })
+                                            .collect()
+                                    },
+                                );
+                                if let Some(reason) = untrusted_reason {
                                     untrusted_mods.push(UntrustedMod::new(
                                         mod_version.mod_id.clone(),
-                                        version_string,
+                                        version_string.clone(),
                                         hash,
+                                        reason,
                                     ));
                                 }
 
-                                background_thread_data.use_cpp_loader = true;
+                                if cpp_loader_enabled {
+                                    background_thread_data.use_cpp_loader = true;
+                                } else {
+                                    debug!(
+                                        "Not loading C++ mod {}, the C++ loader is disabled",
+                                        mod_version.mod_id
+                                    );
+                                }
                             }
                         }
 
+                        if write_report {
+                            mod_report.add_mod(ModReportEntry::new(
+                                mod_version.mod_id.clone(),
+                                mod_version
+                                    .metadata
+                                    .as_ref()
+                                    .map(|e| e.mod_version.clone())
+                                    .unwrap_or_else(|| version_string.clone()),
+                                mod_version.file_name.clone(),
+                                &dst_path,
+                                untrusted_reason,
+                            ));
+                        }
+
                         mods_to_integrate.push(
                             FileMod {
                                 path: dst_path,
@@ -581,9 +760,10 @@ where
 
                     mods_to_integrate.sort_by_key(|a| a.get_priority());
 
+                    let pre_integration_time = start_pre.elapsed();
                     debug!(
                         "Pre Integration took {} milliseconds",
-                        start_pre.elapsed().as_millis()
+                        pre_integration_time.as_millis()
                     );
 
                     let start_integrator = Instant::now();
@@ -598,7 +778,7 @@ where
                         .lock()
                         .refuse_mismatched_connections;
 
-                    match integrate_mods(
+                    let outcome = match integrate_mods_cancellable(
                         config.get_integrator_config(),
                         &mods_to_integrate,
                         &paks_path,
@@ -607,13 +787,29 @@ where
                             .join("Content")
                             .join("Paks"),
                         refuse_mismatched_connections,
+                        &integration_cancel,
                     ) {
-                        Ok(_) => debug!("Integration successful"),
+                        Ok(outcome) => {
+                            debug!("Integration successful");
+                            outcome
+                        }
+                        Err(err) if err.is_cancelled() => return Err(err.into()),
                         Err(err) => {
                             warn!("Integration failed!");
                             return Err(err.into());
                         }
                     };
+                    let metrics = outcome.metrics;
+                    for skipped in outcome.skipped_mods {
+                        warn!("Skipped mod {} during integration", skipped.mod_id);
+                        warnings.push(ModLoaderWarning::mod_skipped(
+                            skipped.mod_id,
+                            skipped.reason,
+                        ));
+                    }
+                    for (phase, duration) in metrics.phases() {
+                        debug!("{} took {} milliseconds", phase, duration.as_millis());
+                    }
 
                     debug!(
                         "Integration took {} milliseconds",
@@ -635,37 +831,29 @@ where
                         }
                     }
 
+                    warnings.extend(run_post_integrate(&config, &paks_path));
+
                     *background_thread_data.last_integration_time.lock() = Instant::now();
+                    background_thread_data.data.lock().last_integration_metrics =
+                        Some(metrics.clone());
+
+                    if write_report {
+                        mod_report.add_timings(pre_integration_time, &metrics);
+                        mod_report.add_warnings(&warnings);
+                        if let Err(err) = write_mod_report(&paks_path, &mod_report) {
+                            warn!("Failed to write mod report: {}", err);
+                        }
+                    }
 
                     // update config file
-                    write_config(&background_thread_data.data.lock());
+                    let mut data_guard = background_thread_data.data.lock();
+                    data_guard.integrated_game_build = data_guard.game_build;
+                    write_config(&data_guard);
 
                     Ok(warnings)
                 };
-                match integration_work() {
-                    Ok(warnings) => {
-                        if warnings.is_empty() {
-                            debug!("Integration work was successful.");
-                        } else {
-                            debug!("Integration work finished with the following warnings:");
-                            for warning in &warnings {
-                                warn!("{}", warning);
-                            }
-                            let mut data_guard = background_thread_data.data.lock();
-                            data_guard.warnings.extend(warnings);
-                        }
-                    }
-                    Err(err) => {
-                        error!("Mayor Integration work error: {}", err);
-                        let mut data_guard = background_thread_data.data.lock();
-                        data_guard.warnings.push(err);
-                        data_guard.failed = true;
-                    }
-                }
-
-                background_thread_data
-                    .working
-                    .store(false, Ordering::Release);
+                let result = integration_work();
+                finish_integration(&background_thread_data, result);
             }
             BackgroundThreadMessage::LaunchGame => {
                 let start = |data: &mut ModLoaderAppData| -> Result<(), ModLoaderWarning> {
@@ -724,3 +912,314 @@ where
         .store(true, Ordering::Release);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+    use std::sync::{mpsc, Arc};
+    use std::time::Instant;
+
+    use parking_lot::Mutex;
+    use unreal_mod_integrator::INTEGRATOR_PAK_FILE_NAME;
+    use unreal_pak::{pakversion::PakVersion, PakReader, PakWriter};
+
+    use super::{
+        background_work, check_game_updated, check_integration_cancelled, finish_integration,
+        run_post_integrate, BackgroundThreadData, BackgroundThreadMessage,
+    };
+    use crate::error::{ModLoaderWarning, ModLoaderWarningKind};
+    use crate::mod_config::{write_config, MemoryConfigStore};
+    use crate::test_utils::{test_dir, TestGameConfig, TEST_GAME_NAME};
+    use crate::version::GameBuild;
+    use crate::{FileToProcess, ModLoaderAppData};
+
+    #[test]
+    fn integration_cancellation() {
+        let mut data = ModLoaderAppData::default();
+
+        let first = data.start_integration();
+        assert!(check_integration_cancelled(&first).is_ok());
+
+        // requesting another integration cancels the running one
+        assert!(matches!(
+            BackgroundThreadMessage::integrate(&data.integration_cancel),
+            BackgroundThreadMessage::Integrate(_)
+        ));
+        assert!(check_integration_cancelled(&first)
+            .unwrap_err()
+            .is_integration_cancelled());
+
+        // the next integration starts out uncancelled without un-cancelling the previous one
+        let second = data.start_integration();
+        assert!(check_integration_cancelled(&second).is_ok());
+        assert!(first.load(Ordering::Acquire));
+
+        BackgroundThreadMessage::integrate(&data.integration_cancel);
+        assert!(check_integration_cancelled(&second).is_err());
+    }
+
+    fn background_thread_data() -> BackgroundThreadData {
+        BackgroundThreadData {
+            data: Arc::new(Mutex::new(ModLoaderAppData::default())),
+            use_cpp_loader: false,
+            ready_exit: Arc::new(AtomicBool::new(false)),
+            last_integration_time: Arc::new(Mutex::new(Instant::now())),
+            working: Arc::new(AtomicBool::new(true)),
+            newer_update: Arc::new(Mutex::new(None)),
+            should_update: Arc::new(AtomicBool::new(false)),
+            update_progress: Arc::new(AtomicI32::new(0)),
+        }
+    }
+
+    #[test]
+    fn post_integrate() {
+        let paks_path = test_dir("post_integrate");
+        let mut config = TestGameConfig::new(None, Some(paks_path.clone()));
+        assert!(run_post_integrate(&config, &paks_path).is_none());
+
+        config.post_integrate_error = Some(io::ErrorKind::PermissionDenied);
+        let warning = run_post_integrate(&config, &paks_path).unwrap();
+        assert!(warning
+            .to_string()
+            .contains(&io::Error::from(io::ErrorKind::PermissionDenied).to_string()));
+
+        // a failing hook is reported without failing the integration or keeping it running
+        let background_thread_data = background_thread_data();
+        finish_integration(&background_thread_data, Ok(vec![warning]));
+        assert!(!background_thread_data.working.load(Ordering::Acquire));
+        let data = background_thread_data.data.lock();
+        assert_eq!(data.warnings.len(), 1);
+        assert!(!data.failed);
+    }
+
+    #[test]
+    fn failed_integration() {
+        let background_thread_data = background_thread_data();
+        finish_integration(
+            &background_thread_data,
+            Err(ModLoaderWarning::other("Integration failed".to_owned())),
+        );
+
+        assert!(!background_thread_data.working.load(Ordering::Acquire));
+        let data = background_thread_data.data.lock();
+        assert_eq!(data.warnings.len(), 1);
+        assert!(data.failed);
+    }
+
+    #[test]
+    fn game_updated() {
+        let paks_path = test_dir("game_updated");
+        fs::create_dir_all(&paks_path).unwrap();
+        let integrator_pak = paks_path.join(INTEGRATOR_PAK_FILE_NAME);
+        fs::write(&integrator_pak, b"integrated").unwrap();
+
+        let previous = GameBuild::new(1, 0, 0, 0);
+        let current = GameBuild::new(1, 1, 0, 0);
+        let mut data = ModLoaderAppData {
+            paks_path: Some(paks_path.clone()),
+            game_build: Some(current),
+            ..Default::default()
+        };
+
+        // nothing was integrated yet
+        assert!(check_game_updated(&mut data).is_none());
+
+        data.integrated_game_build = Some(current);
+        assert!(check_game_updated(&mut data).is_none());
+        assert!(integrator_pak.exists());
+        assert!(data.warnings.is_empty());
+
+        // a new build removes the outdated integrator pak and integrates again
+        data.integrated_game_build = Some(previous);
+        assert!(matches!(
+            check_game_updated(&mut data),
+            Some(BackgroundThreadMessage::Integrate(_))
+        ));
+        assert!(!integrator_pak.exists());
+        assert_eq!(data.warnings.len(), 1);
+        assert!(matches!(
+            data.warnings[0].kind,
+            ModLoaderWarningKind::GameUpdated { previous: p, current: c }
+                if p == previous && c == current
+        ));
+
+        fs::remove_dir_all(&paks_path).unwrap();
+    }
+
+    fn write_pak(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut writer = PakWriter::new(
+            File::create(path).unwrap(),
+            PakVersion::FnameBasedCompressionMethod,
+        );
+        for (name, data) in entries {
+            writer
+                .write_entry(&name.to_string(), &data.to_vec(), false)
+                .unwrap();
+        }
+        writer.finish_write().unwrap();
+    }
+
+    /// Create a game install with a single game pak
+    fn test_install(dir: &Path) -> PathBuf {
+        let install_path = dir.join("Game");
+        let game_paks_path = install_path
+            .join(TEST_GAME_NAME)
+            .join("Content")
+            .join("Paks");
+        fs::create_dir_all(&game_paks_path).unwrap();
+        write_pak(
+            &game_paks_path.join("TestGame-WindowsNoEditor.pak"),
+            &[("TestGame/Content/A.uasset", &[1u8; 16])],
+        );
+        install_path
+    }
+
+    /// Import `mod_file` and integrate it on the background thread, returning the app data afterwards
+    fn import_and_integrate(
+        config: TestGameConfig,
+        data: ModLoaderAppData,
+        mod_file: PathBuf,
+    ) -> Arc<Mutex<ModLoaderAppData>> {
+        let mut background_thread_data = background_thread_data();
+        background_thread_data.data = Arc::new(Mutex::new(data));
+        let data = Arc::clone(&background_thread_data.data);
+
+        let (sender, receiver) = mpsc::channel();
+        sender
+            .send(BackgroundThreadMessage::Import(vec![FileToProcess::new(
+                mod_file, true,
+            )]))
+            .unwrap();
+        sender
+            .send(BackgroundThreadMessage::Integrate(Instant::now()))
+            .unwrap();
+        sender.send(BackgroundThreadMessage::Exit).unwrap();
+
+        background_work(config, background_thread_data, receiver).unwrap();
+        data
+    }
+
+    #[test]
+    fn post_integrate_sentinel() {
+        let dir = test_dir("post_integrate_sentinel");
+        let mods_path = dir.join("Mods");
+        let paks_path = dir.join("Paks");
+        let install_path = test_install(&dir);
+
+        let metadata = serde_json::json!({
+            "schema_version": 2,
+            "name": "SentinelMod",
+            "mod_id": "SentinelMod",
+            "version": "1.0.0",
+        });
+        let mod_file = dir.join("000-SentinelMod-1.0.0_P.pak");
+        write_pak(
+            &mod_file,
+            &[("metadata.json", &serde_json::to_vec(&metadata).unwrap())],
+        );
+
+        let data = ModLoaderAppData {
+            selected_game_platform: Some("Steam".to_owned()),
+            paks_path: Some(paks_path.clone()),
+            game_install_path: Some(install_path),
+            config_store: Some(Box::new(MemoryConfigStore::new())),
+            ..Default::default()
+        };
+        write_config(&data);
+        let sentinel = dir.join("post_integrate.txt");
+        let mut config = TestGameConfig::new(Some(mods_path), Some(paks_path));
+        config.post_integrate_sentinel = Some(sentinel.clone());
+
+        let data = import_and_integrate(config, data, mod_file);
+        let written = fs::read_to_string(&sentinel);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let data = data.lock();
+        assert!(!data.failed, "integration failed: {:?}", data.warnings);
+        assert!(data.warnings.is_empty(), "{:?}", data.warnings);
+
+        // the hook ran once the integrator pak was written
+        let written = written.expect("post_integrate didn't run");
+        assert_eq!(
+            written.lines().collect::<Vec<_>>(),
+            ["000-SentinelMod-1.0.0_P.pak", INTEGRATOR_PAK_FILE_NAME]
+        );
+    }
+
+    #[test]
+    fn cpp_loader_disabled() {
+        let dir = test_dir("cpp_loader_disabled");
+        let mods_path = dir.join("Mods");
+        let paks_path = dir.join("Paks");
+        let install_path = test_install(&dir);
+
+        // a mod with a C++ dll
+        let dll = "TestGame/Content/Mods/CppMod/CppMod.dll";
+        let metadata = serde_json::json!({
+            "schema_version": 2,
+            "name": "CppMod",
+            "mod_id": "CppMod",
+            "version": "1.0.0",
+            "cpp_loader_dlls": ["/Game/Mods/CppMod/CppMod.dll"],
+        });
+        let mod_file = dir.join("000-CppMod-1.0.0_P.pak");
+        write_pak(
+            &mod_file,
+            &[
+                ("metadata.json", &serde_json::to_vec(&metadata).unwrap()),
+                (dll, b"MZ"),
+            ],
+        );
+
+        let mut data = ModLoaderAppData {
+            selected_game_platform: Some("Steam".to_owned()),
+            paks_path: Some(paks_path.clone()),
+            game_install_path: Some(install_path),
+            cpp_loader_enabled: false,
+            config_store: Some(Box::new(MemoryConfigStore::new())),
+            ..Default::default()
+        };
+        #[cfg(feature = "cpp_loader")]
+        {
+            data.cpp_loader_extract_path = dir.join("CppLoader");
+        }
+        write_config(&data);
+        // loaded from the config on startup
+        data.cpp_loader_enabled = true;
+
+        let config = TestGameConfig::new(Some(mods_path), Some(paks_path.clone()));
+        let data = import_and_integrate(config, data, mod_file);
+
+        let mut paks = fs::read_dir(&paks_path)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        paks.sort();
+        let mut integrator_pak =
+            PakReader::new(File::open(paks_path.join(INTEGRATOR_PAK_FILE_NAME)).unwrap());
+        integrator_pak.load_index().unwrap();
+        let integrator_entries = integrator_pak
+            .get_entry_names()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        #[cfg(feature = "cpp_loader")]
+        let extracted = dir.join("CppLoader").exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        // the mod is still integrated as a pak
+        let data = data.lock();
+        assert!(!data.failed, "integration failed: {:?}", data.warnings);
+        assert!(data.game_mods["CppMod"].enabled);
+        assert_eq!(paks, ["000-CppMod-1.0.0_P.pak", INTEGRATOR_PAK_FILE_NAME]);
+        assert!(!integrator_entries.is_empty());
+
+        // but its dll isn't bootstrapped or injected
+        assert!(!integrator_entries.iter().any(|e| e.ends_with(".dll")));
+        #[cfg(feature = "cpp_loader")]
+        assert!(!extracted);
+    }
+}
@@ -2,8 +2,10 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 
+use directories::BaseDirs;
 use unreal_mod_integrator::IntegratorConfig;
 
+use crate::mod_config::ConfigStore;
 use crate::version::GameBuild;
 use crate::{
     error::{ModLoaderError, ModLoaderWarning},
@@ -53,6 +55,46 @@ where
 
     fn get_icon(&self) -> Option<IconData>;
 
+    /// Directory mods are loaded from, `%LocalAppData%\[GameName]\Saved\Mods` by default
+    fn get_mods_path(&self) -> Option<PathBuf> {
+        Some(
+            BaseDirs::new()?
+                .data_local_dir()
+                .join(IC::GAME_NAME)
+                .join("Saved")
+                .join("Mods"),
+        )
+    }
+
+    /// Directory mods are installed to
+    ///
+    /// Returning `None` uses the paks path of the selected install manager.
+    fn get_paks_path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// Storage for the mod loader config
+    ///
+    /// Returning `None` stores the config as `modconfig.json` in the mods path.
+    fn get_config_store(&self) -> Option<Box<dyn ConfigStore>> {
+        None
+    }
+
+    /// Name of the local socket the mod loader accepts commands on
+    ///
+    /// Returning `None` disables the control channel, see [`crate::ipc`].
+    fn get_ipc_name(&self) -> Option<String> {
+        None
+    }
+
+    /// Game specific step run after mods were integrated successfully
+    ///
+    /// `paks_path` is the directory the integrated mods and the integrator pak were written to.
+    /// An error is shown as a warning, the integration itself is not undone.
+    fn post_integrate(&self, _paks_path: &Path) -> Result<(), E> {
+        Ok(())
+    }
+
     #[cfg(feature = "cpp_loader")]
     fn get_cpp_loader_config() -> unreal_cpp_bootstrapper::config::GameSettings;
 
@@ -61,3 +103,59 @@ where
     const CRATE_VERSION: &'static str;
     const ABOUT_TEXT: &'static str;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::game_platform_managers::{CustomInstallManager, CUSTOM_GAME_PLATFORM};
+    use crate::test_utils::{test_dir, TestGameConfig, TEST_GAME_NAME};
+    use crate::ModLoaderAppData;
+
+    use super::GameConfig;
+
+    #[test]
+    fn path_overrides() {
+        let dir = test_dir("path_overrides");
+        let mods_path = dir.join("Mods");
+        let paks_path = dir.join("Paks");
+
+        let config = TestGameConfig::new(Some(mods_path.clone()), Some(paks_path.clone()));
+        assert_eq!(config.get_mods_path(), Some(mods_path));
+        assert_eq!(config.get_paks_path(), Some(paks_path.clone()));
+
+        // the paks path override takes precedence over the one of the install manager
+        let mut data = ModLoaderAppData {
+            paks_path_override: config.get_paks_path(),
+            ..Default::default()
+        };
+        data.set_custom_install_manager(
+            CustomInstallManager::new(dir.join("Game"), TEST_GAME_NAME, None),
+            true,
+        );
+        assert_eq!(data.paks_path, Some(paks_path));
+
+        // without an override the install manager decides
+        let mut data = ModLoaderAppData::default();
+        data.set_custom_install_manager(
+            CustomInstallManager::new(dir.join("Game"), TEST_GAME_NAME, None),
+            true,
+        );
+        assert_eq!(
+            data.selected_game_platform.as_deref(),
+            Some(CUSTOM_GAME_PLATFORM)
+        );
+        assert_eq!(
+            data.paks_path,
+            Some(
+                dir.join("Game")
+                    .join(TEST_GAME_NAME)
+                    .join("Content")
+                    .join("Paks")
+                    .join("~mods")
+            )
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,10 +1,13 @@
 use std::error;
 use std::fmt;
 use std::io;
+use std::path::PathBuf;
 
 use reqwest::StatusCode;
 use unreal_pak::error::PakError;
 
+use crate::version::GameBuild;
+
 /// For critical errors that can happen during runtime which prevent further
 /// operation of the modloader and cannot be handled gracefully.
 #[derive(Debug)]
@@ -18,6 +21,8 @@ pub enum ModLoaderErrorKind {
     IoErrorWithMessage(io::Error, String),
     PakError(PakError),
     NoBasePath,
+    InsufficientSpace(u64, u64),
+    PathNotWritable(PathBuf, io::Error),
     Generic(Box<dyn std::error::Error + Send>),
     Other(Box<str>),
 }
@@ -33,6 +38,16 @@ impl ModLoaderError {
             kind: ModLoaderErrorKind::NoBasePath,
         }
     }
+    pub fn insufficient_space(required: u64, available: u64) -> Self {
+        ModLoaderError {
+            kind: ModLoaderErrorKind::InsufficientSpace(required, available),
+        }
+    }
+    pub fn path_not_writable(path: PathBuf, err: io::Error) -> Self {
+        ModLoaderError {
+            kind: ModLoaderErrorKind::PathNotWritable(path, err),
+        }
+    }
 
     pub fn other(msg: String) -> Self {
         ModLoaderError {
@@ -52,6 +67,14 @@ impl fmt::Display for ModLoaderError {
             ModLoaderErrorKind::NoBasePath => {
                 "No base path found (%localappdata%\\GameName)".to_owned()
             }
+            ModLoaderErrorKind::InsufficientSpace(required, available) => format!(
+                "Not enough disk space to install mods, {:.1} MB needed but only {:.1} MB available",
+                required as f64 / 1_000_000.0,
+                available as f64 / 1_000_000.0
+            ),
+            ModLoaderErrorKind::PathNotWritable(ref path, ref err) => {
+                format!("Cannot write to {}: {err}", path.display())
+            }
             ModLoaderErrorKind::Generic(ref err) => format!("Error: {err}"),
             ModLoaderErrorKind::Other(ref msg) => format!("Other: {msg}"),
         };
@@ -96,12 +119,22 @@ pub enum ModLoaderWarningKind {
     IoErrorWithMessage(io::Error, String),
     UnrealPakError(PakError),
     IntegratorError(unreal_mod_integrator::error::Error),
+    ModSkipped {
+        mod_id: String,
+        reason: unreal_mod_integrator::error::Error,
+    },
 
     UnresolvedDependency(String, Vec<(String, String)>),
     ReferencedByOtherMods(String, Vec<String>),
+    Conflict(String, String),
 
     SteamError,
     WinStoreError,
+    /// The game build changed since mods were last integrated
+    GameUpdated {
+        previous: GameBuild,
+        current: GameBuild,
+    },
 
     MissingMetadata,
     InvalidMetadata,
@@ -131,6 +164,11 @@ impl ModLoaderWarning {
         self
     }
 
+    /// Checks if this is an integration that was cancelled instead of failing
+    pub fn is_integration_cancelled(&self) -> bool {
+        matches!(self.kind, ModLoaderWarningKind::IntegratorError(ref err) if err.is_cancelled())
+    }
+
     pub fn io_error_with_message(message: String, err: io::Error) -> Self {
         ModLoaderWarning {
             kind: ModLoaderWarningKind::IoErrorWithMessage(err, message),
@@ -152,6 +190,23 @@ impl ModLoaderWarning {
         }
     }
 
+    pub fn conflict(a: String, b: String) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::Conflict(a, b),
+            mod_id: None,
+        }
+    }
+
+    pub fn mod_skipped(mod_id: String, reason: unreal_mod_integrator::error::Error) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::ModSkipped {
+                mod_id: mod_id.clone(),
+                reason,
+            },
+            mod_id: Some(mod_id),
+        }
+    }
+
     pub fn steam_error() -> Self {
         ModLoaderWarning {
             kind: ModLoaderWarningKind::SteamError,
@@ -164,6 +219,12 @@ impl ModLoaderWarning {
             mod_id: None,
         }
     }
+    pub fn game_updated(previous: GameBuild, current: GameBuild) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::GameUpdated { previous, current },
+            mod_id: None,
+        }
+    }
     pub fn missing_metadata(mod_id: String) -> Self {
         ModLoaderWarning {
             kind: ModLoaderWarningKind::MissingMetadata,
@@ -231,6 +292,12 @@ impl ModLoaderWarning {
             mod_id: None,
         }
     }
+    pub fn generic(err: Box<dyn std::error::Error + Send>) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::Generic(err),
+            mod_id: None,
+        }
+    }
 }
 
 impl fmt::Display for ModLoaderWarning {
@@ -251,11 +318,18 @@ impl fmt::Display for ModLoaderWarning {
             ModLoaderWarningKind::IntegratorError(ref err) => {
                 format!("{mod_name}Integrator error: {err}")
             }
+            ModLoaderWarningKind::ModSkipped { ref reason, .. } => {
+                format!("{mod_name}Skipped, failed to load: {reason}")
+            }
 
             ModLoaderWarningKind::SteamError => "Failed to locate Steam installation".to_string(),
             ModLoaderWarningKind::WinStoreError => {
                 "Failed to locate WinStore installation".to_string()
             }
+            ModLoaderWarningKind::GameUpdated {
+                ref previous,
+                ref current,
+            } => format!("Game updated from {previous} to {current}, mods are integrated again"),
 
             ModLoaderWarningKind::MissingMetadata => format!("{mod_name}Missing metadata"),
             ModLoaderWarningKind::InvalidMetadata => format!("{mod_name}Invalid metadata"),
@@ -305,6 +379,9 @@ impl fmt::Display for ModLoaderWarning {
                 mod_id,
                 referencers.join("\n")
             ),
+            ModLoaderWarningKind::Conflict(ref a, ref b) => {
+                format!("Error: {a} and {b} are incompatible, disable one of them")
+            }
         };
 
         write!(f, "{err_msg}")
@@ -329,6 +406,15 @@ impl From<PakError> for ModLoaderWarning {
     }
 }
 
+impl From<ModLoaderError> for ModLoaderWarning {
+    fn from(err: ModLoaderError) -> Self {
+        ModLoaderWarning {
+            kind: ModLoaderWarningKind::Generic(Box::new(err)),
+            mod_id: None,
+        }
+    }
+}
+
 impl From<unreal_mod_integrator::error::Error> for ModLoaderWarning {
     fn from(err: unreal_mod_integrator::error::Error) -> Self {
         ModLoaderWarning {
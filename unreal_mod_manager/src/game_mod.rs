@@ -73,6 +73,7 @@ pub struct GameMod {
     pub homepage: Option<String>,
     pub download: Option<DownloadInfo>,
     pub dependencies: HashMap<String, Dependency>,
+    pub conflicts: Vec<String>,
     pub size: u64,
 }
 
@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use directories::BaseDirs;
 use lazy_static::lazy_static;
@@ -107,10 +108,47 @@ pub fn determine_installed_mods_path_winstore(
     base_path
 }
 
+/// Resolve symlinks and junctions in a game install path
+///
+/// Moved Steam libraries are often linked back to their old location,
+/// paks and mods directories need to be derived from the real one.
+pub fn resolve_game_path(path: &Path) -> Result<PathBuf, ModLoaderWarning> {
+    let resolved = fs::canonicalize(path).map_err(|err| {
+        ModLoaderWarning::io_error_with_message(
+            format!(
+                "Failed to resolve game path {}, it might be a broken link",
+                path.display()
+            ),
+            err,
+        )
+    })?;
+    trace!("resolved game path {:?} to {:?}", path, resolved);
+
+    Ok(strip_verbatim_prefix(resolved))
+}
+
+/// Strip the `\\?\` prefix canonicalize adds on windows for paths that don't need it
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    #[cfg(windows)]
+    {
+        use std::path::{Component, Prefix};
+
+        if let Some(Component::Prefix(prefix)) = path.components().next() {
+            if let Prefix::VerbatimDisk(_) = prefix.kind() {
+                if let Some(stripped) = path.to_str().and_then(|e| e.strip_prefix(r"\\?\")) {
+                    return PathBuf::from(stripped);
+                }
+            }
+        }
+    }
+
+    path
+}
+
 pub fn determine_install_path_steam(app_id: u32) -> Result<PathBuf, ModLoaderWarning> {
     if let Some(mut steam_dir) = SteamDir::locate() {
         match steam_dir.app(&app_id) {
-            Some(app) => Ok(app.path.clone()),
+            Some(app) => resolve_game_path(&app.path),
             None => Err(ModLoaderWarning::steam_error()),
         }
     } else {
@@ -156,7 +194,43 @@ pub fn determine_install_path_winstore(vendor: &str) -> Result<MsStoreInfo, ModL
         convert_runtime_id(&package_id).ok_or_else(ModLoaderWarning::winstore_error)?;
 
     Ok(MsStoreInfo {
-        path: PathBuf::from(root_folder),
+        path: resolve_game_path(Path::new(&root_folder))?,
         runtime_id,
     })
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::symlink;
+
+    use super::resolve_game_path;
+    use crate::test_utils::test_dir;
+
+    #[test]
+    fn resolve_linked_game_path() {
+        let dir = test_dir("resolve_game_path");
+        let game_path = dir.join("Library").join("Game");
+        fs::create_dir_all(&game_path).unwrap();
+        let game_path = fs::canonicalize(&game_path).unwrap();
+
+        assert_eq!(resolve_game_path(&game_path).unwrap(), game_path);
+
+        // a moved library linked back to its old location resolves to the real one
+        let link = dir.join("OldLibrary");
+        symlink(dir.join("Library"), &link).unwrap();
+        assert_eq!(resolve_game_path(&link.join("Game")).unwrap(), game_path);
+
+        let game_link = dir.join("GameLink");
+        symlink(&game_path, &game_link).unwrap();
+        assert_eq!(resolve_game_path(&game_link).unwrap(), game_path);
+
+        // a link whose target was removed can't be resolved
+        let broken_link = dir.join("BrokenLink");
+        symlink(dir.join("Removed"), &broken_link).unwrap();
+        assert!(resolve_game_path(&broken_link).is_err());
+        assert!(resolve_game_path(&dir.join("Missing")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,156 @@
+use std::path::PathBuf;
+
+#[cfg(feature = "cpp_loader")]
+use std::env;
+
+use crate::config::InstallManager;
+use crate::error::ModLoaderWarning;
+use crate::version::GameBuild;
+
+/// Install manager for a game install path that was set explicitly instead of being detected
+#[derive(Debug)]
+pub struct CustomInstallManager {
+    pub game_path: PathBuf,
+
+    game_name: &'static str,
+    game_build: Option<GameBuild>,
+}
+
+impl CustomInstallManager {
+    pub fn new(game_path: PathBuf, game_name: &'static str, game_build: Option<GameBuild>) -> Self {
+        CustomInstallManager {
+            game_path,
+            game_name,
+            game_build,
+        }
+    }
+}
+
+impl InstallManager for CustomInstallManager {
+    fn get_game_install_path(&self) -> Option<PathBuf> {
+        Some(self.game_path.clone())
+    }
+
+    fn get_paks_path(&self) -> Option<PathBuf> {
+        // the paks directory is cleared on every integration, so use a subdirectory the game also loads from
+        Some(
+            self.game_path
+                .join(self.game_name)
+                .join("Content")
+                .join("Paks")
+                .join("~mods"),
+        )
+    }
+
+    fn get_game_build(&self) -> Option<GameBuild> {
+        self.game_build
+    }
+
+    fn launch_game(&self) -> Result<(), ModLoaderWarning> {
+        open::that(self.game_path.join(format!("{}.exe", self.game_name)))?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cpp_loader")]
+impl unreal_cpp_bootstrapper::CppLoaderInstallExtension<ModLoaderWarning> for CustomInstallManager {
+    fn get_config_location(&self) -> Result<PathBuf, ModLoaderWarning> {
+        Ok(env::temp_dir()
+            .join("unrealmodding")
+            .join("cpp_loader")
+            .join("config.json"))
+    }
+
+    fn get_extract_path(&self) -> Result<PathBuf, ModLoaderWarning> {
+        Ok(env::temp_dir()
+            .join("unrealmodding")
+            .join("cpp_loader")
+            .join("mods"))
+    }
+
+    fn prepare_load(&self) -> Result<(), ModLoaderWarning> {
+        let dest_path = self
+            .game_path
+            .join(self.game_name)
+            .join("Binaries")
+            .join("Win64");
+
+        super::write_loader_dll(dest_path.as_path())?;
+        super::write_proxy_dll(dest_path.as_path())?;
+
+        Ok(())
+    }
+
+    // the proxy dll handles loading like on steam
+    fn load(&self) -> Result<(), ModLoaderWarning> {
+        Ok(())
+    }
+
+    fn remove(&self) {
+        let dest_path = self
+            .game_path
+            .join(self.game_name)
+            .join("Binaries")
+            .join("Win64");
+
+        super::remove_dlls(dest_path.as_path());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::game_platform_managers::CUSTOM_GAME_PLATFORM;
+    use crate::test_utils::{test_dir, TEST_GAME_NAME};
+    use crate::ModLoaderAppData;
+
+    #[test]
+    fn explicit_install_path() {
+        let dir = test_dir("custom_install_path");
+        fs::create_dir_all(dir.join("Game")).unwrap();
+        let game_path = fs::canonicalize(dir.join("Game")).unwrap();
+
+        let mut data = ModLoaderAppData {
+            game_install_path_override: Some(dir.join("Game")),
+            ..Default::default()
+        };
+        data.apply_custom_game_path(TEST_GAME_NAME, |_| None);
+
+        assert!(data.warnings.is_empty());
+        assert_eq!(
+            data.selected_game_platform.as_deref(),
+            Some(CUSTOM_GAME_PLATFORM)
+        );
+        assert_eq!(data.game_install_path.as_ref(), Some(&game_path));
+        assert_eq!(data.custom_game_install_path.as_ref(), Some(&game_path));
+        assert_eq!(
+            data.paks_path,
+            Some(
+                game_path
+                    .join(TEST_GAME_NAME)
+                    .join("Content")
+                    .join("Paks")
+                    .join("~mods")
+            )
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_install_path() {
+        let dir = test_dir("invalid_install_path");
+
+        let mut data = ModLoaderAppData {
+            game_install_path_override: Some(dir.join("Missing")),
+            ..Default::default()
+        };
+        data.apply_custom_game_path(TEST_GAME_NAME, |_| None);
+
+        assert_eq!(data.warnings.len(), 1);
+        assert!(data.selected_game_platform.is_none());
+        assert!(data.game_install_path.is_none());
+        assert!(!data.install_managers.contains_key(CUSTOM_GAME_PLATFORM));
+    }
+}
@@ -9,6 +9,12 @@ pub trait GetGameBuildTrait<T>: std::fmt::Debug + Send {
     fn get_game_build(&self, manager: &T) -> Option<GameBuild>;
 }
 
+mod custom;
+pub use custom::CustomInstallManager;
+
+/// Platform name of an explicitly set game install path
+pub const CUSTOM_GAME_PLATFORM: &str = "Custom";
+
 #[cfg(windows)]
 mod msstore;
 #[cfg(windows)]
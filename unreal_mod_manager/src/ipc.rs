@@ -0,0 +1,286 @@
+//! Local control channel for driving the mod loader without its GUI
+//!
+//! Commands are sent as newline delimited JSON over a local socket,
+//! a named pipe on Windows and a unix socket elsewhere.
+//! Every command is answered with a single line of JSON.
+//!
+//! The unix socket is created in a directory only the current user can access,
+//! so other users on the same machine can't send commands.
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc::Sender, Arc};
+
+use directories::BaseDirs;
+use interprocess::local_socket::{LocalSocketListener, LocalSocketStream, NameTypeSupport};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::background_work::BackgroundThreadMessage;
+use crate::{FileToProcess, ModLoaderAppData};
+
+/// Command sent to the mod loader over the control channel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IpcCommand {
+    /// Install a mod file
+    Install { path: PathBuf },
+    /// Enable or disable an installed mod by id
+    SetEnabled { id: String, enabled: bool },
+    /// Integrate installed mods
+    Integrate,
+}
+
+/// Response to an `IpcCommand`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IpcResponse {
+    /// The command was queued on the background thread
+    Ok,
+    /// The command was rejected
+    Error(String),
+}
+
+/// Get the platform specific socket name for a control channel name
+///
+/// Namespaced names map to named pipes on Windows,
+/// everywhere else the socket is a file in a directory private to the current user.
+pub fn get_socket_name(name: &str) -> io::Result<String> {
+    match NameTypeSupport::query() {
+        NameTypeSupport::OnlyNamespaced => Ok(format!("@{name}.sock")),
+        NameTypeSupport::OnlyPaths | NameTypeSupport::Both => Ok(get_socket_dir()?
+            .join(format!("{name}.sock"))
+            .to_string_lossy()
+            .into_owned()),
+    }
+}
+
+/// Get the directory sockets are created in, creating it if it doesn't exist
+///
+/// Only the current user can access the directory.
+#[cfg(unix)]
+fn get_socket_dir() -> io::Result<PathBuf> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    let base_dirs = BaseDirs::new()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No home directory"))?;
+    let socket_dir = base_dirs
+        .runtime_dir()
+        .unwrap_or_else(|| base_dirs.data_local_dir())
+        .join("unrealmodding");
+
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(&socket_dir)?;
+    // the directory might have been created with broader permissions
+    fs::set_permissions(&socket_dir, fs::Permissions::from_mode(0o700))?;
+
+    Ok(socket_dir)
+}
+
+#[cfg(not(unix))]
+fn get_socket_dir() -> io::Result<PathBuf> {
+    let base_dirs = BaseDirs::new()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No home directory"))?;
+    let socket_dir = base_dirs.data_local_dir().join("unrealmodding");
+
+    fs::create_dir_all(&socket_dir)?;
+    Ok(socket_dir)
+}
+
+/// Remove a socket file left behind by a mod loader that didn't exit cleanly
+fn remove_stale_socket(socket_name: &str) -> io::Result<()> {
+    if socket_name.starts_with('@') || !Path::new(socket_name).exists() {
+        return Ok(());
+    }
+
+    if LocalSocketStream::connect(socket_name).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            "Another mod loader is listening on the socket",
+        ));
+    }
+
+    fs::remove_file(socket_name)
+}
+
+/// Turn a command into background thread messages
+pub(crate) fn handle_command(
+    command: IpcCommand,
+    data: &Arc<Mutex<ModLoaderAppData>>,
+    background_tx: &Sender<BackgroundThreadMessage>,
+) -> IpcResponse {
+    let messages = match command {
+        IpcCommand::Install { path } => {
+            if !path.is_file() {
+                return IpcResponse::Error(format!("{} is not a file", path.display()));
+            }
+
+            vec![
+                BackgroundThreadMessage::Import(vec![FileToProcess::new(path, true)]),
+                BackgroundThreadMessage::integrate(&data.lock().integration_cancel),
+            ]
+        }
+        IpcCommand::SetEnabled { id, enabled } => {
+            let mut data_guard = data.lock();
+            let Some(game_mod) = data_guard.game_mods.get_mut(&id) else {
+                return IpcResponse::Error(format!("No mod with id {id}"));
+            };
+            game_mod.enabled = enabled;
+
+            vec![
+                BackgroundThreadMessage::WriteConfig,
+                BackgroundThreadMessage::integrate(&data_guard.integration_cancel),
+            ]
+        }
+        IpcCommand::Integrate => vec![BackgroundThreadMessage::integrate(
+            &data.lock().integration_cancel,
+        )],
+    };
+
+    for message in messages {
+        if background_tx.send(message).is_err() {
+            return IpcResponse::Error("Background thread is not running".to_string());
+        }
+    }
+
+    IpcResponse::Ok
+}
+
+/// Answer commands from a single client until it disconnects
+fn handle_client(
+    stream: LocalSocketStream,
+    data: &Arc<Mutex<ModLoaderAppData>>,
+    background_tx: &Sender<BackgroundThreadMessage>,
+) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? != 0 {
+        let response = match serde_json::from_str::<IpcCommand>(line.trim()) {
+            Ok(command) => {
+                debug!("IPC command: {:?}", command);
+                handle_command(command, data, background_tx)
+            }
+            Err(err) => IpcResponse::Error(format!("Invalid command: {err}")),
+        };
+        line.clear();
+
+        let mut response = serde_json::to_string(&response)?;
+        response.push('\n');
+
+        let stream = reader.get_mut();
+        stream.write_all(response.as_bytes())?;
+        stream.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Listen for commands on the control channel
+///
+/// Clients are handled one at a time, this only returns if the socket could not be bound.
+pub(crate) fn listen(
+    name: &str,
+    data: Arc<Mutex<ModLoaderAppData>>,
+    background_tx: Sender<BackgroundThreadMessage>,
+) -> io::Result<()> {
+    let socket_name = get_socket_name(name)?;
+    remove_stale_socket(&socket_name)?;
+    let listener = LocalSocketListener::bind(socket_name.as_str())?;
+    debug!("Listening for IPC commands on {}", socket_name);
+
+    for stream in listener.incoming() {
+        let result = stream.and_then(|stream| handle_client(stream, &data, &background_tx));
+        if let Err(err) = result {
+            warn!("IPC client error: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+    use std::time::Duration;
+
+    use interprocess::local_socket::LocalSocketStream;
+    use parking_lot::Mutex;
+
+    use super::{get_socket_name, listen, IpcCommand, IpcResponse};
+    use crate::background_work::BackgroundThreadMessage;
+    use crate::ModLoaderAppData;
+
+    fn send_command(
+        stream: &mut BufReader<LocalSocketStream>,
+        command: &IpcCommand,
+    ) -> IpcResponse {
+        let mut command = serde_json::to_string(command).unwrap();
+        command.push('\n');
+        stream.get_mut().write_all(command.as_bytes()).unwrap();
+
+        let mut response = String::new();
+        stream.read_line(&mut response).unwrap();
+        serde_json::from_str(response.trim()).unwrap()
+    }
+
+    #[test]
+    fn integrate_round_trip() {
+        let name = format!("unreal_mod_manager_test_{}", std::process::id());
+        let socket_name = get_socket_name(&name).unwrap();
+
+        let data = Arc::new(Mutex::new(ModLoaderAppData::default()));
+        let (background_tx, background_rx) = mpsc::channel();
+        {
+            let name = name.clone();
+            thread::spawn(move || listen(&name, data, background_tx).unwrap());
+        }
+
+        let mut stream = None;
+        for _ in 0..50 {
+            match LocalSocketStream::connect(socket_name.as_str()) {
+                Ok(connected) => {
+                    stream = Some(connected);
+                    break;
+                }
+                Err(_) => thread::sleep(Duration::from_millis(20)),
+            }
+        }
+        let mut stream = BufReader::new(stream.expect("Failed to connect to the socket"));
+
+        assert_eq!(
+            send_command(&mut stream, &IpcCommand::Integrate),
+            IpcResponse::Ok
+        );
+        assert!(matches!(
+            background_rx.recv_timeout(Duration::from_secs(5)),
+            Ok(BackgroundThreadMessage::Integrate(..))
+        ));
+
+        assert!(matches!(
+            send_command(
+                &mut stream,
+                &IpcCommand::SetEnabled {
+                    id: "NotInstalled".to_owned(),
+                    enabled: true,
+                }
+            ),
+            IpcResponse::Error(_)
+        ));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let socket_dir = std::path::Path::new(&socket_name).parent().unwrap();
+            let mode = std::fs::metadata(socket_dir).unwrap().permissions().mode();
+            assert_eq!(mode & 0o077, 0);
+        }
+
+        let _ = std::fs::remove_file(&socket_name);
+    }
+}
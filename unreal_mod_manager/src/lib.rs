@@ -4,8 +4,10 @@
 #![allow(clippy::zombie_processes)]
 
 use std::collections::BTreeMap;
+use std::env;
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::sync::{
     atomic::{AtomicBool, AtomicI32},
     mpsc, Arc,
@@ -17,7 +19,7 @@ use eframe::egui;
 use log::error;
 use parking_lot::Mutex;
 
-use unreal_mod_integrator::IntegratorConfig;
+use unreal_mod_integrator::{IntegrationMetrics, IntegratorConfig};
 
 mod app;
 mod background_work;
@@ -26,18 +28,25 @@ pub mod error;
 pub(crate) mod game_mod;
 pub mod game_path_helpers;
 pub mod game_platform_managers;
-mod mod_config;
+pub mod ipc;
+pub mod mod_config;
 mod mod_processing;
+mod mod_report;
 pub(crate) mod profile;
 pub mod update_info;
 pub mod version;
 
+#[cfg(test)]
+mod test_utils;
+
 use background_work::{BackgroundThreadData, BackgroundThreadMessage};
 use config::InstallManager;
 use error::{ModLoaderError, ModLoaderWarning};
 use game_mod::GameMod;
-use mod_config::write_config;
+use game_platform_managers::{CustomInstallManager, CUSTOM_GAME_PLATFORM};
+use mod_config::{write_config, ConfigStore};
 use mod_processing::dependencies::DependencyGraph;
+use mod_processing::trust::UntrustedReason;
 use version::GameBuild;
 
 pub use unreal_asset;
@@ -65,14 +74,16 @@ pub(crate) struct UntrustedMod {
     pub name: String,
     pub version: String,
     pub hash: Vec<u8>,
+    pub reason: UntrustedReason,
 }
 
 impl UntrustedMod {
-    pub fn new(name: String, version: String, hash: Vec<u8>) -> Self {
+    pub fn new(name: String, version: String, hash: Vec<u8>, reason: UntrustedReason) -> Self {
         UntrustedMod {
             name,
             version,
             hash,
+            reason,
         }
     }
 }
@@ -83,11 +94,25 @@ pub(crate) struct ModLoaderAppData {
     pub mods_path: Option<PathBuf>,
     /// %LocalAppData%\[GameName]\Saved\Paks
     pub paks_path: Option<PathBuf>,
+    /// paks path provided by the game config, takes priority over the install manager
+    pub paks_path_override: Option<PathBuf>,
+    /// where the config is loaded from and saved to, defaults to modconfig.json in the mods path
+    pub config_store: Option<Box<dyn ConfigStore>>,
     /// game install path
     pub game_install_path: Option<PathBuf>,
+    /// explicitly set game install path, used instead of the install managers
+    pub custom_game_install_path: Option<PathBuf>,
+    /// game install path provided on the command line, takes priority over the config
+    pub game_install_path_override: Option<PathBuf>,
 
     pub game_build: Option<GameBuild>,
+    /// game build mods were last integrated for, persisted in the config
+    pub integrated_game_build: Option<GameBuild>,
     pub refuse_mismatched_connections: bool,
+    /// write a mod_report.json next to the integrator pak after integration
+    pub write_mod_report: bool,
+    /// load C++ mods, turning this off skips bootstrapping and dll injection
+    pub cpp_loader_enabled: bool,
     pub files_to_process: Vec<FileToProcess>,
 
     pub game_mods: BTreeMap<String, GameMod>,
@@ -105,7 +130,13 @@ pub(crate) struct ModLoaderAppData {
     pub selected_game_platform: Option<String>,
 
     pub trusted_mods: Vec<Vec<u8>>,
+    /// ids of mods that had a file trusted before
+    pub trusted_mod_ids: Vec<String>,
     pub untrusted_mods: Vec<UntrustedMod>,
+    /// how long each phase of the last successful integration took
+    pub last_integration_metrics: Option<IntegrationMetrics>,
+    /// cancels the running integration, every integration gets a new one
+    pub(crate) integration_cancel: Arc<AtomicBool>,
 
     #[cfg(feature = "cpp_loader")]
     pub(crate) cpp_loader_config: unreal_cpp_bootstrapper::config::GameSettings,
@@ -120,7 +151,10 @@ impl ModLoaderAppData {
         if let Some(manager) = manager {
             self.game_install_path = manager.get_game_install_path();
             self.game_build = manager.get_game_build();
-            self.paks_path = manager.get_paks_path();
+            self.paks_path = self
+                .paks_path_override
+                .clone()
+                .or_else(|| manager.get_paks_path());
 
             #[cfg(feature = "cpp_loader")]
             {
@@ -135,6 +169,55 @@ impl ModLoaderAppData {
         Err(())
     }
 
+    /// Registers the install manager for an explicitly set game install path
+    /// and selects it if requested or if no other platform is selected.
+    pub fn set_custom_install_manager(&mut self, manager: CustomInstallManager, select: bool) {
+        self.custom_game_install_path = Some(manager.game_path.clone());
+        self.install_managers
+            .insert(CUSTOM_GAME_PLATFORM, Box::new(manager));
+
+        if select || self.selected_game_platform.is_none() {
+            let _ = self.set_game_platform(CUSTOM_GAME_PLATFORM);
+        }
+    }
+
+    /// Start a new integration, returning the flag that cancels it
+    pub(crate) fn start_integration(&mut self) -> Arc<AtomicBool> {
+        self.integration_cancel = Arc::new(AtomicBool::new(false));
+        self.integration_cancel.clone()
+    }
+
+    /// Registers the install manager for the game install path passed on the command line
+    /// or saved in the config, see [`ModLoaderAppData::set_custom_install_manager`].
+    ///
+    /// A path passed on the command line is selected even if another platform was saved,
+    /// a path that can't be resolved is reported as a warning.
+    pub(crate) fn apply_custom_game_path(
+        &mut self,
+        game_name: &'static str,
+        get_game_build: impl FnOnce(&Path) -> Option<GameBuild>,
+    ) {
+        let select = self.game_install_path_override.is_some();
+        let Some(game_install_path) = self
+            .game_install_path_override
+            .clone()
+            .or_else(|| self.custom_game_install_path.clone())
+        else {
+            return;
+        };
+
+        match game_path_helpers::resolve_game_path(&game_install_path) {
+            Ok(game_install_path) => {
+                let game_build = get_game_build(&game_install_path);
+                self.set_custom_install_manager(
+                    CustomInstallManager::new(game_install_path, game_name, game_build),
+                    select,
+                );
+            }
+            Err(warning) => self.warnings.push(warning),
+        }
+    }
+
     #[allow(clippy::borrowed_box)]
     pub fn get_install_manager(&self) -> Option<&Box<dyn InstallManager>> {
         if let Some(platform) = &self.selected_game_platform {
@@ -150,9 +233,17 @@ where
     GC: 'static + config::GameConfig<'data, IC, D, E>,
     IC: 'static + IntegratorConfig<'data, D, E>,
 {
+    if let Some(mods_path) = validate_arg() {
+        process::exit(validate(mods_path.or_else(|| config.get_mods_path())));
+    }
+
     let data = Arc::new(Mutex::new(ModLoaderAppData {
         refuse_mismatched_connections: true,
+        cpp_loader_enabled: true,
         install_managers: config.get_install_managers(),
+        paks_path_override: config.get_paks_path(),
+        game_install_path_override: game_install_path_arg(),
+        config_store: config.get_config_store(),
         #[cfg(feature = "cpp_loader")]
         cpp_loader_config: GC::get_cpp_loader_config(),
         ..Default::default()
@@ -178,7 +269,25 @@ where
         .unwrap_or(false);
 
     if !has_newer_update {
-        let _ = background_tx.send(BackgroundThreadMessage::integrate());
+        let _ = background_tx.send(BackgroundThreadMessage::integrate(
+            &data.lock().integration_cancel,
+        ));
+    }
+
+    if let Some(ipc_name) = config.get_ipc_name() {
+        let data = data.clone();
+        let background_tx = background_tx.clone();
+        thread::Builder::new()
+            .name("ipc".to_string())
+            .spawn(move || {
+                if let Err(err) = ipc::listen(&ipc_name, data, background_tx) {
+                    error!("Failed to start IPC listener: {}", err);
+                }
+            })
+            .unwrap_or_else(|_| {
+                error!("Failed to start IPC thread");
+                panic!();
+            });
     }
 
     let mut about_text = format!("# Mod manager version: {}\n", GC::CRATE_VERSION);
@@ -256,6 +365,70 @@ where
     });
 }
 
+/// Game install path passed as `--game-path <path>` or `--game-path=<path>`
+fn game_install_path_arg() -> Option<PathBuf> {
+    let mut args = env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--game-path" {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.to_str().and_then(|e| e.strip_prefix("--game-path=")) {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    None
+}
+
+/// Runs `--validate`, printing every problem found and returning the process exit code
+fn validate(mods_path: Option<PathBuf>) -> i32 {
+    let Some(mods_path) = mods_path else {
+        eprintln!("Error: {}", ModLoaderError::no_base_path());
+        return 2;
+    };
+
+    match mod_processing::validate::validate_mods(&mods_path) {
+        Ok(warnings) if warnings.is_empty() => {
+            println!("All mods in {} are valid", mods_path.display());
+            0
+        }
+        Ok(warnings) => {
+            for warning in &warnings {
+                eprintln!("{warning}");
+            }
+            eprintln!(
+                "Found {} problem(s) in {}",
+                warnings.len(),
+                mods_path.display()
+            );
+            1
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            2
+        }
+    }
+}
+
+/// Mods directory passed as `--validate [path]` or `--validate=<path>`,
+/// `Some(None)` if the flag was passed without a path to use the default mods directory
+fn validate_arg() -> Option<Option<PathBuf>> {
+    let mut args = env::args_os().skip(1).peekable();
+    while let Some(arg) = args.next() {
+        if arg == "--validate" {
+            let path = args
+                .next_if(|e| !e.to_string_lossy().starts_with("--"))
+                .map(PathBuf::from);
+            return Some(path);
+        }
+        if let Some(path) = arg.to_str().and_then(|e| e.strip_prefix("--validate=")) {
+            return Some(Some(PathBuf::from(path)));
+        }
+    }
+
+    None
+}
+
 pub const fn default_true() -> bool {
     true
 }
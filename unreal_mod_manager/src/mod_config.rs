@@ -1,37 +1,150 @@
 use std::collections::HashMap;
+use std::fmt::Debug;
 use std::fs;
+use std::io;
+use std::path::PathBuf;
 
 use log::{debug, error, warn};
+use parking_lot::Mutex;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::game_mod::SelectedVersion;
 use crate::profile::parse_profile_config;
+use crate::version::GameBuild;
 use crate::ModLoaderAppData;
 
+/// Name of the config file in the mods directory
+pub const CONFIG_FILE_NAME: &str = "modconfig.json";
+
+/// Persisted mod loader config
 #[derive(Serialize, Deserialize, Debug)]
-struct ModConfig {
-    selected_game_platform: Option<String>,
-    refuse_mismatched_connections: bool,
-    current: ModsConfigData,
+pub struct ModConfig {
+    /// name of the install manager that was selected
+    pub selected_game_platform: Option<String>,
+    /// explicitly set game install path, used instead of detecting the install
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game_install_path: Option<PathBuf>,
+    /// refuse connections to servers with a different set of mods
+    pub refuse_mismatched_connections: bool,
+    /// write a report of the integrated mods next to the integrator pak
+    #[serde(default)]
+    pub write_mod_report: bool,
+    /// load the mods' C++ code
+    #[serde(default = "crate::default_true")]
+    pub cpp_loader_enabled: bool,
+    /// game build mods were last integrated for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrated_game_build: Option<String>,
+    /// settings of the installed mods
+    pub current: ModsConfigData,
+    /// hex encoded hashes of mod files that were trusted to load code
     #[serde(default)]
-    trusted_mods: Vec<String>,
-    profiles: Value,
+    pub trusted_mods: Vec<String>,
+    /// ids of mods that had a file trusted before
+    #[serde(default)]
+    pub trusted_mod_ids: Vec<String>,
+    /// saved mod profiles
+    pub profiles: Value,
 }
 
+/// Settings of the installed mods
 #[derive(Serialize, Deserialize, Debug)]
-struct ModsConfigData {
-    mods: HashMap<String, ModConfigData>,
+pub struct ModsConfigData {
+    /// settings of each mod by mod id
+    pub mods: HashMap<String, ModConfigData>,
 }
 
+/// Settings of a single mod
 #[derive(Serialize, Deserialize, Debug)]
-struct ModConfigData {
+pub struct ModConfigData {
+    /// always use the newest installed version instead of `version`
     #[serde(default = "crate::default_true")]
-    force_latest: bool,
-    priority: u16,
-    enabled: bool,
-    version: String,
+    pub force_latest: bool,
+    /// load order priority
+    pub priority: u16,
+    /// if the mod gets integrated
+    pub enabled: bool,
+    /// selected version
+    pub version: String,
+}
+
+/// Storage backend for the mod loader config
+pub trait ConfigStore: Debug + Send {
+    /// Load the config, `Ok(None)` if nothing has been saved yet
+    fn load(&self) -> io::Result<Option<ModConfig>>;
+    /// Save the config
+    fn save(&self, config: &ModConfig) -> io::Result<()>;
+}
+
+/// Stores the config as a json file, used by default with `modconfig.json` in the mods directory
+#[derive(Debug)]
+pub struct FileConfigStore {
+    path: PathBuf,
+}
+
+impl FileConfigStore {
+    pub fn new(path: PathBuf) -> Self {
+        FileConfigStore { path }
+    }
+
+    /// Path a config that fails to parse gets moved to
+    pub fn backup_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".bak");
+        PathBuf::from(path)
+    }
+}
+
+impl ConfigStore for FileConfigStore {
+    fn load(&self) -> io::Result<Option<ModConfig>> {
+        if !self.path.is_file() {
+            return Ok(None);
+        }
+
+        match serde_json::from_str(&fs::read_to_string(&self.path)?) {
+            Ok(config) => Ok(Some(config)),
+            Err(err) => {
+                // a broken config would fail to load every time, keep it around for fixing it by hand
+                let backup_path = self.backup_path();
+                fs::rename(&self.path, &backup_path)?;
+                warn!("Moved unreadable config to {}", backup_path.display());
+                Err(err.into())
+            }
+        }
+    }
+
+    fn save(&self, config: &ModConfig) -> io::Result<()> {
+        let config_str = serde_json::to_string(config)?;
+        fs::write(&self.path, config_str)
+    }
+}
+
+/// Keeps the config in memory, for embedding the mod loader or testing
+#[derive(Debug, Default)]
+pub struct MemoryConfigStore {
+    config_str: Mutex<Option<String>>,
+}
+
+impl MemoryConfigStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ConfigStore for MemoryConfigStore {
+    fn load(&self) -> io::Result<Option<ModConfig>> {
+        match *self.config_str.lock() {
+            Some(ref config_str) => Ok(Some(serde_json::from_str(config_str)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn save(&self, config: &ModConfig) -> io::Result<()> {
+        *self.config_str.lock() = Some(serde_json::to_string(config)?);
+        Ok(())
+    }
 }
 
 pub(crate) fn load_config(data: &mut ModLoaderAppData) {
@@ -46,22 +159,23 @@ pub(crate) fn load_config(data: &mut ModLoaderAppData) {
         }};
     }
 
-    let config_path = data.mods_path.as_ref().unwrap().join("modconfig.json");
-
-    if !config_path.is_file() {
-        bail!()
-    }
-
-    let config: ModConfig = match serde_json::from_str(&fs::read_to_string(&config_path).unwrap()) {
-        Ok(config) => config,
-        Err(err) => {
+    let config = match data.config_store.as_ref().map(|store| store.load()) {
+        Some(Ok(Some(config))) => config,
+        Some(Ok(None)) | None => bail!(),
+        Some(Err(err)) => {
             error!("{}", err);
-            let _ = fs::remove_file(&config_path);
             bail!();
         }
     };
 
     data.refuse_mismatched_connections = config.refuse_mismatched_connections;
+    data.custom_game_install_path = config.game_install_path;
+    data.write_mod_report = config.write_mod_report;
+    data.cpp_loader_enabled = config.cpp_loader_enabled;
+    data.integrated_game_build = config
+        .integrated_game_build
+        .as_ref()
+        .and_then(|e| GameBuild::try_from(e).ok());
 
     for (mod_id, mod_config) in config.current.mods.iter() {
         let game_mod = data.game_mods.get_mut(mod_id);
@@ -95,6 +209,7 @@ pub(crate) fn load_config(data: &mut ModLoaderAppData) {
         .iter()
         .filter_map(|e| hex::decode(e).ok())
         .collect::<Vec<_>>();
+    data.trusted_mod_ids = config.trusted_mod_ids;
 
     data.profiles = match parse_profile_config(config.profiles) {
         Ok(profiles) => profiles,
@@ -116,10 +231,18 @@ pub(crate) fn load_config(data: &mut ModLoaderAppData) {
 }
 
 pub(crate) fn write_config(data: &ModLoaderAppData) {
-    let config_path = data.mods_path.as_ref().unwrap().join("modconfig.json");
+    let Some(ref config_store) = data.config_store else {
+        warn!("No config store to write config to");
+        return;
+    };
+
     let mut config = ModConfig {
         selected_game_platform: data.selected_game_platform.clone(),
+        game_install_path: data.custom_game_install_path.clone(),
         refuse_mismatched_connections: data.refuse_mismatched_connections,
+        write_mod_report: data.write_mod_report,
+        cpp_loader_enabled: data.cpp_loader_enabled,
+        integrated_game_build: data.integrated_game_build.map(|e| e.to_string()),
         current: ModsConfigData {
             mods: HashMap::new(),
         },
@@ -128,6 +251,7 @@ pub(crate) fn write_config(data: &ModLoaderAppData) {
             .iter()
             .map(hex::encode)
             .collect::<Vec<_>>(),
+        trusted_mod_ids: data.trusted_mod_ids.clone(),
         profiles: serde_json::to_value(data.profiles.clone()).unwrap(),
     };
 
@@ -142,8 +266,122 @@ pub(crate) fn write_config(data: &ModLoaderAppData) {
         config.current.mods.insert(mod_id.to_owned(), mod_config);
     }
 
-    let config_str = serde_json::to_string(&config).unwrap();
-    fs::write(config_path, config_str).unwrap();
+    if let Err(err) = config_store.save(&config) {
+        error!("Failed to write config: {}", err);
+        return;
+    }
 
     debug!("Wrote config");
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+
+    use serde_json::Value;
+
+    use super::{
+        load_config, write_config, ConfigStore, FileConfigStore, MemoryConfigStore, ModConfig,
+        ModConfigData, ModsConfigData, CONFIG_FILE_NAME,
+    };
+    use crate::test_utils::test_dir;
+    use crate::ModLoaderAppData;
+
+    fn test_config() -> ModConfig {
+        ModConfig {
+            selected_game_platform: Some("Steam".to_owned()),
+            game_install_path: None,
+            refuse_mismatched_connections: true,
+            write_mod_report: true,
+            cpp_loader_enabled: false,
+            integrated_game_build: Some("1.2.3.4".to_owned()),
+            current: ModsConfigData {
+                mods: HashMap::from([(
+                    "TestMod".to_owned(),
+                    ModConfigData {
+                        force_latest: false,
+                        priority: 10,
+                        enabled: true,
+                        version: "0.1.0".to_owned(),
+                    },
+                )]),
+            },
+            trusted_mods: vec!["00ff".to_owned()],
+            trusted_mod_ids: vec!["TestMod".to_owned()],
+            profiles: Value::Array(Vec::new()),
+        }
+    }
+
+    fn assert_round_trip(store: &dyn ConfigStore) {
+        assert!(store.load().unwrap().is_none());
+
+        let config = test_config();
+        store.save(&config).unwrap();
+        let loaded = store.load().unwrap().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn memory_store_round_trip() {
+        assert_round_trip(&MemoryConfigStore::new());
+    }
+
+    #[test]
+    fn file_store_round_trip() {
+        let dir = test_dir("config_round_trip");
+        fs::create_dir_all(&dir).unwrap();
+
+        assert_round_trip(&FileConfigStore::new(dir.join(CONFIG_FILE_NAME)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn file_store_keeps_broken_config() {
+        let dir = test_dir("config_broken");
+        fs::create_dir_all(&dir).unwrap();
+
+        let store = FileConfigStore::new(dir.join(CONFIG_FILE_NAME));
+        fs::write(dir.join(CONFIG_FILE_NAME), "{ not json").unwrap();
+
+        assert!(store.load().is_err());
+        assert_eq!(
+            fs::read_to_string(store.backup_path()).unwrap(),
+            "{ not json"
+        );
+        // the next start doesn't fail on the same file again
+        assert!(store.load().unwrap().is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn cpp_loader_toggle() {
+        let data = ModLoaderAppData {
+            selected_game_platform: Some("Steam".to_owned()),
+            cpp_loader_enabled: false,
+            config_store: Some(Box::new(MemoryConfigStore::new())),
+            ..Default::default()
+        };
+        write_config(&data);
+
+        let mut data = ModLoaderAppData {
+            cpp_loader_enabled: true,
+            config_store: data.config_store,
+            ..Default::default()
+        };
+        load_config(&mut data);
+        assert!(!data.cpp_loader_enabled);
+
+        // configs written before the toggle existed keep loading C++ mods
+        let mut config = serde_json::to_value(test_config()).unwrap();
+        config.as_object_mut().unwrap().remove("cpp_loader_enabled");
+        let config: ModConfig = serde_json::from_value(config).unwrap();
+        assert!(config.cpp_loader_enabled);
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use petgraph::{graph::NodeIndex, prelude::DiGraph, visit::IntoNodeReferences, Direction};
 use semver::{Version, VersionReq};
@@ -18,6 +18,7 @@ pub struct ModWithDependencies {
     pub mod_id: String,
     pub versions: Vec<Version>,
     pub dependencies: HashMap<String, Dependency>,
+    pub conflicts: Vec<String>,
 }
 
 impl ModWithDependencies {
@@ -25,11 +26,13 @@ impl ModWithDependencies {
         mod_id: String,
         versions: Vec<Version>,
         dependencies: HashMap<String, Dependency>,
+        conflicts: Vec<String>,
     ) -> Self {
         ModWithDependencies {
             mod_id,
             versions,
             dependencies,
+            conflicts,
         }
     }
 }
@@ -38,6 +41,8 @@ impl ModWithDependencies {
 pub struct DependencyGraph {
     graph: DiGraph<GraphMod, VersionReq, u32>,
     node_lookup: HashMap<String, NodeIndex>,
+    /// Mod ids each mod declared as incompatible in its metadata
+    conflicts: HashMap<String, Vec<String>>,
 }
 
 // https://github.com/dtolnay/semver/issues/170#issuecomment-734284639
@@ -94,6 +99,11 @@ impl DependencyGraph {
                 downloads: Vec::new(),
             });
 
+            if !game_mod.conflicts.is_empty() {
+                self.conflicts
+                    .insert(game_mod.mod_id.clone(), game_mod.conflicts.clone());
+            }
+
             for (dependency_mod_id, dependency) in &game_mod.dependencies {
                 let dependency_node = self.get_or_add_mod(GraphMod {
                     mod_id: dependency_mod_id.clone(),
@@ -169,9 +179,45 @@ impl DependencyGraph {
             }
         }
 
+        warnings.extend(self.find_conflicts(&matching_versions));
+
         (matching_versions, warnings)
     }
 
+    /// Finds pairs of resolved mods where either mod declares the other as a conflict
+    ///
+    /// Each pair is only reported once, even if both mods list each other.
+    fn find_conflicts(&self, resolved: &HashMap<String, Version>) -> Vec<ModLoaderWarning> {
+        let mut reported = HashSet::new();
+        let mut warnings = Vec::new();
+
+        let mut mod_ids = resolved.keys().collect::<Vec<_>>();
+        mod_ids.sort();
+
+        for mod_id in mod_ids {
+            let Some(conflicts) = self.conflicts.get(mod_id) else {
+                continue;
+            };
+
+            for conflict in conflicts {
+                if conflict == mod_id || !resolved.contains_key(conflict) {
+                    continue;
+                }
+
+                let pair = if mod_id < conflict {
+                    (mod_id.clone(), conflict.clone())
+                } else {
+                    (conflict.clone(), mod_id.clone())
+                };
+                if reported.insert(pair.clone()) {
+                    warnings.push(ModLoaderWarning::conflict(pair.0, pair.1));
+                }
+            }
+        }
+
+        warnings
+    }
+
     pub fn find_mod_dependents(&self, mod_id: &str) -> Vec<String> {
         match self
             .graph
@@ -216,3 +262,50 @@ impl DependencyGraph {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use semver::Version;
+
+    use super::{DependencyGraph, ModWithDependencies};
+    use crate::error::ModLoaderWarningKind;
+
+    fn enabled_mod(mod_id: &str, conflicts: &[&str]) -> ModWithDependencies {
+        ModWithDependencies::new(
+            mod_id.to_owned(),
+            vec![Version::new(1, 0, 0)],
+            HashMap::new(),
+            conflicts.iter().map(|e| e.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn find_conflicts() {
+        let mut graph = DependencyGraph::default();
+        graph.add_mods(&[
+            // only one side declares the conflict
+            enabled_mod("ModA", &["ModB"]),
+            enabled_mod("ModB", &[]),
+            // both sides declare the conflict
+            enabled_mod("ModC", &["ModD"]),
+            enabled_mod("ModD", &["ModC"]),
+            // conflicts with a mod that isn't installed
+            enabled_mod("ModE", &["ModF"]),
+        ]);
+
+        let (resolved, warnings) = graph.validate_graph();
+        assert_eq!(resolved.len(), 5);
+
+        let mut conflicts = warnings
+            .iter()
+            .map(|warning| match warning.kind {
+                ModLoaderWarningKind::Conflict(ref a, ref b) => (a.as_str(), b.as_str()),
+                ref kind => panic!("unexpected warning {kind:?}"),
+            })
+            .collect::<Vec<_>>();
+        conflicts.sort();
+        assert_eq!(conflicts, vec![("ModA", "ModB"), ("ModC", "ModD")]);
+    }
+}
@@ -10,6 +10,9 @@ pub(crate) mod index_file;
 use index_file::{download_index_files, gather_index_files, insert_index_file_data};
 mod pakfile_reading;
 use pakfile_reading::{insert_mods_from_readdata, read_pak_files};
+pub(crate) mod preflight;
+pub(crate) mod trust;
+pub(crate) mod validate;
 mod version_handling;
 use version_handling::{auto_pick_versions, set_mod_data_from_version};
 
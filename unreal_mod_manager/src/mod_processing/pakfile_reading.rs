@@ -13,7 +13,7 @@ use crate::{FileToProcess, ModLoaderAppData};
 use super::verify::{self, MOD_FILENAME_REGEX};
 
 #[derive(Debug)]
-pub(crate) struct ReadData(String, Metadata);
+pub(crate) struct ReadData(pub String, pub Metadata);
 
 pub(crate) fn read_pak_files(
     mod_files: &[FileToProcess],
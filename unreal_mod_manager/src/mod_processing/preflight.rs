@@ -0,0 +1,167 @@
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+use crate::error::{ModLoaderError, ModLoaderWarning};
+
+const WRITE_TEST_FILE_NAME: &str = ".write_test";
+
+/// Checks that mods can be installed into `paks_path` before anything is copied.
+///
+/// `mods_size` is the combined size of all mod files that will be installed.
+/// The integrator pak can be about as large as the mods it was built from,
+/// so twice that size is required to be free.
+/// The files of the last integration are removed before installing, so their size counts as free.
+pub(crate) fn check_paks_dir(paks_path: &Path, mods_size: u64) -> Result<(), ModLoaderError> {
+    let test_file = paks_path.join(WRITE_TEST_FILE_NAME);
+    File::create(&test_file)
+        .and_then(|_| fs::remove_file(&test_file))
+        .map_err(|err| ModLoaderError::path_not_writable(paks_path.to_owned(), err))?;
+
+    let available = fs2::available_space(paks_path).map_err(|err| {
+        ModLoaderError::io_error_with_message("query free disk space".to_owned(), err)
+    })?;
+    let available = available.saturating_add(dir_size(paks_path));
+
+    let required = mods_size.saturating_mul(2);
+    if available < required {
+        return Err(ModLoaderError::insufficient_space(required, available));
+    }
+
+    Ok(())
+}
+
+/// Combined size of the files in `path` and its subdirectories, files that can't be read are left out
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| Some((e.path(), e.file_type().ok()?)))
+        .map(|(path, file_type)| match file_type.is_dir() {
+            true => dir_size(&path),
+            false => fs::metadata(&path).map_or(0, |e| e.len()),
+        })
+        .fold(0, u64::saturating_add)
+}
+
+/// Removes the files of the last integration from `paks_path`.
+///
+/// [`check_paks_dir`] runs first, so if the new mods can't be installed
+/// the last integration is left in place.
+pub(crate) fn clear_paks_dir(
+    paks_path: &Path,
+    mods_size: u64,
+) -> Result<Vec<ModLoaderWarning>, ModLoaderError> {
+    let mut warnings = Vec::new();
+
+    fs::create_dir_all(paks_path)
+        .map_err(|err| ModLoaderError::path_not_writable(paks_path.to_owned(), err))?;
+    check_paks_dir(paks_path, mods_size)?;
+
+    if let Err(err) = fs::remove_dir_all(paks_path) {
+        if !matches!(err.kind(), io::ErrorKind::NotFound) {
+            warnings.push(ModLoaderWarning::io_error_with_message(
+                "Removing old paks directory failed".to_owned(),
+                err,
+            ));
+        }
+    };
+    if let Err(err) = fs::create_dir_all(paks_path) {
+        warnings.push(err.into());
+    };
+
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+
+    use super::{check_paks_dir, clear_paks_dir};
+    use crate::error::ModLoaderErrorKind;
+    use crate::test_utils::test_dir;
+
+    #[test]
+    fn clear_paks_dir_checks_first() {
+        let paks_path = test_dir("clear_paks_dir");
+        fs::create_dir_all(&paks_path).unwrap();
+        let old_pak = paks_path.join("000-OldMod-1.0.0_P.pak");
+        fs::write(&old_pak, b"old").unwrap();
+
+        // the mods don't fit, the last integration is kept
+        assert!(clear_paks_dir(&paks_path, u64::MAX).is_err());
+        assert!(old_pak.exists());
+
+        let warnings = clear_paks_dir(&paks_path, 0).unwrap();
+        assert!(warnings.is_empty());
+        assert!(paks_path.is_dir());
+        assert!(!old_pak.exists());
+
+        fs::remove_dir_all(&paks_path).unwrap();
+
+        // a missing paks directory is created
+        clear_paks_dir(&paks_path, 0).unwrap();
+        assert!(paks_path.is_dir());
+
+        fs::remove_dir_all(&paks_path).unwrap();
+    }
+
+    #[test]
+    fn old_paks_count_as_free() {
+        const OLD_PAK_SIZE: u64 = 64 * 1024 * 1024;
+
+        let paks_path = test_dir("old_paks_count_as_free");
+        fs::create_dir_all(&paks_path).unwrap();
+        // sparse, so the free space barely changes
+        File::create(paks_path.join("000-OldMod-1.0.0_P.pak"))
+            .and_then(|e| e.set_len(OLD_PAK_SIZE))
+            .unwrap();
+
+        // the new mods only fit once the old pak is gone
+        let available = fs2::available_space(&paks_path).unwrap();
+        let mods_size = (available + OLD_PAK_SIZE / 2) / 2;
+        let result = check_paks_dir(&paks_path, mods_size);
+
+        fs::remove_dir_all(&paks_path).unwrap();
+        result.unwrap();
+    }
+
+    #[test]
+    fn paks_dir_not_writable() {
+        let dir = test_dir("paks_dir_not_writable");
+        fs::create_dir_all(&dir).unwrap();
+
+        // the paks directory can't be created below a file
+        let blocker = dir.join("Paks");
+        fs::write(&blocker, b"").unwrap();
+        let result = clear_paks_dir(&blocker.join("LogicMods"), 0);
+        assert!(matches!(
+            result.unwrap_err().kind,
+            ModLoaderErrorKind::PathNotWritable(..)
+        ));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let paks_path = dir.join("ReadOnly");
+            fs::create_dir_all(&paks_path).unwrap();
+            fs::set_permissions(&paks_path, fs::Permissions::from_mode(0o555)).unwrap();
+
+            // permissions don't apply to root, there is nothing to check then
+            if fs::write(paks_path.join("probe"), b"").is_err() {
+                let result = clear_paks_dir(&paks_path, 0);
+                assert!(matches!(
+                    result.unwrap_err().kind,
+                    ModLoaderErrorKind::PathNotWritable(..)
+                ));
+            }
+            fs::set_permissions(&paks_path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Why a mod needs to be approved before its code is loaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub(crate) enum UntrustedReason {
+    /// No version of this mod has been trusted before
+    NotPreviouslyTrusted,
+    /// A different file of this mod was trusted, but this one doesn't match its hash
+    HashChanged,
+}
+
+impl fmt::Display for UntrustedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UntrustedReason::NotPreviouslyTrusted => write!(f, "not trusted before"),
+            UntrustedReason::HashChanged => write!(f, "file changed since it was trusted"),
+        }
+    }
+}
+
+/// Check whether a mod file is trusted, returns the reason if it isn't
+///
+/// `other_version_hashes` are only requested if the mod id wasn't recorded as trusted,
+/// configs from before mod ids were recorded only have the hashes of trusted files.
+pub(crate) fn evaluate_trust(
+    mod_id: &str,
+    hash: &[u8],
+    trusted_mods: &[Vec<u8>],
+    trusted_mod_ids: &[String],
+    other_version_hashes: impl FnOnce() -> Vec<Vec<u8>>,
+) -> Option<UntrustedReason> {
+    if trusted_mods.iter().any(|trusted| trusted == hash) {
+        return None;
+    }
+
+    let previously_trusted = trusted_mod_ids.iter().any(|trusted| trusted == mod_id)
+        || other_version_hashes()
+            .iter()
+            .any(|other| trusted_mods.contains(other));
+
+    match previously_trusted {
+        true => Some(UntrustedReason::HashChanged),
+        false => Some(UntrustedReason::NotPreviouslyTrusted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate_trust, UntrustedReason};
+
+    #[test]
+    fn trust() {
+        let trusted_mods = vec![vec![1u8; 32]];
+        let trusted_mod_ids = vec!["TrustedMod".to_owned()];
+
+        // the file itself was trusted
+        assert_eq!(
+            evaluate_trust(
+                "TrustedMod",
+                &[1u8; 32],
+                &trusted_mods,
+                &trusted_mod_ids,
+                || { unreachable!() }
+            ),
+            None
+        );
+        assert_eq!(
+            evaluate_trust("OtherMod", &[1u8; 32], &trusted_mods, &[], Vec::new),
+            None
+        );
+
+        // a new version of a trusted mod
+        assert_eq!(
+            evaluate_trust(
+                "TrustedMod",
+                &[2u8; 32],
+                &trusted_mods,
+                &trusted_mod_ids,
+                || { unreachable!() }
+            ),
+            Some(UntrustedReason::HashChanged)
+        );
+
+        // a new version of a mod trusted before mod ids were recorded
+        assert_eq!(
+            evaluate_trust(
+                "OldTrustedMod",
+                &[2u8; 32],
+                &trusted_mods,
+                &trusted_mod_ids,
+                || { vec![vec![1u8; 32]] }
+            ),
+            Some(UntrustedReason::HashChanged)
+        );
+
+        assert_eq!(
+            evaluate_trust(
+                "NewMod",
+                &[2u8; 32],
+                &trusted_mods,
+                &trusted_mod_ids,
+                || { vec![vec![3u8; 32]] }
+            ),
+            Some(UntrustedReason::NotPreviouslyTrusted)
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::Path;
+
+use semver::Version;
+use unreal_mod_integrator::INTEGRATOR_PAK_FILE_NAME;
+
+use crate::error::{ModLoaderError, ModLoaderWarning};
+use crate::FileToProcess;
+
+use super::dependencies::{DependencyGraph, ModWithDependencies};
+use super::pakfile_reading::read_pak_files;
+
+/// Checks every mod in `mods_path` without integrating or downloading anything.
+///
+/// Reads the metadata of each mod, checks its version and resolves dependencies
+/// and conflicts between the installed mods. Every problem found is returned as a warning.
+pub(crate) fn validate_mods(mods_path: &Path) -> Result<Vec<ModLoaderWarning>, ModLoaderError> {
+    let mods_dir = fs::read_dir(mods_path).map_err(|err| {
+        ModLoaderError::io_error_with_message("read Mods directory".to_owned(), err)
+    })?;
+
+    let mod_files = mods_dir
+        .filter_map(|e| e.ok())
+        .filter(|e| match e.file_name().into_string() {
+            Ok(s) => s.ends_with("_P.pak") && s != INTEGRATOR_PAK_FILE_NAME,
+            Err(_) => false,
+        })
+        .map(|e| FileToProcess::new(e.path(), false))
+        .collect::<Vec<_>>();
+
+    let (mods_read, mut warnings) = read_pak_files(&mod_files);
+
+    let mut mods = Vec::new();
+    for (mod_id, mod_files) in mods_read {
+        let mut versions = Vec::new();
+        for read_data in mod_files {
+            match Version::parse(&read_data.1.mod_version) {
+                Ok(version) => versions.push((version, read_data.1)),
+                Err(_) => warnings.push(ModLoaderWarning::invalid_version(read_data.0)),
+            }
+        }
+        versions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        // dependencies are checked for the version that would be picked by default
+        let Some((_, latest)) = versions.last() else {
+            continue;
+        };
+        let (dependencies, conflicts) = (latest.dependencies.clone(), latest.conflicts.clone());
+
+        mods.push(ModWithDependencies::new(
+            mod_id,
+            versions.into_iter().map(|(version, _)| version).collect(),
+            dependencies,
+            conflicts,
+        ));
+    }
+
+    let mut graph = DependencyGraph::default();
+    graph.add_mods(&mods);
+
+    let (_, graph_warnings) = graph.validate_graph();
+    warnings.extend(graph_warnings);
+
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::path::Path;
+
+    use unreal_pak::{pakversion::PakVersion, PakWriter};
+
+    use super::validate_mods;
+    use crate::error::ModLoaderWarningKind;
+    use crate::test_utils::test_dir;
+
+    fn write_mod(mods_path: &Path, mod_id: &str, version: &str, conflicts: &[&str]) {
+        let metadata = serde_json::json!({
+            "schema_version": 2,
+            "name": mod_id,
+            "mod_id": mod_id,
+            "version": version,
+            "conflicts": conflicts,
+        });
+
+        let file_name = format!("000-{mod_id}-{version}_P.pak");
+        let mut writer = PakWriter::new(
+            File::create(mods_path.join(file_name)).unwrap(),
+            PakVersion::FnameBasedCompressionMethod,
+        );
+        writer
+            .write_entry(
+                &"metadata.json".to_string(),
+                &serde_json::to_vec(&metadata).unwrap(),
+                false,
+            )
+            .unwrap();
+        writer.finish_write().unwrap();
+    }
+
+    #[test]
+    fn validate() {
+        let dir = test_dir("validate");
+        fs::create_dir_all(&dir).unwrap();
+
+        write_mod(&dir, "ModA", "1.0.0", &[]);
+        assert!(validate_mods(&dir).unwrap().is_empty());
+        assert_eq!(crate::validate(Some(dir.clone())), 0);
+
+        write_mod(&dir, "ModB", "1.0.0", &["ModA"]);
+        fs::write(dir.join("001-Broken-1.0.0_P.pak"), b"not a pak").unwrap();
+
+        let warnings = validate_mods(&dir).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .any(|warning| matches!(warning.kind, ModLoaderWarningKind::Conflict(..))));
+        assert!(warnings
+            .iter()
+            .any(|warning| warning.mod_id.as_deref() == Some("001-Broken-1.0.0_P.pak")));
+        assert_eq!(crate::validate(Some(dir.clone())), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        // a missing mods directory can't be validated at all
+        assert!(validate_mods(&dir).is_err());
+        assert_eq!(crate::validate(Some(dir)), 2);
+        assert_eq!(crate::validate(None), 2);
+    }
+}
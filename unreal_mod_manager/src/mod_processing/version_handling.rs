@@ -52,6 +52,7 @@ pub(crate) fn set_mod_data_from_version(data: &mut ModLoaderAppData, filter: &[S
             game_mod.homepage = metadata.homepage.clone();
             game_mod.download = metadata.download.clone();
             game_mod.dependencies = metadata.dependencies.clone();
+            game_mod.conflicts = metadata.conflicts.clone();
             let path = data
                 .mods_path
                 .as_ref()
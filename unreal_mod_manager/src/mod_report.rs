@@ -0,0 +1,234 @@
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use log::debug;
+use serde::Serialize;
+use unreal_mod_integrator::IntegrationMetrics;
+use unreal_pak::{PakError, PakReader};
+
+use crate::error::ModLoaderWarning;
+use crate::mod_processing::trust::UntrustedReason;
+
+/// Name of the report file written next to the integrator pak
+pub(crate) const MOD_REPORT_FILE_NAME: &str = "mod_report.json";
+
+/// Listing of everything that went into the last integration, used for troubleshooting
+#[derive(Serialize, Debug, Default)]
+pub(crate) struct ModReport {
+    pub mods: Vec<ModReportEntry>,
+    pub warnings: Vec<String>,
+    /// How long each integration phase took
+    pub timings: Vec<ModReportTiming>,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct ModReportEntry {
+    pub mod_id: String,
+    pub version: String,
+    pub file_name: String,
+    /// Pak paths contributed by this mod
+    pub paks: Vec<String>,
+    /// Why the mod needs approval before its code is loaded, if it does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub untrusted_reason: Option<UntrustedReason>,
+    /// Why the pak paths of this mod could not be listed, if they couldn't
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ModReportEntry {
+    /// Create an entry for an installed mod, listing the pak paths of its pak file.
+    ///
+    /// A pak that can't be read is recorded in the entry, it doesn't fail the integration.
+    pub fn new(
+        mod_id: String,
+        version: String,
+        file_name: String,
+        pak_path: &Path,
+        untrusted_reason: Option<UntrustedReason>,
+    ) -> Self {
+        let (paks, error) = match read_pak_paths(pak_path) {
+            Ok(paks) => (paks, None),
+            Err(err) => (Vec::new(), Some(err.to_string())),
+        };
+
+        ModReportEntry {
+            mod_id,
+            version,
+            file_name,
+            paks,
+            untrusted_reason,
+            error,
+        }
+    }
+}
+
+fn read_pak_paths(pak_path: &Path) -> Result<Vec<String>, PakError> {
+    let file = File::open(pak_path)?;
+    let mut pak = PakReader::new(&file);
+    pak.load_index()?;
+    Ok(pak.get_entry_names().into_iter().cloned().collect())
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct ModReportTiming {
+    pub phase: String,
+    pub milliseconds: u128,
+}
+
+impl ModReportTiming {
+    fn new(phase: String, duration: Duration) -> Self {
+        ModReportTiming {
+            phase,
+            milliseconds: duration.as_millis(),
+        }
+    }
+}
+
+impl ModReport {
+    pub fn add_mod(&mut self, entry: ModReportEntry) {
+        self.mods.push(entry);
+    }
+
+    pub fn add_timings(&mut self, pre_integration: Duration, metrics: &IntegrationMetrics) {
+        self.timings.push(ModReportTiming::new(
+            "pre_integration".to_owned(),
+            pre_integration,
+        ));
+        self.timings.extend(
+            metrics
+                .phases()
+                .into_iter()
+                .map(|(phase, duration)| ModReportTiming::new(phase, duration)),
+        );
+        self.timings.push(ModReportTiming::new(
+            "integration_total".to_owned(),
+            metrics.total,
+        ));
+    }
+
+    pub fn add_warnings(&mut self, warnings: &[ModLoaderWarning]) {
+        self.warnings
+            .extend(warnings.iter().map(|warning| warning.to_string()));
+    }
+}
+
+pub(crate) fn write_mod_report(paks_path: &Path, report: &ModReport) -> io::Result<()> {
+    let report_str = serde_json::to_string_pretty(report)?;
+    fs::write(paks_path.join(MOD_REPORT_FILE_NAME), report_str)?;
+
+    debug!("Wrote mod report");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs::{self, File};
+    use std::process;
+    use std::time::Duration;
+
+    use unreal_mod_integrator::IntegrationMetrics;
+    use unreal_pak::{pakversion::PakVersion, PakWriter};
+
+    use super::{write_mod_report, ModReport, ModReportEntry, MOD_REPORT_FILE_NAME};
+    use crate::mod_processing::trust::UntrustedReason;
+
+    #[test]
+    fn report_lists_mods() {
+        let dir = env::temp_dir().join(format!("unreal_mod_manager_report_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let pak_path = dir.join("000-TestMod-0.1.0_P.pak");
+        let mut writer = PakWriter::new(
+            File::create(&pak_path).unwrap(),
+            PakVersion::FnameBasedCompressionMethod,
+        );
+        writer
+            .write_entry(
+                &"TestGame/Content/TestMod/A.uasset".to_string(),
+                &vec![1u8; 16],
+                false,
+            )
+            .unwrap();
+        writer.finish_write().unwrap();
+
+        // an unreadable pak is recorded in the report instead of failing the integration
+        let broken_path = dir.join("001-BrokenMod-1.0.0_P.pak");
+        fs::write(&broken_path, b"not a pak").unwrap();
+
+        let mut report = ModReport::default();
+        report.add_mod(ModReportEntry::new(
+            "TestMod".to_owned(),
+            "0.1.0".to_owned(),
+            "000-TestMod-0.1.0_P.pak".to_owned(),
+            &pak_path,
+            None,
+        ));
+        report.add_mod(ModReportEntry::new(
+            "BrokenMod".to_owned(),
+            "1.0.0".to_owned(),
+            "001-BrokenMod-1.0.0_P.pak".to_owned(),
+            &broken_path,
+            Some(UntrustedReason::NotPreviouslyTrusted),
+        ));
+        report.add_timings(
+            Duration::from_millis(5),
+            &IntegrationMetrics {
+                pak_discovery: Duration::from_millis(10),
+                handlers: vec![("persistent_actors".to_owned(), Duration::from_millis(20))],
+                total: Duration::from_millis(40),
+                ..Default::default()
+            },
+        );
+        write_mod_report(&dir, &report).unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_slice(&fs::read(dir.join(MOD_REPORT_FILE_NAME)).unwrap()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mods = written["mods"].as_array().unwrap();
+        assert_eq!(mods.len(), 2);
+
+        assert_eq!(mods[0]["mod_id"], "TestMod");
+        assert_eq!(mods[0]["version"], "0.1.0");
+        assert_eq!(mods[0]["file_name"], "000-TestMod-0.1.0_P.pak");
+        assert_eq!(
+            mods[0]["paks"],
+            serde_json::json!(["TestGame/Content/TestMod/A.uasset"])
+        );
+        assert!(mods[0].get("error").is_none());
+        assert!(mods[0].get("untrusted_reason").is_none());
+
+        assert_eq!(mods[1]["mod_id"], "BrokenMod");
+        assert_eq!(mods[1]["paks"], serde_json::json!([]));
+        assert!(mods[1]["error"].is_string());
+        assert!(mods[1].get("untrusted_reason").is_some());
+
+        let timings = written["timings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| {
+                (
+                    e["phase"].as_str().unwrap(),
+                    e["milliseconds"].as_u64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            timings,
+            vec![
+                ("pre_integration", 5),
+                ("pak_discovery", 10),
+                ("metadata_read", 0),
+                ("asset_baking", 0),
+                ("handler:persistent_actors", 20),
+                ("pak_write", 0),
+                ("integration_total", 40),
+            ]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Game configuration shared by the tests
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use unreal_asset::engine_version::EngineVersion;
+use unreal_mod_integrator::{HandlerFn, IntegratorConfig, IntegratorMod};
+
+use crate::config::{GameConfig, IconData, InstallManager};
+use crate::error::ModLoaderError;
+use crate::update_info::UpdateInfo;
+use crate::version::GameBuild;
+
+pub(crate) const TEST_GAME_NAME: &str = "TestGame";
+
+pub(crate) struct TestIntegratorConfig;
+
+impl<'data> IntegratorConfig<'data, (), io::Error> for TestIntegratorConfig {
+    fn get_data(&self) -> &'data () {
+        &()
+    }
+
+    fn get_handlers(&self) -> HashMap<String, Box<HandlerFn<(), io::Error>>> {
+        HashMap::new()
+    }
+
+    fn get_baked_mods(&self) -> Vec<IntegratorMod<io::Error>> {
+        Vec::new()
+    }
+
+    const GAME_NAME: &'static str = TEST_GAME_NAME;
+    const INTEGRATOR_VERSION: &'static str = "0.1.0";
+    const ENGINE_VERSION: EngineVersion = EngineVersion::VER_UE4_23;
+}
+
+/// Game config with overridable paths that installs nothing
+pub(crate) struct TestGameConfig {
+    pub integrator_config: TestIntegratorConfig,
+    pub mods_path: Option<PathBuf>,
+    pub paks_path: Option<PathBuf>,
+    /// Error returned by `post_integrate`
+    pub post_integrate_error: Option<io::ErrorKind>,
+    /// File `post_integrate` writes the names of the files in the paks directory to
+    pub post_integrate_sentinel: Option<PathBuf>,
+}
+
+impl TestGameConfig {
+    pub fn new(mods_path: Option<PathBuf>, paks_path: Option<PathBuf>) -> Self {
+        TestGameConfig {
+            integrator_config: TestIntegratorConfig,
+            mods_path,
+            paks_path,
+            post_integrate_error: None,
+            post_integrate_sentinel: None,
+        }
+    }
+}
+
+impl<'data> GameConfig<'data, TestIntegratorConfig, (), io::Error> for TestGameConfig {
+    fn get_integrator_config(&self) -> &TestIntegratorConfig {
+        &self.integrator_config
+    }
+
+    fn get_game_build(&self, _install_path: &Path) -> Option<GameBuild> {
+        None
+    }
+
+    fn get_install_managers(&self) -> BTreeMap<&'static str, Box<dyn InstallManager>> {
+        BTreeMap::new()
+    }
+
+    fn get_newer_update(&self) -> Result<Option<UpdateInfo>, ModLoaderError> {
+        Ok(None)
+    }
+
+    fn update_modloader(&self, _progress_callback: Box<dyn Fn(f32)>) -> Result<(), ModLoaderError> {
+        Ok(())
+    }
+
+    fn get_icon(&self) -> Option<IconData> {
+        None
+    }
+
+    fn get_mods_path(&self) -> Option<PathBuf> {
+        self.mods_path.clone()
+    }
+
+    fn get_paks_path(&self) -> Option<PathBuf> {
+        self.paks_path.clone()
+    }
+
+    fn post_integrate(&self, paks_path: &Path) -> Result<(), io::Error> {
+        if let Some(kind) = self.post_integrate_error {
+            return Err(kind.into());
+        }
+
+        if let Some(sentinel) = &self.post_integrate_sentinel {
+            let mut files = fs::read_dir(paks_path)?
+                .map(|e| e.map(|e| e.file_name().to_string_lossy().into_owned()))
+                .collect::<Result<Vec<_>, _>>()?;
+            files.sort();
+            fs::write(sentinel, files.join("\n"))?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cpp_loader")]
+    fn get_cpp_loader_config() -> unreal_cpp_bootstrapper::config::GameSettings {
+        Default::default()
+    }
+
+    const WINDOW_TITLE: &'static str = "Test Mod Manager";
+    const CONFIG_DIR: &'static str = "TestModManager";
+    const CRATE_VERSION: &'static str = "0.1.0";
+    const ABOUT_TEXT: &'static str = "";
+}
+
+/// Temporary directory unique to this test process
+pub(crate) fn test_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "unreal_mod_manager_{}_{}",
+        name,
+        std::process::id()
+    ))
+}
@@ -8,7 +8,7 @@ use error::Error;
 pub mod error;
 pub(crate) mod v1;
 pub mod v2;
-pub use crate::v2::Metadata;
+pub use crate::v2::{DependencyMergeMode, Metadata, PartialMetadata};
 
 #[macro_export]
 macro_rules! hash_value {
@@ -127,6 +127,7 @@ impl Metadata {
             integrator,
             dependencies: HashMap::new(),
             cpp_loader_dlls: Vec::new(),
+            conflicts: Vec::new(),
         })
     }
 }
@@ -58,6 +58,15 @@ where
     Ok(a.into_iter().map(|(k, Wrapper(v))| (k, v)).collect())
 }
 
+fn deserialize_optional_dependency_map<'de, D>(
+    deserializer: D,
+) -> Result<Option<HashMap<String, Dependency>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_dependency_map(deserializer).map(Some)
+}
+
 #[derive(Debug, Default, Clone, Eq, Serialize, Deserialize)]
 pub struct Metadata {
     pub schema_version: usize,
@@ -80,6 +89,63 @@ pub struct Metadata {
 
     #[serde(default)]
     pub cpp_loader_dlls: Vec<String>,
+
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+}
+
+impl Metadata {
+    /// Creates a copy of this metadata with the fields set in `overrides` replaced.
+    ///
+    /// Fields that are `None` in `overrides` are inherited from `self`.
+    /// How dependencies are combined is controlled by [`PartialMetadata::dependency_merge`].
+    pub fn merge_override(&self, overrides: &PartialMetadata) -> Metadata {
+        let mut merged = self.clone();
+
+        if let Some(ref game_build) = overrides.game_build {
+            merged.game_build = Some(game_build.clone());
+        }
+        if let Some(sync) = overrides.sync {
+            merged.sync = Some(sync);
+        }
+        if let Some(ref dependencies) = overrides.dependencies {
+            match overrides.dependency_merge {
+                DependencyMergeMode::Union => merged.dependencies.extend(
+                    dependencies
+                        .iter()
+                        .map(|(mod_id, dependency)| (mod_id.clone(), dependency.clone())),
+                ),
+                DependencyMergeMode::Replace => merged.dependencies = dependencies.clone(),
+            }
+        }
+
+        merged
+    }
+}
+
+/// How overridden dependencies are combined with the dependencies of the base metadata
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum DependencyMergeMode {
+    /// Overridden dependencies replace base dependencies with the same mod id, all others are kept
+    #[default]
+    #[serde(rename = "union")]
+    Union,
+    /// Overridden dependencies replace all base dependencies
+    #[serde(rename = "replace")]
+    Replace,
+}
+
+/// Metadata fields that can be overridden by a profile, see [`Metadata::merge_override`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialMetadata {
+    pub game_build: Option<String>,
+    pub sync: Option<SyncMode>,
+
+    #[serde(default, deserialize_with = "deserialize_optional_dependency_map")]
+    pub dependencies: Option<HashMap<String, Dependency>>,
+
+    #[serde(default)]
+    pub dependency_merge: DependencyMergeMode,
 }
 
 impl Hash for Metadata {
@@ -108,6 +174,7 @@ impl Hash for Metadata {
         }
 
         self.cpp_loader_dlls.hash(state);
+        self.conflicts.hash(state);
     }
 }
 
@@ -125,6 +192,7 @@ impl PartialEq for Metadata {
             && self.download == other.download
             && self.dependencies == other.dependencies
             && self.cpp_loader_dlls == other.cpp_loader_dlls
+            && self.conflicts == other.conflicts
             && self.integrator.len() == other.integrator.len();
 
         let mut hasher = DefaultHasher::new();
@@ -149,7 +217,10 @@ mod tests {
 
     use semver::VersionReq;
 
-    use crate::{v2::Metadata, DownloadInfo, SyncMode};
+    use crate::{
+        v2::{DependencyMergeMode, Metadata, PartialMetadata},
+        DownloadInfo, SyncMode,
+    };
 
     use super::Dependency;
 
@@ -318,4 +389,100 @@ mod tests {
 
         assert_eq!(parsed, expected);
     }
+
+    #[test]
+    fn v2_conflicts_test() {
+        let src = r#"
+        {
+            "schema_version": 2,
+            "name": "Test",
+            "mod_id": "TestModId",
+            "version": "1.0.0",
+            "conflicts": [
+                "FirstMod",
+                "SecondMod"
+            ]
+        }
+        "#;
+
+        let parsed: Metadata = serde_json::from_str(src).unwrap();
+
+        let expected = Metadata {
+            schema_version: 2,
+            name: "Test".to_string(),
+            mod_id: "TestModId".to_string(),
+            mod_version: "1.0.0".to_string(),
+            conflicts: Vec::from(["FirstMod".to_string(), "SecondMod".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn merge_override_test() {
+        let base = Metadata {
+            schema_version: 2,
+            name: "Test".to_string(),
+            mod_id: "TestModId".to_string(),
+            mod_version: "1.0.0".to_string(),
+            game_build: Some("1.2.3".to_string()),
+            sync: Some(SyncMode::ServerAndClient),
+            dependencies: HashMap::from([
+                (
+                    "FirstMod".to_string(),
+                    Dependency::new(VersionReq::parse("1.0.0").unwrap(), None),
+                ),
+                (
+                    "SecondMod".to_string(),
+                    Dependency::new(VersionReq::parse("2.0.0").unwrap(), None),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let src = r#"
+        {
+            "sync": "client",
+            "dependencies": {
+                "SecondMod": "2.1.0"
+            }
+        }
+        "#;
+        let overrides: PartialMetadata = serde_json::from_str(src).unwrap();
+        assert_eq!(overrides.dependency_merge, DependencyMergeMode::Union);
+
+        let merged = base.merge_override(&overrides);
+
+        let expected = Metadata {
+            sync: Some(SyncMode::ClientOnly),
+            dependencies: HashMap::from([
+                (
+                    "FirstMod".to_string(),
+                    Dependency::new(VersionReq::parse("1.0.0").unwrap(), None),
+                ),
+                (
+                    "SecondMod".to_string(),
+                    Dependency::new(VersionReq::parse("2.1.0").unwrap(), None),
+                ),
+            ]),
+            ..base.clone()
+        };
+        assert_eq!(merged, expected);
+
+        let overrides = PartialMetadata {
+            dependency_merge: DependencyMergeMode::Replace,
+            ..overrides
+        };
+        let merged = base.merge_override(&overrides);
+
+        assert_eq!(merged.game_build, base.game_build);
+        assert_eq!(
+            merged.dependencies,
+            HashMap::from([(
+                "SecondMod".to_string(),
+                Dependency::new(VersionReq::parse("2.1.0").unwrap(), None),
+            )])
+        );
+    }
 }
@@ -0,0 +1,117 @@
+//! Compares writing a pak entry by entry on one thread with [`PakWriter::write_entries_parallel`]
+//!
+//! Run with `cargo bench -p unreal_pak --features parallel -- [directory]`.
+//! Without a directory the unreal_asset test assets are used, repeated to a few hundred entries.
+
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use unreal_pak::{pakversion::PakVersion, PakWriter};
+
+/// Entries written when the default assets are used
+const DEFAULT_ENTRY_COUNT: usize = 400;
+const RUNS: u32 = 3;
+
+fn read_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            read_files(&path, files);
+        } else if path
+            .extension()
+            .is_some_and(|e| e == "uasset" || e == "uexp" || e == "umap")
+        {
+            files.push(path);
+        }
+    }
+}
+
+fn entries(dir: Option<PathBuf>) -> Vec<(String, Vec<u8>, bool)> {
+    let default_dir = dir.is_none();
+    let dir = dir.unwrap_or_else(|| {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../unreal_asset/tests/assets")
+    });
+
+    let mut files = Vec::new();
+    read_files(&dir, &mut files);
+    files.sort();
+    assert!(!files.is_empty(), "No assets found in {dir:?}");
+
+    let count = match default_dir {
+        true => DEFAULT_ENTRY_COUNT,
+        false => files.len(),
+    };
+    files
+        .iter()
+        .cycle()
+        .take(count)
+        .enumerate()
+        .map(|(i, path)| {
+            let name = path.strip_prefix(&dir).unwrap().to_string_lossy();
+            (
+                format!("Game/Content/{i:04}/{name}"),
+                fs::read(path).unwrap(),
+                true,
+            )
+        })
+        .collect()
+}
+
+/// Fastest of `RUNS` runs, with the size of the written pak
+fn measure(mut write: impl FnMut(&mut PakWriter<&mut Cursor<Vec<u8>>>)) -> (Duration, usize) {
+    let mut fastest = Duration::MAX;
+    let mut size = 0;
+    for _ in 0..RUNS {
+        let mut cursor = Cursor::new(Vec::new());
+        let start = Instant::now();
+        let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+        write(&mut writer);
+        writer.finish_write().unwrap();
+        fastest = fastest.min(start.elapsed());
+        size = cursor.into_inner().len();
+    }
+    (fastest, size)
+}
+
+fn main() {
+    // cargo bench passes --bench
+    let dir = std::env::args().skip(1).find(|e| !e.starts_with('-'));
+    let entries = entries(dir.map(PathBuf::from));
+    let input_size = entries.iter().map(|(_, data, _)| data.len()).sum::<usize>();
+    println!(
+        "{} entries, {:.1} MiB",
+        entries.len(),
+        input_size as f64 / (1024.0 * 1024.0)
+    );
+
+    let single_thread = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let serial = single_thread.install(|| {
+        measure(|writer| {
+            for (name, data, compress) in &entries {
+                writer.write_entry(name, data, *compress).unwrap();
+            }
+        })
+    });
+    let parallel = measure(|writer| writer.write_entries_parallel(&entries).unwrap());
+    assert_eq!(
+        serial.1, parallel.1,
+        "Serial and parallel paks differ in size"
+    );
+
+    for (name, (time, _)) in [("serial", serial), ("parallel", parallel)] {
+        println!(
+            "{name:>8}: {time:>10.2?} {:>8.1} MiB/s",
+            input_size as f64 / (1024.0 * 1024.0) / time.as_secs_f64()
+        );
+    }
+    println!(
+        "{} threads, {:.2}x speedup",
+        rayon::current_num_threads(),
+        serial.0.as_secs_f64() / parallel.0.as_secs_f64()
+    );
+}
@@ -1,9 +1,11 @@
 //! Compression abstraction
 //! Currently supportted compressions (in addition to no compression):
 //! - Zlib
+//! - Oodle, only decompression and only with an external decompressor
 
 //* Note: when adding more compressions you should only have to update stuff in this file, but in a few places.
 
+use std::fmt;
 use std::io::{self, Read, Seek, SeekFrom, Write};
 
 use flate2::{read::ZlibDecoder, write::ZlibEncoder};
@@ -11,6 +13,12 @@ use flate2::{read::ZlibDecoder, write::ZlibEncoder};
 use crate::error::PakError;
 use crate::pakversion::PakVersion;
 
+/// Compression level used unless a different one is chosen, the Zlib default
+pub(crate) const DEFAULT_COMPRESSION_LEVEL: u32 = 6;
+
+/// Highest supported compression level
+pub(crate) const MAX_COMPRESSION_LEVEL: u32 = 9;
+
 /// Enum representing which compression method is being used for an entry
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Compression {
@@ -29,6 +37,11 @@ impl Compression {
         Self::Known("Zlib")
     }
 
+    /// Create Oodle Compression configuration
+    pub fn oodle() -> Self {
+        Self::Known("Oodle")
+    }
+
     pub(crate) fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
         let mut buf = [0; 0x20];
         reader.read_exact(&mut buf)?;
@@ -37,6 +50,8 @@ impl Compression {
             Self::None
         } else if buf == pad_zeroes("Zlib".as_bytes()) {
             Self::zlib()
+        } else if buf == pad_zeroes("Oodle".as_bytes()) {
+            Self::oodle()
         } else {
             Self::Unknown(buf)
         })
@@ -104,13 +119,13 @@ impl Compression {
 
     // These are panics becasue they should hard fail during developement.
 
-    pub(crate) fn decompress(&self, buf: &mut Vec<u8>, data: &[u8]) -> io::Result<()> {
+    /// Decompress `data` into `writer`, returning the number of decompressed bytes
+    pub(crate) fn decompress_to<W: Write>(&self, writer: &mut W, data: &[u8]) -> io::Result<u64> {
         match self {
             Self::Known(method) => match *method {
                 "Zlib" => {
                     let mut decoder = ZlibDecoder::new(data);
-                    decoder.read_to_end(buf)?;
-                    Ok(())
+                    io::copy(&mut decoder, writer)
                 }
                 _ => panic!("Found Compression::Known with unknown compression."),
             },
@@ -118,11 +133,16 @@ impl Compression {
         }
     }
 
-    pub(crate) fn compress(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+    /// Checks if entries can be written with this method, Oodle can only be decompressed
+    pub(crate) fn can_compress(&self) -> bool {
+        matches!(self, Self::None | Self::Known("Zlib"))
+    }
+
+    pub(crate) fn compress(&self, data: &[u8], level: u32) -> io::Result<Vec<u8>> {
         match self {
             Self::Known(method) => match *method {
                 "Zlib" => {
-                    let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+                    let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::new(level));
                     encoder.write_all(data)?;
                     Ok(encoder.finish()?)
                 }
@@ -133,6 +153,26 @@ impl Compression {
     }
 }
 
+/// Decompresses an Oodle compressed block, see [`PakReader::set_oodle_decompressor`](crate::PakReader::set_oodle_decompressor)
+///
+/// Called with the compressed block and the size it decompresses to.
+pub type OodleDecompressor = dyn Fn(&[u8], usize) -> io::Result<Vec<u8>> + Send + Sync;
+
+/// Wrapper around a user provided [`OodleDecompressor`]
+pub(crate) struct Oodle(pub Box<OodleDecompressor>);
+
+impl Oodle {
+    pub fn decompress(&self, data: &[u8], decompressed_size: usize) -> io::Result<Vec<u8>> {
+        (self.0)(data, decompressed_size)
+    }
+}
+
+impl fmt::Debug for Oodle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Oodle")
+    }
+}
+
 fn pad_zeroes(slice: &[u8]) -> [u8; 0x20] {
     let mut arr = [0; 0x20];
     arr[..slice.len()].copy_from_slice(slice);
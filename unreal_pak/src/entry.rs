@@ -1,8 +1,9 @@
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 
-use crate::compression::CompressionMethods;
+use crate::compression::{CompressionMethods, Oodle};
+use crate::entry_reader::PakEntryReader;
 use crate::error::PakError;
-use crate::hash;
+use crate::hasher::EntryHasher;
 use crate::header::{Block, Header};
 use crate::pakversion::PakVersion;
 use crate::Compression;
@@ -14,12 +15,68 @@ use crate::Compression;
 /// * `reader` - Anything that implements Read + Seek
 /// * `pak_version` - Version of the pak format used
 /// * `offset` - The offset of the start of the header of the file
+/// * `oodle` - Decompressor for Oodle compressed entries
 pub(crate) fn read_entry<R>(
     reader: &mut R,
     pak_version: PakVersion,
     compression: &CompressionMethods,
     offset: u64,
+    oodle: Option<&Oodle>,
 ) -> Result<Vec<u8>, PakError>
+where
+    R: Read + Seek,
+{
+    let mut entry_reader = PakEntryReader::new(reader, pak_version, compression, offset, oodle)?;
+
+    let mut data = Vec::with_capacity(entry_reader.len() as usize);
+    entry_reader.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Read a pak entry at the given offset in the reader and write its decompressed data to `writer`
+///
+/// Only one compression block is kept in memory at a time. Returns the number of bytes written.
+/// Data that doesn't decompress to the size in the entry header is an error,
+/// the decompressed blocks before the bad one have already been written to `writer` by then.
+///
+/// # Arguments
+///
+/// * `reader` - Anything that implements Read + Seek
+/// * `pak_version` - Version of the pak format used
+/// * `offset` - The offset of the start of the header of the file
+/// * `oodle` - Decompressor for Oodle compressed entries
+/// * `writer` - Where the decompressed data is written to
+pub(crate) fn read_entry_to<R, W>(
+    reader: &mut R,
+    pak_version: PakVersion,
+    compression: &CompressionMethods,
+    offset: u64,
+    oodle: Option<&Oodle>,
+    writer: &mut W,
+) -> Result<u64, PakError>
+where
+    R: Read + Seek,
+    W: Write,
+{
+    let mut entry_reader = PakEntryReader::new(reader, pak_version, compression, offset, oodle)?;
+    Ok(io::copy(&mut entry_reader, writer)?)
+}
+
+/// Read a pak entry at the given offset in the reader without decompressing it
+///
+/// Returns the entry header and the data as it is stored in the pak, which is what the header hash covers.
+///
+/// # Arguments
+///
+/// * `reader` - Anything that implements Read + Seek
+/// * `pak_version` - Version of the pak format used
+/// * `offset` - The offset of the start of the header of the file
+pub(crate) fn read_entry_raw<R>(
+    reader: &mut R,
+    pak_version: PakVersion,
+    compression: &CompressionMethods,
+    offset: u64,
+) -> Result<(Header, Vec<u8>), PakError>
 where
     R: Read + Seek,
 {
@@ -27,99 +84,245 @@ where
 
     let header = Header::read(reader, pak_version, compression)?;
 
-    match header.compression_method {
-        Compression::None => {
-            let mut data = vec![0u8; header.decompressed_size as usize];
-            reader.read_exact(data.as_mut_slice())?;
-            Ok(data)
-        }
-        Compression::Known(_) => {
-            let mut data = Vec::with_capacity(header.decompressed_size as usize);
-
-            let compression_blocks = header
-                .compression_blocks
-                .as_ref()
-                .ok_or_else(PakError::entry_invalid)?;
-            for block in compression_blocks {
-                // we do not need to seek here because the reader is at the end of the header and compression blocks are continuous
-                let mut compressed_data = vec![0u8; block.size as usize];
-                reader.read_exact(&mut compressed_data)?;
-                header
-                    .compression_method
-                    .decompress(&mut data, compressed_data.as_slice())?;
-            }
-
-            Ok(data)
-        }
-        _ => Err(PakError::compression_unsupported(header.compression_method)),
+    // compression blocks directly follow the header and are continuous
+    let data_start = reader.stream_position()?;
+    let pak_len = reader.seek(SeekFrom::End(0))?;
+    if header.compressed_size > pak_len.saturating_sub(data_start) {
+        return Err(PakError::entry_invalid());
     }
+    reader.seek(SeekFrom::Start(data_start))?;
+
+    let mut data = vec![0u8; header.compressed_size as usize];
+    reader.read_exact(&mut data)?;
+
+    Ok((header, data))
+}
+
+/// Check that the data of a pak entry matches the SHA1 hash stored in its header,
+/// or the hash `expected` of a custom hash algorithm
+///
+/// # Arguments
+///
+/// * `reader` - Anything that implements Read + Seek
+/// * `pak_version` - Version of the pak format used
+/// * `offset` - The offset of the start of the header of the file
+/// * `expected` - Hash algorithm and the hash it created when the entry was written
+pub(crate) fn verify_entry<R>(
+    reader: &mut R,
+    pak_version: PakVersion,
+    compression: &CompressionMethods,
+    offset: u64,
+    expected: Option<(&dyn EntryHasher, &[u8; 20])>,
+) -> Result<bool, PakError>
+where
+    R: Read + Seek,
+{
+    let (header, data) = read_entry_raw(reader, pak_version, compression, offset)?;
+    Ok(match expected {
+        Some((hasher, hash)) => hasher.hash(&data) == *hash,
+        None => crate::hash(&data) == header.hash,
+    })
+}
+
+/// Settings of the pak an entry is written into
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WriteOptions<'a> {
+    /// Version of the pak format to be used
+    pub pak_version: PakVersion,
+    /// Compression methods of the pak
+    pub compression: &'a CompressionMethods,
+    /// size of the used compression blocks
+    pub block_size: u32,
+    /// level passed to the compressor, 0-9
+    pub compression_level: u32,
+    /// Hash algorithm for a hash of the entry besides the SHA1 in its header
+    pub hasher: Option<&'a dyn EntryHasher>,
 }
 
 /// Write an entry with Header at the position the write is at
 ///
+/// Returns the header and the hash created by `options.hasher`, if set.
+///
 /// # Arguments
 ///
 /// * `writer` - Anything that implements Write + Seek
-/// * `pak_version` - Version of the pak format to be used
+/// * `options` - Settings of the pak
 /// * `data` - Uncompressed data to be written
-/// * `compression_method` - What compression to use
-/// * `block_size` - size of the used compression blocks
+/// * `compress` - Whether to compress with the first compression method of the pak
 pub(crate) fn write_entry<W>(
     writer: &mut W,
-    pak_version: PakVersion,
+    options: WriteOptions,
     data: &Vec<u8>,
     compress: bool,
-    compression: &CompressionMethods,
-    block_size: u32,
-) -> Result<Header, PakError>
+) -> Result<(Header, Option<[u8; 20]>), PakError>
 where
     W: Write + Seek,
 {
-    let offset = writer.stream_position()?;
-    let decompressed_size = data.len() as u64;
+    write_entry_with_method(
+        writer,
+        options,
+        data,
+        entry_compression_method(data, compress, options.compression),
+    )
+}
 
-    let compress = compress && decompressed_size >= 32;
-    let compression_method = if compress {
+/// Compression method used for an entry by [`write_entry`], entries under 32 bytes are never compressed
+pub(crate) fn entry_compression_method(
+    data: &[u8],
+    compress: bool,
+    compression: &CompressionMethods,
+) -> Compression {
+    if compress && data.len() >= 32 {
         compression.0[0]
     } else {
         Compression::None
-    };
+    }
+}
 
-    // compress data in memory
-    let mut compressed_data = if compress {
-        Vec::with_capacity(data.len())
-    } else {
-        // this will actually never be used
-        Vec::new()
-    };
-    let mut compression_blocks = None;
-    let data = match compression_method {
+/// Write an entry with Header at the position the write is at, using a specific compression method
+///
+/// Returns the header and the hash created by `options.hasher`, if set.
+///
+/// # Arguments
+///
+/// * `writer` - Anything that implements Write + Seek
+/// * `options` - Settings of the pak
+/// * `data` - Uncompressed data to be written
+/// * `compression_method` - What compression to use, has to be in `options.compression` unless it is `None`
+// the data is a Vec because the public write functions take one
+#[allow(clippy::ptr_arg)]
+pub(crate) fn write_entry_with_method<W>(
+    writer: &mut W,
+    options: WriteOptions,
+    data: &Vec<u8>,
+    compression_method: Compression,
+) -> Result<(Header, Option<[u8; 20]>), PakError>
+where
+    W: Write + Seek,
+{
+    let entry = compress_entry(
+        options.pak_version,
+        data,
+        compression_method,
+        options.block_size,
+        options.compression_level,
+    )?;
+    write_compressed_entry(writer, options, entry)
+}
+
+/// Entry data that is ready to be written by [`write_compressed_entry`]
+#[derive(Debug)]
+pub(crate) struct CompressedEntry<'a> {
+    /// uncompressed data
+    data: &'a [u8],
+    compression_method: Compression,
+    /// compressed data of every block, `None` when not compressed
+    blocks: Option<Vec<Vec<u8>>>,
+}
+
+/// Compress entry data in blocks of `block_size`
+///
+/// With the `parallel` feature the blocks are compressed on the rayon thread pool,
+/// the compressed blocks are the same either way.
+///
+/// # Arguments
+///
+/// * `pak_version` - Version of the pak format to be used
+/// * `data` - Uncompressed data to be written
+/// * `compression_method` - What compression to use
+/// * `block_size` - size of the used compression blocks
+/// * `compression_level` - level passed to the compressor, 0-9
+pub(crate) fn compress_entry(
+    pak_version: PakVersion,
+    data: &[u8],
+    compression_method: Compression,
+    block_size: u32,
+    compression_level: u32,
+) -> Result<CompressedEntry<'_>, PakError> {
+    let blocks = match compression_method {
+        _ if !compression_method.can_compress() => {
+            return Err(PakError::compression_unsupported(compression_method))
+        }
         Compression::Known(_) => {
             if pak_version < PakVersion::CompressionEncryption {
                 return Err(PakError::configuration_invalid());
             }
 
-            let block_count = (data.len() as f64 / block_size as f64).ceil() as usize;
-            let mut compression_blocks_inner = Vec::with_capacity(block_count);
-            let header_len = Header::calculate_header_len(pak_version, Some(block_count as u32));
+            let compress = |chunk| compression_method.compress(chunk, compression_level);
+
+            #[cfg(feature = "parallel")]
+            let blocks = {
+                use rayon::prelude::*;
+                data.par_chunks(block_size as usize)
+                    .map(compress)
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+            #[cfg(not(feature = "parallel"))]
+            let blocks = data
+                .chunks(block_size as usize)
+                .map(compress)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Some(blocks)
+        }
+        Compression::None => None,
+        _ => return Err(PakError::compression_unsupported(compression_method)),
+    };
+
+    Ok(CompressedEntry {
+        data,
+        compression_method,
+        blocks,
+    })
+}
 
-            for chunk in data.chunks(block_size as usize) {
-                let begin = compressed_data.len() as u64;
+/// Write an entry compressed by [`compress_entry`] with Header at the position the write is at
+///
+/// Returns the header and the hash created by `options.hasher`, if set.
+///
+/// # Arguments
+///
+/// * `writer` - Anything that implements Write + Seek
+/// * `options` - Settings of the pak, `compression_level` is not used
+/// * `entry` - Compressed entry data
+pub(crate) fn write_compressed_entry<W>(
+    writer: &mut W,
+    options: WriteOptions,
+    entry: CompressedEntry,
+) -> Result<(Header, Option<[u8; 20]>), PakError>
+where
+    W: Write + Seek,
+{
+    let WriteOptions {
+        pak_version,
+        compression,
+        block_size,
+        hasher,
+        ..
+    } = options;
+    let offset = writer.stream_position()?;
+    let decompressed_size = entry.data.len() as u64;
 
-                let block_compressed_data = compression_method.compress(chunk)?;
-                compressed_data.extend_from_slice(&block_compressed_data);
+    let mut compressed_data = Vec::new();
+    let mut compression_blocks = None;
+    let data = match entry.blocks {
+        Some(blocks) => {
+            let header_len = Header::calculate_header_len(pak_version, Some(blocks.len() as u32));
+            compressed_data.reserve(blocks.iter().map(Vec::len).sum());
 
+            let mut compression_blocks_inner = Vec::with_capacity(blocks.len());
+            for block in blocks {
                 compression_blocks_inner.push(Block {
-                    start: begin + header_len,
-                    size: block_compressed_data.len() as u64,
+                    start: compressed_data.len() as u64 + header_len,
+                    size: block.len() as u64,
                 });
+                compressed_data.extend_from_slice(&block);
             }
 
             compression_blocks = Some(compression_blocks_inner);
             &compressed_data
         }
-        Compression::None => data,
-        _ => return Err(PakError::compression_unsupported(compression_method)),
+        None => entry.data,
     };
 
     let compression_block_size = if pak_version >= PakVersion::CompressionEncryption {
@@ -138,8 +341,8 @@ where
         offset: 0x00,
         compressed_size: data.len() as u64,
         decompressed_size,
-        compression_method,
-        hash: hash(data),
+        compression_method: entry.compression_method,
+        hash: crate::hash(data),
         compression_blocks,
         compression_block_size,
         flags: Some(0x00),
@@ -151,5 +354,5 @@ where
     // the offset in the header right before the data is always 0x00, so only set here
     header.offset = offset;
 
-    Ok(header)
+    Ok((header, hasher.map(|hasher| hasher.hash(data))))
 }
@@ -0,0 +1,235 @@
+//! Reader streaming the decompressed data of a single pak entry
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use crate::compression::{CompressionMethods, Oodle};
+use crate::error::PakError;
+use crate::header::Header;
+use crate::pakversion::PakVersion;
+use crate::Compression;
+
+/// A `Read` + `Seek` implementation over the decompressed data of one pak entry,
+/// created by [`PakReader::entry_reader`](crate::PakReader::entry_reader).
+///
+/// Compressed entries are decompressed one block at a time when it is first read from,
+/// so only a single decompressed block is kept in memory. Seeking back into an earlier block
+/// decompresses it again.
+///
+/// Blocks that don't decompress to the size given by the entry header fail with an io error of kind
+/// [`io::ErrorKind::InvalidData`] wrapping a
+/// [`PakErrorKind::DecompressedSizeMismatch`](crate::error::PakErrorKind::DecompressedSizeMismatch),
+/// which converts back into that [`PakError`]. Its `actual` size counts up to the end of the first bad block.
+#[derive(Debug)]
+pub struct PakEntryReader<'a, R>
+where
+    R: Read + Seek,
+{
+    reader: &'a mut R,
+    compression_method: Compression,
+    oodle: Option<&'a Oodle>,
+    /// offset of the entry data for uncompressed entries,
+    /// offset and size of every compression block for compressed entries
+    blocks: Vec<(u64, u64)>,
+    /// decompressed size of every block but the last one
+    block_size: u64,
+    /// decompressed size of the entry
+    len: u64,
+    /// position in the decompressed data
+    pos: u64,
+    /// the reader is positioned at `pos`, only used for uncompressed entries
+    synced: bool,
+    /// index and data of the last decompressed block
+    current_block: Option<(usize, Vec<u8>)>,
+}
+
+impl<'a, R> PakEntryReader<'a, R>
+where
+    R: Read + Seek,
+{
+    /// Reads the header of the entry at the given offset, nothing is decompressed yet
+    ///
+    /// Oodle compressed entries fail with [`PakErrorKind::OodleUnavailable`](crate::error::PakErrorKind::OodleUnavailable)
+    /// without an Oodle decompressor.
+    pub(crate) fn new(
+        reader: &'a mut R,
+        pak_version: PakVersion,
+        compression: &CompressionMethods,
+        offset: u64,
+        oodle: Option<&'a Oodle>,
+    ) -> Result<Self, PakError> {
+        reader.seek(SeekFrom::Start(offset))?;
+        let header = Header::read(reader, pak_version, compression)?;
+
+        if header.compression_method == Compression::oodle() && oodle.is_none() {
+            return Err(PakError::oodle_unavailable());
+        }
+
+        let (blocks, block_size) = match header.compression_method {
+            Compression::None => (
+                vec![(reader.stream_position()?, header.decompressed_size)],
+                header.decompressed_size,
+            ),
+            Compression::Known(_) => {
+                let blocks = header
+                    .compression_blocks
+                    .as_ref()
+                    .ok_or_else(PakError::entry_invalid)?
+                    .iter()
+                    .map(|block| (offset + block.start, block.size))
+                    .collect();
+                let block_size = header
+                    .compression_block_size
+                    .filter(|size| *size != 0)
+                    .map(u64::from)
+                    .unwrap_or(header.decompressed_size);
+                (blocks, block_size)
+            }
+            method => return Err(PakError::compression_unsupported(method)),
+        };
+
+        // blocks are read into memory whole, so they have to fit into the pak
+        let pak_len = reader.seek(SeekFrom::End(0))?;
+        if blocks
+            .iter()
+            .any(|(start, size)| start.saturating_add(*size) > pak_len)
+        {
+            return Err(PakError::entry_invalid());
+        }
+
+        Ok(Self {
+            reader,
+            compression_method: header.compression_method,
+            oodle,
+            blocks,
+            block_size,
+            len: header.decompressed_size,
+            pos: 0,
+            synced: false,
+            current_block: None,
+        })
+    }
+
+    /// Returns the decompressed size of the entry.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Checks if the entry has no data.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decompresses the block at `index` unless it is the current block already
+    fn load_block(&mut self, index: usize) -> io::Result<&[u8]> {
+        if self.current_block.as_ref().map(|(i, _)| *i) != Some(index) {
+            let (offset, size) = *self.blocks.get(index).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, PakError::entry_invalid())
+            })?;
+
+            let mut compressed_data = vec![0u8; size as usize];
+            self.reader.seek(SeekFrom::Start(offset))?;
+            self.reader.read_exact(&mut compressed_data)?;
+
+            let block_start = index as u64 * self.block_size;
+            let expected = self.block_size.min(self.len - block_start);
+
+            // reuse the allocation of the previous block
+            let mut data = self
+                .current_block
+                .take()
+                .map(|(_, data)| data)
+                .unwrap_or_default();
+            data.clear();
+            let actual = match (self.compression_method, self.oodle) {
+                (Compression::Known("Oodle"), Some(oodle)) => {
+                    data = oodle.decompress(&compressed_data, expected as usize)?;
+                    data.len() as u64
+                }
+                (method, _) => method.decompress_to(&mut data, &compressed_data)?,
+            };
+
+            // a corrupted block can still be a valid stream, just of the wrong data
+            if actual != expected {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    PakError::decompressed_size_mismatch(self.len, block_start + actual),
+                ));
+            }
+
+            self.current_block = Some((index, data));
+        }
+
+        Ok(self
+            .current_block
+            .as_ref()
+            .map(|(_, data)| data.as_slice())
+            .unwrap_or_default())
+    }
+}
+
+impl<R> Read for PakEntryReader<'_, R>
+where
+    R: Read + Seek,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.len {
+            return Ok(0);
+        }
+
+        let max_len = usize::try_from(self.len - self.pos)
+            .unwrap_or(usize::MAX)
+            .min(buf.len());
+
+        let read = match self.compression_method {
+            Compression::None => {
+                if !self.synced {
+                    let (offset, _) = self.blocks[0];
+                    self.reader.seek(SeekFrom::Start(offset + self.pos))?;
+                    self.synced = true;
+                }
+                match self.reader.read(&mut buf[..max_len])? {
+                    0 => return Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+                    read => read,
+                }
+            }
+            _ => {
+                let index = (self.pos / self.block_size) as usize;
+                let block_pos = (self.pos % self.block_size) as usize;
+                let block = self.load_block(index)?;
+
+                let read = max_len.min(block.len() - block_pos);
+                buf[..read].copy_from_slice(&block[block_pos..block_pos + read]);
+                read
+            }
+        };
+
+        self.pos += read as u64;
+        Ok(read)
+    }
+}
+
+impl<R> Seek for PakEntryReader<'_, R>
+where
+    R: Read + Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        if new_pos != self.pos {
+            self.pos = new_pos;
+            self.synced = false;
+        }
+
+        Ok(self.pos)
+    }
+}
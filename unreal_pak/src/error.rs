@@ -4,7 +4,6 @@ use std::error;
 use std::fmt;
 use std::io;
 
-use crate::pakversion::PakVersion;
 use crate::Compression;
 
 /// Error type used by unreal_pak
@@ -16,7 +15,7 @@ pub struct PakError {
 
 impl PakError {
     /// construct UnsupportedPakVersion error
-    pub fn pak_version_unsupported(version: PakVersion) -> Self {
+    pub fn pak_version_unsupported(version: u32) -> Self {
         PakError {
             kind: PakErrorKind::PakVersionUnsupported(version),
         }
@@ -58,25 +57,61 @@ impl PakError {
             kind: PakErrorKind::PakInvalid,
         }
     }
+    /// construct FooterInvalid error
+    pub fn footer_invalid() -> Self {
+        PakError {
+            kind: PakErrorKind::FooterInvalid,
+        }
+    }
     /// construct FileNotFound error
     pub fn entry_not_found(file_name: String) -> Self {
         PakError {
             kind: PakErrorKind::EntryNotFound(file_name),
         }
     }
+    /// construct EntryIndexOutOfRange error
+    pub fn entry_index_out_of_range(index: usize) -> Self {
+        PakError {
+            kind: PakErrorKind::EntryIndexOutOfRange(index),
+        }
+    }
+    /// construct DuplicateEntry error
+    pub fn duplicate_entry(file_name: String) -> Self {
+        PakError {
+            kind: PakErrorKind::DuplicateEntry(file_name),
+        }
+    }
     /// construct InvalidFile error
     pub fn entry_invalid() -> Self {
         PakError {
             kind: PakErrorKind::EntryInvalid,
         }
     }
+    /// construct IndexInvalid error
+    pub fn index_invalid() -> Self {
+        PakError {
+            kind: PakErrorKind::IndexInvalid,
+        }
+    }
+    /// construct OodleUnavailable error
+    pub fn oodle_unavailable() -> Self {
+        PakError {
+            kind: PakErrorKind::OodleUnavailable,
+        }
+    }
+    /// construct DecompressedSizeMismatch error
+    pub fn decompressed_size_mismatch(expected: u64, actual: u64) -> Self {
+        PakError {
+            kind: PakErrorKind::DecompressedSizeMismatch { expected, actual },
+        }
+    }
 }
 
 impl fmt::Display for PakError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let err_msg = match self.kind {
-            PakErrorKind::PakVersionUnsupported(ref version) => {
-                format!("Unsupported pak version: {}", *version as u32)
+            PakErrorKind::PakVersionUnsupported(version) => {
+                format!("Unsupported pak version: {version}")
             }
             PakErrorKind::CompressionUnsupported(ref method) => {
                 format!("Unsupported compression method: {method:?}")
@@ -88,10 +123,29 @@ impl fmt::Display for PakError {
             }
 
             PakErrorKind::PakInvalid => "Invalid pak file".to_string(),
+            PakErrorKind::FooterInvalid => {
+                "No valid pak footer found, the file might be truncated or not a pak file"
+                    .to_string()
+            }
             PakErrorKind::EntryNotFound(ref file_name) => {
                 format!("File not found: {file_name}")
             }
+            PakErrorKind::EntryIndexOutOfRange(index) => {
+                format!("No entry at index {index}")
+            }
+            PakErrorKind::DuplicateEntry(ref file_name) => {
+                format!("Entry name appears more than once in the index: {file_name}")
+            }
             PakErrorKind::EntryInvalid => "Invalid file".to_string(),
+            PakErrorKind::IndexInvalid => {
+                "Index is compressed but could not be decompressed".to_string()
+            }
+            PakErrorKind::OodleUnavailable => {
+                "Entry is Oodle compressed but no Oodle decompressor was set".to_string()
+            }
+            PakErrorKind::DecompressedSizeMismatch { expected, actual } => {
+                format!("Entry decompressed to {actual} bytes but its header says {expected}")
+            }
 
             PakErrorKind::IoError(ref err) => {
                 format!("IO error: {err}")
@@ -99,6 +153,9 @@ impl fmt::Display for PakError {
             PakErrorKind::FString(ref err) => {
                 format!("FString error: {err}")
             }
+            PakErrorKind::Path(ref err) => {
+                format!("Path error: {err}")
+            }
         };
 
         write!(f, "{err_msg}")
@@ -107,12 +164,28 @@ impl fmt::Display for PakError {
 
 impl From<io::Error> for PakError {
     fn from(error: io::Error) -> Self {
+        // io errors wrapping a PakError, like the ones from PakEntryReader, are unwrapped again
+        if error.get_ref().is_some_and(|inner| inner.is::<PakError>()) {
+            return *error
+                .into_inner()
+                .and_then(|inner| inner.downcast().ok())
+                .expect("inner error is a PakError");
+        }
+
         PakError {
             kind: PakErrorKind::IoError(error),
         }
     }
 }
 
+impl From<unreal_helpers::error::PathError> for PakError {
+    fn from(error: unreal_helpers::error::PathError) -> Self {
+        PakError {
+            kind: PakErrorKind::Path(error),
+        }
+    }
+}
+
 impl From<unreal_helpers::error::FStringError> for PakError {
     fn from(error: unreal_helpers::error::FStringError) -> Self {
         PakError {
@@ -126,8 +199,8 @@ impl error::Error for PakError {}
 /// Error representation of PakError
 #[derive(Debug)]
 pub enum PakErrorKind {
-    /// the pak version found is not supported by the library
-    PakVersionUnsupported(PakVersion),
+    /// the pak version found is not supported by the library, as stored in the file
+    PakVersionUnsupported(u32),
     /// the compression found is not supported by the library
     CompressionUnsupported(Compression),
     /// encryption is not supported
@@ -139,13 +212,85 @@ pub enum PakErrorKind {
 
     /// a pak file is not correctly formatted ot the file is not even a pak file
     PakInvalid,
+    /// no footer of a supported pak version was found at the end of the file
+    FooterInvalid,
     /// a file inside the pak file was not found
     EntryNotFound(String),
+    /// there is no entry at the given position in the index
+    EntryIndexOutOfRange(usize),
+    /// the index contains more than one entry with the same name
+    DuplicateEntry(String),
     /// a (compressed) file is corrupted or similar
     EntryInvalid,
+    /// the index is stored compressed but does not decompress
+    IndexInvalid,
+    /// an entry is Oodle compressed but the reader has no Oodle decompressor
+    OodleUnavailable,
+    /// the compression blocks of an entry decompress to a different size than stored in its header
+    DecompressedSizeMismatch {
+        /// decompressed size stored in the entry header
+        expected: u64,
+        /// number of bytes the compression blocks decompressed to
+        actual: u64,
+    },
 
     /// something went wrong during reading
     IoError(io::Error),
     /// an FString failed to serialize
     FString(unreal_helpers::error::FStringError),
+    /// an entry name can't be used as a path on disk
+    Path(unreal_helpers::error::PathError),
+}
+
+/// A problem with where an entry is stored in the pak file, found by
+/// [`PakReader::check_layout`](crate::PakReader::check_layout)
+#[derive(Debug)]
+pub struct LayoutError {
+    /// name of the entry
+    pub entry: String,
+    /// offset of the entry as stored in the index
+    pub offset: u64,
+    /// type of the problem
+    pub kind: LayoutErrorKind,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let err_msg = match self.kind {
+            LayoutErrorKind::HeaderUnreadable(ref err) => {
+                format!("entry header can't be read: {err}")
+            }
+            LayoutErrorKind::HeaderMismatch => {
+                "entry header does not match the header in the index".to_string()
+            }
+            LayoutErrorKind::OutOfBounds { end, limit } => {
+                format!("entry data ends at {end} but entries must end before the index at {limit}")
+            }
+            LayoutErrorKind::Overlap(ref other) => {
+                format!("entry overlaps entry {other}")
+            }
+        };
+
+        write!(f, "{} at offset {}: {err_msg}", self.entry, self.offset)
+    }
+}
+
+impl error::Error for LayoutError {}
+
+/// Error representation of LayoutError
+#[derive(Debug)]
+pub enum LayoutErrorKind {
+    /// the entry header could not be read at the offset stored in the index
+    HeaderUnreadable(PakError),
+    /// the entry header at the offset has different sizes than the header in the index
+    HeaderMismatch,
+    /// the entry header and data run into the index or past the end of the file
+    OutOfBounds {
+        /// offset the entry data ends at
+        end: u64,
+        /// offset entries have to end before
+        limit: u64,
+    },
+    /// the entry shares bytes with the named entry
+    Overlap(String),
 }
@@ -0,0 +1,196 @@
+//! Pak file footer
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
+
+use crate::compression::{Compression, CompressionMethods};
+use crate::error::PakError;
+use crate::pakversion::PakVersion;
+use crate::PAK_MAGIC;
+
+/// Parse only the footer of a pak file.
+///
+/// The position of the reader after return is not specified.
+pub fn parse_footer<R: Read + Seek>(reader: &mut R) -> Result<PakFooter, PakError> {
+    PakFooter::read(reader)
+}
+
+/// Detect the version of a pak file from its footer, without reading anything else.
+///
+/// Fails with [`PakErrorKind::FooterInvalid`](crate::error::PakErrorKind::FooterInvalid)
+/// if no footer of a supported version is found, e.g. because the file is truncated.
+///
+/// The position of the reader after return is not specified.
+pub fn detect_version<R: Read + Seek>(reader: &mut R) -> Result<PakVersion, PakError> {
+    PakFooter::find_magic(reader).map(|(_, pak_version)| pak_version)
+}
+
+/// Footer of a pak file, located at the very end of the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PakFooter {
+    /// version of the pak file format
+    pub pak_version: PakVersion,
+    /// offset of the index from the start of the file
+    pub index_offset: u64,
+    /// size of the index in bytes
+    pub index_size: u64,
+    /// sha1 hash of the index
+    pub index_hash: [u8; 20],
+    pub(crate) compression_methods: CompressionMethods,
+    /// is the index encrypted, only present since [`PakVersion::IndexEncryption`]
+    pub index_encrypted: Option<bool>,
+    /// guid of the encryption key, only present since [`PakVersion::EncryptionKeyGuid`]
+    pub encryption_key_guid: Option<[u8; 0x10]>,
+}
+
+impl Default for PakFooter {
+    fn default() -> Self {
+        Self {
+            pak_version: PakVersion::Invalid,
+            index_offset: 0,
+            index_size: 0,
+            index_hash: [0; 20],
+            compression_methods: Default::default(),
+            index_encrypted: None,
+            encryption_key_guid: None,
+        }
+    }
+}
+
+impl PakFooter {
+    /// Compression methods listed in the footer, unused slots are [`Compression::None`]
+    pub fn compression_methods(&self) -> &[Compression] {
+        &self.compression_methods.0
+    }
+
+    pub(crate) fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, PakError> {
+        let (magic_offset, pak_version) = Self::find_magic(reader)?;
+
+        // seek past magic and file version
+        reader.seek(SeekFrom::End(magic_offset + 8))?;
+
+        let index_offset = reader.read_u64::<LE>()?;
+        let index_size = reader.read_u64::<LE>()?;
+
+        let mut index_hash = [0u8; 20];
+        reader.read_exact(&mut index_hash)?;
+
+        // if version 9 skip frozen index byte
+        if pak_version == PakVersion::FrozenIndex {
+            reader.seek(SeekFrom::Current(1))?;
+        }
+
+        let compression_methods = if pak_version >= PakVersion::FnameBasedCompressionMethod {
+            CompressionMethods::from_reader(reader)?
+        } else {
+            CompressionMethods::default()
+        };
+
+        // index_encrypted is one byte before magic
+        let mut index_encrypted = None;
+        if pak_version >= PakVersion::IndexEncryption {
+            reader.seek(SeekFrom::End(magic_offset - 1))?;
+            index_encrypted = Some(reader.read_u8()? != 0);
+        }
+
+        // encryption key guid is 0x10 bytes before index_encrypted flag
+        let mut encryption_key_guid = None;
+        if pak_version >= PakVersion::EncryptionKeyGuid {
+            reader.seek(SeekFrom::End(magic_offset - 0x11))?;
+            let mut buf = [0u8; 0x10];
+            reader.read_exact(&mut buf)?;
+            encryption_key_guid = Some(buf);
+        }
+
+        Ok(PakFooter {
+            pak_version,
+            index_offset,
+            index_size,
+            index_hash,
+            compression_methods,
+            index_encrypted,
+            encryption_key_guid,
+        })
+    }
+
+    /// Find the magic of the footer, returns its offset from the end of the file and the pak version
+    fn find_magic<R: Read + Seek>(reader: &mut R) -> Result<(i64, PakVersion), PakError> {
+        let pak_len = reader.seek(SeekFrom::End(0))?;
+
+        // magic offset (from bottom) can only be 0x2C (v1-v7), 0xAC (v8a), 0xCC (v8b,v10,v11), 0xCD (v9)
+        // the version has to match the offset, otherwise the magic is just part of other data
+        let mut unknown_version = None;
+        for offset in [0xCD, 0xCC, 0xAC, 0x2C] {
+            if pak_len < offset {
+                continue;
+            }
+
+            reader.seek(SeekFrom::End(-(offset as i64)))?;
+            if reader.read_u32::<BE>()? != PAK_MAGIC {
+                continue;
+            }
+
+            let version = reader.read_u32::<LE>()?;
+            let matches_offset = match offset {
+                0x2C => (1..=7).contains(&version),
+                0xAC => version == 8,
+                0xCC => matches!(version, 8 | 10 | 11),
+                _ => version == 9,
+            };
+
+            let mut pak_version = PakVersion::from_num(version);
+            if matches_offset {
+                if offset == 0xAC {
+                    pak_version.set_subversion();
+                }
+                return Ok((-(offset as i64), pak_version));
+            }
+            if pak_version == PakVersion::Invalid {
+                unknown_version = Some(version);
+            }
+        }
+
+        match unknown_version {
+            Some(version) => Err(PakError::pak_version_unsupported(version)),
+            None => Err(PakError::footer_invalid()),
+        }
+    }
+
+    pub(crate) fn write<W: Write>(writer: &mut W, footer: Self) -> Result<(), PakError> {
+        // write encryption key guid first
+        if footer.pak_version >= PakVersion::EncryptionKeyGuid {
+            if let Some(encryption_key_guid) = footer.encryption_key_guid {
+                writer.write_all(&encryption_key_guid)?;
+            }
+        }
+
+        // write index_encrypted
+        if footer.pak_version >= PakVersion::IndexEncryption {
+            writer.write_u8(u8::from(footer.index_encrypted.unwrap_or_default()))?;
+        }
+
+        // write magic and pak version
+        writer.write_u32::<BE>(PAK_MAGIC)?;
+        writer.write_u32::<LE>(footer.pak_version.to_num())?;
+
+        // write index offset and length
+        writer.write_u64::<LE>(footer.index_offset)?;
+        writer.write_u64::<LE>(footer.index_size)?;
+
+        // write hash
+        writer.write_all(&footer.index_hash)?;
+
+        // frozen index
+        if footer.pak_version == PakVersion::FrozenIndex {
+            writer.write_u8(0)?;
+        }
+
+        // compression methods
+        if footer.pak_version >= PakVersion::FnameBasedCompressionMethod {
+            writer.write_all(footer.compression_methods.as_bytes().as_slice())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! Simple glob matching of entry names
+
+/// Checks if an entry name matches a glob pattern, see [`PakReader::entry_names_matching`](crate::PakReader::entry_names_matching)
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern
+        .replace('\\', "/")
+        .trim_end_matches('/')
+        .chars()
+        .collect::<Vec<_>>();
+    let name = name.replace('\\', "/").chars().collect::<Vec<_>>();
+
+    // the whole name or one of its parent directories
+    (0..name.len())
+        .filter(|i| name[*i] == '/')
+        .chain([name.len()])
+        .any(|end| matches(&pattern, &name[..end]))
+}
+
+fn matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern {
+        [] => name.is_empty(),
+        // `**/` also matches no directory at all
+        ['*', '*', '/', rest @ ..] => (0..=name.len())
+            .filter(|i| *i == 0 || name[i - 1] == '/')
+            .any(|i| matches(rest, &name[i..])),
+        ['*', '*', rest @ ..] => (0..=name.len()).any(|i| matches(rest, &name[i..])),
+        ['*', rest @ ..] => (0..=name.len())
+            .take_while(|i| *i == 0 || name[i - 1] != '/')
+            .any(|i| matches(rest, &name[i..])),
+        ['?', rest @ ..] => name.first().is_some_and(|c| *c != '/') && matches(rest, &name[1..]),
+        [c, rest @ ..] => name.first() == Some(c) && matches(rest, &name[1..]),
+    }
+}
@@ -0,0 +1,23 @@
+//! Pluggable hashing of entry data
+
+use std::fmt::Debug;
+
+/// Hash algorithm for entry integrity hashes kept outside of the pak, e.g. in a sidecar file
+///
+/// The hash covers the entry data as it is stored in the pak, after compression.
+/// Entry headers and the index hash in the footer are always SHA1 and not affected by this.
+pub trait EntryHasher: Debug + Send + Sync {
+    /// Hash the stored data of an entry.
+    /// Shorter hashes than 20 bytes should be padded with zeroes.
+    fn hash(&self, data: &[u8]) -> [u8; 20];
+}
+
+/// SHA1, the entry hash used by Unreal Engine
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sha1Hasher;
+
+impl EntryHasher for Sha1Hasher {
+    fn hash(&self, data: &[u8]) -> [u8; 20] {
+        crate::hash(data)
+    }
+}
@@ -12,6 +12,19 @@
             - u64 block end
     - u8 is encrypted flag
     - u32 block size
+
+    encoded entry header (version >= 10, only in the index):
+    - u32 flags
+        - bits 0-5 block size >> 11, 0x3f when the block size is stored separately
+        - bits 6-21 number of blocks
+        - bit 22 is encrypted flag
+        - bits 23-28 compression method
+        - bits 29, 30, 31 size, size decompressed, offset fit into a u32
+    - u32 block size (only when stored separately)
+    - u32/u64 offset
+    - u32/u64 size decompressed
+    - u32/u64 size (only when compression method is not 0)
+    - u32 block sizes (only with more than one block or when encrypted)
 */
 
 use std::io::{self, Read, Seek, Write};
@@ -23,7 +36,10 @@ use crate::compression::{Compression, CompressionMethods};
 use crate::error::PakError;
 use crate::pakversion::PakVersion;
 
-#[derive(Debug)]
+/// Entry flag set when the entry data is encrypted
+pub(crate) const FLAG_ENCRYPTED: u8 = 0x01;
+
+#[derive(Debug, Clone)]
 pub(crate) struct Header {
     /// This may incorrectly be 0x00
     pub offset: u64,
@@ -46,6 +62,29 @@ pub(crate) struct Block {
 }
 
 impl Header {
+    /// Check if the entry data is encrypted
+    pub(crate) fn is_encrypted(&self) -> bool {
+        self.flags.unwrap_or(0) & FLAG_ENCRYPTED != 0
+    }
+
+    /// Number of compression blocks, uncompressed entries have none.
+    /// Encoded headers don't contain the blocks, so their count is computed from the block size.
+    pub(crate) fn block_count(&self) -> u64 {
+        if matches!(self.compression_method, Compression::None) {
+            return 0;
+        }
+        if let Some(compression_blocks) = &self.compression_blocks {
+            return compression_blocks.len() as u64;
+        }
+
+        match self.compression_block_size {
+            Some(block_size) if block_size != 0 => {
+                self.decompressed_size.div_ceil(block_size as u64)
+            }
+            _ => 1,
+        }
+    }
+
     /// Read data from the reader into a Header, reader needs to be set at start of a header
     pub(crate) fn read<R: Read>(
         reader: &mut R,
@@ -164,7 +203,7 @@ impl Header {
             hash: [0; 20],
             compression_blocks: None,
             compression_block_size: Some(block_size),
-            flags: Some(if is_encrypted { 1 } else { 0 }),
+            flags: Some(if is_encrypted { FLAG_ENCRYPTED } else { 0 }),
         })
     }
 
@@ -200,6 +239,71 @@ impl Header {
         Ok(())
     }
 
+    /// Write a (bit)encoded header, returns false without writing anything when the header can't be encoded
+    pub(crate) fn write_encoded<W: Write>(
+        writer: &mut W,
+        pak_version: PakVersion,
+        compression: &CompressionMethods,
+        header: &Self,
+    ) -> Result<bool, PakError> {
+        let compression_method = header.compression_method.as_u32(pak_version, compression)?;
+        let block_count = header.compression_blocks.as_ref().map_or(0, Vec::len);
+        if compression_method > 0x3f || block_count > 0xffff {
+            return Ok(false);
+        }
+
+        let block_size = header.compression_block_size.unwrap_or(0);
+        let mut packed_block_size = (block_size >> 11) & 0x3f;
+        if packed_block_size << 11 != block_size {
+            packed_block_size = 0x3f;
+        }
+
+        let offset_32_bit = header.offset <= u32::MAX as u64;
+        let decompressed_size_32_bit = header.decompressed_size <= u32::MAX as u64;
+        let compressed_size_32_bit = header.compressed_size <= u32::MAX as u64;
+
+        let mut header_bits = [0u8; 4];
+        let bits = header_bits.view_bits_mut::<Lsb0>();
+        bits[0..=5].store_le(packed_block_size);
+        bits[6..=21].store_le(block_count as u32);
+        bits.set(22, header.is_encrypted());
+        bits[23..=28].store_le(compression_method);
+        bits.set(29, compressed_size_32_bit);
+        bits.set(30, decompressed_size_32_bit);
+        bits.set(31, offset_32_bit);
+        writer.write_all(&header_bits)?;
+
+        if packed_block_size == 0x3f {
+            writer.write_u32::<LE>(block_size)?;
+        }
+
+        let mut write_size = |size: u64, is_32_bit: bool| -> io::Result<()> {
+            match is_32_bit {
+                true => writer.write_u32::<LE>(size as u32),
+                false => writer.write_u64::<LE>(size),
+            }
+        };
+
+        write_size(header.offset, offset_32_bit)?;
+        write_size(header.decompressed_size, decompressed_size_32_bit)?;
+        if !matches!(header.compression_method, Compression::None) {
+            write_size(header.compressed_size, compressed_size_32_bit)?;
+        }
+
+        // a single unencrypted block can be derived from the entry size
+        if let Some(compression_blocks) = &header.compression_blocks {
+            if compression_blocks.len() > 1
+                || (compression_blocks.len() == 1 && header.is_encrypted())
+            {
+                for block in compression_blocks {
+                    writer.write_u32::<LE>(block.size as u32)?;
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
     pub(crate) fn calculate_header_len(pak_version: PakVersion, block_count: Option<u32>) -> u64 {
         let mut len = 0;
 
@@ -1,28 +1,53 @@
+use std::collections::BTreeMap;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
-use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 use unreal_helpers::{UnrealReadExt, UnrealWriteExt};
 
-use crate::compression::CompressionMethods;
+use crate::compression::Compression;
 use crate::error::PakError;
+use crate::footer::PakFooter;
+use crate::hash;
 use crate::header::Header;
 use crate::pakversion::PakVersion;
-use crate::{hash, PAK_MAGIC};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct Index {
     pub mount_point: String,
     pub path_hash_seed: Option<u64>,
     pub entries: Vec<(String, Header)>,
-    pub footer: Footer,
+    pub footer: PakFooter,
 }
 
 impl Index {
-    pub(crate) fn read<R: Read + Seek>(mut reader: &mut R) -> Result<Self, PakError> {
-        let footer = Footer::read(&mut reader)?;
+    pub(crate) fn read<R: Read + Seek>(pak: &mut R) -> Result<Self, PakError> {
+        let footer = PakFooter::read(pak)?;
 
-        reader.seek(SeekFrom::Start(footer.index_offset))?;
+        if footer.index_encrypted == Some(true) {
+            return Err(PakError::enrcryption_unsupported());
+        }
+
+        let pak_len = pak.seek(SeekFrom::End(0))?;
+        if footer.index_offset.saturating_add(footer.index_size) > pak_len {
+            return Err(PakError::pak_invalid());
+        }
+
+        pak.seek(SeekFrom::Start(footer.index_offset))?;
+        let mut index_data = vec![0u8; footer.index_size as usize];
+        pak.read_exact(&mut index_data)?;
+
+        let index_data = match index_compression(&index_data) {
+            Some(compression) => {
+                let mut decompressed = Vec::new();
+                compression
+                    .decompress_to(&mut decompressed, &index_data)
+                    .map_err(|_| PakError::index_invalid())?;
+                decompressed
+            }
+            None => index_data,
+        };
+        let mut reader = Cursor::new(index_data);
 
         let mount_point = reader.read_fstring()?.unwrap_or_default();
         let mut path_hash_seed = None;
@@ -36,7 +61,7 @@ impl Index {
 
                 entries.push((
                     file_name,
-                    Header::read(reader, footer.pak_version, &footer.compression_methods)?,
+                    Header::read(&mut reader, footer.pak_version, &footer.compression_methods)?,
                 ));
             }
         } else {
@@ -56,42 +81,61 @@ impl Index {
                 // skip hash
                 reader.seek(SeekFrom::Current(20))?;
 
-                let previous_pos = reader.stream_position()?;
-                reader.seek(SeekFrom::Start(full_directory_index_offset))?;
+                // secondary indices are stored separately in the pak, never compressed
+                pak.seek(SeekFrom::Start(full_directory_index_offset))?;
 
-                let directory_count = reader.read_u32::<LE>()? as usize;
+                let directory_count = pak.read_u32::<LE>()? as usize;
                 let mut directories = Vec::new();
                 for _ in 0..directory_count {
-                    let directory_name = reader.read_fstring()?.unwrap_or_default();
-                    let file_count = reader.read_u32::<LE>()? as usize;
+                    let directory_name = pak.read_fstring()?.unwrap_or_default();
+                    let file_count = pak.read_u32::<LE>()? as usize;
                     let mut files = Vec::new();
                     for _ in 0..file_count {
-                        let file_name = reader.read_fstring()?.unwrap_or_default();
-                        files.push((file_name, reader.read_u32::<LE>()?));
+                        let file_name = pak.read_fstring()?.unwrap_or_default();
+                        files.push((file_name, pak.read_i32::<LE>()?));
                     }
                     directories.push((directory_name, files));
                 }
 
-                reader.seek(SeekFrom::Start(previous_pos))?;
                 directories
             } else {
                 return Err(PakError::pak_invalid());
             };
 
-            let _encoded_size = reader.read_u32::<LE>()? as usize;
+            let encoded_size = reader.read_u32::<LE>()? as u64;
             let position = reader.stream_position()?;
 
+            // entries that couldn't be encoded are stored after the encoded ones
+            reader.seek(SeekFrom::Start(position + encoded_size))?;
+            let unencoded_count = reader.read_u32::<LE>()?;
+            let mut unencoded_entries = Vec::with_capacity(unencoded_count as usize);
+            for _ in 0..unencoded_count {
+                unencoded_entries.push(Some(Header::read(
+                    &mut reader,
+                    footer.pak_version,
+                    &footer.compression_methods,
+                )?));
+            }
+
             for (dir_name, dir) in &full_directory_index {
                 for (file_name, encoded_offset) in dir {
                     let mut path = dir_name.strip_prefix('/').unwrap_or(dir_name).to_owned();
                     path.push_str(file_name);
 
-                    reader.seek(SeekFrom::Start(position + *encoded_offset as u64))?;
-                    let entry = Header::read_encoded(
-                        &mut reader,
-                        footer.pak_version,
-                        &footer.compression_methods,
-                    )?;
+                    let entry = match *encoded_offset {
+                        offset if offset >= 0 => {
+                            reader.seek(SeekFrom::Start(position + offset as u64))?;
+                            Header::read_encoded(
+                                &mut reader,
+                                footer.pak_version,
+                                &footer.compression_methods,
+                            )?
+                        }
+                        offset => unencoded_entries
+                            .get_mut((-offset - 1) as usize)
+                            .and_then(Option::take)
+                            .ok_or_else(PakError::pak_invalid)?,
+                    };
 
                     entries.push((path, entry));
                 }
@@ -106,7 +150,15 @@ impl Index {
         })
     }
 
-    pub(crate) fn write<W: Write + Seek>(writer: &mut W, mut index: Self) -> Result<(), PakError> {
+    /// Write the index and footer
+    ///
+    /// `on_progress` is called after each entry with the number of entries written and the total.
+    pub(crate) fn write<W: Write + Seek>(
+        writer: &mut W,
+        mut index: Self,
+        on_progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<(), PakError> {
+        let entry_count = index.entries.len();
         let index_offset = writer.stream_position()?;
 
         let mut index_writer = Cursor::new(Vec::new());
@@ -115,8 +167,11 @@ impl Index {
 
         index_writer.write_u32::<LE>(index.entries.len() as u32)?;
 
+        // only the primary index is covered by the footer hash, secondary indices have their own
+        let mut secondary_index = Vec::new();
+
         if index.footer.pak_version < PakVersion::PathHashIndex {
-            for (name, header) in index.entries {
+            for (i, (name, header)) in index.entries.into_iter().enumerate() {
                 index_writer.write_fstring(Some(name.as_str()))?;
                 Header::write(
                     &mut index_writer,
@@ -124,138 +179,127 @@ impl Index {
                     &index.footer.compression_methods,
                     &header,
                 )?;
+                on_progress(i + 1, entry_count);
             }
         } else {
-            dbg!(index.path_hash_seed);
-            return Err(PakError::pak_version_unsupported(index.footer.pak_version));
-        }
-
-        let index_data = index_writer.into_inner();
-        index.footer.index_offset = index_offset;
-        index.footer.index_size = index_data.len() as u64;
-
-        index.footer.index_hash = hash(&index_data);
-
-        writer.write_all(&index_data)?;
-
-        Footer::write(writer, index.footer)?;
-
-        Ok(())
-    }
-}
-
-#[derive(Debug)]
-pub(crate) struct Footer {
-    pub pak_version: PakVersion,
-    pub index_offset: u64,
-    pub index_size: u64,
-    pub index_hash: [u8; 20],
-    pub compression_methods: CompressionMethods,
-    pub index_encrypted: Option<bool>,
-    pub encryption_key_guid: Option<[u8; 0x10]>,
-}
+            index_writer
+                .write_u64::<LE>(index.path_hash_seed.unwrap_or_else(random_path_hash_seed))?;
+
+            // no path hash index, the full directory index is enough to find entries
+            index_writer.write_u32::<LE>(0)?;
+
+            let mut encoded_entries = Cursor::new(Vec::new());
+            let mut unencoded_entries = Cursor::new(Vec::new());
+            let mut unencoded_count = 0;
+
+            // directory names end with a slash, the root directory is just "/"
+            let mut directories = BTreeMap::<String, Vec<(String, i32)>>::new();
+            for (i, (name, header)) in index.entries.iter().enumerate() {
+                let (directory, file_name) = match name.rfind('/') {
+                    Some(i) => name.split_at(i + 1),
+                    None => ("/", name.as_str()),
+                };
+
+                let encoded_offset = encoded_entries.position() as i32;
+                let encoded_offset = match Header::write_encoded(
+                    &mut encoded_entries,
+                    index.footer.pak_version,
+                    &index.footer.compression_methods,
+                    header,
+                )? {
+                    true => encoded_offset,
+                    false => {
+                        Header::write(
+                            &mut unencoded_entries,
+                            index.footer.pak_version,
+                            &index.footer.compression_methods,
+                            header,
+                        )?;
+                        unencoded_count += 1;
+                        -unencoded_count
+                    }
+                };
 
-impl Footer {
-    pub(crate) fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, PakError> {
-        // magic offset (from bottom) can only be 0x2C (v2-v7), 0xAC (v8a), 0xCC (v8b,v11), 0xCD (v9)
-        let possible_offsets = vec![-0x2C, -0xAC, -0xCC, -0xCD];
+                directories
+                    .entry(directory.to_owned())
+                    .or_default()
+                    .push((file_name.to_owned(), encoded_offset));
+
+                // parent directories are listed too, even without files of their own
+                let mut parent = directory.trim_end_matches('/');
+                while let Some(i) = parent.rfind('/') {
+                    parent = &parent[..i];
+                    directories.entry(format!("{parent}/")).or_default();
+                }
+                directories.entry("/".to_owned()).or_default();
 
-        let mut magic_offset = None;
-        for offset in possible_offsets {
-            reader.seek(SeekFrom::End(offset))?;
-            if reader.read_u32::<BE>()? == PAK_MAGIC {
-                magic_offset = Some(offset);
+                on_progress(i + 1, entry_count);
             }
-        }
-        let magic_offset = magic_offset.ok_or_else(PakError::pak_invalid)?;
 
-        // seek to file version
-        reader.seek(SeekFrom::End(magic_offset + 4))?;
+            let mut directory_index = Cursor::new(Vec::new());
+            directory_index.write_u32::<LE>(directories.len() as u32)?;
+            for (directory, files) in directories {
+                directory_index.write_fstring(Some(&directory))?;
+                directory_index.write_u32::<LE>(files.len() as u32)?;
+                for (file_name, encoded_offset) in files {
+                    directory_index.write_fstring(Some(&file_name))?;
+                    directory_index.write_i32::<LE>(encoded_offset)?;
+                }
+            }
+            let directory_index = directory_index.into_inner();
 
-        let mut pak_version = PakVersion::from_num(reader.read_u32::<LE>()?);
-        if magic_offset == -0xAC {
-            pak_version.set_subversion();
-        }
+            // the directory index offset is patched in once the size of this index is known
+            index_writer.write_u32::<LE>(1)?;
+            let directory_index_offset_position = index_writer.position();
+            index_writer.write_u64::<LE>(0)?;
+            index_writer.write_u64::<LE>(directory_index.len() as u64)?;
+            index_writer.write_all(&hash(&directory_index))?;
 
-        let index_offset = reader.read_u64::<LE>()?;
-        let index_size = reader.read_u64::<LE>()?;
+            let encoded_entries = encoded_entries.into_inner();
+            index_writer.write_u32::<LE>(encoded_entries.len() as u32)?;
+            index_writer.write_all(&encoded_entries)?;
 
-        let mut index_hash = [0u8; 20];
-        reader.read_exact(&mut index_hash)?;
+            index_writer.write_u32::<LE>(unencoded_count as u32)?;
+            index_writer.write_all(&unencoded_entries.into_inner())?;
 
-        // if version 9 skip frozen index byte
-        if pak_version == PakVersion::FrozenIndex {
-            reader.seek(SeekFrom::Current(1))?;
-        }
+            let directory_index_offset = index_offset + index_writer.position();
+            index_writer.set_position(directory_index_offset_position);
+            index_writer.write_u64::<LE>(directory_index_offset)?;
 
-        let compression_methods = if pak_version >= PakVersion::FnameBasedCompressionMethod {
-            CompressionMethods::from_reader(reader)?
-        } else {
-            CompressionMethods::default()
-        };
-
-        // index_encrypted is one byte before magic
-        let mut index_encrypted = None;
-        if pak_version >= PakVersion::IndexEncryption {
-            reader.seek(SeekFrom::End(magic_offset - 1))?;
-            index_encrypted = Some(reader.read_u8()? != 0);
-        }
-
-        // encryption key guid is 0x10 bytes before index_encrypted flag
-        let mut encryption_key_guid = None;
-        if pak_version >= PakVersion::EncryptionKeyGuid {
-            reader.seek(SeekFrom::End(magic_offset - 0x11))?;
-            let mut buf = [0u8; 0x10];
-            reader.read_exact(&mut buf)?;
-            encryption_key_guid = Some(buf);
+            secondary_index = directory_index;
         }
 
-        Ok(Footer {
-            pak_version,
-            index_offset,
-            index_size,
-            index_hash,
-            compression_methods,
-            index_encrypted,
-            encryption_key_guid,
-        })
-    }
-
-    pub(crate) fn write<W: Write>(writer: &mut W, footer: Self) -> Result<(), PakError> {
-        // write encryption key guid first
-        if footer.pak_version >= PakVersion::EncryptionKeyGuid {
-            if let Some(encryption_key_guid) = footer.encryption_key_guid {
-                writer.write_all(&encryption_key_guid)?;
-            }
-        }
-
-        // write index_encrypted
-        if footer.pak_version >= PakVersion::IndexEncryption {
-            writer.write_u8(u8::from(footer.index_encrypted.unwrap_or_default()))?;
-        }
+        let index_data = index_writer.into_inner();
+        index.footer.index_offset = index_offset;
+        index.footer.index_size = index_data.len() as u64;
 
-        // write magic and pak version
-        writer.write_u32::<BE>(PAK_MAGIC)?;
-        writer.write_u32::<LE>(footer.pak_version.to_num())?;
+        index.footer.index_hash = hash(&index_data);
 
-        // write index offset and length
-        writer.write_u64::<LE>(footer.index_offset)?;
-        writer.write_u64::<LE>(footer.index_size)?;
+        writer.write_all(&index_data)?;
+        writer.write_all(&secondary_index)?;
 
-        // write hash
-        writer.write_all(&footer.index_hash)?;
+        PakFooter::write(writer, index.footer)?;
 
-        // frozen index
-        if footer.pak_version == PakVersion::FrozenIndex {
-            writer.write_u8(0)?;
-        }
+        Ok(())
+    }
+}
 
-        // compression methods
-        if footer.pak_version >= PakVersion::FnameBasedCompressionMethod {
-            writer.write_all(footer.compression_methods.as_bytes().as_slice())?;
+/// Get the compression of a stored index from its first bytes.
+///
+/// The footer has no field for it, but a Zlib stream starts with a two byte header:
+/// CMF holds the method (8, deflate) and window size, FLG makes `CMF << 8 | FLG` a multiple of 31.
+/// A raw index starts with the length of the mount point, which for any mount point
+/// shorter than 255 characters never forms such a header, so the two can't be confused.
+fn index_compression(data: &[u8]) -> Option<Compression> {
+    match data {
+        [cmf, flg, ..]
+            if cmf & 0x0f == 8
+                && cmf >> 4 <= 7
+                && ((*cmf as u16) << 8 | *flg as u16).is_multiple_of(31) =>
+        {
+            Some(Compression::zlib())
         }
-
-        Ok(())
+        _ => None,
     }
 }
 
@@ -20,20 +20,31 @@ File parts:
 
 pub mod compression;
 mod entry;
+pub mod entry_reader;
 pub mod error;
+pub mod footer;
+mod glob;
+pub mod hasher;
 mod header;
 mod index;
+pub mod pakbuilder;
 pub mod pakmemory;
 pub mod pakreader;
 pub mod pakversion;
 pub mod pakwriter;
+pub mod split_reader;
 
+pub use entry_reader::PakEntryReader;
+pub use pakbuilder::PakBuilder;
 pub use pakmemory::PakMemory;
 pub use pakreader::PakReader;
 pub use pakwriter::PakWriter;
+pub use split_reader::SplitReader;
 
 pub use compression::Compression;
 pub use error::PakError;
+pub use footer::{detect_version, parse_footer, PakFooter};
+pub use hasher::{EntryHasher, Sha1Hasher};
 
 pub(crate) const PAK_MAGIC: u32 = u32::from_be_bytes([0xE1, 0x12, 0x6F, 0x5A]);
 
@@ -0,0 +1,95 @@
+//! Pak writer that only replaces its target file once the pak was written completely
+
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use crate::error::PakError;
+use crate::pakversion::PakVersion;
+use crate::pakwriter::PakWriter;
+
+/// A [`PakWriter`] that writes to a temporary file next to the target
+/// and renames it over the target in [`PakBuilder::finish_write`].
+///
+/// If building fails or the builder is dropped before finishing, the temporary file is removed
+/// and an existing file at the target path is left untouched.
+#[derive(Debug)]
+pub struct PakBuilder {
+    /// writer for the temporary file, `None` once finished
+    writer: Option<PakWriter<BufWriter<File>>>,
+    /// path the pak ends up at
+    target_path: PathBuf,
+    /// path of the temporary file
+    temp_path: PathBuf,
+}
+
+impl PakBuilder {
+    /// Creates a new `PakBuilder` for a pak at `target_path`.
+    /// The temporary file is created next to it with a `.tmp` extension appended.
+    pub fn create(
+        target_path: impl AsRef<Path>,
+        pak_version: PakVersion,
+    ) -> Result<Self, PakError> {
+        let target_path = target_path.as_ref().to_owned();
+        let mut temp_name = target_path
+            .file_name()
+            .ok_or_else(PakError::configuration_invalid)?
+            .to_owned();
+        temp_name.push(".tmp");
+        let temp_path = target_path.with_file_name(temp_name);
+
+        let file = File::create(&temp_path)?;
+
+        Ok(Self {
+            writer: Some(PakWriter::new(BufWriter::new(file), pak_version)),
+            target_path,
+            temp_path,
+        })
+    }
+
+    /// Returns the path of the temporary file the pak is written to.
+    pub fn temp_path(&self) -> &Path {
+        &self.temp_path
+    }
+
+    /// Returns the underlying [`PakWriter`], e.g. to change the mount point.
+    pub fn writer(&mut self) -> &mut PakWriter<BufWriter<File>> {
+        // the writer is only taken by finish_write which consumes self
+        self.writer.as_mut().unwrap()
+    }
+
+    /// Writes the given data into the temporary pak file, see [`PakWriter::write_entry`].
+    pub fn write_entry(
+        &mut self,
+        name: &String,
+        data: &Vec<u8>,
+        compress: bool,
+    ) -> Result<(), PakError> {
+        self.writer().write_entry(name, data, compress)
+    }
+
+    /// Finish writing the pak file and move it to the target path, replacing any existing file.
+    pub fn finish_write(mut self) -> Result<(), PakError> {
+        // the builder has not been finished, so the writer is always present
+        let writer = self.writer.take().unwrap();
+
+        let file = writer
+            .finish(|_, _| {})?
+            .into_inner()
+            .map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        drop(file);
+
+        fs::rename(&self.temp_path, &self.target_path)?;
+        Ok(())
+    }
+}
+
+impl Drop for PakBuilder {
+    fn drop(&mut self) {
+        // after a successful finish_write the temporary file has already been renamed
+        if self.temp_path.exists() {
+            let _ = fs::remove_file(&self.temp_path);
+        }
+    }
+}
@@ -3,10 +3,11 @@
 use std::collections::BTreeMap;
 use std::io::{Read, Seek, Write};
 
-use crate::compression::CompressionMethods;
-use crate::entry::{read_entry, write_entry};
+use crate::compression::{Compression, CompressionMethods, DEFAULT_COMPRESSION_LEVEL};
+use crate::entry::{read_entry, write_entry_with_method, WriteOptions};
 use crate::error::PakError;
-use crate::index::{random_path_hash_seed, Footer, Index};
+use crate::footer::PakFooter;
+use crate::index::{random_path_hash_seed, Index};
 use crate::pakversion::PakVersion;
 
 /// A Unreal Pak file which keeps all of it's data in memory.
@@ -22,6 +23,8 @@ pub struct PakMemory {
     /// the compression block size
     pub block_size: u32,
     entries: BTreeMap<String, Vec<u8>>,
+    /// compression methods of loaded and recompressed entries, others use the preferred one
+    entry_compression: BTreeMap<String, Compression>,
 }
 
 impl PakMemory {
@@ -33,6 +36,7 @@ impl PakMemory {
             compression: CompressionMethods::default(),
             block_size: 0x010000,
             entries: BTreeMap::new(),
+            entry_compression: BTreeMap::new(),
         }
     }
 
@@ -45,6 +49,9 @@ impl PakMemory {
         self.compression = index.footer.compression_methods;
 
         for (name, header) in index.entries {
+            // entries keep the method they are stored with
+            self.entry_compression
+                .insert(name.clone(), header.compression_method);
             self.entries.insert(
                 name,
                 read_entry(
@@ -52,6 +59,7 @@ impl PakMemory {
                     self.pak_version,
                     &self.compression,
                     header.offset,
+                    None,
                 )?,
             );
         }
@@ -86,23 +94,85 @@ impl PakMemory {
         self.entries.insert(name, data);
     }
 
+    /// Returns the compression method an entry will be written with.
+    ///
+    /// Loaded entries keep the method they were stored with unless changed with [`PakMemory::recompress_entry`].
+    /// New entries of at least 32 bytes use the preferred compression method of this file,
+    /// smaller ones are stored uncompressed.
+    pub fn entry_compression(&self, name: &str) -> Option<Compression> {
+        let data = self.entries.get(name)?;
+        Some(match self.entry_compression.get(name) {
+            Some(method) => *method,
+            None if data.len() >= 32 => self.compression.0[0],
+            None => Compression::None,
+        })
+    }
+
+    /// Changes the compression method an entry is written with.
+    ///
+    /// Entries are kept decompressed in memory, so nothing is compressed until the pak is written.
+    /// Methods not yet used by this file are added to its compression methods,
+    /// other entries keep the method they were going to be written with.
+    /// Fails if the method can't compress, or if the file has no free slot for a new method.
+    pub fn recompress_entry(&mut self, name: &str, method: Compression) -> Result<(), PakError> {
+        if !self.entries.contains_key(name) {
+            return Err(PakError::entry_not_found(name.to_owned()));
+        }
+        if !method.can_compress() {
+            return Err(PakError::compression_unsupported(method));
+        }
+
+        if let Compression::Known(_) = method {
+            if self.pak_version < PakVersion::CompressionEncryption {
+                return Err(PakError::configuration_invalid());
+            }
+
+            if !self.compression.0.contains(&method) {
+                let free_slot = self
+                    .compression
+                    .0
+                    .iter()
+                    .position(|e| *e == Compression::None)
+                    .ok_or_else(|| PakError::compression_unsupported(method))?;
+
+                // the first method is the preferred one, keep entries from switching to it
+                if free_slot == 0 {
+                    for entry in self.entries.keys() {
+                        self.entry_compression
+                            .entry(entry.clone())
+                            .or_insert(Compression::None);
+                    }
+                }
+                self.compression.0[free_slot] = method;
+            }
+        }
+
+        self.entry_compression.insert(name.to_owned(), method);
+        Ok(())
+    }
+
     /// Write all the data as a finished pak file into the provided writer.
     pub fn write<W: Write + Seek>(&self, writer: &mut W) -> Result<(), PakError> {
         let mut written_entries = Vec::new();
 
         for (name, data) in self.entries.iter() {
-            let header = write_entry(
+            let compression_method = self.entry_compression(name).unwrap_or(Compression::None);
+            let (header, _) = write_entry_with_method(
                 writer,
-                self.pak_version,
+                WriteOptions {
+                    pak_version: self.pak_version,
+                    compression: &self.compression,
+                    block_size: self.block_size,
+                    compression_level: DEFAULT_COMPRESSION_LEVEL,
+                    hasher: None,
+                },
                 data,
-                true,
-                &self.compression,
-                self.block_size,
+                compression_method,
             )?;
             written_entries.push((name.clone(), header));
         }
 
-        let footer = Footer {
+        let footer = PakFooter {
             pak_version: self.pak_version,
             // these are set in write_index
             index_offset: 0,
@@ -120,7 +190,7 @@ impl PakMemory {
             footer,
         };
 
-        Index::write(writer, index)
+        Index::write(writer, index, &mut |_, _| {})
     }
 
     /// Iterate over the entries in the PakMemory
@@ -1,14 +1,97 @@
 //! PakFile data structure for reading large pak files
 
 use std::collections::BTreeMap;
-use std::io::{Read, Seek};
+use std::fs::{self, File};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
 
-use crate::compression::CompressionMethods;
-use crate::entry::read_entry;
-use crate::error::PakError;
+use unreal_helpers::{error::PathError, sanitize_extract_path};
+
+use crate::compression::{Compression, CompressionMethods, Oodle, OodleDecompressor};
+use crate::entry::{read_entry, read_entry_to, verify_entry};
+use crate::entry_reader::PakEntryReader;
+use crate::error::{LayoutError, LayoutErrorKind, PakError};
+use crate::footer::PakFooter;
+use crate::glob::glob_match;
+use crate::hasher::EntryHasher;
 use crate::header::Header;
 use crate::index::Index;
 use crate::pakversion::PakVersion;
+use crate::split_reader::SplitReader;
+
+/// Location of one compression block of an entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockInfo {
+    /// Offset of the block from the start of the pak file
+    pub offset: u64,
+    /// Size of the block as stored in the pak file
+    pub compressed_size: u64,
+    /// Size of the block after decompression, the last block may be shorter than the others
+    pub decompressed_size: u64,
+}
+
+/// Size and compression of an entry as stored in the index, see [`PakReader::entry_info`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntryInfo {
+    /// Offset of the entry header from the start of the pak file
+    pub offset: u64,
+    /// Size of the entry data as stored in the pak file
+    pub compressed_size: u64,
+    /// Size of the entry data after decompression
+    pub decompressed_size: u64,
+    /// Number of compression blocks, uncompressed entries have none
+    pub block_count: u64,
+    /// Compression method of the entry
+    pub compression_method: Compression,
+}
+
+/// Combined sizes of a group of entries
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EntryStats {
+    /// Number of entries
+    pub entry_count: usize,
+    /// Size of the entry data as stored in the pak file
+    pub compressed_size: u64,
+    /// Size of the entry data after decompression
+    pub decompressed_size: u64,
+}
+
+impl EntryStats {
+    fn add(&mut self, header: &Header) {
+        self.entry_count += 1;
+        self.compressed_size += header.compressed_size;
+        self.decompressed_size += header.decompressed_size;
+    }
+
+    /// Stored size divided by decompressed size, `1.0` if there is no data
+    pub fn compression_ratio(&self) -> f64 {
+        match self.decompressed_size {
+            0 => 1.0,
+            decompressed_size => self.compressed_size as f64 / decompressed_size as f64,
+        }
+    }
+}
+
+/// Entry statistics of a pak file, see [`PakReader::stats`]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PakStats {
+    /// All entries
+    pub total: EntryStats,
+    /// Entries per compression method, in the order the methods are first used
+    pub by_compression: Vec<(Compression, EntryStats)>,
+}
+
+/// How [`PakReader::load_index`] handles an index that contains the same entry name more than once
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateNamePolicy {
+    /// keep the header of the first entry with the name
+    KeepFirst,
+    /// keep the header of the last entry with the name
+    KeepLast,
+    /// fail loading with [`PakErrorKind::DuplicateEntry`](crate::error::PakErrorKind::DuplicateEntry)
+    #[default]
+    Error,
+}
 
 /// An Unreal pak file reader with it's data kept on disk and only read on demand.
 #[derive(Debug)]
@@ -16,12 +99,18 @@ pub struct PakReader<R>
 where
     R: Read + Seek,
 {
-    /// version of the pak file format this one is using
-    pak_version: PakVersion,
+    /// footer of the pak file, contains the version of the pak file format this one is using
+    footer: PakFooter,
     /// mount point (Unreal stuff)
     pub mount_point: String,
-    compression: CompressionMethods,
     entries: BTreeMap<String, Header>,
+    /// entry names in the same order as `entries`, for lookups by index
+    entry_names: Vec<String>,
+    duplicate_name_policy: DuplicateNamePolicy,
+    /// Hash algorithm entries are verified with instead of the SHA1 in their headers
+    entry_hasher: Option<CustomEntryHashes>,
+    /// Decompressor for Oodle compressed entries
+    oodle: Option<Oodle>,
     reader: R,
 }
 
@@ -34,10 +123,13 @@ where
     /// [`std::io::BufReader`] to avoid unnecessary syscalls.
     pub fn new(reader: R) -> Self {
         Self {
-            pak_version: PakVersion::Invalid,
+            footer: PakFooter::default(),
             mount_point: "".to_owned(),
-            compression: Default::default(),
             entries: BTreeMap::new(),
+            entry_names: Vec::new(),
+            duplicate_name_policy: DuplicateNamePolicy::default(),
+            entry_hasher: None,
+            oodle: None,
             reader,
         }
     }
@@ -46,28 +138,162 @@ where
     pub fn load_index(&mut self) -> Result<(), PakError> {
         let index = Index::read(&mut self.reader)?;
 
-        self.pak_version = index.footer.pak_version;
+        self.footer = index.footer;
         self.mount_point = index.mount_point.clone();
-        self.compression = index.footer.compression_methods;
 
+        let mut entries = BTreeMap::new();
         for (name, header) in index.entries {
-            self.entries.insert(name, header);
+            if entries.contains_key(&name) {
+                match self.duplicate_name_policy {
+                    DuplicateNamePolicy::KeepFirst => continue,
+                    DuplicateNamePolicy::KeepLast => {}
+                    DuplicateNamePolicy::Error => return Err(PakError::duplicate_entry(name)),
+                }
+            }
+            entries.insert(name, header);
         }
+        self.entries = entries;
+        self.entry_names = self.entries.keys().cloned().collect();
 
         Ok(())
     }
 
+    /// Sets how duplicate entry names are handled by [`PakReader::load_index`].
+    /// Defaults to [`DuplicateNamePolicy::Error`].
+    pub fn set_duplicate_name_policy(&mut self, policy: DuplicateNamePolicy) {
+        self.duplicate_name_policy = policy;
+    }
+
+    /// Sets a hash algorithm and the hashes it created when the pak was written, see
+    /// [`PakWriter::entry_hashes`](crate::PakWriter::entry_hashes).
+    ///
+    /// [`PakReader::verify_entry`], [`PakReader::verify_all`] and [`PakReader::verify_all_parallel`]
+    /// then check entries against these hashes instead of the SHA1 in their headers.
+    /// Entries without a hash fail verification.
+    pub fn set_entry_hasher(
+        &mut self,
+        hasher: Box<dyn EntryHasher>,
+        hashes: BTreeMap<String, [u8; 20]>,
+    ) {
+        self.entry_hasher = Some(CustomEntryHashes { hasher, hashes });
+    }
+
+    /// Sets the decompressor used for Oodle compressed entries.
+    ///
+    /// Oodle is proprietary and not bundled with this crate, without a decompressor reading
+    /// Oodle compressed entries fails with [`PakErrorKind::OodleUnavailable`](crate::error::PakErrorKind::OodleUnavailable).
+    pub fn set_oodle_decompressor(&mut self, decompressor: Box<OodleDecompressor>) {
+        self.oodle = Some(Oodle(decompressor));
+    }
+
+    /// Returns the footer of the pak file.
+    /// Before [`PakReader::load_index`] was called this is a default footer with [`PakVersion::Invalid`].
+    pub fn footer(&self) -> &PakFooter {
+        &self.footer
+    }
+
+    /// Returns the mount point entry names are relative to, e.g. `../../../`.
+    pub fn mount_point(&self) -> &str {
+        &self.mount_point
+    }
+
     /// Returns the names of all entries which have been found.
     pub fn get_entry_names(&self) -> Vec<&String> {
         self.entries.keys().collect()
     }
 
+    /// Returns the names of all entries matching a glob pattern, sorted like [`PakReader::get_entry_names`].
+    ///
+    /// `?` matches one character and `*` any number of characters within a directory,
+    /// `**` also matches across directories. A pattern matching a directory matches everything in it,
+    /// so `Game/Content/Maps` and `Game/*/Maps/` both match `Game/Content/Maps/Level.umap`.
+    /// Patterns are matched against the stored entry names with backslashes treated as forward slashes.
+    pub fn entry_names_matching(&self, pattern: &str) -> Vec<&String> {
+        self.entries
+            .keys()
+            .filter(|name| glob_match(pattern, name))
+            .collect()
+    }
+
+    /// Returns the name of the entry at the given index.
+    /// Entries are sorted by name, so indices match the order of [`PakReader::get_entry_names`].
+    pub fn entry_name_at(&self, index: usize) -> Option<&str> {
+        self.entry_names.get(index).map(String::as_str)
+    }
+
     /// Checks if the pak file contains an entry with the given name
     pub fn contains_entry(&self, name: &String) -> bool {
         self.entries.contains_key(name)
     }
 
+    /// Returns the raw flags of an entry as stored in the index.
+    /// Pak versions before [`PakVersion::CompressionEncryption`] don't have entry flags and return `None`,
+    /// paks with a path hash index only store the encrypted flag.
+    pub fn entry_flags(&self, name: &str) -> Result<Option<u8>, PakError> {
+        self.entries
+            .get(name)
+            .map(|header| header.flags)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))
+    }
+
+    /// Returns the compression method of an entry as stored in the index.
+    pub fn entry_compression(&self, name: &str) -> Result<Compression, PakError> {
+        self.entries
+            .get(name)
+            .map(|header| header.compression_method)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))
+    }
+
+    /// Checks if an entry is encrypted according to the index.
+    pub fn is_entry_encrypted(&self, name: &str) -> Result<bool, PakError> {
+        self.entries
+            .get(name)
+            .map(Header::is_encrypted)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))
+    }
+
+    /// Returns the size and compression of an entry as stored in the index, without reading any entry data.
+    pub fn entry_info(&self, name: &str) -> Result<EntryInfo, PakError> {
+        self.entries
+            .get(name)
+            .map(|header| EntryInfo {
+                offset: header.offset,
+                compressed_size: header.compressed_size,
+                decompressed_size: header.decompressed_size,
+                block_count: header.block_count(),
+                compression_method: header.compression_method,
+            })
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))
+    }
+
+    /// Returns the combined sizes of all entries, in total and per compression method.
+    /// Only the index is used, no entry data is read.
+    pub fn stats(&self) -> PakStats {
+        let mut stats = PakStats::default();
+        for header in self.entries.values() {
+            stats.total.add(header);
+
+            match stats
+                .by_compression
+                .iter_mut()
+                .find(|(method, _)| *method == header.compression_method)
+            {
+                Some((_, method_stats)) => method_stats.add(header),
+                None => {
+                    let mut method_stats = EntryStats::default();
+                    method_stats.add(header);
+                    stats
+                        .by_compression
+                        .push((header.compression_method, method_stats));
+                }
+            }
+        }
+        stats
+    }
+
     /// Reads an entry from the pak on disk into memory and returns it's data.
+    /// Compressed entries that don't decompress to the size in their header fail with
+    /// [`PakErrorKind::DecompressedSizeMismatch`](crate::error::PakErrorKind::DecompressedSizeMismatch).
     pub fn read_entry(&mut self, name: &String) -> Result<Vec<u8>, PakError> {
         let header = self
             .entries
@@ -76,21 +302,314 @@ where
         self.read_entry_at_offset(header.offset)
     }
 
+    /// Reads the entry at the given index from the pak on disk into memory and returns it's data.
+    /// See [`PakReader::entry_name_at`] for the ordering of entries.
+    pub fn read_entry_by_index(&mut self, index: usize) -> Result<Vec<u8>, PakError> {
+        let offset = self
+            .entry_names
+            .get(index)
+            .and_then(|name| self.entries.get(name))
+            .map(|header| header.offset)
+            .ok_or_else(|| PakError::entry_index_out_of_range(index))?;
+        self.read_entry_at_offset(offset)
+    }
+
+    /// Reads an entry from the pak on disk and writes it's data to `writer`.
+    /// Unlike [`PakReader::read_entry`] the entry is never fully kept in memory,
+    /// only one compression block at a time. Returns the number of bytes written.
+    pub fn read_entry_to<W: Write>(&mut self, name: &str, writer: &mut W) -> Result<u64, PakError> {
+        let header = self
+            .entries
+            .get(name)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))?;
+        read_entry_to(
+            &mut self.reader,
+            self.footer.pak_version,
+            &self.footer.compression_methods,
+            header.offset,
+            self.oodle.as_ref(),
+            writer,
+        )
+    }
+
+    /// Returns a reader over the decompressed data of an entry, see [`PakEntryReader`].
+    /// Compression blocks are only decompressed when they are read from.
+    pub fn entry_reader(&mut self, name: &str) -> Result<PakEntryReader<'_, R>, PakError> {
+        let header = self
+            .entries
+            .get(name)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))?;
+        PakEntryReader::new(
+            &mut self.reader,
+            self.footer.pak_version,
+            &self.footer.compression_methods,
+            header.offset,
+            self.oodle.as_ref(),
+        )
+    }
+
+    /// Extracts all entries to files in `out_dir`, streaming each entry to disk.
+    ///
+    /// With `mount_relative` entry names are resolved against the mount point first,
+    /// see [`resolve_entry_path`], otherwise the stored names are used.
+    /// Names that would end up outside of `out_dir` are rejected with [`PakErrorKind::Path`](crate::error::PakErrorKind::Path)
+    /// before anything is written for them.
+    pub fn extract_all(&mut self, out_dir: &Path, mount_relative: bool) -> Result<(), PakError> {
+        let names = self.entry_names.clone();
+        self.extract_entries(&names, out_dir, mount_relative)
+    }
+
+    /// Extracts the given entries to files in `out_dir` like [`PakReader::extract_all`],
+    /// e.g. the ones returned by [`PakReader::entry_names_matching`].
+    pub fn extract_entries<S: AsRef<str>>(
+        &mut self,
+        names: &[S],
+        out_dir: &Path,
+        mount_relative: bool,
+    ) -> Result<(), PakError> {
+        for name in names {
+            let name = name.as_ref();
+            let header = self
+                .entries
+                .get(name)
+                .ok_or_else(|| PakError::entry_not_found(name.to_owned()))?;
+
+            let entry_name = match mount_relative {
+                true => resolve_entry_path(&self.mount_point, name)
+                    .ok_or_else(|| PathError::Traversal(format!("{}{}", self.mount_point, name)))?,
+                false => name.to_owned(),
+            };
+            let path = sanitize_extract_path(out_dir, &entry_name)?;
+
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
+            let mut file = BufWriter::new(File::create(&path)?);
+            read_entry_to(
+                &mut self.reader,
+                self.footer.pak_version,
+                &self.footer.compression_methods,
+                header.offset,
+                self.oodle.as_ref(),
+                &mut file,
+            )?;
+            file.flush()?;
+        }
+
+        Ok(())
+    }
+
     fn read_entry_at_offset(&mut self, offset: u64) -> Result<Vec<u8>, PakError> {
         read_entry(
             &mut self.reader,
-            self.pak_version,
-            &self.compression,
+            self.footer.pak_version,
+            &self.footer.compression_methods,
             offset,
+            self.oodle.as_ref(),
+        )
+    }
+
+    /// Returns the compression block layout of an entry.
+    /// Uncompressed entries are returned as a single block with equal sizes.
+    pub fn entry_blocks(&mut self, name: &str) -> Result<Vec<BlockInfo>, PakError> {
+        let offset = self
+            .entries
+            .get(name)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))?
+            .offset;
+
+        self.reader.seek(SeekFrom::Start(offset))?;
+        let header = Header::read(
+            &mut self.reader,
+            self.footer.pak_version,
+            &self.footer.compression_methods,
+        )?;
+
+        if matches!(header.compression_method, Compression::None) {
+            return Ok(vec![BlockInfo {
+                offset: self.reader.stream_position()?,
+                compressed_size: header.compressed_size,
+                decompressed_size: header.decompressed_size,
+            }]);
+        }
+
+        let compression_blocks = header
+            .compression_blocks
+            .as_ref()
+            .ok_or_else(PakError::entry_invalid)?;
+        let block_size = header
+            .compression_block_size
+            .filter(|size| *size != 0)
+            .map(u64::from)
+            .unwrap_or(header.decompressed_size);
+
+        let mut remaining = header.decompressed_size;
+        Ok(compression_blocks
+            .iter()
+            .map(|block| {
+                let decompressed_size = remaining.min(block_size);
+                remaining -= decompressed_size;
+
+                BlockInfo {
+                    offset: offset + block.start,
+                    compressed_size: block.size,
+                    decompressed_size,
+                }
+            })
+            .collect())
+    }
+
+    /// Checks that the stored data of an entry matches the hash in its header,
+    /// or the hash set with [`PakReader::set_entry_hasher`].
+    /// The hash covers the data as it is stored in the pak, so compressed entries are not decompressed.
+    pub fn verify_entry(&mut self, name: &str) -> Result<bool, PakError> {
+        let header = self
+            .entries
+            .get(name)
+            .ok_or_else(|| PakError::entry_not_found(name.to_owned()))?;
+        verify_named_entry(
+            &mut self.reader,
+            &self.footer,
+            name,
+            header,
+            self.entry_hasher.as_ref(),
         )
     }
 
+    /// Checks the hashes of all entries, see [`PakReader::verify_entry`].
+    /// The results are sorted by entry name.
+    pub fn verify_all(&mut self) -> Vec<(String, Result<bool, PakError>)> {
+        self.entries
+            .iter()
+            .map(|(name, header)| {
+                let result = verify_named_entry(
+                    &mut self.reader,
+                    &self.footer,
+                    name,
+                    header,
+                    self.entry_hasher.as_ref(),
+                );
+                (name.clone(), result)
+            })
+            .collect()
+    }
+
+    /// Checks that the entry offsets in the index match the layout of the pak file.
+    ///
+    /// Every entry needs a readable header at its offset matching the header in the index,
+    /// its data has to end before the index and no two entries may share bytes.
+    /// Unlike [`PakReader::verify_entry`] the entry data itself is not read.
+    // `Option::is_none_or` would need Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn check_layout(&mut self) -> Result<(), Vec<LayoutError>> {
+        let limit = self.footer.index_offset;
+        let mut errors = Vec::new();
+        let mut spans = Vec::with_capacity(self.entries.len());
+
+        for (name, index_header) in &self.entries {
+            let offset = index_header.offset;
+            let error = |kind| LayoutError {
+                entry: name.clone(),
+                offset,
+                kind,
+            };
+
+            let header = self
+                .reader
+                .seek(SeekFrom::Start(offset))
+                .map_err(PakError::from)
+                .and_then(|_| {
+                    Header::read(
+                        &mut self.reader,
+                        self.footer.pak_version,
+                        &self.footer.compression_methods,
+                    )
+                })
+                .and_then(|header| Ok((header, self.reader.stream_position()?)));
+            let (header, data_start) = match header {
+                Ok(header) => header,
+                Err(err) => {
+                    errors.push(error(LayoutErrorKind::HeaderUnreadable(err)));
+                    continue;
+                }
+            };
+
+            if header.compressed_size != index_header.compressed_size
+                || header.decompressed_size != index_header.decompressed_size
+            {
+                errors.push(error(LayoutErrorKind::HeaderMismatch));
+                continue;
+            }
+
+            let end = data_start.saturating_add(header.compressed_size);
+            if end > limit {
+                errors.push(error(LayoutErrorKind::OutOfBounds { end, limit }));
+                continue;
+            }
+
+            spans.push((offset, end, name));
+        }
+
+        // compare every entry with the one reaching furthest before it, not just its neighbour
+        spans.sort_unstable();
+        let mut furthest: Option<(u64, &String)> = None;
+        for (offset, end, name) in spans {
+            if let Some((furthest_end, other)) = furthest {
+                if offset < furthest_end {
+                    errors.push(LayoutError {
+                        entry: name.clone(),
+                        offset,
+                        kind: LayoutErrorKind::Overlap(other.clone()),
+                    });
+                }
+            }
+            if furthest.map_or(true, |(furthest_end, _)| end > furthest_end) {
+                furthest = Some((end, name));
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Checks the hashes of all entries in parallel.
+    ///
+    /// Every worker thread reads through its own reader created by `open_reader`, usually by opening
+    /// the pak file again. The results are sorted by entry name.
+    #[cfg(feature = "parallel")]
+    pub fn verify_all_parallel<F, O>(&self, open_reader: F) -> Vec<(String, Result<bool, PakError>)>
+    where
+        F: Fn() -> std::io::Result<O> + Sync + Send,
+        O: Read + Seek,
+    {
+        use rayon::prelude::*;
+
+        let footer = &self.footer;
+        let entry_hasher = self.entry_hasher.as_ref();
+
+        self.entries
+            .par_iter()
+            .map_init(open_reader, |reader, (name, header)| {
+                let result = match reader {
+                    Ok(reader) => verify_named_entry(reader, footer, name, header, entry_hasher),
+                    // io errors can't be cloned, so every entry gets its own copy
+                    Err(err) => Err(std::io::Error::new(err.kind(), err.to_string()).into()),
+                };
+                (name.clone(), result)
+            })
+            .collect()
+    }
+
     /// Iterate over the entries in the PakReader
     pub fn iter(&mut self) -> PakReaderIter<R> {
         PakReaderIter {
             reader: &mut self.reader,
-            pak_version: self.pak_version,
-            compression: self.compression,
+            pak_version: self.footer.pak_version,
+            compression: self.footer.compression_methods,
+            oodle: self.oodle.as_ref(),
             iter: self.entries.iter(),
         }
     }
@@ -102,6 +621,82 @@ where
     }
 }
 
+impl PakReader<SplitReader<File>> {
+    /// Creates a new `PakReader` for a pak split across multiple files, like `pak.part0`, `pak.part1`.
+    /// The parts are read as one pak in the given order without copying them together.
+    pub fn open_split(parts: &[File]) -> Result<Self, PakError> {
+        let parts = parts
+            .iter()
+            .map(File::try_clone)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(SplitReader::new(parts)?))
+    }
+}
+
+/// Resolves an entry name against the pak's mount point, giving the path relative to the engine root
+///
+/// The leading `../` of the mount point lead up to the engine root and are dropped, so with the default
+/// mount point `../../../` the entry `Game/Content/Maps/Map.umap` resolves to itself, and with
+/// `../../../Game/Content/` the entry `Maps/Map.umap` resolves to `Game/Content/Maps/Map.umap`.
+/// Returns `None` if there are any other `..` components, e.g. in the entry name.
+pub fn resolve_entry_path(mount_point: &str, file_name: &str) -> Option<String> {
+    let mount_point = mount_point.replace('\\', "/");
+    let file_name = file_name.replace('\\', "/");
+
+    // leading `..` components of the mount point lead up to the engine root
+    let mount_components = mount_point
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .skip_while(|component| *component == "..");
+    let components = file_name
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".");
+
+    let mut path = Vec::new();
+    for component in mount_components.chain(components) {
+        if component == ".." {
+            return None;
+        }
+        path.push(component);
+    }
+
+    Some(path.join("/"))
+}
+
+/// Hash algorithm set with [`PakReader::set_entry_hasher`]
+#[derive(Debug)]
+struct CustomEntryHashes {
+    hasher: Box<dyn EntryHasher>,
+    /// Hashes created by `hasher` when the pak was written
+    hashes: BTreeMap<String, [u8; 20]>,
+}
+
+/// Checks an entry against the hash in its header, or the hash of a custom hash algorithm for its name
+fn verify_named_entry<R: Read + Seek>(
+    reader: &mut R,
+    footer: &PakFooter,
+    name: &str,
+    header: &Header,
+    entry_hasher: Option<&CustomEntryHashes>,
+) -> Result<bool, PakError> {
+    let expected = match entry_hasher {
+        Some(CustomEntryHashes { hasher, hashes }) => match hashes.get(name) {
+            Some(hash) => Some((hasher.as_ref(), hash)),
+            None => return Ok(false),
+        },
+        None => None,
+    };
+
+    verify_entry(
+        reader,
+        footer.pak_version,
+        &footer.compression_methods,
+        header.offset,
+        expected,
+    )
+}
+
 /// An iterator over the entries of a PakReader
 pub struct PakReaderIter<'a, R>
 where
@@ -110,6 +705,7 @@ where
     reader: &'a mut R,
     pak_version: PakVersion,
     compression: CompressionMethods,
+    oodle: Option<&'a Oodle>,
     iter: std::collections::btree_map::Iter<'a, String, Header>,
 }
 
@@ -128,6 +724,7 @@ where
                     self.pak_version,
                     &self.compression,
                     header.offset,
+                    self.oodle,
                 ),
             )
         })
@@ -1,13 +1,20 @@
 //! PakFile data structure for writing large pak files
 
-use std::collections::BTreeMap;
-use std::io::{Seek, Write};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 
-use crate::compression::CompressionMethods;
-use crate::entry::write_entry;
+use crate::compression::{
+    Compression, CompressionMethods, DEFAULT_COMPRESSION_LEVEL, MAX_COMPRESSION_LEVEL,
+};
+#[cfg(feature = "parallel")]
+use crate::entry::{compress_entry, entry_compression_method, write_compressed_entry};
+use crate::entry::{write_entry, write_entry_with_method, WriteOptions};
 use crate::error::PakError;
+use crate::footer::PakFooter;
+use crate::hasher::EntryHasher;
 use crate::header::Header;
-use crate::index::{random_path_hash_seed, Footer, Index};
+use crate::index::{random_path_hash_seed, Index};
+use crate::pakreader::PakReader;
 use crate::pakversion::PakVersion;
 
 /// An Unreal pak file writer which allows incrementally writing data.
@@ -28,7 +35,21 @@ where
     compression: CompressionMethods,
     /// Compression block size
     pub block_size: u32,
-    entries: BTreeMap<String, Header>,
+    /// Compression level, 0-9
+    compression_level: u32,
+    /// Hash algorithm for the hashes kept besides the SHA1 in entry headers
+    entry_hasher: Option<Box<dyn EntryHasher>>,
+    /// Hashes created by `entry_hasher`
+    entry_hashes: BTreeMap<String, [u8; 20]>,
+    entries: HashMap<String, Header>,
+    /// Entries of the pak opened with [`PakWriter::open_append`] that may still be overwritten
+    replaceable: HashSet<String>,
+    /// Path hash seed of the pak opened with [`PakWriter::open_append`]
+    path_hash_seed: Option<u64>,
+    /// Length of the pak opened with [`PakWriter::open_append`], the new pak is padded to be at least as long
+    original_len: Option<u64>,
+    /// Size of all entry data written so far
+    bytes_written: u64,
     writer: W,
 }
 
@@ -45,14 +66,63 @@ where
             mount_point: "../../../".to_owned(),
             compression: CompressionMethods::zlib(),
             block_size: 0x010000,
-            entries: BTreeMap::new(),
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
+            entry_hasher: None,
+            entry_hashes: BTreeMap::new(),
+            entries: HashMap::new(),
+            replaceable: HashSet::new(),
+            path_hash_seed: None,
+            original_len: None,
+            bytes_written: 0,
             writer,
         }
     }
 
     /// Returns the names of all entries which have been found.
     pub fn get_entry_names(&self) -> Vec<&String> {
-        self.entries.keys().collect()
+        let mut names = self.entries.keys().collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Reserves capacity for at least `num_entries` more entries.
+    /// Useful to avoid reallocating the index when writing many entries.
+    pub fn reserve(&mut self, num_entries: usize) {
+        self.entries.reserve(num_entries);
+    }
+
+    /// Returns the number of bytes of entry data written so far.
+    /// The index and footer are only written by [`PakWriter::finish_write`] and are not included.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// Returns the compression level entries are compressed with.
+    pub fn compression_level(&self) -> u32 {
+        self.compression_level
+    }
+
+    /// Sets the compression level used for entries written after this call.
+    /// Ranges from 0 (fastest) to 9 (smallest), defaults to 6.
+    pub fn set_compression_level(&mut self, level: u32) -> Result<(), PakError> {
+        if level > MAX_COMPRESSION_LEVEL {
+            return Err(PakError::configuration_invalid());
+        }
+        self.compression_level = level;
+        Ok(())
+    }
+
+    /// Sets a hash algorithm to hash entries written after this call with, besides the SHA1 in their headers.
+    ///
+    /// The hashes are not stored in the pak, get them with [`PakWriter::entry_hashes`] to keep them
+    /// somewhere else and check them with [`PakReader::set_entry_hasher`].
+    pub fn set_entry_hasher(&mut self, hasher: Box<dyn EntryHasher>) {
+        self.entry_hasher = Some(hasher);
+    }
+
+    /// Returns the hashes created by the hasher set with [`PakWriter::set_entry_hasher`], by entry name.
+    pub fn entry_hashes(&self) -> &BTreeMap<String, [u8; 20]> {
+        &self.entry_hashes
     }
 
     /// Writes the given data into the pak file on disk.
@@ -64,26 +134,179 @@ where
         data: &Vec<u8>,
         compress: bool,
     ) -> Result<(), PakError> {
-        if self.entries.contains_key(name) {
+        if !self.can_write(name) {
             return Err(PakError::double_write(name.clone()));
         }
 
-        let header = write_entry(
+        let start = self.writer.stream_position()?;
+        let entry = write_entry(
             &mut self.writer,
-            self.pak_version,
+            WriteOptions {
+                pak_version: self.pak_version,
+                compression: &self.compression,
+                block_size: self.block_size,
+                compression_level: self.compression_level,
+                hasher: self.entry_hasher.as_deref(),
+            },
             data,
             compress,
-            &self.compression,
-            self.block_size,
         )?;
-        self.entries.insert(name.clone(), header);
+        self.bytes_written += self.writer.stream_position()? - start;
+        self.insert_entry(name, entry);
+
+        Ok(())
+    }
+
+    /// Writes multiple entries like [`PakWriter::write_entry`], compressing them in parallel first.
+    ///
+    /// Entries are written in the given order and the pak is the same as when writing them one by one,
+    /// but the compressed data of all given entries is kept in memory until it is written.
+    /// Nothing is written if one of the names was already written or appears twice.
+    #[cfg(feature = "parallel")]
+    pub fn write_entries_parallel(
+        &mut self,
+        entries: &[(String, Vec<u8>, bool)],
+    ) -> Result<(), PakError> {
+        use std::collections::HashSet;
+
+        use rayon::prelude::*;
+
+        let mut names = HashSet::with_capacity(entries.len());
+        for (name, _, _) in entries {
+            if !self.can_write(name) || !names.insert(name) {
+                return Err(PakError::double_write(name.clone()));
+            }
+        }
+
+        let compressed_entries = entries
+            .par_iter()
+            .map(|(_, data, compress)| {
+                compress_entry(
+                    self.pak_version,
+                    data,
+                    entry_compression_method(data, *compress, &self.compression),
+                    self.block_size,
+                    self.compression_level,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for ((name, _, _), entry) in entries.iter().zip(compressed_entries) {
+            let start = self.writer.stream_position()?;
+            let entry = write_compressed_entry(
+                &mut self.writer,
+                WriteOptions {
+                    pak_version: self.pak_version,
+                    compression: &self.compression,
+                    block_size: self.block_size,
+                    compression_level: self.compression_level,
+                    hasher: self.entry_hasher.as_deref(),
+                },
+                entry,
+            )?;
+            self.bytes_written += self.writer.stream_position()? - start;
+            self.insert_entry(name, entry);
+        }
+
+        Ok(())
+    }
+
+    /// Writes every entry of an already loaded pak into this one, compressing them with `method` at `level`.
+    /// Like with [`PakWriter::write_entry`] entries under 32 bytes are stored uncompressed.
+    ///
+    /// The mount point of the source pak is carried over. `method` is added to the compression methods
+    /// of this file if it isn't part of them yet, use [`Compression::None`] to decompress all entries.
+    pub fn recompress_from<R: Read + Seek>(
+        &mut self,
+        reader: &mut PakReader<R>,
+        method: Compression,
+        level: u32,
+    ) -> Result<(), PakError> {
+        self.set_compression_level(level)?;
+
+        if !method.can_compress() {
+            return Err(PakError::compression_unsupported(method));
+        }
+        if method != Compression::None && !self.compression.0.contains(&method) {
+            let free_slot = self
+                .compression
+                .0
+                .iter()
+                .position(|e| *e == Compression::None)
+                .ok_or_else(|| PakError::compression_unsupported(method))?;
+            self.compression.0[free_slot] = method;
+        }
+
+        self.mount_point = reader.mount_point().to_owned();
+        self.reserve(reader.get_entry_names().len());
+
+        for (name, data) in reader.iter() {
+            let data = data?;
+            if !self.can_write(name) {
+                return Err(PakError::double_write(name.clone()));
+            }
+
+            let compression_method = match data.len() >= 32 {
+                true => method,
+                false => Compression::None,
+            };
+
+            let start = self.writer.stream_position()?;
+            let entry = write_entry_with_method(
+                &mut self.writer,
+                WriteOptions {
+                    pak_version: self.pak_version,
+                    compression: &self.compression,
+                    block_size: self.block_size,
+                    compression_level: self.compression_level,
+                    hasher: self.entry_hasher.as_deref(),
+                },
+                &data,
+                compression_method,
+            )?;
+            self.bytes_written += self.writer.stream_position()? - start;
+            self.insert_entry(name, entry);
+        }
 
         Ok(())
     }
 
+    /// Checks if an entry can be written without writing the same name twice
+    fn can_write(&self, name: &String) -> bool {
+        !self.entries.contains_key(name) || self.replaceable.contains(name)
+    }
+
+    fn insert_entry(&mut self, name: &String, (header, hash): (Header, Option<[u8; 20]>)) {
+        // an overwritten entry of the original pak can't be overwritten again
+        self.replaceable.remove(name);
+        self.entries.insert(name.clone(), header);
+        if let Some(hash) = hash {
+            self.entry_hashes.insert(name.clone(), hash);
+        }
+    }
+
     /// Finish writing the pak file by writing index and footer
-    pub fn finish_write(mut self) -> Result<(), PakError> {
-        let footer = Footer {
+    pub fn finish_write(self) -> Result<(), PakError> {
+        self.finish_write_with_progress(|_, _| {})
+    }
+
+    /// Finish writing the pak file by writing index and footer, reporting progress
+    ///
+    /// `on_progress` is called once per entry with the number of entries added to the index
+    /// and the total number of entries.
+    pub fn finish_write_with_progress(
+        self,
+        on_progress: impl FnMut(usize, usize),
+    ) -> Result<(), PakError> {
+        self.finish(on_progress).map(|_| ())
+    }
+
+    /// Write index and footer, returning the wrapped writer
+    pub(crate) fn finish(
+        mut self,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<W, PakError> {
+        let footer = PakFooter {
             pak_version: self.pak_version,
             // these are set in write_index
             index_offset: 0,
@@ -94,13 +317,116 @@ where
             encryption_key_guid: Some([0u8; 0x10]),
         };
 
+        let mut entries = self.entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
         let index = Index {
             mount_point: self.mount_point,
-            path_hash_seed: Some(random_path_hash_seed()),
-            entries: self.entries.into_iter().collect::<Vec<_>>(),
+            path_hash_seed: Some(self.path_hash_seed.unwrap_or_else(random_path_hash_seed)),
+            entries,
             footer,
         };
 
-        Index::write(&mut self.writer, index)
+        // the footer is found from the end of the file, so an appended pak that ends before
+        // the old one did is padded in front of the index to not leave parts of the old footer
+        if let Some(original_len) = self.original_len {
+            // measured at the real position, as the index stores offsets into the pak
+            let mut end = PositionCounter(self.writer.stream_position()?);
+            Index::write(&mut end, index.clone(), &mut |_, _| {})?;
+
+            let end = end.0;
+            if end < original_len {
+                io::copy(
+                    &mut io::repeat(0).take(original_len - end),
+                    &mut self.writer,
+                )?;
+            }
+        }
+
+        Index::write(&mut self.writer, index, &mut on_progress)?;
+        Ok(self.writer)
+    }
+}
+
+impl<W> PakWriter<W>
+where
+    W: Read + Write + Seek,
+{
+    /// Opens an existing pak to add entries to it.
+    ///
+    /// New entries are written over the old index, which is written again with all entries by
+    /// [`PakWriter::finish_write`]. The pak version, mount point and compression methods are kept,
+    /// the block size is taken from the existing entries with more than one compression block.
+    ///
+    /// With `overwrite_existing` writing an entry that is already in the pak replaces it,
+    /// the data of the old entry is left in the file. Otherwise it fails with
+    /// [`PakErrorKind::DoubleWrite`](crate::error::PakErrorKind::DoubleWrite).
+    pub fn open_append(mut writer: W, overwrite_existing: bool) -> Result<Self, PakError> {
+        let index = Index::read(&mut writer)?;
+        let original_len = writer.seek(SeekFrom::End(0))?;
+
+        let mut pak_writer = Self::new(writer, index.footer.pak_version);
+        pak_writer.mount_point = index.mount_point;
+        pak_writer.path_hash_seed = index.path_hash_seed;
+        pak_writer.original_len = Some(original_len);
+
+        // compression is only added in a free slot, so existing entries keep their method
+        pak_writer.compression = index.footer.compression_methods;
+        if pak_writer.compression.0[0] == Compression::None {
+            pak_writer.compression.0[0] = Compression::zlib();
+        }
+
+        // entries with a single block store their decompressed size as block size,
+        // encoded index headers don't include the blocks themselves
+        if let Some(block_size) = index
+            .entries
+            .iter()
+            .filter(|(_, header)| header.compression_method != Compression::None)
+            .filter_map(|(_, header)| {
+                header
+                    .compression_block_size
+                    .filter(|block_size| (*block_size as u64) < header.decompressed_size)
+            })
+            .max()
+        {
+            pak_writer.block_size = block_size;
+        }
+
+        if overwrite_existing {
+            pak_writer.replaceable = index.entries.iter().map(|(name, _)| name.clone()).collect();
+        }
+        pak_writer.entries = index.entries.into_iter().collect();
+
+        pak_writer
+            .writer
+            .seek(SeekFrom::Start(index.footer.index_offset))?;
+
+        Ok(pak_writer)
+    }
+}
+
+/// Writer that discards data and only keeps track of its position
+struct PositionCounter(u64);
+
+impl Write for PositionCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for PositionCounter {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.0 = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::Current(offset) => self.0.checked_add_signed(offset),
+            SeekFrom::End(_) => None,
+        }
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid seek"))?;
+        Ok(self.0)
     }
 }
@@ -0,0 +1,115 @@
+//! Reader presenting a pak split across multiple files as one stream
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// A `Read` + `Seek` implementation over the concatenation of multiple parts,
+/// used for paks that are split across multiple physical files.
+///
+/// Offsets are relative to the start of the first part, the parts are never copied together.
+#[derive(Debug)]
+pub struct SplitReader<R>
+where
+    R: Read + Seek,
+{
+    /// parts and the offset they start at in the concatenation
+    parts: Vec<(u64, R)>,
+    /// total length of all parts
+    len: u64,
+    /// position in the concatenation
+    pos: u64,
+    /// part that is positioned at `pos`, if any
+    synced_part: Option<usize>,
+}
+
+impl<R> SplitReader<R>
+where
+    R: Read + Seek,
+{
+    /// Creates a new `SplitReader` over the given parts in order.
+    pub fn new(parts: Vec<R>) -> io::Result<Self> {
+        let mut len = 0;
+        let parts = parts
+            .into_iter()
+            .map(|mut part| {
+                let start = len;
+                len += part.seek(SeekFrom::End(0))?;
+                Ok((start, part))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            parts,
+            len,
+            pos: 0,
+            synced_part: None,
+        })
+    }
+
+    /// Consumes the `SplitReader`, returning the parts.
+    pub fn into_inner(self) -> Vec<R> {
+        self.parts.into_iter().map(|(_, part)| part).collect()
+    }
+}
+
+impl<R> Read for SplitReader<R>
+where
+    R: Read + Seek,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.len {
+            return Ok(0);
+        }
+
+        // the last part starting at or before pos, skipping empty parts
+        let index = self.parts.partition_point(|(start, _)| *start <= self.pos) - 1;
+        let part_end = self
+            .parts
+            .get(index + 1)
+            .map(|(start, _)| *start)
+            .unwrap_or(self.len);
+        let (start, part) = &mut self.parts[index];
+
+        if self.synced_part != Some(index) {
+            part.seek(SeekFrom::Start(self.pos - *start))?;
+        }
+
+        let max_len = usize::try_from(part_end - self.pos)
+            .unwrap_or(usize::MAX)
+            .min(buf.len());
+        let read = part.read(&mut buf[..max_len])?;
+
+        self.pos += read as u64;
+        self.synced_part = match self.pos < part_end {
+            true => Some(index),
+            false => None,
+        };
+
+        Ok(read)
+    }
+}
+
+impl<R> Seek for SplitReader<R>
+where
+    R: Read + Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        if new_pos != self.pos {
+            self.pos = new_pos;
+            self.synced_part = None;
+        }
+
+        Ok(self.pos)
+    }
+}
@@ -0,0 +1,103 @@
+use std::io::Cursor;
+
+use unreal_pak::{error::PakErrorKind, pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+const BLOCK_SIZE: u32 = 0x1000;
+
+fn large_data() -> Vec<u8> {
+    (0..BLOCK_SIZE * 64).map(|i| (i * 7 % 251) as u8).collect()
+}
+
+fn write_pak(pak_version: PakVersion) -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, pak_version);
+    writer.mount_point = "../../../Astro/".to_owned();
+    writer.block_size = BLOCK_SIZE;
+    writer.write_entry(&"Content/A.bin".to_owned(), &large_data(), true)?;
+    writer.write_entry(&"Content/B.bin".to_owned(), &vec![2u8; 64], false)?;
+    writer.finish_write()?;
+    Ok(cursor.into_inner())
+}
+
+fn read_pak(data: Vec<u8>) -> Result<PakReader<Cursor<Vec<u8>>>, PakError> {
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+    assert_eq!(pak.check_layout().map_err(|e| e.len()), Ok(()));
+    for name in pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        assert!(pak.verify_entry(&name)?, "{name} failed verification");
+    }
+    Ok(pak)
+}
+
+#[test]
+fn append_entries() -> Result<(), PakError> {
+    for pak_version in [
+        PakVersion::FnameBasedCompressionMethod,
+        PakVersion::Fnv64BugFix,
+    ] {
+        let mut cursor = Cursor::new(write_pak(pak_version)?);
+
+        let mut writer = PakWriter::open_append(&mut cursor, false)?;
+        assert_eq!(writer.pak_version, pak_version);
+        assert_eq!(writer.mount_point, "../../../Astro/");
+        assert_eq!(writer.block_size, BLOCK_SIZE);
+        assert_eq!(writer.get_entry_names(), ["Content/A.bin", "Content/B.bin"]);
+
+        writer.write_entry(&"Content/C.bin".to_owned(), &vec![3u8; 0x2000], true)?;
+        let err = writer
+            .write_entry(&"Content/A.bin".to_owned(), &vec![1u8; 64], true)
+            .expect_err("Overwrote an existing entry");
+        assert!(matches!(err.kind, PakErrorKind::DoubleWrite(_)));
+        writer.finish_write()?;
+
+        let mut pak = read_pak(cursor.into_inner())?;
+        assert_eq!(pak.footer().pak_version, pak_version);
+        assert_eq!(pak.mount_point(), "../../../Astro/");
+        assert_eq!(
+            pak.get_entry_names(),
+            ["Content/A.bin", "Content/B.bin", "Content/C.bin"]
+        );
+        assert_eq!(pak.read_entry(&"Content/A.bin".to_owned())?, large_data());
+        assert_eq!(pak.read_entry(&"Content/B.bin".to_owned())?, [2u8; 64]);
+        assert_eq!(pak.read_entry(&"Content/C.bin".to_owned())?, [3u8; 0x2000]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn append_overwrite_entries() -> Result<(), PakError> {
+    for pak_version in [
+        PakVersion::FnameBasedCompressionMethod,
+        PakVersion::Fnv64BugFix,
+    ] {
+        let original = write_pak(pak_version)?;
+        let original_len = original.len();
+        let mut cursor = Cursor::new(original);
+
+        // replacing the large entry with a small uncompressed one shrinks the index
+        // by more than the new entry adds
+        let mut writer = PakWriter::open_append(&mut cursor, true)?;
+        writer.write_entry(&"Content/A.bin".to_owned(), &vec![1u8; 16], false)?;
+        let err = writer
+            .write_entry(&"Content/A.bin".to_owned(), &vec![1u8; 16], false)
+            .expect_err("Wrote an entry twice");
+        assert!(matches!(err.kind, PakErrorKind::DoubleWrite(_)));
+        writer.finish_write()?;
+
+        let data = cursor.into_inner();
+        assert!(data.len() >= original_len);
+
+        let mut pak = read_pak(data)?;
+        assert_eq!(pak.get_entry_names(), ["Content/A.bin", "Content/B.bin"]);
+        assert_eq!(pak.read_entry(&"Content/A.bin".to_owned())?, [1u8; 16]);
+        assert_eq!(pak.read_entry(&"Content/B.bin".to_owned())?, [2u8; 64]);
+    }
+
+    Ok(())
+}
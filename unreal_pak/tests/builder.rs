@@ -0,0 +1,63 @@
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use unreal_pak::{pakversion::PakVersion, PakBuilder, PakError, PakReader};
+
+fn test_dir(name: &str) -> Result<PathBuf, PakError> {
+    let dir = std::env::temp_dir().join(format!(
+        "unreal_pak_builder_{}_{}",
+        name,
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+#[test]
+fn builder_replaces_target() -> Result<(), PakError> {
+    let dir = test_dir("replace")?;
+    let target = dir.join("900-ModIntegrator_P.pak");
+    fs::write(&target, b"old pak")?;
+
+    let data = vec![7u8; 1000];
+    let mut builder = PakBuilder::create(&target, PakVersion::FnameBasedCompressionMethod)?;
+    let temp_path = builder.temp_path().to_owned();
+    builder.write_entry(&"Game/Test.bin".to_string(), &data, true)?;
+
+    // nothing is replaced until the pak has been finished
+    assert_eq!(fs::read(&target)?, b"old pak");
+    assert!(temp_path.exists());
+
+    builder.finish_write()?;
+    assert!(!temp_path.exists());
+
+    let mut pak = PakReader::new(BufReader::new(File::open(&target)?));
+    pak.load_index()?;
+    assert_eq!(pak.read_entry(&"Game/Test.bin".to_string())?, data);
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn builder_failure_keeps_target() -> Result<(), PakError> {
+    let dir = test_dir("failure")?;
+    let target = dir.join("900-ModIntegrator_P.pak");
+    fs::write(&target, b"old pak")?;
+
+    let mut builder = PakBuilder::create(&target, PakVersion::FnameBasedCompressionMethod)?;
+    let temp_path = builder.temp_path().to_owned();
+    builder.write_entry(&"Game/Test.bin".to_string(), &vec![7u8; 1000], false)?;
+
+    // writing the same entry twice fails partway through the build
+    let result = builder.write_entry(&"Game/Test.bin".to_string(), &vec![8u8; 1000], false);
+    assert!(result.is_err());
+    drop(builder);
+
+    assert_eq!(fs::read(&target)?, b"old pak");
+    assert!(!temp_path.exists());
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::io::{Cursor, Read};
+
+use byteorder::{ReadBytesExt, LE};
+use sha1::{Digest, Sha1};
+use unreal_pak::{
+    compression::Compression, pakversion::PakVersion, PakError, PakReader, PakWriter,
+};
+
+fn entries() -> Vec<(String, Vec<u8>, bool)> {
+    vec![
+        ("Game/Content/A.bin".to_string(), vec![1u8; 0x30000], true),
+        ("Game/B.bin".to_string(), (0..=255u8).collect(), false),
+        ("Root.bin".to_string(), vec![3u8; 64], true),
+    ]
+}
+
+fn write_pak(pak_version: PakVersion) -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, pak_version);
+    for (name, data, compress) in entries() {
+        writer.write_entry(&name, &data, compress)?;
+    }
+    writer.finish_write()?;
+    Ok(cursor.into_inner())
+}
+
+fn read_fstring(reader: &mut Cursor<&[u8]>) -> String {
+    let len = reader.read_i32::<LE>().unwrap() as usize;
+    let mut data = vec![0u8; len];
+    reader.read_exact(&mut data).unwrap();
+    String::from_utf8(data[..len - 1].to_vec()).unwrap()
+}
+
+#[test]
+fn compact_index_roundtrip() -> Result<(), PakError> {
+    for pak_version in [PakVersion::PathHashIndex, PakVersion::Fnv64BugFix] {
+        let mut pak = PakReader::new(Cursor::new(write_pak(pak_version)?));
+        pak.load_index()?;
+        assert_eq!(pak.footer().pak_version, pak_version);
+        assert_eq!(
+            pak.get_entry_names(),
+            ["Game/B.bin", "Game/Content/A.bin", "Root.bin"]
+        );
+
+        for (name, data, compress) in entries() {
+            assert_eq!(pak.read_entry(&name)?, data);
+            assert_eq!(
+                matches!(pak.entry_compression(&name)?, Compression::None),
+                !compress
+            );
+            assert!(pak.verify_entry(&name)?);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn compact_index_layout() -> Result<(), PakError> {
+    let data = write_pak(PakVersion::Fnv64BugFix)?;
+    let mut pak = PakReader::new(Cursor::new(data.clone()));
+    pak.load_index()?;
+    let index_offset = pak.footer().index_offset as usize;
+    let index_size = pak.footer().index_size as usize;
+
+    let mut index = Cursor::new(&data[index_offset..index_offset + index_size]);
+    assert_eq!(read_fstring(&mut index), "../../../");
+    assert_eq!(index.read_u32::<LE>()?, 3);
+    let _path_hash_seed = index.read_u64::<LE>()?;
+
+    // no path hash index, only the full directory index
+    assert_eq!(index.read_u32::<LE>()?, 0);
+    assert_eq!(index.read_u32::<LE>()?, 1);
+    let directory_index_offset = index.read_u64::<LE>()? as usize;
+    let directory_index_size = index.read_u64::<LE>()? as usize;
+    let mut directory_index_hash = [0u8; 20];
+    index.read_exact(&mut directory_index_hash)?;
+
+    // the directory index follows the primary index and has its own hash
+    assert_eq!(directory_index_offset, index_offset + index_size);
+    let directory_index_data =
+        &data[directory_index_offset..directory_index_offset + directory_index_size];
+    assert_eq!(
+        directory_index_hash,
+        Sha1::digest(directory_index_data).as_slice()
+    );
+
+    let encoded_size = index.read_u32::<LE>()? as usize;
+    let encoded_start = index.position() as usize;
+    index.set_position((encoded_start + encoded_size) as u64);
+    // everything could be encoded
+    assert_eq!(index.read_u32::<LE>()?, 0);
+    assert_eq!(index.position() as usize, index_size);
+
+    let mut directory_index = Cursor::new(directory_index_data);
+    let mut files = Vec::new();
+    let mut directories = Vec::new();
+    for _ in 0..directory_index.read_u32::<LE>()? {
+        let directory = read_fstring(&mut directory_index);
+        for _ in 0..directory_index.read_u32::<LE>()? {
+            let file_name = read_fstring(&mut directory_index);
+            let encoded_offset = directory_index.read_i32::<LE>()?;
+            files.push((directory.clone(), file_name, encoded_offset));
+        }
+        directories.push(directory);
+    }
+    assert_eq!(directories, ["/", "Game/", "Game/Content/"]);
+
+    for (directory, file_name, encoded_offset) in files {
+        let name = format!("{}{file_name}", directory.trim_start_matches('/'));
+        let (_, entry_data, compress) = entries()
+            .into_iter()
+            .find(|(e, _, _)| *e == name)
+            .expect("Unknown file in directory index");
+
+        let mut encoded = Cursor::new(&data[index_offset + encoded_start..]);
+        encoded.set_position(encoded_offset as u64);
+        let flags = encoded.read_u32::<LE>()?;
+
+        // offset and sizes fit into 32 bits, nothing is encrypted
+        assert_eq!(flags >> 29, 0b111);
+        assert_eq!(flags & (1 << 22), 0);
+
+        let compression_method = (flags >> 23) & 0x3f;
+        let block_count = (flags >> 6) & 0xffff;
+        // block sizes that aren't a multiple of 2 KiB are stored after the flags
+        let block_size = match flags & 0x3f {
+            0x3f => encoded.read_u32::<LE>()? as usize,
+            packed => (packed as usize) << 11,
+        };
+
+        let _offset = encoded.read_u32::<LE>()?;
+        assert_eq!(encoded.read_u32::<LE>()? as usize, entry_data.len());
+        if compress {
+            assert_eq!(compression_method, 1);
+            assert_eq!(block_count as usize, entry_data.len().div_ceil(0x010000));
+            // single block entries use the entry size as block size
+            assert_eq!(block_size, entry_data.len().min(0x010000));
+            let _compressed_size = encoded.read_u32::<LE>()?;
+        } else {
+            assert_eq!(compression_method, 0);
+            assert_eq!(block_count, 0);
+            assert_eq!(block_size, 0);
+        }
+    }
+
+    Ok(())
+}
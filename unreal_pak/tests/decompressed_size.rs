@@ -0,0 +1,68 @@
+use std::io::{Cursor, Write};
+
+use flate2::write::ZlibEncoder;
+use unreal_pak::{error::PakErrorKind, pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+const ENTRY_NAME: &str = "Game/Data.bin";
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn read_entry_decompressed_size_mismatch() -> Result<(), PakError> {
+    let data = (0..0x100u32)
+        .map(|i| (i * 7 % 251) as u8)
+        .collect::<Vec<_>>();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.write_entry(&ENTRY_NAME.to_string(), &data, true)?;
+    writer.finish_write()?;
+
+    let mut pak = PakReader::new(cursor);
+    pak.load_index()?;
+    assert_eq!(pak.read_entry(&ENTRY_NAME.to_string())?, data);
+
+    let blocks = pak.entry_blocks(ENTRY_NAME)?;
+    assert_eq!(blocks.len(), 1);
+    let block = blocks[0];
+
+    // replace the block with a valid stream of only half the data,
+    // padding after the end of the stream is never read by the decompressor
+    let replacement = zlib(&data[..data.len() / 2]);
+    assert!(replacement.len() as u64 <= block.compressed_size);
+
+    let mut pak_data = pak.into_inner().into_inner();
+    let block_data =
+        &mut pak_data[block.offset as usize..(block.offset + block.compressed_size) as usize];
+    block_data.fill(0);
+    block_data[..replacement.len()].copy_from_slice(&replacement);
+
+    let mut pak = PakReader::new(Cursor::new(pak_data));
+    pak.load_index()?;
+
+    let err = pak
+        .read_entry(&ENTRY_NAME.to_string())
+        .expect_err("Read an entry with a corrupted block");
+    assert!(matches!(
+        err.kind,
+        PakErrorKind::DecompressedSizeMismatch {
+            expected: 0x100,
+            actual: 0x80
+        }
+    ));
+
+    let mut out = Vec::new();
+    let err = pak
+        .read_entry_to(ENTRY_NAME, &mut out)
+        .expect_err("Streamed an entry with a corrupted block");
+    assert!(matches!(
+        err.kind,
+        PakErrorKind::DecompressedSizeMismatch { .. }
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::io::Cursor;
+
+use sha1::{Digest, Sha1};
+use unreal_pak::{
+    error::PakErrorKind, pakreader::DuplicateNamePolicy, pakversion::PakVersion, PakError,
+    PakReader, PakWriter,
+};
+
+const PAK_MAGIC: [u8; 4] = [0xE1, 0x12, 0x6F, 0x5A];
+
+/// Write a pak where the index contains `Game/a.bin` twice, first with zeros and then with ones
+fn write_duplicate_pak() -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.write_entry(&"Game/a.bin".to_owned(), &vec![0u8; 16], false)?;
+    writer.write_entry(&"Game/b.bin".to_owned(), &vec![1u8; 16], false)?;
+    writer.finish_write()?;
+    let mut data = cursor.into_inner();
+
+    let mut pak = PakReader::new(Cursor::new(data.clone()));
+    pak.load_index()?;
+    let index_offset = pak.footer().index_offset as usize;
+    let index_size = pak.footer().index_size as usize;
+
+    // rename the second entry in the index, names have the same length so nothing moves
+    let name_offset = data[index_offset..]
+        .windows(10)
+        .position(|e| e == b"Game/b.bin")
+        .expect("Failed to find entry name in index");
+    data[index_offset + name_offset + 5] = b'a';
+
+    // the index hash follows the magic, version, index offset and index size
+    let hash = Sha1::digest(&data[index_offset..index_offset + index_size]);
+    let magic_offset = data
+        .windows(4)
+        .rposition(|e| e == PAK_MAGIC)
+        .expect("Failed to find footer magic");
+    data[magic_offset + 24..magic_offset + 44].copy_from_slice(&hash);
+
+    Ok(data)
+}
+
+fn load(policy: Option<DuplicateNamePolicy>) -> Result<PakReader<Cursor<Vec<u8>>>, PakError> {
+    let mut pak = PakReader::new(Cursor::new(write_duplicate_pak()?));
+    if let Some(policy) = policy {
+        pak.set_duplicate_name_policy(policy);
+    }
+    pak.load_index()?;
+    Ok(pak)
+}
+
+#[test]
+fn duplicate_names_keep_first() -> Result<(), PakError> {
+    let mut pak = load(Some(DuplicateNamePolicy::KeepFirst))?;
+    assert_eq!(pak.get_entry_names(), ["Game/a.bin"]);
+    assert_eq!(pak.read_entry(&"Game/a.bin".to_owned())?, vec![0u8; 16]);
+    Ok(())
+}
+
+#[test]
+fn duplicate_names_keep_last() -> Result<(), PakError> {
+    let mut pak = load(Some(DuplicateNamePolicy::KeepLast))?;
+    assert_eq!(pak.get_entry_names(), ["Game/a.bin"]);
+    assert_eq!(pak.read_entry(&"Game/a.bin".to_owned())?, vec![1u8; 16]);
+    Ok(())
+}
+
+#[test]
+fn duplicate_names_error() {
+    for policy in [None, Some(DuplicateNamePolicy::Error)] {
+        let err = load(policy).expect_err("Duplicate entry name was accepted");
+        assert!(matches!(err.kind, PakErrorKind::DuplicateEntry(ref name) if name == "Game/a.bin"));
+    }
+}
@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+
+use unreal_pak::{pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+#[test]
+fn entry_blocks_multi_block() -> Result<(), PakError> {
+    let data = (0..10_000u32)
+        .map(|i| (i * 7 % 251) as u8)
+        .collect::<Vec<_>>();
+    let small = vec![1u8; 16];
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.block_size = 0x1000;
+    writer.write_entry(&"Game/Large.bin".to_string(), &data, true)?;
+    writer.write_entry(&"Game/Small.bin".to_string(), &small, true)?;
+    writer.finish_write()?;
+
+    let mut pak = PakReader::new(cursor);
+    pak.load_index()?;
+
+    let blocks = pak.entry_blocks("Game/Large.bin")?;
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(
+        blocks
+            .iter()
+            .map(|e| e.decompressed_size)
+            .collect::<Vec<_>>(),
+        [0x1000, 0x1000, 10_000 - 0x2000]
+    );
+    assert_eq!(
+        blocks.iter().map(|e| e.decompressed_size).sum::<u64>(),
+        data.len() as u64
+    );
+    for pair in blocks.windows(2) {
+        assert_eq!(pair[0].offset + pair[0].compressed_size, pair[1].offset);
+    }
+
+    // entries under 32 bytes are never compressed
+    let small_blocks = pak.entry_blocks("Game/Small.bin")?;
+    assert_eq!(small_blocks.len(), 1);
+    assert_eq!(small_blocks[0].compressed_size, 16);
+    assert_eq!(small_blocks[0].decompressed_size, 16);
+
+    let block = small_blocks[0];
+    let pak_data = pak.into_inner().into_inner();
+    assert_eq!(
+        &pak_data[block.offset as usize..(block.offset + block.compressed_size) as usize],
+        small.as_slice()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn entry_blocks_fixture() -> Result<(), PakError> {
+    let mut pak = PakReader::new(BufReader::new(File::open(test_pak!(
+        "000-TestPak-cus-C_P.pak"
+    ))?));
+    pak.load_index()?;
+
+    let names = pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in names {
+        let blocks = pak.entry_blocks(&name)?;
+        assert!(!blocks.is_empty());
+
+        let data = pak.read_entry(&name)?;
+        assert_eq!(
+            blocks.iter().map(|e| e.decompressed_size).sum::<u64>(),
+            data.len() as u64
+        );
+    }
+
+    assert!(pak.entry_blocks("Missing/Entry.uasset").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::io::Cursor;
+
+use sha1::{Digest, Sha1};
+use unreal_pak::{error::PakErrorKind, pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+const PAK_MAGIC: [u8; 4] = [0xE1, 0x12, 0x6F, 0x5A];
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+/// Write a pak where every entry with an odd index is marked as encrypted in the index
+fn write_mixed_pak() -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    for i in 0..4u8 {
+        writer.write_entry(&format!("Game/{i}.bin"), &vec![i; 16], false)?;
+    }
+    writer.finish_write()?;
+    let mut data = cursor.into_inner();
+
+    let mut pak = PakReader::new(Cursor::new(data.clone()));
+    pak.load_index()?;
+    let index_offset = pak.footer().index_offset as usize;
+    let index_size = pak.footer().index_size as usize;
+
+    // uncompressed legacy headers have the flags after the sizes, compression method and hash
+    for i in [1, 3] {
+        let name = format!("Game/{i}.bin\0");
+        let name_offset = data[index_offset..]
+            .windows(name.len())
+            .position(|e| e == name.as_bytes())
+            .expect("Failed to find entry name in index");
+        data[index_offset + name_offset + name.len() + 8 + 16 + 4 + 20] = 0x01;
+    }
+
+    // the index hash follows the magic, version, index offset and index size
+    let hash = Sha1::digest(&data[index_offset..index_offset + index_size]);
+    let magic_offset = data
+        .windows(4)
+        .rposition(|e| e == PAK_MAGIC)
+        .expect("Failed to find footer magic");
+    data[magic_offset + 24..magic_offset + 44].copy_from_slice(&hash);
+
+    Ok(data)
+}
+
+#[test]
+fn entry_flags() -> Result<(), PakError> {
+    let mut pak = PakReader::new(Cursor::new(write_mixed_pak()?));
+    pak.load_index()?;
+
+    let names = pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    let encrypted = names
+        .iter()
+        .filter(|name| pak.is_entry_encrypted(name).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(encrypted, ["Game/1.bin", "Game/3.bin"]);
+
+    assert_eq!(pak.entry_flags("Game/0.bin")?, Some(0x00));
+    assert_eq!(pak.entry_flags("Game/1.bin")?, Some(0x01));
+
+    let err = pak
+        .entry_flags("Game/4.bin")
+        .expect_err("Missing entry has flags");
+    assert!(matches!(err.kind, PakErrorKind::EntryNotFound(_)));
+
+    Ok(())
+}
+
+#[test]
+fn entry_flags_unencrypted_pak() -> Result<(), PakError> {
+    let mut pak = PakReader::new(Cursor::new(include_bytes!(test_pak!(
+        "000-TestPak-cus-NoC_P.pak"
+    ))));
+    pak.load_index()?;
+
+    for name in pak.get_entry_names() {
+        assert!(!pak.is_entry_encrypted(name)?);
+        assert_eq!(pak.entry_flags(name)?, Some(0x00));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::fs::File;
+use std::io::BufReader;
+
+use unreal_pak::{error::PakErrorKind, PakError, PakReader};
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+#[test]
+fn read_entries_by_index() -> Result<(), PakError> {
+    for path in [
+        test_pak!("000-TestPak-cus-C_P.pak"),
+        test_pak!("000-TestPak-cus-NoC_P.pak"),
+    ] {
+        let mut pak = PakReader::new(BufReader::new(File::open(path)?));
+        pak.load_index()?;
+
+        let names = pak
+            .get_entry_names()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert!(!names.is_empty());
+
+        for (i, name) in names.iter().enumerate() {
+            assert_eq!(pak.entry_name_at(i), Some(name.as_str()));
+            assert_eq!(pak.read_entry_by_index(i)?, pak.read_entry(name)?);
+        }
+
+        assert_eq!(pak.entry_name_at(names.len()), None);
+        let err = pak.read_entry_by_index(names.len()).unwrap_err();
+        assert!(matches!(err.kind, PakErrorKind::EntryIndexOutOfRange(i) if i == names.len()));
+    }
+
+    Ok(())
+}
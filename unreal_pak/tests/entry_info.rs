@@ -0,0 +1,103 @@
+use std::io::Cursor;
+
+use unreal_pak::{
+    error::PakErrorKind, pakversion::PakVersion, Compression, PakError, PakReader, PakWriter,
+};
+
+fn entries() -> Vec<(String, Vec<u8>, bool)> {
+    vec![
+        (
+            "Game/Large.bin".to_string(),
+            (0..10_000u32).map(|i| (i * 7 % 251) as u8).collect(),
+            true,
+        ),
+        ("Game/Raw.bin".to_string(), vec![2u8; 0x3000], false),
+        ("Game/Small.bin".to_string(), vec![3u8; 0x800], true),
+    ]
+}
+
+#[test]
+fn entry_info_from_index() -> Result<(), PakError> {
+    for pak_version in [
+        PakVersion::FnameBasedCompressionMethod,
+        PakVersion::Fnv64BugFix,
+    ] {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = PakWriter::new(&mut cursor, pak_version);
+        writer.block_size = 0x1000;
+        for (name, data, compress) in entries() {
+            writer.write_entry(&name, &data, compress)?;
+        }
+        writer.finish_write()?;
+
+        let mut pak = PakReader::new(cursor);
+        pak.load_index()?;
+
+        let large = pak.entry_info("Game/Large.bin")?;
+        assert_eq!(large.decompressed_size, 10_000);
+        assert_eq!(large.compression_method, Compression::zlib());
+        assert_eq!(large.block_count, 3);
+
+        let raw = pak.entry_info("Game/Raw.bin")?;
+        assert_eq!(raw.compressed_size, 0x3000);
+        assert_eq!(raw.decompressed_size, 0x3000);
+        assert_eq!(raw.compression_method, Compression::None);
+        assert_eq!(raw.block_count, 0);
+
+        // the block count of encoded headers is computed, check it against the real blocks
+        for (name, _, compress) in entries() {
+            let info = pak.entry_info(&name)?;
+            let blocks = pak.entry_blocks(&name)?;
+            if compress {
+                assert_eq!(info.block_count, blocks.len() as u64, "{name}");
+            }
+            assert_eq!(
+                info.compressed_size,
+                blocks.iter().map(|e| e.compressed_size).sum::<u64>(),
+                "{name}"
+            );
+        }
+
+        let stats = pak.stats();
+        assert_eq!(stats.total.entry_count, 3);
+        assert_eq!(stats.total.decompressed_size, 10_000 + 0x3000 + 0x800);
+        assert_eq!(
+            stats.total.compressed_size,
+            large.compressed_size + 0x3000 + pak.entry_info("Game/Small.bin")?.compressed_size
+        );
+        assert!(stats.total.compression_ratio() < 1.0);
+
+        assert_eq!(
+            stats
+                .by_compression
+                .iter()
+                .map(|(method, stats)| (*method, stats.entry_count))
+                .collect::<Vec<_>>(),
+            [(Compression::zlib(), 2), (Compression::None, 1)]
+        );
+        assert_eq!(stats.by_compression[1].1.compression_ratio(), 1.0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn entry_info_not_found() -> Result<(), PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod).finish_write()?;
+
+    let mut pak = PakReader::new(cursor);
+    pak.load_index()?;
+
+    let err = pak
+        .entry_info("Game/Missing.bin")
+        .expect_err("Found a missing entry");
+    assert!(matches!(err.kind, PakErrorKind::EntryNotFound(_)));
+
+    let stats = pak.stats();
+    assert_eq!(stats.total.entry_count, 0);
+    assert!(stats.by_compression.is_empty());
+    assert_eq!(stats.total.compression_ratio(), 1.0);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+
+use unreal_pak::{error::PakErrorKind, pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+const BLOCK_SIZE: usize = 0x1000;
+
+fn write_pak(data: &[u8]) -> Result<PakReader<Cursor<Vec<u8>>>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.block_size = BLOCK_SIZE as u32;
+    writer.write_entry(&"Game/Compressed.bin".to_string(), &data.to_vec(), true)?;
+    writer.write_entry(&"Game/Raw.bin".to_string(), &data.to_vec(), false)?;
+    writer.finish_write()?;
+
+    let mut pak = PakReader::new(cursor);
+    pak.load_index()?;
+    Ok(pak)
+}
+
+#[test]
+fn entry_reader_streams_blocks() -> Result<(), PakError> {
+    let data = (0..10_000u32)
+        .map(|i| (i * 7 % 251) as u8)
+        .collect::<Vec<_>>();
+    let mut pak = write_pak(&data)?;
+    assert_eq!(pak.entry_blocks("Game/Compressed.bin")?.len(), 3);
+
+    for name in ["Game/Compressed.bin", "Game/Raw.bin"] {
+        let mut entry_reader = pak.entry_reader(name)?;
+        assert_eq!(entry_reader.len(), data.len() as u64);
+
+        // small buffer, so writes never line up with the block boundaries
+        let mut out = BufWriter::with_capacity(100, Vec::new());
+        let mut buf = [0u8; 777];
+        loop {
+            match entry_reader.read(&mut buf)? {
+                0 => break,
+                read => out.write_all(&buf[..read])?,
+            }
+        }
+        assert_eq!(out.into_inner().unwrap(), data, "{name}");
+
+        // reading across a block boundary after seeking back
+        let start = BLOCK_SIZE - 10;
+        entry_reader.seek(SeekFrom::Start(start as u64))?;
+        let mut across = vec![0u8; 20];
+        entry_reader.read_exact(&mut across)?;
+        assert_eq!(across, data[start..start + 20]);
+
+        entry_reader.seek(SeekFrom::End(-5))?;
+        let mut end = Vec::new();
+        entry_reader.read_to_end(&mut end)?;
+        assert_eq!(end, data[data.len() - 5..]);
+
+        // seeking back into the first block after the last one was read
+        entry_reader.rewind()?;
+        let mut first = vec![0u8; 16];
+        entry_reader.read_exact(&mut first)?;
+        assert_eq!(first, data[..16]);
+
+        // reading past the end gives no more data
+        entry_reader.seek(SeekFrom::Start(data.len() as u64 + 10))?;
+        assert_eq!(entry_reader.read(&mut buf)?, 0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn entry_reader_not_found() -> Result<(), PakError> {
+    let mut pak = write_pak(&[1u8; 64])?;
+    let err = pak
+        .entry_reader("Game/Missing.bin")
+        .expect_err("Found a missing entry");
+    assert!(matches!(err.kind, PakErrorKind::EntryNotFound(_)));
+
+    Ok(())
+}
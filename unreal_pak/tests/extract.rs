@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufReader, Cursor};
+use std::path::{Path, PathBuf};
+
+use unreal_pak::{
+    error::PakErrorKind, pakreader::resolve_entry_path, pakversion::PakVersion, PakError,
+    PakReader, PakWriter,
+};
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("unreal_pak_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Collect all files below `dir` keyed by their `/` separated path relative to `root`
+fn read_tree(root: &Path, dir: &Path, files: &mut BTreeMap<String, Vec<u8>>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            read_tree(root, &path, files);
+        } else {
+            let relative = path
+                .strip_prefix(root)
+                .unwrap()
+                .components()
+                .map(|component| component.as_os_str().to_str().unwrap())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.insert(relative, fs::read(&path).unwrap());
+        }
+    }
+}
+
+#[test]
+fn extract_fixture_paks() -> Result<(), PakError> {
+    let dir = test_dir("extract_fixture_paks");
+
+    for (i, path) in [
+        test_pak!("000-TestPak-cus-C_P.pak"),
+        test_pak!("000-TestPak-cus-NoC_P.pak"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut pak = PakReader::new(BufReader::new(File::open(path)?));
+        pak.load_index()?;
+
+        for mount_relative in [true, false] {
+            let mut expected = BTreeMap::new();
+            for name in pak
+                .get_entry_names()
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>()
+            {
+                let data = pak.read_entry(&name)?;
+                let path = match mount_relative {
+                    true => resolve_entry_path(pak.mount_point(), &name).unwrap(),
+                    false => name,
+                };
+                expected.insert(path, data);
+            }
+            assert!(!expected.is_empty());
+
+            let out_dir = dir.join(format!("{i}_{mount_relative}"));
+            pak.extract_all(&out_dir, mount_relative)?;
+
+            let mut files = BTreeMap::new();
+            read_tree(&out_dir, &out_dir, &mut files);
+            assert_eq!(files, expected);
+        }
+    }
+
+    // compressed and uncompressed fixtures hold the same files
+    let mut compressed = BTreeMap::new();
+    read_tree(&dir.join("0_true"), &dir.join("0_true"), &mut compressed);
+    let mut uncompressed = BTreeMap::new();
+    read_tree(&dir.join("1_true"), &dir.join("1_true"), &mut uncompressed);
+    assert_eq!(compressed, uncompressed);
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn extract_rejects_escaping_names() -> Result<(), PakError> {
+    let dir = test_dir("extract_rejects_escaping_names");
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.mount_point = "../../../".to_owned();
+    writer.write_entry(&"Game/../../evil.bin".to_owned(), &vec![1, 2, 3], false)?;
+    writer.finish_write()?;
+
+    let mut pak = PakReader::new(cursor);
+    pak.load_index()?;
+
+    let out_dir = dir.join("out");
+    for mount_relative in [true, false] {
+        let err = pak
+            .extract_all(&out_dir, mount_relative)
+            .expect_err("Entry outside of the output directory was extracted");
+        assert!(matches!(err.kind, PakErrorKind::Path(_)));
+    }
+    assert!(!dir.join("evil.bin").exists());
+    assert!(!out_dir.exists());
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn resolve_entry_paths() {
+    let resolve = |mount_point, name| resolve_entry_path(mount_point, name);
+
+    assert_eq!(
+        resolve("../../../", "Game/Content/Maps/Map.umap").as_deref(),
+        Some("Game/Content/Maps/Map.umap")
+    );
+    assert_eq!(
+        resolve("../../../Game/Content/", "Maps/Map.umap").as_deref(),
+        Some("Game/Content/Maps/Map.umap")
+    );
+    assert_eq!(
+        resolve("..\\..\\..\\Game\\", "Maps\\Map.umap").as_deref(),
+        Some("Game/Maps/Map.umap")
+    );
+
+    // only the mount point leads up to the engine root
+    assert_eq!(resolve("../../../", "../Map.umap"), None);
+    assert_eq!(resolve("", "../Map.umap"), None);
+    assert_eq!(resolve("../../../Game/../", "Map.umap"), None);
+}
+
+#[test]
+fn read_entry_to_matches_read_entry() -> Result<(), PakError> {
+    let mut pak = PakReader::new(BufReader::new(File::open(test_pak!(
+        "000-TestPak-cus-C_P.pak"
+    ))?));
+    pak.load_index()?;
+
+    for name in pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        let mut streamed = Vec::new();
+        let written = pak.read_entry_to(&name, &mut streamed)?;
+        assert_eq!(written, streamed.len() as u64);
+        assert_eq!(streamed, pak.read_entry(&name)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn extract_matching_entries() -> Result<(), PakError> {
+    let dir = test_dir("extract_matching_entries");
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    for name in [
+        "Game/Content/Maps/Level.umap",
+        "Game/Content/Maps/Sub/Level2.umap",
+        "Game/Content/Maps/Sub/Level2.uexp",
+        "Game/Content/MapsOld/Level.umap",
+        "Game/Content/UI/Menu.uasset",
+        "Game/Config/Game.ini",
+    ] {
+        writer.write_entry(&name.to_owned(), &name.as_bytes().to_vec(), true)?;
+    }
+    writer.finish_write()?;
+
+    let mut pak = PakReader::new(cursor);
+    pak.load_index()?;
+
+    let matching = |pak: &PakReader<_>, pattern| {
+        pak.entry_names_matching(pattern)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    // directories match everything in them, but not siblings sharing a prefix
+    let maps = [
+        "Game/Content/Maps/Level.umap",
+        "Game/Content/Maps/Sub/Level2.uexp",
+        "Game/Content/Maps/Sub/Level2.umap",
+    ];
+    assert_eq!(matching(&pak, "Game/Content/Maps"), maps);
+    assert_eq!(matching(&pak, "Game/Content/Maps/"), maps);
+    assert_eq!(matching(&pak, "Game\\*\\Maps\\"), maps);
+
+    assert_eq!(
+        matching(&pak, "Game/Content/*/*.umap"),
+        [
+            "Game/Content/Maps/Level.umap",
+            "Game/Content/MapsOld/Level.umap"
+        ]
+    );
+    assert_eq!(
+        matching(&pak, "**/Level?.*"),
+        [
+            "Game/Content/Maps/Sub/Level2.uexp",
+            "Game/Content/Maps/Sub/Level2.umap"
+        ]
+    );
+    assert_eq!(matching(&pak, "**/*.ini"), ["Game/Config/Game.ini"]);
+    assert_eq!(
+        matching(&pak, "Game/**/Menu.uasset"),
+        ["Game/Content/UI/Menu.uasset"]
+    );
+    assert!(matching(&pak, "Game/Content/Map").is_empty());
+    assert!(matching(&pak, "Content/**").is_empty());
+
+    // only the given entries are written
+    let names = matching(&pak, "**/*.umap");
+    let out_dir = dir.join("out");
+    pak.extract_entries(&names, &out_dir, false)?;
+
+    let mut files = BTreeMap::new();
+    read_tree(&out_dir, &out_dir, &mut files);
+    assert_eq!(
+        files,
+        names
+            .iter()
+            .map(|name| (name.clone(), name.as_bytes().to_vec()))
+            .collect::<BTreeMap<_, _>>()
+    );
+
+    let err = pak
+        .extract_entries(&["Game/Missing.bin"], &out_dir, false)
+        .expect_err("Extracted a missing entry");
+    assert!(matches!(err.kind, PakErrorKind::EntryNotFound(_)));
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
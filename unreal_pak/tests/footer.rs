@@ -0,0 +1,125 @@
+use std::fs::{self, File};
+use std::io::{BufReader, Cursor};
+
+use unreal_pak::{
+    detect_version, error::PakErrorKind, pakversion::PakVersion, parse_footer, Compression,
+    PakError, PakReader, PakWriter,
+};
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+#[test]
+fn parse_footer_compressed() -> Result<(), PakError> {
+    let mut reader = BufReader::new(File::open(test_pak!("000-TestPak-cus-C_P.pak"))?);
+    let footer = parse_footer(&mut reader)?;
+
+    assert_eq!(footer.pak_version, PakVersion::FnameBasedCompressionMethod);
+    assert_eq!(footer.index_offset, 2641);
+    assert_eq!(footer.index_size, 540);
+    assert_eq!(
+        footer.index_hash,
+        [
+            0x81, 0x63, 0xaf, 0x0a, 0xac, 0x71, 0x65, 0xe7, 0xda, 0x86, 0xe2, 0x33, 0xc5, 0xce,
+            0x03, 0xc7, 0x81, 0x7b, 0x0c, 0xbc
+        ]
+    );
+    assert_eq!(footer.index_encrypted, Some(false));
+    assert_eq!(
+        footer.compression_methods(),
+        [
+            Compression::zlib(),
+            Compression::None,
+            Compression::None,
+            Compression::None,
+            Compression::None
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reader_footer() -> Result<(), PakError> {
+    let file = File::open(test_pak!("000-TestPak-cus-NoC_P.pak"))?;
+    let mut pak = PakReader::new(BufReader::new(file));
+    assert_eq!(pak.footer().pak_version, PakVersion::Invalid);
+
+    pak.load_index()?;
+
+    let footer = pak.footer();
+    assert_eq!(footer.pak_version, PakVersion::FnameBasedCompressionMethod);
+    assert_eq!(footer.index_offset, 200662);
+    assert_eq!(footer.index_size, 432);
+    assert!(footer
+        .compression_methods()
+        .iter()
+        .all(|e| *e == Compression::None));
+
+    Ok(())
+}
+
+#[test]
+fn detect_versions() -> Result<(), PakError> {
+    let mut reader = BufReader::new(File::open(test_pak!("000-TestPak-cus-C_P.pak"))?);
+    assert_eq!(
+        detect_version(&mut reader)?,
+        PakVersion::FnameBasedCompressionMethod
+    );
+
+    for pak_version in [
+        PakVersion::FnameBasedCompressionMethod,
+        PakVersion::FrozenIndex,
+        PakVersion::PathHashIndex,
+        PakVersion::Fnv64BugFix,
+    ] {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = PakWriter::new(&mut cursor, pak_version);
+        writer.write_entry(&"Game/A.bin".to_string(), &vec![1u8; 64], true)?;
+        writer.finish_write()?;
+
+        assert_eq!(detect_version(&mut cursor)?, pak_version);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn truncated_footer() -> Result<(), PakError> {
+    let data = fs::read(test_pak!("000-TestPak-cus-C_P.pak"))?;
+
+    for len in [data.len() - 1, data.len() - 0x30, 0x20, 0] {
+        let err = detect_version(&mut Cursor::new(&data[..len]))
+            .expect_err("Detected a version in a truncated pak");
+        assert!(matches!(err.kind, PakErrorKind::FooterInvalid), "{len}");
+
+        let mut pak = PakReader::new(Cursor::new(&data[..len]));
+        let err = pak
+            .load_index()
+            .expect_err("Loaded the index of a truncated pak");
+        assert!(matches!(err.kind, PakErrorKind::FooterInvalid), "{len}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn corrupted_footer_version() -> Result<(), PakError> {
+    let mut data = fs::read(test_pak!("000-TestPak-cus-C_P.pak"))?;
+    let version = data.len() - 0xCC + 4;
+
+    // a known version that doesn't fit the footer size means the footer is corrupted
+    data[version] = 3;
+    let err = detect_version(&mut Cursor::new(&data)).expect_err("Detected a corrupted footer");
+    assert!(matches!(err.kind, PakErrorKind::FooterInvalid));
+
+    data[version] = 12;
+    let err = detect_version(&mut Cursor::new(&data)).expect_err("Detected an unknown version");
+    assert!(matches!(err.kind, PakErrorKind::PakVersionUnsupported(12)));
+    assert_eq!(err.to_string(), "Unsupported pak version: 12");
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::io::{Cursor, Write};
+
+use flate2::write::ZlibEncoder;
+
+use unreal_pak::{error::PakErrorKind, pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+const PAK_MAGIC: [u8; 4] = [0xE1, 0x12, 0x6F, 0x5A];
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+fn write_test_pak(pak_version: PakVersion) -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, pak_version);
+    writer.write_entry(&"Game/A.bin".to_string(), &vec![1u8; 64], true)?;
+    writer.write_entry(&"Game/B.bin".to_string(), &vec![2u8; 64], false)?;
+    writer.write_entry(&"Game/Sub/C.bin".to_string(), &vec![3u8; 16], false)?;
+    writer.finish_write()?;
+    Ok(cursor.into_inner())
+}
+
+fn load_index(data: Vec<u8>) -> Result<PakReader<Cursor<Vec<u8>>>, PakError> {
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+    Ok(pak)
+}
+
+fn assert_entries(pak: &mut PakReader<Cursor<Vec<u8>>>) -> Result<(), PakError> {
+    assert_eq!(
+        pak.get_entry_names(),
+        ["Game/A.bin", "Game/B.bin", "Game/Sub/C.bin"]
+    );
+    assert_eq!(pak.read_entry(&"Game/A.bin".to_string())?, vec![1u8; 64]);
+    assert_eq!(pak.read_entry(&"Game/B.bin".to_string())?, vec![2u8; 64]);
+    assert_eq!(
+        pak.read_entry(&"Game/Sub/C.bin".to_string())?,
+        vec![3u8; 16]
+    );
+    Ok(())
+}
+
+fn magic_offset(data: &[u8]) -> usize {
+    data.windows(4)
+        .rposition(|e| e == PAK_MAGIC)
+        .expect("Failed to find footer magic")
+}
+
+/// Replace the raw index of a pak with the given bytes and fix up the index size in the footer
+fn replace_index(data: &[u8], index: &[u8]) -> Vec<u8> {
+    let magic_offset = magic_offset(data);
+    let index_offset = u64::from_le_bytes(
+        data[magic_offset + 8..magic_offset + 16]
+            .try_into()
+            .unwrap(),
+    );
+    let index_size = u64::from_le_bytes(
+        data[magic_offset + 16..magic_offset + 24]
+            .try_into()
+            .unwrap(),
+    );
+    let footer_offset = (index_offset + index_size) as usize;
+
+    let mut replaced = data[..index_offset as usize].to_vec();
+    replaced.extend_from_slice(index);
+    let new_magic_offset = replaced.len() + magic_offset - footer_offset;
+    replaced.extend_from_slice(&data[footer_offset..]);
+    replaced[new_magic_offset + 16..new_magic_offset + 24]
+        .copy_from_slice(&(index.len() as u64).to_le_bytes());
+    replaced
+}
+
+fn compress_index(data: &[u8]) -> Vec<u8> {
+    let pak = load_index(data.to_vec()).unwrap();
+    let index_offset = pak.footer().index_offset as usize;
+    let index_size = pak.footer().index_size as usize;
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&data[index_offset..index_offset + index_size])
+        .unwrap();
+    replace_index(data, &encoder.finish().unwrap())
+}
+
+#[test]
+fn raw_index() -> Result<(), PakError> {
+    for data in [
+        include_bytes!(test_pak!("000-TestPak-cus-C_P.pak")).to_vec(),
+        include_bytes!(test_pak!("000-TestPak-off-NoC_P.pak")).to_vec(),
+    ] {
+        assert!(!load_index(data)?.get_entry_names().is_empty());
+    }
+
+    assert_entries(&mut load_index(write_test_pak(
+        PakVersion::FnameBasedCompressionMethod,
+    )?)?)?;
+
+    Ok(())
+}
+
+#[test]
+fn index_hash_not_checked() -> Result<(), PakError> {
+    let mut data = write_test_pak(PakVersion::FnameBasedCompressionMethod)?;
+
+    // some games zero or obfuscate the index hash, the index is still read as is
+    let hash_offset = magic_offset(&data) + 24;
+    data[hash_offset..hash_offset + 20].fill(0);
+    assert_entries(&mut load_index(data)?)?;
+
+    Ok(())
+}
+
+#[test]
+fn encrypted_index() -> Result<(), PakError> {
+    let mut data = write_test_pak(PakVersion::FnameBasedCompressionMethod)?;
+
+    // index_encrypted is the byte before the footer magic
+    let magic_offset = magic_offset(&data);
+    data[magic_offset - 1] = 1;
+    let err = load_index(data).expect_err("Encrypted index was parsed");
+    assert!(matches!(err.kind, PakErrorKind::EncryptionUnsupported));
+
+    Ok(())
+}
+
+#[test]
+fn compressed_index() -> Result<(), PakError> {
+    for pak_version in [
+        PakVersion::RelativeChunkOffsets,
+        PakVersion::FnameBasedCompressionMethod,
+    ] {
+        let data = compress_index(&write_test_pak(pak_version)?);
+        let mut pak = load_index(data.clone())?;
+        assert_entries(&mut pak)?;
+        assert_eq!(data[pak.footer().index_offset as usize], 0x78);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn compressed_index_invalid() -> Result<(), PakError> {
+    let data = write_test_pak(PakVersion::FnameBasedCompressionMethod)?;
+
+    // a zlib header followed by garbage is not mistaken for a raw index
+    let data = replace_index(&data, &[0x78, 0x9c, 0xff, 0xff, 0xff, 0xff]);
+    let err = load_index(data).expect_err("Invalid compressed index was parsed");
+    assert!(matches!(err.kind, PakErrorKind::IndexInvalid));
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::io::Cursor;
+
+use sha1::{Digest, Sha1};
+use unreal_pak::{
+    error::{LayoutError, LayoutErrorKind},
+    pakversion::PakVersion,
+    PakError, PakReader, PakWriter,
+};
+
+const PAK_MAGIC: [u8; 4] = [0xE1, 0x12, 0x6F, 0x5A];
+
+fn write_pak() -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.write_entry(&"Game/a.bin".to_owned(), &vec![0u8; 16], false)?;
+    writer.write_entry(&"Game/b.bin".to_owned(), &vec![1u8; 16], false)?;
+    writer.write_entry(&"Game/c.bin".to_owned(), &vec![2u8; 32], false)?;
+    writer.finish_write()?;
+    Ok(cursor.into_inner())
+}
+
+/// Replace the offset of `name` in the index with the result of `patch` and fix the index hash
+fn patch_offset(
+    mut data: Vec<u8>,
+    name: &str,
+    patch: impl Fn(u64) -> u64,
+) -> Result<Vec<u8>, PakError> {
+    let mut pak = PakReader::new(Cursor::new(data.clone()));
+    pak.load_index()?;
+    let index_offset = pak.footer().index_offset as usize;
+    let index_size = pak.footer().index_size as usize;
+
+    // the entry header follows the null terminated name, starting with the offset
+    let name_offset = data[index_offset..]
+        .windows(name.len())
+        .position(|e| e == name.as_bytes())
+        .expect("Failed to find entry name in index");
+    let offset_start = index_offset + name_offset + name.len() + 1;
+    let offset = u64::from_le_bytes(data[offset_start..offset_start + 8].try_into().unwrap());
+    data[offset_start..offset_start + 8].copy_from_slice(&patch(offset).to_le_bytes());
+
+    // the index hash follows the magic, version, index offset and index size
+    let hash = Sha1::digest(&data[index_offset..index_offset + index_size]);
+    let magic_offset = data
+        .windows(4)
+        .rposition(|e| e == PAK_MAGIC)
+        .expect("Failed to find footer magic");
+    data[magic_offset + 24..magic_offset + 44].copy_from_slice(&hash);
+
+    Ok(data)
+}
+
+fn check_layout(data: Vec<u8>) -> Result<Result<(), Vec<LayoutError>>, PakError> {
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+    Ok(pak.check_layout())
+}
+
+#[test]
+fn layout_valid() -> Result<(), PakError> {
+    assert!(check_layout(write_pak()?)?.is_ok());
+    Ok(())
+}
+
+#[test]
+fn layout_wrong_offset() -> Result<(), PakError> {
+    // point into the middle of the header of the first entry
+    let data = patch_offset(write_pak()?, "Game/c.bin", |_| 8)?;
+
+    let errors = check_layout(data)?.expect_err("Wrong offset was accepted");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entry, "Game/c.bin");
+    assert_eq!(errors[0].offset, 8);
+    assert!(!matches!(errors[0].kind, LayoutErrorKind::Overlap(_)));
+    Ok(())
+}
+
+#[test]
+fn layout_out_of_bounds() -> Result<(), PakError> {
+    let data = patch_offset(write_pak()?, "Game/b.bin", |_| u64::MAX - 4)?;
+
+    let errors = check_layout(data)?.expect_err("Offset past the end was accepted");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entry, "Game/b.bin");
+    assert!(matches!(
+        errors[0].kind,
+        LayoutErrorKind::HeaderUnreadable(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn layout_overlap() -> Result<(), PakError> {
+    // both entries have the same size, so the header of the first one matches the index of the second one
+    let data = patch_offset(write_pak()?, "Game/b.bin", |_| 0)?;
+
+    let errors = check_layout(data)?.expect_err("Overlapping entries were accepted");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].entry, "Game/b.bin");
+    assert!(matches!(errors[0].kind, LayoutErrorKind::Overlap(ref other) if other == "Game/a.bin"));
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Mutex};
+
+use flate2::read::ZlibDecoder;
+use unreal_pak::{
+    error::PakErrorKind, pakversion::PakVersion, Compression, PakError, PakReader, PakWriter,
+};
+
+const ENTRY_NAME: &str = "Game/Content/Data.uasset";
+
+/// Writes a Zlib compressed pak and renames the compression method in the footer to Oodle.
+/// The footer isn't covered by the index hash, so the pak stays valid.
+fn oodle_pak(data: &[u8]) -> Result<Vec<u8>, PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.block_size = 0x1000;
+    writer.write_entry(&ENTRY_NAME.to_string(), &data.to_vec(), true)?;
+    writer.finish_write()?;
+
+    // the compression method names are the last 5 * 0x20 bytes of a v8 pak
+    let mut pak_data = cursor.into_inner();
+    let first_method = pak_data.len() - 5 * 0x20;
+    assert_eq!(&pak_data[first_method..first_method + 5], b"Zlib\0");
+    pak_data[first_method..first_method + 5].copy_from_slice(b"Oodle");
+
+    Ok(pak_data)
+}
+
+#[test]
+fn oodle_unavailable() -> Result<(), PakError> {
+    let data = vec![7u8; 0x2800];
+    let mut pak = PakReader::new(Cursor::new(oodle_pak(&data)?));
+    pak.load_index()?;
+
+    assert_eq!(pak.footer().compression_methods()[0], Compression::oodle());
+    assert_eq!(pak.entry_compression(ENTRY_NAME)?, Compression::oodle());
+
+    let err = pak
+        .read_entry(&ENTRY_NAME.to_string())
+        .expect_err("Read an Oodle entry without a decompressor");
+    assert!(matches!(err.kind, PakErrorKind::OodleUnavailable));
+
+    // hashes cover the stored data, so they can be checked without decompressing
+    assert!(pak.verify_entry(ENTRY_NAME)?);
+
+    Ok(())
+}
+
+#[test]
+fn oodle_decompressor() -> Result<(), PakError> {
+    let data = (0..0x2800u32)
+        .map(|i| (i * 7 % 251) as u8)
+        .collect::<Vec<_>>();
+    let mut pak = PakReader::new(Cursor::new(oodle_pak(&data)?));
+    pak.load_index()?;
+
+    // stand in for Oodle with Zlib, the data was compressed with it
+    let sizes = Arc::new(Mutex::new(Vec::new()));
+    let decompressor_sizes = Arc::clone(&sizes);
+    pak.set_oodle_decompressor(Box::new(move |data, decompressed_size| {
+        decompressor_sizes.lock().unwrap().push(decompressed_size);
+
+        let mut decompressed = Vec::with_capacity(decompressed_size);
+        ZlibDecoder::new(data).read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    }));
+
+    assert_eq!(pak.read_entry(&ENTRY_NAME.to_string())?, data);
+    assert_eq!(*sizes.lock().unwrap(), [0x1000, 0x1000, 0x800]);
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::io::Cursor;
+
+use unreal_pak::{pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+fn entries() -> Vec<(String, Vec<u8>, bool)> {
+    vec![
+        (
+            "Game/Content/A.uasset".to_string(),
+            vec![1u8; 0x30000],
+            true,
+        ),
+        (
+            "Game/Content/Maps/B.umap".to_string(),
+            (0..0x1000u32).map(|i| (i % 251) as u8).collect(),
+            false,
+        ),
+        ("Game/C.bin".to_string(), vec![3u8; 64], true),
+    ]
+}
+
+#[test]
+fn pak_versions_roundtrip() -> Result<(), PakError> {
+    for pak_version in [
+        PakVersion::FnameBasedCompressionMethod,
+        PakVersion::FrozenIndex,
+        PakVersion::PathHashIndex,
+        PakVersion::Fnv64BugFix,
+    ] {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = PakWriter::new(&mut cursor, pak_version);
+        for (name, data, compress) in entries() {
+            writer.write_entry(&name, &data, compress)?;
+        }
+        writer.finish_write()?;
+
+        let mut pak = PakReader::new(cursor);
+        pak.load_index()?;
+        assert_eq!(pak.footer().pak_version, pak_version);
+        assert_eq!(pak.footer().index_encrypted, Some(false));
+        assert_eq!(pak.get_entry_names().len(), entries().len());
+
+        for (name, data, _) in entries() {
+            assert_eq!(pak.read_entry(&name)?, data, "{name} in {pak_version:?}");
+            assert!(pak.verify_entry(&name)?);
+        }
+        assert_eq!(pak.check_layout().map_err(|e| e.len()), Ok(()));
+    }
+
+    Ok(())
+}
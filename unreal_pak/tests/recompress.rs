@@ -0,0 +1,205 @@
+use std::io::Cursor;
+
+use unreal_pak::{
+    error::PakErrorKind, pakversion::PakVersion, Compression, PakError, PakMemory, PakReader,
+    PakWriter,
+};
+
+#[test]
+fn recompress_entry_zlib() -> Result<(), PakError> {
+    let compressible = vec![7u8; 0x1000];
+    let other = (0..64u8).collect::<Vec<_>>();
+
+    let mut pak = PakMemory::new(PakVersion::FnameBasedCompressionMethod);
+    pak.set_entry("Game/a.bin".to_owned(), compressible.clone());
+    pak.set_entry("Game/b.bin".to_owned(), other.clone());
+    assert_eq!(pak.entry_compression("Game/a.bin"), Some(Compression::None));
+
+    pak.recompress_entry("Game/a.bin", Compression::zlib())?;
+    assert_eq!(
+        pak.entry_compression("Game/a.bin"),
+        Some(Compression::zlib())
+    );
+    assert_eq!(pak.entry_compression("Game/b.bin"), Some(Compression::None));
+
+    let mut cursor = Cursor::new(Vec::new());
+    pak.write(&mut cursor)?;
+
+    let mut reader = PakReader::new(cursor);
+    reader.load_index()?;
+    assert_eq!(reader.entry_compression("Game/a.bin")?, Compression::zlib());
+    assert_eq!(reader.entry_compression("Game/b.bin")?, Compression::None);
+    assert_eq!(reader.read_entry(&"Game/a.bin".to_owned())?, compressible);
+    assert_eq!(reader.read_entry(&"Game/b.bin".to_owned())?, other);
+
+    let blocks = reader.entry_blocks("Game/a.bin")?;
+    assert!(blocks.iter().map(|e| e.compressed_size).sum::<u64>() < compressible.len() as u64);
+
+    Ok(())
+}
+
+#[test]
+fn recompress_entry_missing() {
+    let mut pak = PakMemory::new(PakVersion::FnameBasedCompressionMethod);
+    let err = pak
+        .recompress_entry("Game/a.bin", Compression::zlib())
+        .expect_err("Missing entry was recompressed");
+    assert!(matches!(err.kind, PakErrorKind::EntryNotFound(_)));
+}
+
+#[test]
+fn recompress_entry_oodle() -> Result<(), PakError> {
+    let data = vec![7u8; 0x1000];
+    let mut pak = PakMemory::new(PakVersion::FnameBasedCompressionMethod);
+    pak.set_entry("Game/a.bin".to_owned(), data.clone());
+
+    // Oodle can only be decompressed, so it's rejected before the entry or the pak change
+    let err = pak
+        .recompress_entry("Game/a.bin", Compression::oodle())
+        .expect_err("Entry was recompressed with Oodle");
+    assert!(matches!(
+        err.kind,
+        PakErrorKind::CompressionUnsupported(method) if method == Compression::oodle()
+    ));
+    assert_eq!(pak.entry_compression("Game/a.bin"), Some(Compression::None));
+
+    let mut cursor = Cursor::new(Vec::new());
+    pak.write(&mut cursor)?;
+
+    let mut reader = PakReader::new(cursor);
+    reader.load_index()?;
+    assert_eq!(reader.entry_compression("Game/a.bin")?, Compression::None);
+    assert_eq!(reader.read_entry(&"Game/a.bin".to_owned())?, data);
+
+    Ok(())
+}
+
+#[test]
+fn recompress_loaded_entry() -> Result<(), PakError> {
+    let original = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/testfiles/000-TestPak-cus-C_P.pak"
+    ))?;
+    let mut reader = PakReader::new(Cursor::new(original.as_slice()));
+    reader.load_index()?;
+
+    // loaded entries report and keep the method they are stored with
+    let mut pak = PakMemory::load_from(&mut Cursor::new(original.as_slice()))?;
+    let names = reader
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in &names {
+        assert_eq!(
+            pak.entry_compression(name),
+            Some(reader.entry_compression(name)?)
+        );
+    }
+
+    let name = names
+        .iter()
+        .find(|name| {
+            reader
+                .entry_compression(name)
+                .is_ok_and(|e| e != Compression::None)
+        })
+        .expect("No compressed entry");
+    pak.recompress_entry(name, Compression::None)?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    pak.write(&mut cursor)?;
+
+    let mut written = PakReader::new(cursor);
+    written.load_index()?;
+    for other in &names {
+        let expected = match other == name {
+            true => Compression::None,
+            false => reader.entry_compression(other)?,
+        };
+        assert_eq!(written.entry_compression(other)?, expected);
+        assert_eq!(written.read_entry(other)?, reader.read_entry(other)?);
+    }
+
+    Ok(())
+}
+
+type PakEntries = Vec<(String, Vec<u8>)>;
+
+/// Write an uncompressed pak with a mix of compressible, incompressible and tiny entries
+fn uncompressed_pak() -> Result<(PakEntries, Vec<u8>), PakError> {
+    let entries = vec![
+        (
+            "Game/Content/a.uasset".to_owned(),
+            b"Compress me please! ".repeat(2000),
+        ),
+        ("Game/Content/b.uexp".to_owned(), vec![0x42; 0x30000]),
+        (
+            "Game/Content/c.bin".to_owned(),
+            (0..=255u8).cycle().take(5000).collect(),
+        ),
+        ("Game/Content/small.txt".to_owned(), b"tiny".to_vec()),
+    ];
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.mount_point = "../../../Mod/".to_owned();
+    for (name, data) in &entries {
+        writer.write_entry(name, data, false)?;
+    }
+    writer.finish_write()?;
+
+    Ok((entries, cursor.into_inner()))
+}
+
+#[test]
+fn recompress_pak_max_zlib() -> Result<(), PakError> {
+    let (entries, original) = uncompressed_pak()?;
+
+    let mut reader = PakReader::new(Cursor::new(original.clone()));
+    reader.load_index()?;
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.recompress_from(&mut reader, Compression::zlib(), 9)?;
+    assert_eq!(writer.compression_level(), 9);
+    writer.finish_write()?;
+
+    let recompressed = cursor.into_inner();
+    assert!(recompressed.len() < original.len());
+
+    let mut reader = PakReader::new(Cursor::new(recompressed));
+    reader.load_index()?;
+    assert_eq!(reader.mount_point(), "../../../Mod/");
+    assert_eq!(reader.get_entry_names().len(), entries.len());
+    for (name, data) in &entries {
+        assert_eq!(&reader.read_entry(name)?, data);
+
+        let expected = match data.len() >= 32 {
+            true => Compression::zlib(),
+            false => Compression::None,
+        };
+        assert_eq!(reader.entry_compression(name)?, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn recompress_pak_invalid_level() -> Result<(), PakError> {
+    let (_, original) = uncompressed_pak()?;
+
+    let mut reader = PakReader::new(Cursor::new(original));
+    reader.load_index()?;
+
+    let mut writer = PakWriter::new(
+        Cursor::new(Vec::new()),
+        PakVersion::FnameBasedCompressionMethod,
+    );
+    let err = writer
+        .recompress_from(&mut reader, Compression::zlib(), 10)
+        .expect_err("Invalid compression level was accepted");
+    assert!(matches!(err.kind, PakErrorKind::ConfigurationInvalid));
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::fs::{self, File};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+use unreal_pak::{pakversion::PakVersion, PakError, PakReader, PakWriter, SplitReader};
+
+fn write_test_pak() -> Result<(Vec<u8>, Vec<u8>), PakError> {
+    let data = (0..10_000u32)
+        .map(|i| (i * 7 % 251) as u8)
+        .collect::<Vec<_>>();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.write_entry(&"Game/Compressed.bin".to_string(), &data, true)?;
+    writer.write_entry(&"Game/Uncompressed.bin".to_string(), &data, false)?;
+    writer.finish_write()?;
+
+    Ok((cursor.into_inner(), data))
+}
+
+#[test]
+fn open_split() -> Result<(), PakError> {
+    let (pak_data, data) = write_test_pak()?;
+
+    let mut pak = PakReader::new(Cursor::new(pak_data.as_slice()));
+    pak.load_index()?;
+
+    // split in the middle of the uncompressed entry's data
+    let block = pak.entry_blocks("Game/Uncompressed.bin")?[0];
+    let split = (block.offset + block.compressed_size / 2) as usize;
+
+    let dir = std::env::temp_dir().join(format!("unreal_pak_split_{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let paths: Vec<PathBuf> = [&pak_data[..split], &pak_data[split..]]
+        .iter()
+        .enumerate()
+        .map(|(i, part)| {
+            let path = dir.join(format!("test.pak.part{i}"));
+            fs::write(&path, part).map(|_| path)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let parts = paths
+        .iter()
+        .map(File::open)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut split_pak = PakReader::open_split(&parts)?;
+    split_pak.load_index()?;
+
+    assert_eq!(split_pak.get_entry_names(), pak.get_entry_names());
+    assert_eq!(
+        split_pak.read_entry(&"Game/Uncompressed.bin".to_string())?,
+        data
+    );
+    assert_eq!(
+        split_pak.read_entry(&"Game/Compressed.bin".to_string())?,
+        data
+    );
+    assert!(split_pak.verify_entry("Game/Uncompressed.bin")?);
+
+    fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn split_reader_seek() -> std::io::Result<()> {
+    let parts = vec![
+        Cursor::new(vec![0u8, 1, 2]),
+        Cursor::new(vec![]),
+        Cursor::new(vec![3u8, 4]),
+        Cursor::new(vec![5u8, 6, 7, 8]),
+    ];
+    let mut reader = SplitReader::new(parts)?;
+
+    let mut all = Vec::new();
+    reader.read_to_end(&mut all)?;
+    assert_eq!(all, (0..9).collect::<Vec<u8>>());
+
+    let mut buf = [0u8; 4];
+    reader.seek(SeekFrom::Start(2))?;
+    reader.read_exact(&mut buf)?;
+    assert_eq!(buf, [2, 3, 4, 5]);
+
+    assert_eq!(reader.seek(SeekFrom::End(-2))?, 7);
+    assert_eq!(reader.seek(SeekFrom::Current(-6))?, 1);
+    reader.read_exact(&mut buf)?;
+    assert_eq!(buf, [1, 2, 3, 4]);
+
+    assert!(reader.seek(SeekFrom::Current(-10)).is_err());
+    assert_eq!(reader.seek(SeekFrom::End(3))?, 12);
+    assert_eq!(reader.read(&mut buf)?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+
+use unreal_pak::{
+    pakversion::PakVersion, Compression, EntryHasher, PakError, PakReader, PakWriter,
+};
+
+macro_rules! test_pak {
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/testfiles/", $name)
+    };
+}
+
+#[test]
+fn verify_entries() -> Result<(), PakError> {
+    for path in [
+        test_pak!("000-TestPak-cus-C_P.pak"),
+        test_pak!("000-TestPak-cus-NoC_P.pak"),
+    ] {
+        let mut pak = PakReader::new(BufReader::new(File::open(path)?));
+        pak.load_index()?;
+
+        let names = pak
+            .get_entry_names()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert!(!names.is_empty());
+
+        for name in &names {
+            assert!(pak.verify_entry(name)?, "{name} failed verification");
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn verify_corrupted_entry() -> Result<(), PakError> {
+    let mut data = std::fs::read(test_pak!("000-TestPak-cus-NoC_P.pak"))?;
+    // flip a byte in the data of the first entry, right after its header
+    data[60] ^= 0xff;
+
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+
+    let results = pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|name| pak.verify_entry(&name))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(results.iter().filter(|e| !**e).count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn verify_all_compressed() -> Result<(), PakError> {
+    let mut data = std::fs::read(test_pak!("000-TestPak-cus-C_P.pak"))?;
+
+    let mut pak = PakReader::new(Cursor::new(data.as_slice()));
+    pak.load_index()?;
+    let results = pak.verify_all();
+    let names = results.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, pak.get_entry_names());
+    assert!(results.iter().all(|(_, result)| matches!(result, Ok(true))));
+
+    // the hash covers the compressed data, so corrupting a block is found without decompressing
+    let name = names
+        .iter()
+        .find(|name| {
+            pak.entry_compression(name)
+                .is_ok_and(|e| e != Compression::None)
+        })
+        .map(|name| name.to_string())
+        .expect("No compressed entry");
+    let block = pak.entry_blocks(&name)?[0];
+    data[(block.offset + block.compressed_size / 2) as usize] ^= 0xff;
+
+    let mut pak = PakReader::new(Cursor::new(data.as_slice()));
+    pak.load_index()?;
+    let failed = pak
+        .verify_all()
+        .into_iter()
+        .filter(|(_, result)| !matches!(result, Ok(true)))
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(failed, [name]);
+
+    Ok(())
+}
+
+/// 64 bit FNV-1a, padded to 20 bytes
+#[derive(Debug)]
+struct Fnv1aHasher;
+
+impl EntryHasher for Fnv1aHasher {
+    fn hash(&self, data: &[u8]) -> [u8; 20] {
+        let hash = data.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+        let mut padded = [0u8; 20];
+        padded[..8].copy_from_slice(&hash.to_le_bytes());
+        padded
+    }
+}
+
+#[test]
+fn verify_custom_hasher() -> Result<(), PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.set_entry_hasher(Box::new(Fnv1aHasher));
+    writer.write_entry(&"Game/a.bin".to_owned(), &vec![1; 0x2000], true)?;
+    writer.write_entry(&"Game/b.bin".to_owned(), &(0..=255).collect(), false)?;
+    let hashes = writer.entry_hashes().clone();
+    writer.finish_write()?;
+    let mut data = cursor.into_inner();
+
+    let mut pak = PakReader::new(Cursor::new(data.as_slice()));
+    pak.load_index()?;
+    let names = pak
+        .get_entry_names()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(hashes.keys().cloned().collect::<Vec<_>>(), names);
+
+    // the entry headers still hold SHA1 hashes
+    for name in &names {
+        assert!(pak.verify_entry(name)?, "{name} failed SHA1 verification");
+    }
+    pak.set_entry_hasher(Box::new(Fnv1aHasher), hashes.clone());
+    for name in &names {
+        assert!(pak.verify_entry(name)?, "{name} failed verification");
+    }
+
+    // flip the last byte of the uncompressed entry, which is written last
+    let last = pak.entry_blocks("Game/b.bin")?[0];
+    data[(last.offset + last.compressed_size - 1) as usize] ^= 0xff;
+
+    let mut pak = PakReader::new(Cursor::new(data.as_slice()));
+    pak.set_entry_hasher(Box::new(Fnv1aHasher), hashes.clone());
+    pak.load_index()?;
+    assert!(pak.verify_entry("Game/a.bin")?);
+    assert!(!pak.verify_entry("Game/b.bin")?);
+
+    // an entry without a hash can't be verified
+    let mut partial_hashes = hashes;
+    partial_hashes.remove("Game/a.bin");
+    pak.set_entry_hasher(Box::new(Fnv1aHasher), partial_hashes);
+    assert!(!pak.verify_entry("Game/a.bin")?);
+
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn verify_all_parallel() -> Result<(), PakError> {
+    let path = test_pak!("000-TestPak-cus-C_P.pak");
+    let mut pak = PakReader::new(BufReader::new(File::open(path)?));
+    pak.load_index()?;
+
+    let results = pak.verify_all_parallel(|| File::open(path).map(BufReader::new));
+
+    let names = results.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, pak.get_entry_names());
+    for (name, result) in results {
+        assert!(result?, "{name} failed verification");
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn verify_all_parallel_in_memory() -> Result<(), PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    for i in 0..64 {
+        writer.write_entry(&format!("Game/Entry{i:02}.bin"), &vec![i as u8; 1024], true)?;
+    }
+    writer.finish_write()?;
+
+    let data = cursor.into_inner();
+    let mut pak = PakReader::new(Cursor::new(data.as_slice()));
+    pak.load_index()?;
+
+    let results = pak.verify_all_parallel(|| Ok(Cursor::new(data.as_slice())));
+    assert_eq!(results.len(), 64);
+    assert!(results
+        .into_iter()
+        .all(|(_, result)| matches!(result, Ok(true))));
+
+    Ok(())
+}
+
+#[test]
+fn verify_oversized_entry() -> Result<(), PakError> {
+    let mut data = std::fs::read(test_pak!("000-TestPak-cus-NoC_P.pak"))?;
+    // the compressed size of the first entry header, larger than the whole pak
+    data[8..16].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+
+    let results = pak.verify_all();
+    assert_eq!(
+        results.iter().filter(|(_, result)| result.is_err()).count(),
+        1
+    );
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use std::io::Cursor;
+
+#[cfg(feature = "parallel")]
+use unreal_pak::error::PakErrorKind;
+use unreal_pak::{pakversion::PakVersion, PakError, PakReader, PakWriter};
+
+#[test]
+fn reserve_and_bytes_written() -> Result<(), PakError> {
+    const ENTRY_COUNT: usize = 1000;
+
+    let entries = (0..ENTRY_COUNT)
+        .map(|i| {
+            (
+                format!("Game/Content/Entry_{i:04}.bin"),
+                vec![(i % 256) as u8; 64 + i % 32],
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.reserve(ENTRY_COUNT);
+    assert_eq!(writer.bytes_written(), 0);
+
+    let mut last_written = 0;
+    for (i, (name, data)) in entries.iter().enumerate() {
+        writer.write_entry(name, data, i % 2 == 0)?;
+        assert!(writer.bytes_written() > last_written);
+        last_written = writer.bytes_written();
+    }
+    assert_eq!(writer.get_entry_names().len(), ENTRY_COUNT);
+    writer.finish_write()?;
+
+    let data = cursor.into_inner();
+    let mut pak = PakReader::new(Cursor::new(data.clone()));
+    pak.load_index()?;
+
+    // entry data ends where the index starts
+    assert_eq!(last_written, pak.footer().index_offset);
+    assert!((data.len() as u64) > last_written);
+
+    assert_eq!(pak.get_entry_names().len(), ENTRY_COUNT);
+    for (name, data) in &entries {
+        assert_eq!(&pak.read_entry(name)?, data);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn finish_write_with_progress() -> Result<(), PakError> {
+    const ENTRY_COUNT: usize = 250;
+
+    // both the legacy index and the path hash index report progress
+    for pak_version in [
+        PakVersion::FnameBasedCompressionMethod,
+        PakVersion::Fnv64BugFix,
+    ] {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = PakWriter::new(&mut cursor, pak_version);
+        for i in 0..ENTRY_COUNT {
+            writer.write_entry(
+                &format!("Game/Content/Dir_{}/Entry_{i}.bin", i % 7),
+                &vec![i as u8; 16],
+                false,
+            )?;
+        }
+
+        let mut progress = Vec::new();
+        writer.finish_write_with_progress(|written, total| progress.push((written, total)))?;
+
+        assert_eq!(
+            progress,
+            (1..=ENTRY_COUNT)
+                .map(|written| (written, ENTRY_COUNT))
+                .collect::<Vec<_>>()
+        );
+
+        let mut pak = PakReader::new(cursor);
+        pak.load_index()?;
+        assert_eq!(pak.get_entry_names().len(), ENTRY_COUNT);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn write_entries_parallel() -> Result<(), PakError> {
+    // a mix of multi block, single block, uncompressed and too small to compress entries
+    let entries = (0..40)
+        .map(|i: usize| {
+            let len = match i % 4 {
+                0 => 0x1000 * 3 + i,
+                1 => 0x800 + i,
+                _ => 16 + i,
+            };
+            let data = (0..len).map(|e| (e * 7 % (i + 13)) as u8).collect();
+            (format!("Game/Content/Entry_{i:02}.bin"), data, i % 4 != 2)
+        })
+        .collect::<Vec<(String, Vec<u8>, bool)>>();
+
+    let write = |parallel: bool| -> Result<Vec<u8>, PakError> {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+        writer.block_size = 0x1000;
+        match parallel {
+            true => {
+                let (first, second) = entries.split_at(17);
+                writer.write_entries_parallel(first)?;
+                writer.write_entries_parallel(second)?;
+            }
+            false => {
+                for (name, data, compress) in &entries {
+                    writer.write_entry(name, data, *compress)?;
+                }
+            }
+        }
+        writer.finish_write()?;
+        Ok(cursor.into_inner())
+    };
+
+    let data = write(true)?;
+    assert_eq!(data, write(false)?);
+
+    let mut pak = PakReader::new(Cursor::new(data));
+    pak.load_index()?;
+    for (name, data, _) in &entries {
+        assert_eq!(&pak.read_entry(name)?, data);
+        assert!(pak.verify_entry(name)?);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn write_entries_parallel_double_write() -> Result<(), PakError> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut writer = PakWriter::new(&mut cursor, PakVersion::FnameBasedCompressionMethod);
+    writer.write_entry(&"Game/A.bin".to_string(), &vec![1u8; 64], true)?;
+
+    for entries in [
+        vec![("Game/A.bin".to_string(), vec![2u8; 64], true)],
+        vec![
+            ("Game/B.bin".to_string(), vec![2u8; 64], true),
+            ("Game/B.bin".to_string(), vec![3u8; 64], true),
+        ],
+    ] {
+        let bytes_written = writer.bytes_written();
+        let err = writer
+            .write_entries_parallel(&entries)
+            .expect_err("Wrote an entry twice");
+        assert!(matches!(err.kind, PakErrorKind::DoubleWrite(_)));
+        assert_eq!(writer.bytes_written(), bytes_written);
+    }
+
+    Ok(())
+}
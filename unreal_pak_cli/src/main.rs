@@ -1,12 +1,15 @@
+use std::collections::HashSet;
 use std::fs::{File, OpenOptions};
-use std::io::{BufReader, BufWriter, Write};
+use std::io::{BufReader, BufWriter, Seek, Write};
 use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::time::SystemTime;
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use path_absolutize::Absolutize;
-use unreal_pak::{pakversion::PakVersion, PakReader, PakWriter};
+use unreal_pak::{
+    detect_version, pakversion::PakVersion, Compression, PakBuilder, PakReader, PakWriter,
+};
 use walkdir::WalkDir;
 
 /// Command line tool for working with Unreal Engine .pak files.
@@ -26,6 +29,9 @@ enum Commands {
     Check {
         /// The .pak file to check
         pakfile: String,
+        /// Also check the stored data of every record against the hash in its header
+        #[clap(long)]
+        verify: bool,
     },
 
     /// Only check the header of a .pak file if it is valid.
@@ -40,6 +46,17 @@ enum Commands {
         pakfile: String,
         /// The directory to extract to, if not specified the .pak file name will be used
         outdir: Option<String>,
+        /// Use the stored entry names instead of resolving them against the mount point
+        #[clap(long)]
+        raw_names: bool,
+        /// Only extract entries matching this glob pattern, e.g. `Game/Content/Maps/**/*.umap`.
+        /// Patterns matching a directory include everything in it. Can be given multiple times.
+        #[clap(long)]
+        include: Vec<String>,
+        /// Skip entries matching this glob pattern, applied after `--include`.
+        /// Can be given multiple times.
+        #[clap(long)]
+        exclude: Vec<String>,
     },
 
     /// create a new .pak file from the files from a directory, optionally disabling compression.
@@ -52,6 +69,38 @@ enum Commands {
         #[clap(short, long)]
         no_compression: bool,
     },
+
+    /// Rewrite every entry of a .pak file with a different compression method and level.
+    Recompress {
+        /// The .pak file to read
+        inpak: String,
+        /// The .pak file to write
+        outpak: String,
+        /// Compression method to use for the entries
+        #[clap(short, long, value_enum, default_value_t = CompressionArg::Zlib)]
+        method: CompressionArg,
+        /// Compression level from 0 (fastest) to 9 (smallest)
+        #[clap(short, long, default_value_t = 9)]
+        level: u32,
+    },
+}
+
+/// Compression methods selectable on the command line
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum CompressionArg {
+    /// Store entries uncompressed
+    None,
+    /// Zlib compression
+    Zlib,
+}
+
+impl From<CompressionArg> for Compression {
+    fn from(value: CompressionArg) -> Self {
+        match value {
+            CompressionArg::None => Compression::None,
+            CompressionArg::Zlib => Compression::zlib(),
+        }
+    }
 }
 
 fn main() {
@@ -61,15 +110,38 @@ fn main() {
 
     match args.commands {
         Commands::CheckHeader { pakfile } => {
-            let file = open_file(Path::new(&pakfile));
-            let mut pak = PakReader::new(file);
+            let mut pak = open_pak(Path::new(&pakfile));
             check_header(&mut pak);
         }
-        Commands::Check { pakfile } => {
-            let file = open_file(Path::new(&pakfile));
-            let mut pak = PakReader::new(file);
+        Commands::Check { pakfile, verify } => {
+            let mut pak = open_pak(Path::new(&pakfile));
             check_header(&mut pak);
 
+            if verify {
+                // hashes are checked before decompressing,
+                // so every corrupted record is reported even if it doesn't decompress
+                let mut failed = 0;
+                for (i, (file_name, result)) in pak.verify_all().into_iter().enumerate() {
+                    match result {
+                        Ok(true) => (),
+                        Ok(false) => {
+                            eprintln!("Hash mismatch in record {i}: {file_name:?}");
+                            failed += 1;
+                        }
+                        Err(e) => {
+                            eprintln!("Error verifying record {i}: {file_name:?}! Error: {e}");
+                            failed += 1;
+                        }
+                    }
+                }
+
+                if failed > 0 {
+                    eprintln!("{failed} records failed verification");
+                    exit(1);
+                }
+                println!("All record hashes match");
+            }
+
             for (i, (file_name, data)) in pak.iter().enumerate() {
                 println!("Record {i}: {file_name:?}");
 
@@ -81,11 +153,39 @@ fn main() {
                     }
                 }
             }
+
+            let stats = pak.stats();
+            println!(
+                "{} records, {} bytes stored, {} bytes decompressed, ratio {:.3}",
+                stats.total.entry_count,
+                stats.total.compressed_size,
+                stats.total.decompressed_size,
+                stats.total.compression_ratio()
+            );
+            for (method, method_stats) in stats.by_compression {
+                let method = match method {
+                    Compression::None => "None",
+                    Compression::Known(name) => name,
+                    Compression::Unknown(_) => "Unknown",
+                };
+                println!(
+                    "  {method}: {} records, {} bytes stored, {} bytes decompressed, ratio {:.3}",
+                    method_stats.entry_count,
+                    method_stats.compressed_size,
+                    method_stats.decompressed_size,
+                    method_stats.compression_ratio()
+                );
+            }
         }
-        Commands::Extract { pakfile, outdir } => {
+        Commands::Extract {
+            pakfile,
+            outdir,
+            raw_names,
+            include,
+            exclude,
+        } => {
             let path = Path::new(&pakfile);
-            let file = open_file(path);
-            let mut pak = PakReader::new(file);
+            let mut pak = open_pak(path);
             check_header(&mut pak);
 
             // temp values required to extend lifetimes outside of match scope
@@ -94,56 +194,37 @@ fn main() {
                 None => path.parent().unwrap().join(path.file_stem().unwrap()),
             };
 
-            println!("Extracting to {output_folder:?}");
+            // entries are filtered by their stored names before any of them is read
+            let mut names = match include.is_empty() {
+                true => pak.get_entry_names(),
+                false => include
+                    .iter()
+                    .flat_map(|pattern| pak.entry_names_matching(pattern))
+                    .collect(),
+            };
+            for pattern in &exclude {
+                let excluded = pak
+                    .entry_names_matching(pattern)
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+                names.retain(|name| !excluded.contains(name));
+            }
+            names.sort_unstable();
+            names.dedup();
+            let names = names.into_iter().cloned().collect::<Vec<_>>();
 
-            for (i, (file_name, data)) in pak.iter().enumerate() {
-                match data {
-                    Ok(data) => {
-                        let path = output_folder.join(file_name);
-                        let dir_path = match path.parent() {
-                            Some(dir) => dir,
-                            None => {
-                                eprintln!("No parent directories found! {i}: {file_name:?}");
-                                exit(1);
-                            }
-                        };
+            if names.is_empty() {
+                eprintln!("No entries match the include and exclude patterns, nothing to extract");
+                exit(1);
+            }
 
-                        // Create the parent directories, then files.
-                        match std::fs::create_dir_all(dir_path) {
-                            Ok(_) => {
-                                // Create the file
-                                let mut file = match File::create(&path) {
-                                    Ok(file) => file,
-                                    Err(err) => {
-                                        eprintln!(
-                                            "Error creating file {i}: {path:?}! Error: {err}"
-                                        );
-                                        exit(1);
-                                    }
-                                };
-                                // Write the file
-                                match file.write_all(data.as_slice()) {
-                                    Ok(_) => {
-                                        println!("Record {i}: {file_name}");
-                                    }
-                                    Err(err) => {
-                                        eprintln!(
-                                            "Error writing to file {i}: {path:?}! Error: {err}"
-                                        );
-                                        exit(1);
-                                    }
-                                }
-                            }
-                            Err(err) => {
-                                eprintln!("Error creating directories {dir_path:?}! Error: {err}");
-                                exit(1);
-                            }
-                        };
-                    }
-                    Err(err) => {
-                        eprintln!("Error reading record {i}: {file_name:?}! Error: {err}");
-                        exit(1);
-                    }
+            println!("Extracting {} entries to {output_folder:?}", names.len());
+
+            match pak.extract_entries(&names, &output_folder, !raw_names) {
+                Ok(_) => println!("Extracted {} entries", names.len()),
+                Err(err) => {
+                    eprintln!("Error extracting {pakfile:?}! Error: {err}");
+                    exit(1);
                 }
             }
         }
@@ -193,35 +274,39 @@ fn main() {
             //files.sort_unstable_by_key(|entry| entry.file_name().to_owned());
 
             println!("Writing {} files", files.len());
+            pak.reserve(files.len());
 
-            for (i, entry) in files.iter().enumerate() {
-                // file_path is the OS absolute path, file_name is the folders and file name written to the pak
-                let file_path = entry.path();
-                let mut components = file_path.components();
-                for _ in 0..indir_len {
-                    components.next();
-                }
+            // with the parallel feature every batch is compressed on all cores before it is written
+            for (batch_index, batch) in files.chunks(WRITE_BATCH_SIZE).enumerate() {
+                let entries = batch
+                    .iter()
+                    .map(|entry| {
+                        // file_path is the OS absolute path, file_name is the folders and file name written to the pak
+                        let file_path = entry.path();
+                        let mut components = file_path.components();
+                        for _ in 0..indir_len {
+                            components.next();
+                        }
 
-                let mut file_name = components.as_path().to_string_lossy().replace('\\', "/");
-                if file_name.starts_with('/') {
-                    file_name = file_name[1..].to_owned();
-                }
+                        let mut file_name =
+                            components.as_path().to_string_lossy().replace('\\', "/");
+                        if file_name.starts_with('/') {
+                            file_name = file_name[1..].to_owned();
+                        }
 
-                let file_data = match std::fs::read(file_path) {
-                    Ok(file_data) => file_data,
-                    Err(err) => {
-                        eprintln!("Error reading file {file_path:?}! Error: {err}");
-                        exit(1);
-                    }
-                };
+                        let file_data = match std::fs::read(file_path) {
+                            Ok(file_data) => file_data,
+                            Err(err) => {
+                                eprintln!("Error reading file {file_path:?}! Error: {err}");
+                                exit(1);
+                            }
+                        };
 
-                match pak.write_entry(&file_name, &file_data, !no_compression) {
-                    Ok(_) => println!("Wrote file {i}: {file_name}"),
-                    Err(err) => {
-                        eprintln!("Error writing file in pak {file_name:?}! Error: {err}");
-                        exit(1);
-                    }
-                }
+                        (file_name, file_data, !no_compression)
+                    })
+                    .collect::<Vec<_>>();
+
+                write_batch(&mut pak, &entries, batch_index * WRITE_BATCH_SIZE);
             }
 
             match pak.finish_write() {
@@ -232,6 +317,45 @@ fn main() {
                 }
             }
         }
+        Commands::Recompress {
+            inpak,
+            outpak,
+            method,
+            level,
+        } => {
+            let mut pak = open_pak(Path::new(&inpak));
+            check_header(&mut pak);
+
+            println!("Recompressing to {outpak:?} with {method:?} level {level}");
+
+            let mut builder = match PakBuilder::create(&outpak, pak.footer().pak_version) {
+                Ok(builder) => builder,
+                Err(err) => {
+                    eprintln!("Error creating {outpak:?}! Error: {err}");
+                    exit(1);
+                }
+            };
+
+            if let Err(err) = builder
+                .writer()
+                .recompress_from(&mut pak, method.into(), level)
+            {
+                eprintln!("Error recompressing pak! Error: {err}");
+                exit(1);
+            }
+
+            if let Err(err) = builder.finish_write() {
+                eprintln!("Error writing pak index or footer! Error: {err}");
+                exit(1);
+            }
+
+            let before = file_size(Path::new(&inpak));
+            let after = file_size(Path::new(&outpak));
+            println!(
+                "Size: {before} -> {after} bytes ({:.1}%)",
+                after as f64 / before.max(1) as f64 * 100.0
+            );
+        }
     }
     println!(
         "unreal_pak_cli took {:?} seconds...",
@@ -239,6 +363,43 @@ fn main() {
     )
 }
 
+/// Number of files read into memory at once by the create subcommand
+const WRITE_BATCH_SIZE: usize = 64;
+
+/// Writes a batch of entries, compressing them in parallel
+#[cfg(feature = "parallel")]
+fn write_batch<W: Write + Seek>(
+    pak: &mut PakWriter<W>,
+    entries: &[(String, Vec<u8>, bool)],
+    first_index: usize,
+) {
+    if let Err(err) = pak.write_entries_parallel(entries) {
+        eprintln!("Error writing files in pak! Error: {err}");
+        exit(1);
+    }
+    for (i, (file_name, _, _)) in entries.iter().enumerate() {
+        println!("Wrote file {}: {file_name}", first_index + i);
+    }
+}
+
+/// Writes a batch of entries one by one
+#[cfg(not(feature = "parallel"))]
+fn write_batch<W: Write + Seek>(
+    pak: &mut PakWriter<W>,
+    entries: &[(String, Vec<u8>, bool)],
+    first_index: usize,
+) {
+    for (i, (file_name, file_data, compress)) in entries.iter().enumerate() {
+        match pak.write_entry(file_name, file_data, *compress) {
+            Ok(_) => println!("Wrote file {}: {file_name}", first_index + i),
+            Err(err) => {
+                eprintln!("Error writing file in pak {file_name:?}! Error: {err}");
+                exit(1);
+            }
+        }
+    }
+}
+
 fn open_file(path: &Path) -> BufReader<File> {
     match OpenOptions::new().read(true).open(path) {
         Ok(file) => BufReader::new(file),
@@ -249,6 +410,28 @@ fn open_file(path: &Path) -> BufReader<File> {
     }
 }
 
+fn file_size(path: &Path) -> u64 {
+    match std::fs::metadata(path) {
+        Ok(metadata) => metadata.len(),
+        Err(err) => {
+            eprintln!("Could not read size of {path:?}! Error: {err}");
+            exit(1);
+        }
+    }
+}
+
+fn open_pak(path: &Path) -> PakReader<BufReader<File>> {
+    let mut file = open_file(path);
+    match detect_version(&mut file) {
+        Ok(pak_version) => println!("Pak version {} ({pak_version:?})", pak_version.to_num()),
+        Err(err) => {
+            eprintln!("Error reading footer! Error: {err}");
+            exit(1);
+        }
+    }
+    PakReader::new(file)
+}
+
 fn check_header(pak: &mut PakReader<BufReader<File>>) {
     match pak.load_index() {
         Ok(_) => println!("Header is ok"),
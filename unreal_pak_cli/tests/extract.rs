@@ -0,0 +1,77 @@
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use unreal_pak::{pakversion::PakVersion, PakWriter};
+
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("unreal_pak_cli_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pak(path: &Path, mount_point: &str) {
+    let file = File::create(path).unwrap();
+    let mut pak = PakWriter::new(
+        BufWriter::new(file),
+        PakVersion::FnameBasedCompressionMethod,
+    );
+    pak.mount_point = mount_point.to_owned();
+    pak.write_entry(&"Maps/Test.umap".to_string(), &vec![1, 2, 3], false)
+        .unwrap();
+    pak.finish_write().unwrap();
+}
+
+fn extract(pakfile: &Path, outdir: &Path, raw_names: bool) {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_unreal_pak_cli"));
+    command.arg("extract").arg(pakfile).arg(outdir);
+    if raw_names {
+        command.arg("--raw-names");
+    }
+
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn extract_resolves_mount_point() {
+    let dir = test_dir("mount_point");
+    let pakfile = dir.join("Test_P.pak");
+    write_pak(&pakfile, "../../../Game/Content/");
+
+    let outdir = dir.join("out");
+    extract(&pakfile, &outdir, false);
+    assert_eq!(
+        fs::read(outdir.join("Game/Content/Maps/Test.umap")).unwrap(),
+        [1, 2, 3]
+    );
+    assert!(!outdir.join("Maps").exists());
+
+    let raw_outdir = dir.join("raw");
+    extract(&pakfile, &raw_outdir, true);
+    assert_eq!(
+        fs::read(raw_outdir.join("Maps/Test.umap")).unwrap(),
+        [1, 2, 3]
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn extract_default_mount_point() {
+    let dir = test_dir("default_mount_point");
+    let pakfile = dir.join("Test_P.pak");
+    write_pak(&pakfile, "../../../");
+
+    let outdir = dir.join("out");
+    extract(&pakfile, &outdir, false);
+    assert_eq!(fs::read(outdir.join("Maps/Test.umap")).unwrap(), [1, 2, 3]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}